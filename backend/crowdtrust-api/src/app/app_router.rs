@@ -12,7 +12,12 @@ use axum::{
     Router,
 };
 
-use super::{health, pledge, project_asset, reward, reward_asset};
+use super::{
+    follow, health, notification, pledge, project_asset, project_comment, project_invitation,
+    project_member, project_milestone, project_preview_token, project_revision, project_signup,
+    project_transfer, project_update, project_update_asset, reward, reward_asset, user_asset,
+    user_email_change, user_export, user_wallet,
+};
 
 pub fn app_router(context: &ApiContext) -> Router<ApiContext> {
     Router::new().nest("/api", api_router(context))
@@ -32,6 +37,56 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
                 .patch(user::update_user::update_user)
                 .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
         )
+        .route(
+            "/users/:user_id/actions/delete",
+            post(user::delete_user::delete_user)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/users/:user_id/email-changes",
+            post(user_email_change::create_user_email_change::create_user_email_change)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/users/:user_id/wallets",
+            get(user_wallet::list_user_wallets::list_user_wallets)
+                .post(user_wallet::link_user_wallet::link_user_wallet)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-wallets/:wallet_id/actions/unlink",
+            post(user_wallet::unlink_user_wallet::unlink_user_wallet)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-wallets/:wallet_id/actions/make-primary",
+            post(user_wallet::make_primary_user_wallet::make_primary_user_wallet)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/users/:user_id/exports",
+            post(user_export::create_user_export::create_user_export)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-exports/:export_id",
+            get(user_export::get_user_export::get_user_export)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-exports/:export_id/download",
+            get(user_export::download_user_export::download_user_export)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-exports/actions/build",
+            post(user_export::build_user_exports::build_user_exports)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin)),
+        )
+        .route(
+            "/users/:user_id/profile",
+            get(user::get_user_profile::get_user_profile),
+        )
         .route(
             "/users/registrations",
             post(user::register_user::register_user),
@@ -51,6 +106,14 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
             "/auth/confirm-email",
             post(auth::confirm_email::confirm_email),
         )
+        .route(
+            "/auth/email-changes/confirm",
+            post(user_email_change::confirm_user_email_change::confirm_user_email_change),
+        )
+        .route(
+            "/auth/email-changes/revert",
+            post(user_email_change::revert_user_email_change::revert_user_email_change),
+        )
         .route(
             "/auth/resend-confirm-email",
             post(auth::resend_confirm_email::resend_confirm_email)
@@ -69,6 +132,15 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
                 )),
             ),
         )
+        .route(
+            "/projects/by-slug/:slug",
+            get(
+                project::get_project_by_slug::get_project_by_slug.layer(from_fn_with_state(
+                    context.clone(),
+                    auth_admin_user_anonymous,
+                )),
+            ),
+        )
         .route(
             "/projects/:project_id",
             get(project::get_project::get_project.layer(from_fn_with_state(
@@ -80,6 +152,296 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
                     .layer(from_fn_with_state(context.clone(), auth_admin_user)),
             ),
         )
+        .route(
+            "/projects/:project_id/preview-tokens",
+            post(project_preview_token::create_project_preview_token::create_project_preview_token)
+                .get(
+                    project_preview_token::list_project_preview_tokens::list_project_preview_tokens,
+                )
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/revisions",
+            get(
+                project_revision::list_project_revisions::list_project_revisions.layer(
+                    from_fn_with_state(context.clone(), auth_admin_user_anonymous),
+                ),
+            ),
+        )
+        .route(
+            "/projects/:project_id/updates",
+            post(
+                project_update::create_project_update::create_project_update
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            )
+            .get(
+                project_update::list_project_updates::list_project_updates.layer(
+                    from_fn_with_state(context.clone(), auth_admin_user_anonymous),
+                ),
+            ),
+        )
+        .route(
+            "/project-updates/:update_id",
+            get(
+                project_update::get_project_update::get_project_update.layer(from_fn_with_state(
+                    context.clone(),
+                    auth_admin_user_anonymous,
+                )),
+            )
+            .patch(
+                project_update::update_project_update::update_project_update
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            )
+            .delete(
+                project_update::delete_project_update::delete_project_update
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            ),
+        )
+        .route(
+            "/user-assets",
+            post(user_asset::create_user_asset::create_user_asset)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-assets/:asset_id",
+            delete(user_asset::delete_user_asset::delete_user_asset)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/user-assets/:asset_id/actions/verify",
+            post(user_asset::verify_user_asset::verify_user_asset)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-update-assets",
+            post(project_update_asset::create_project_update_asset::create_project_update_asset)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-update-assets/:asset_id",
+            delete(project_update_asset::delete_project_update_asset::delete_project_update_asset)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-update-assets/:asset_id/actions/verify",
+            post(project_update_asset::verify_project_update_asset::verify_project_update_asset)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/comments",
+            post(
+                project_comment::create_project_comment::create_project_comment
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            )
+            .get(
+                project_comment::list_project_comments::list_project_comments.layer(
+                    from_fn_with_state(context.clone(), auth_admin_user_anonymous),
+                ),
+            ),
+        )
+        .route(
+            "/project-comments/:comment_id",
+            patch(project_comment::update_project_comment::update_project_comment)
+                .delete(project_comment::delete_project_comment::delete_project_comment)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-comments/:comment_id/reports",
+            post(project_comment::report_project_comment::report_project_comment)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-comments/:comment_id/actions/hide",
+            post(project_comment::hide_project_comment::hide_project_comment)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-comments/:comment_id/actions/restore",
+            post(project_comment::restore_project_comment::restore_project_comment)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/invitations",
+            post(project_invitation::create_project_invitation::create_project_invitation)
+                .get(project_invitation::list_project_invitations::list_project_invitations)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-invitations",
+            get(project_invitation::list_user_invitations::list_user_invitations)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-invitations/:invitation_id/actions/accept",
+            post(project_invitation::accept_project_invitation::accept_project_invitation)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-invitations/:invitation_id/actions/decline",
+            post(project_invitation::decline_project_invitation::decline_project_invitation)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-invitations/:invitation_id/actions/revoke",
+            post(project_invitation::revoke_project_invitation::revoke_project_invitation)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/members",
+            get(project_member::list_project_members::list_project_members)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/members/:user_id",
+            patch(project_member::update_project_member::update_project_member)
+                .delete(project_member::delete_project_member::delete_project_member)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/transfers",
+            post(project_transfer::create_project_transfer::create_project_transfer)
+                .get(project_transfer::list_project_transfers::list_project_transfers)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-transfers",
+            get(project_transfer::list_user_transfers::list_user_transfers)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-transfers/:transfer_id/actions/accept",
+            post(project_transfer::accept_project_transfer::accept_project_transfer)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-transfers/:transfer_id/actions/decline",
+            post(project_transfer::decline_project_transfer::decline_project_transfer)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-transfers/:transfer_id/actions/cancel",
+            post(project_transfer::cancel_project_transfer::cancel_project_transfer)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/actions/follow",
+            post(follow::follow_project::follow_project)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/actions/unfollow",
+            post(follow::unfollow_project::unfollow_project)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/users/:user_id/actions/follow",
+            post(follow::follow_creator::follow_creator)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/users/:user_id/actions/unfollow",
+            post(follow::unfollow_creator::unfollow_creator)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/follows",
+            get(follow::list_follows::list_follows)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/notifications",
+            get(notification::list_notifications::list_notifications)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/notifications/:notification_id/actions/read",
+            post(notification::read_notification::read_notification)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/notifications/actions/read-all",
+            post(notification::read_all_notifications::read_all_notifications)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/notifications/actions/send-ending-soon",
+            post(notification::send_ending_soon_notifications::send_ending_soon_notifications)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin)),
+        )
+        .route(
+            "/notification-preferences",
+            get(notification::get_notification_preferences::get_notification_preferences)
+                .patch(
+                    notification::update_notification_preferences::update_notification_preferences,
+                )
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/signups",
+            post(project_signup::create_project_signup::create_project_signup),
+        )
+        .route(
+            "/projects/:project_id/signups/counts",
+            get(project_signup::get_project_signup_counts::get_project_signup_counts)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/projects/:project_id/signups/export",
+            get(project_signup::export_project_signups::export_project_signups)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-signups/confirm",
+            post(project_signup::confirm_project_signup::confirm_project_signup),
+        )
+        .route(
+            "/users/actions/compute-reputation",
+            post(user::compute_user_reputations::compute_user_reputations)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin)),
+        )
+        .route(
+            "/projects/actions/evaluate-ended",
+            post(project::evaluate_ended_projects::evaluate_ended_projects)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin)),
+        )
+        .route(
+            "/projects/:project_id/milestones",
+            post(
+                project_milestone::create_project_milestone::create_project_milestone
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            )
+            .get(
+                project_milestone::list_project_milestones::list_project_milestones.layer(
+                    from_fn_with_state(context.clone(), auth_admin_user_anonymous),
+                ),
+            ),
+        )
+        .route(
+            "/project-milestones/:milestone_id",
+            patch(project_milestone::update_project_milestone::update_project_milestone)
+                .delete(project_milestone::delete_project_milestone::delete_project_milestone)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-milestones/:milestone_id/actions/open-voting",
+            post(project_milestone::open_milestone_voting::open_milestone_voting)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-milestones/:milestone_id/votes",
+            post(project_milestone::vote_project_milestone::vote_project_milestone)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
+        .route(
+            "/project-milestones/actions/resolve-ended",
+            post(project_milestone::resolve_project_milestones::resolve_project_milestones)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin)),
+        )
+        .route(
+            "/project-preview-tokens/:preview_token_id/actions/revoke",
+            post(project_preview_token::revoke_project_preview_token::revoke_project_preview_token)
+                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+        )
         .route(
             "/projects/:project_id/actions/back",
             post(
@@ -92,6 +454,17 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
             post(
                 reward::create_reward::create_reward
                     .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            )
+            .get(reward::list_rewards::list_rewards.layer(from_fn_with_state(
+                context.clone(),
+                auth_admin_user_anonymous,
+            ))),
+        )
+        .route(
+            "/projects/:project_id/pledge-items",
+            patch(
+                pledge::update_pledge_items::update_pledge_items
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
             ),
         )
         .route(
@@ -105,6 +478,13 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
                 .get(pledge::get_pledge::get_pledge)
                 .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
         )
+        .route(
+            "/pledge-items/:pledge_item_id/actions/confirm",
+            post(
+                pledge::confirm_pledge_item::confirm_pledge_item
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            ),
+        )
         .route(
             "/rewards/:reward_id",
             patch(reward::update_reward::update_reward)
@@ -128,9 +508,16 @@ pub fn api_router(context: &ApiContext) -> Router<ApiContext> {
         )
         .route(
             "/project-assets",
-            post(project_asset::create_project_asset::create_project_asset)
-                .get(project_asset::list_project_assets::list_project_assets)
-                .route_layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            post(
+                project_asset::create_project_asset::create_project_asset
+                    .layer(from_fn_with_state(context.clone(), auth_admin_user)),
+            )
+            .get(
+                project_asset::list_project_assets::list_project_assets.layer(from_fn_with_state(
+                    context.clone(),
+                    auth_admin_user_anonymous,
+                )),
+            ),
         )
         .route(
             "/project-assets/:asset_id",
@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::not_found_or_internal};
+
+pub async fn follow_creator(
+    Path(creator_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+    if user_id == creator_id {
+        return Err(ApiError::bad_request().message("Cannot follow yourself"));
+    }
+    context
+        .repo
+        .user
+        .get_user_by_id(creator_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    context
+        .repo
+        .follow
+        .follow_creator(user_id, creator_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to follow creator: {}", e))
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::project::helpers::get_visible_project};
+
+pub async fn follow_project(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+    get_visible_project(&context, &request_user, project_id, None).await?;
+
+    context
+        .repo
+        .follow
+        .follow_project(user_id, project_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to follow project: {}", e))
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
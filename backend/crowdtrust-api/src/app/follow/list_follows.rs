@@ -0,0 +1,27 @@
+use axum::{extract::State, Extension, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::follow::follows_view_model::{to_api_response, FollowsViewModel},
+    shared::user::RequestUser,
+};
+
+use crate::api_context::ApiContext;
+
+// Projects and creators followed by the current user
+pub async fn list_follows(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<FollowsViewModel>, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let follows = context
+        .repo
+        .follow
+        .list_follows(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list follows: {}", e))
+        })?;
+
+    Ok(Json(to_api_response(follows)))
+}
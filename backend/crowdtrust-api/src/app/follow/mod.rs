@@ -0,0 +1,5 @@
+pub mod follow_creator;
+pub mod follow_project;
+pub mod list_follows;
+pub mod unfollow_creator;
+pub mod unfollow_project;
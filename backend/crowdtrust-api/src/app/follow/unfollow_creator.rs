@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+pub async fn unfollow_creator(
+    Path(creator_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    context
+        .repo
+        .follow
+        .unfollow_creator(user_id, creator_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to unfollow creator: {}", e))
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
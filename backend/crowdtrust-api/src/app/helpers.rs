@@ -1,7 +1,10 @@
 use std::str::FromStr;
 
 use bigdecimal::BigDecimal;
-use lib_api::{db::db_error::DbError, error::api_error::ApiError};
+use lib_api::{
+    db::{db_error::DbError, util::CursorPage},
+    error::api_error::ApiError,
+};
 use lib_types::{
     entity::user_entity::UserEntity,
     shared::{
@@ -10,6 +13,8 @@ use lib_types::{
     },
 };
 
+use uuid::Uuid;
+
 use crate::api_context::ApiContext;
 
 // Returns 404 not found if a DB entry does not exist
@@ -30,6 +35,11 @@ pub fn verify_admin_or_user(user: &RequestUser, user_id: String) -> Result<(), A
     return Ok(());
 }
 
+// Returns true if the requester is Admin, or the User that owns the target resource
+pub fn is_admin_or_user(user: &RequestUser, user_id: Uuid) -> bool {
+    user.user_type == UserType::Admin || user.user_id == Some(user_id)
+}
+
 // Verifies the request user is Admin
 pub fn verify_admin(user: &RequestUser) -> Result<(), ApiError> {
     if user.user_type != UserType::Admin {
@@ -72,6 +82,19 @@ pub async fn get_request_user(
         .map_err(not_found_or_internal)?)
 }
 
+// Parses list query cursor pagination options. None selects offset pagination
+pub fn to_cursor_page(
+    cursor: Option<String>,
+    limit: Option<i32>,
+    include_total: Option<bool>,
+) -> Result<Option<CursorPage>, ApiError> {
+    CursorPage::from_query(cursor, limit, include_total).map_err(|_| {
+        ApiError::bad_request()
+            .code(ApiErrorCode::InvalidCursor)
+            .message("Invalid cursor")
+    })
+}
+
 pub fn str_to_bigdecimal(val: &str, field: &str) -> Result<BigDecimal, ApiError> {
     BigDecimal::from_str(val).map_err(|_| {
         ApiError::bad_request()
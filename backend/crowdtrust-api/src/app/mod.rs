@@ -6,14 +6,30 @@ use axum::{
 
 pub mod app_router;
 pub mod auth;
+pub mod follow;
 pub mod health;
 pub mod helpers;
+pub mod notification;
 pub mod pledge;
 pub mod project;
 pub mod project_asset;
+pub mod project_comment;
+pub mod project_invitation;
+pub mod project_member;
+pub mod project_milestone;
+pub mod project_preview_token;
+pub mod project_revision;
+pub mod project_signup;
+pub mod project_transfer;
+pub mod project_update;
+pub mod project_update_asset;
 pub mod reward;
 pub mod reward_asset;
 pub mod user;
+pub mod user_asset;
+pub mod user_email_change;
+pub mod user_export;
+pub mod user_wallet;
 
 // Workaround for query string arrays
 // https://github.com/tokio-rs/axum/issues/434#issuecomment-954898159
@@ -0,0 +1,43 @@
+use axum::{extract::State, Extension, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::notification::notification_preferences_dto::{
+        to_api_response, NotificationPreferencesResponse,
+    },
+    entity::notification_entity::{NotificationPreferenceEntity, NOTIFICATION_KINDS},
+    shared::user::RequestUser,
+};
+
+use crate::api_context::ApiContext;
+
+// Lists a preference for every kind, with defaults for kinds the user hasn't set
+pub async fn get_notification_preferences(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<NotificationPreferencesResponse>, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let saved = context
+        .repo
+        .notification
+        .get_preferences(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error()
+                .message(format!("Failed to get notification preferences: {}", e))
+        })?;
+
+    let preferences = NOTIFICATION_KINDS
+        .iter()
+        .map(|kind| {
+            saved
+                .iter()
+                .find(|preference| preference.kind == *kind)
+                .cloned()
+                .unwrap_or_else(|| NotificationPreferenceEntity::default_for(*kind))
+        })
+        .map(to_api_response)
+        .collect();
+
+    Ok(Json(NotificationPreferencesResponse { preferences }))
+}
@@ -0,0 +1,35 @@
+use lib_types::entity::notification_entity::{NotificationData, NotificationKind};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    db::notification_repo::{NotificationAudience, NotificationCreateProps},
+};
+
+// Notifications are a side effect, so failures are logged and don't fail the request
+pub async fn notify_project(
+    context: &ApiContext,
+    kind: NotificationKind,
+    project_id: Uuid,
+    data: NotificationData,
+    audience: NotificationAudience,
+) {
+    let result = context
+        .repo
+        .notification
+        .create_notifications(NotificationCreateProps {
+            kind,
+            project_id,
+            data,
+            audience,
+        })
+        .await;
+    if let Err(e) = result {
+        error!(
+            err = e.to_string(),
+            kind = kind.to_string(),
+            "Failed to create notifications"
+        );
+    }
+}
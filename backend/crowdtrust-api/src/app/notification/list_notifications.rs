@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_types::{
+    dto::notification::{
+        list_notifications_dto::{ListNotificationsQuery, ListNotificationsResponse},
+        notification_view_model::to_api_response,
+    },
+    shared::user::RequestUser,
+};
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::helpers::to_cursor_page};
+
+// In-app notifications for the current user, newest first
+pub async fn list_notifications(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    Query(query): Query<ListNotificationsQuery>,
+) -> Result<Json<ListNotificationsResponse>, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+    check_bad_form(query.validate())?;
+    let page = to_cursor_page(query.cursor.clone(), query.limit, query.include_total)?;
+
+    let notifications = context
+        .repo
+        .notification
+        .list_notifications(user_id, query, page)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list notifications: {}", e))
+        })?;
+
+    Ok(Json(ListNotificationsResponse {
+        total: notifications.total,
+        next_cursor: notifications.next_cursor,
+        unread_count: notifications.unread_count,
+        results: notifications
+            .results
+            .into_iter()
+            .map(to_api_response)
+            .collect(),
+    }))
+}
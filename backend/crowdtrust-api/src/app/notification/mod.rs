@@ -0,0 +1,7 @@
+pub mod get_notification_preferences;
+pub mod helpers;
+pub mod list_notifications;
+pub mod read_all_notifications;
+pub mod read_notification;
+pub mod send_ending_soon_notifications;
+pub mod update_notification_preferences;
@@ -0,0 +1,23 @@
+use axum::{extract::State, http::StatusCode, Extension};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+
+use crate::api_context::ApiContext;
+
+pub async fn read_all_notifications(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    context
+        .repo
+        .notification
+        .mark_all_read(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to read notifications: {}", e))
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,27 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::not_found_or_internal};
+
+pub async fn read_notification(
+    Path(notification_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    context
+        .repo
+        .notification
+        .mark_read(user_id, notification_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,45 @@
+use axum::{extract::State, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::entity::notification_entity::{NotificationData, NotificationKind};
+use serde::Serialize;
+
+use crate::{api_context::ApiContext, db::notification_repo::NotificationAudience};
+
+use super::helpers::notify_project;
+
+// Projects are notified once, when they're within this many hours of ending
+const ENDING_SOON_HOURS: i64 = 48;
+
+#[derive(Serialize)]
+pub struct SendEndingSoonResponse {
+    pub projects: usize,
+}
+
+// Run periodically by a scheduled job
+pub async fn send_ending_soon_notifications(
+    State(context): State<ApiContext>,
+) -> Result<Json<SendEndingSoonResponse>, ApiError> {
+    let project_ids = context
+        .repo
+        .notification
+        .list_ending_soon_projects(ENDING_SOON_HOURS)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list projects: {}", e))
+        })?;
+
+    for project_id in project_ids.iter() {
+        notify_project(
+            &context,
+            NotificationKind::EndingSoon,
+            *project_id,
+            NotificationData::default(),
+            NotificationAudience::Followers,
+        )
+        .await;
+    }
+
+    Ok(Json(SendEndingSoonResponse {
+        projects: project_ids.len(),
+    }))
+}
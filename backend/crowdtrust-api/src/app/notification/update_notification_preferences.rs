@@ -0,0 +1,48 @@
+use axum::{extract::State, Extension, Json};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::notification::notification_preferences_dto::{
+        NotificationPreferencesResponse, UpdateNotificationPreferencesDto,
+    },
+    entity::notification_entity::NotificationPreferenceEntity,
+    shared::user::RequestUser,
+};
+use validator::Validate;
+
+use crate::api_context::ApiContext;
+
+use super::get_notification_preferences::get_notification_preferences;
+
+pub async fn update_notification_preferences(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UpdateNotificationPreferencesDto>,
+) -> Result<Json<NotificationPreferencesResponse>, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+    check_bad_form(dto.validate())?;
+
+    let preferences = dto
+        .preferences
+        .into_iter()
+        .map(|preference| NotificationPreferenceEntity {
+            kind: preference.kind,
+            in_app: preference.in_app,
+            email: preference.email,
+        })
+        .collect();
+
+    context
+        .repo
+        .notification
+        .set_preferences(user_id, preferences)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error()
+                .message(format!("Failed to update notification preferences: {}", e))
+        })?;
+
+    get_notification_preferences(State(context), Extension(request_user)).await
+}
@@ -0,0 +1,72 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::Utc;
+use lib_api::error::api_error::ApiError;
+use lib_api::util::json_extractor::CtJson;
+use lib_types::dto::pledge::confirm_pledge_item_dto::ConfirmPledgeItemDto;
+use lib_types::dto::pledge::pledge_view_model::{pledge_item_api_response, PledgeItemViewModel};
+use lib_types::shared::api_error::ApiErrorCode;
+use lib_types::shared::pledge::FulfillmentStatus;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+use crate::app::helpers::{not_found_or_internal, verify_admin_or_user};
+use crate::db::pledge_repo::PledgeItemFulfillmentProps;
+
+// Backer confirms receipt of a pledge item, or opens a dispute
+pub async fn confirm_pledge_item(
+    Path(pledge_item_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<ConfirmPledgeItemDto>,
+) -> Result<(StatusCode, Json<PledgeItemViewModel>), ApiError> {
+    let pledge_item = context
+        .repo
+        .pledge
+        .get_pledge_item_by_id(pledge_item_id)
+        .await
+        .map_err(not_found_or_internal)?;
+    let pledge = context
+        .repo
+        .pledge
+        .get_pledge_by_id(pledge_item.pledge_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    verify_admin_or_user(&request_user, pledge.user_id.to_string())?;
+
+    if pledge_item.fulfillment_status == FulfillmentStatus::Pending {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::FulfillmentStatus)
+            .message("Pledge item has not been shipped"));
+    }
+
+    let props = if dto.received {
+        PledgeItemFulfillmentProps {
+            fulfillment_status: FulfillmentStatus::Delivered,
+            tracking_number: pledge_item.tracking_number.clone(),
+            shipped_at: pledge_item.shipped_at,
+            delivered_at: Some(Utc::now()),
+        }
+    } else {
+        PledgeItemFulfillmentProps {
+            fulfillment_status: FulfillmentStatus::Disputed,
+            tracking_number: pledge_item.tracking_number.clone(),
+            shipped_at: pledge_item.shipped_at,
+            delivered_at: None,
+        }
+    };
+
+    let result = context
+        .repo
+        .pledge
+        .update_pledge_item_fulfillment(pledge_item_id, props)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to update pledge item: {}", e))
+        })?;
+
+    Ok((StatusCode::OK, Json(pledge_item_api_response(result))))
+}
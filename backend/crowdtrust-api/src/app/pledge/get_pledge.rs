@@ -14,6 +14,8 @@ use crate::{
     app::helpers::{not_found_or_internal, verify_admin_or_user},
 };
 
+use super::helpers::decrypt_shipping_address;
+
 pub async fn get_pledge(
     Path(id): Path<Uuid>,
     State(context): State<ApiContext>,
@@ -29,5 +31,7 @@ pub async fn get_pledge(
 
     verify_admin_or_user(&request_user, pledge.user_id.to_string())?;
 
-    Ok(Json(to_api_response_relations(pledge)))
+    let shipping_address = decrypt_shipping_address(&context, pledge.shipping_address.clone())?;
+
+    Ok(Json(to_api_response_relations(pledge, shipping_address)))
 }
@@ -0,0 +1,31 @@
+use lib_api::{
+    error::api_error::ApiError,
+    util::encryption::{decrypt_string, encrypt_string},
+};
+use lib_types::dto::pledge::shipping_address_dto::ShippingAddressDto;
+
+use crate::api_context::ApiContext;
+
+pub fn encrypt_shipping_address(
+    context: &ApiContext,
+    address: &ShippingAddressDto,
+) -> Result<String, ApiError> {
+    let serialized = serde_json::to_string(address).map_err(|e| {
+        ApiError::internal_error().message(format!("Failed to serialize address: {}", e))
+    })?;
+    encrypt_string(&context.config.shipping_encryption_key, &serialized)
+}
+
+pub fn decrypt_shipping_address(
+    context: &ApiContext,
+    address: Option<String>,
+) -> Result<Option<ShippingAddressDto>, ApiError> {
+    let Some(address) = address else {
+        return Ok(None);
+    };
+    let decrypted = decrypt_string(&context.config.shipping_encryption_key, &address)?;
+    let result = serde_json::from_str(&decrypted).map_err(|e| {
+        ApiError::internal_error().message(format!("Failed to deserialize address: {}", e))
+    })?;
+    Ok(Some(result))
+}
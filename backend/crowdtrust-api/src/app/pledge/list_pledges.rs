@@ -8,11 +8,21 @@ use lib_types::{
         list_pledges_dto::{ListPledgesQuery, ListPledgesResponse},
         pledge_view_model::{to_api_response_relations, GetPledgeViewModel},
     },
+    entity::project_member_entity::ProjectPermission,
     shared::user::{RequestUser, UserType},
 };
 use validator::Validate;
 
-use crate::{api_context::ApiContext, app::Qs};
+use crate::{
+    api_context::ApiContext,
+    app::{
+        helpers::to_cursor_page,
+        project::helpers::{has_project_permission, verify_project_exist},
+        Qs,
+    },
+};
+
+use super::helpers::decrypt_shipping_address;
 
 pub async fn list_pledges(
     State(context): State<ApiContext>,
@@ -20,19 +30,37 @@ pub async fn list_pledges(
     Extension(request_user): Extension<RequestUser>,
 ) -> Result<Json<ListPledgesResponse>, ApiError> {
     check_bad_form(query.validate())?;
+    let page = to_cursor_page(query.cursor.clone(), query.limit, query.include_total)?;
 
-    // User must filter by their own ID
+    // User must filter by their own ID, or by a project they own or fulfill
     if request_user.user_type == UserType::User {
-        if query.user_id.is_none()
-            || option_string_to_uuid(query.user_id.clone()) != request_user.user_id
+        let own_user = query.user_id.is_some()
+            && option_string_to_uuid(query.user_id.clone()) == request_user.user_id;
+        let own_project = if let Some(project_id) = option_string_to_uuid(query.project_id.clone())
         {
-            return Err(ApiError::forbidden().message("User must filter by own ID"));
+            let project = verify_project_exist(&context, project_id).await?;
+            has_project_permission(
+                &context,
+                &request_user,
+                project.id,
+                project.user_id,
+                ProjectPermission::Fulfill,
+            )
+            .await?
+        } else {
+            false
+        };
+        if !own_user && !own_project {
+            return Err(ApiError::forbidden().message("User must filter by own ID or project"));
         }
     }
 
     let validated_query = ListPledgesQuery {
         from: query.from,
         to: query.to,
+        cursor: query.cursor,
+        limit: query.limit,
+        include_total: query.include_total,
         user_id: query.user_id,
         project_id: query.project_id,
         column: query.column,
@@ -42,20 +70,25 @@ pub async fn list_pledges(
     let pledges = context
         .repo
         .pledge
-        .list_pledges(validated_query)
+        .list_pledges(validated_query, page)
         .await
         .map_err(|e| {
             ApiError::internal_error().message(format!("Failed to list pledges: {}", e))
         })?;
 
-    let view_models: Vec<GetPledgeViewModel> = pledges
+    let view_models = pledges
         .results
         .into_iter()
-        .map(|pledges| to_api_response_relations(pledges))
-        .collect();
+        .map(|pledge| {
+            let shipping_address =
+                decrypt_shipping_address(&context, pledge.shipping_address.clone())?;
+            Ok(to_api_response_relations(pledge, shipping_address))
+        })
+        .collect::<Result<Vec<GetPledgeViewModel>, ApiError>>()?;
 
     Ok(Json(ListPledgesResponse {
         total: pledges.total,
+        next_cursor: pledges.next_cursor,
         results: view_models,
     }))
 }
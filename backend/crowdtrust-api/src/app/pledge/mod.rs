@@ -1,3 +1,6 @@
+pub mod confirm_pledge_item;
 pub mod get_pledge;
+pub mod helpers;
 pub mod list_pledges;
 pub mod update_pledge;
+pub mod update_pledge_items;
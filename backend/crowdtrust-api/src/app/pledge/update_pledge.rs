@@ -9,6 +9,7 @@ use lib_api::util::json_extractor::CtJson;
 use lib_types::dto::pledge::pledge_view_model::{to_api_response, PledgeViewModel};
 use lib_types::dto::pledge::update_pledge_dto::UpdatePledgeDto;
 use lib_types::shared::api_error::ApiErrorCode;
+use lib_types::shared::pledge::FulfillmentStatus;
 use lib_types::shared::user::RequestUser;
 use uuid::Uuid;
 use validator::Validate;
@@ -18,6 +19,8 @@ use crate::api_context::ApiContext;
 use crate::app::helpers::verify_admin_or_user;
 use crate::db::pledge_repo::PledgeUpdateProps;
 
+use super::helpers::encrypt_shipping_address;
+
 pub async fn update_pledge(
     Path(pledge_id): Path<Uuid>,
     State(context): State<ApiContext>,
@@ -39,10 +42,56 @@ pub async fn update_pledge(
     // Verify request
     verify_admin_or_user(&request_user, pledge_to_be_updated.user_id.to_string())?;
 
+    let shipping_address = if let Some(address) = dto.shipping_address {
+        // Address is locked once any item has been shipped
+        let pledge = context
+            .repo
+            .pledge
+            .get_pledge_relations_by_id(pledge_id)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to get pledge: {}", e))
+            })?;
+        if pledge
+            .pledge_items
+            .iter()
+            .any(|item| item.fulfillment_status != FulfillmentStatus::Pending)
+        {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::ShippingLocked)
+                .message("Shipping address cannot change after fulfillment has started"));
+        }
+        Some(encrypt_shipping_address(&context, &address)?)
+    } else {
+        None
+    };
+
+    // On-chain backing is accepted from any wallet linked to the backer
+    if let Some(eth_address) = &dto.eth_address {
+        let linked = context
+            .repo
+            .user_wallet
+            .is_user_wallet(pledge_to_be_updated.user_id, eth_address.clone())
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to check wallet: {}", e))
+            })?;
+        if !linked {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::UnknownWallet)
+                .message(format!(
+                    "Eth address {} is not linked to the backer",
+                    eth_address
+                )));
+        }
+    }
+
     let props = PledgeUpdateProps {
         comment: dto.comment,
+        shipping_address,
         blockchain_status: dto.blockchain_status,
         transaction_hash: dto.transaction_hash,
+        eth_address: dto.eth_address,
     };
 
     // Update pledge
@@ -0,0 +1,160 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::Utc;
+use lib_api::db::util::commit_or_rollback;
+use lib_api::error::api_error::ApiError;
+use lib_api::error::helpers::check_bad_form;
+use lib_api::util::conversion::str_to_uuid;
+use lib_api::util::json_extractor::CtJson;
+use lib_types::dto::pledge::pledge_view_model::pledge_item_api_response;
+use lib_types::dto::pledge::update_pledge_items_dto::{
+    UpdatePledgeItemsDto, UpdatePledgeItemsResponse,
+};
+use lib_types::entity::notification_entity::{NotificationData, NotificationKind};
+use lib_types::entity::project_member_entity::ProjectPermission;
+use lib_types::shared::api_error::ApiErrorCode;
+use lib_types::shared::pledge::FulfillmentStatus;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api_context::ApiContext;
+use crate::app::notification::helpers::notify_project;
+use crate::app::project::helpers::{verify_project_exist_relations, verify_project_permission};
+use crate::db::notification_repo::NotificationAudience;
+use crate::db::pledge_repo::PledgeItemFulfillmentProps;
+
+// Bulk update of pledge item fulfillment, used by project creators
+pub async fn update_pledge_items(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UpdatePledgeItemsDto>,
+) -> Result<(StatusCode, Json<UpdatePledgeItemsResponse>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let project = verify_project_exist_relations(&context, project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Fulfill,
+    )
+    .await?;
+
+    let ids = dto
+        .items
+        .iter()
+        .map(|item| str_to_uuid(&item.id))
+        .collect::<Result<Vec<Uuid>, ApiError>>()?;
+
+    let pledge_items = context
+        .repo
+        .pledge
+        .get_project_pledge_items(project_id, ids)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get pledge items: {}", e))
+        })?;
+
+    let mut updates: Vec<(Uuid, PledgeItemFulfillmentProps)> = vec![];
+    // Pledges with items that are newly shipped
+    let mut shipped_pledge_ids: Vec<Uuid> = vec![];
+    for item in dto.items.into_iter() {
+        let pledge_item = pledge_items
+            .iter()
+            .find(|p| p.id.to_string() == item.id)
+            .ok_or(ApiError::not_found().message(format!("Pledge item {} not found", item.id)))?;
+
+        // Disputes can only be opened by backers, and delivered or disputed items are theirs
+        let current_status = pledge_item.fulfillment_status;
+        if !current_status.creator_can_change_to(item.fulfillment_status) {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::FulfillmentStatus)
+                .message(format!(
+                    "Cannot change pledge item from {} to {}",
+                    current_status, item.fulfillment_status
+                )));
+        }
+        let shipping_required = project
+            .rewards
+            .iter()
+            .find(|r| r.id == pledge_item.reward_id)
+            .map(|r| r.shipping_required)
+            .unwrap_or(false);
+
+        if item.fulfillment_status == FulfillmentStatus::Shipped
+            && shipping_required
+            && item.tracking_number.is_none()
+            && pledge_item.tracking_number.is_none()
+        {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::ShippingRequired)
+                .message("Tracking number required for shipped items"));
+        }
+        if item.fulfillment_status == FulfillmentStatus::Shipped
+            && pledge_item.fulfillment_status != FulfillmentStatus::Shipped
+            && !shipped_pledge_ids.contains(&pledge_item.pledge_id)
+        {
+            shipped_pledge_ids.push(pledge_item.pledge_id);
+        }
+        let now = Utc::now();
+        // Rolling back to Pending clears shipping. `shipped_at` is only set when first shipped
+        let props = match item.fulfillment_status {
+            FulfillmentStatus::Pending => PledgeItemFulfillmentProps {
+                fulfillment_status: FulfillmentStatus::Pending,
+                tracking_number: None,
+                shipped_at: None,
+                delivered_at: None,
+            },
+            status => PledgeItemFulfillmentProps {
+                fulfillment_status: status,
+                tracking_number: item.tracking_number.or(pledge_item.tracking_number.clone()),
+                shipped_at: match pledge_item.shipped_at {
+                    Some(shipped_at) => Some(shipped_at),
+                    None if status == FulfillmentStatus::Shipped => Some(now),
+                    None => None,
+                },
+                delivered_at: if status == FulfillmentStatus::Delivered {
+                    Some(now)
+                } else {
+                    None
+                },
+            },
+        };
+        updates.push((pledge_item.id, props));
+    }
+
+    let mut tx = context.repo.start_transaction().await?;
+    let mut results = vec![];
+    for (id, props) in updates.into_iter() {
+        let result = context
+            .repo
+            .pledge
+            .update_pledge_item_fulfillment_tx(&mut tx, id, props)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to update pledge item: {}", e))
+            })?;
+        results.push(pledge_item_api_response(result));
+    }
+    commit_or_rollback(tx, Ok(())).await?;
+
+    for pledge_id in shipped_pledge_ids.into_iter() {
+        notify_project(
+            &context,
+            NotificationKind::RewardShipped,
+            project_id,
+            NotificationData {
+                pledge_id: Some(pledge_id),
+                ..Default::default()
+            },
+            NotificationAudience::PledgeBacker(pledge_id),
+        )
+        .await;
+    }
+
+    Ok((StatusCode::OK, Json(UpdatePledgeItemsResponse { results })))
+}
@@ -3,6 +3,7 @@ use axum::http::StatusCode;
 use axum::{Extension, Json};
 use axum_macros::debug_handler;
 use bigdecimal::BigDecimal;
+use chrono::Utc;
 use lib_api::db::db_error::DbError;
 use lib_api::db::util::commit_or_rollback;
 use lib_api::error::api_error::ApiError;
@@ -10,16 +11,22 @@ use lib_api::error::helpers::check_bad_form;
 use lib_api::util::conversion::str_to_uuid;
 use lib_api::util::json_extractor::CtJson;
 use lib_types::dto::project::back_project_dto::{BackProjectDto, BackProjectResponse};
+use lib_types::dto::project::get_project_dto::serialize_big;
+use lib_types::entity::notification_entity::{NotificationData, NotificationKind};
 use lib_types::entity::pledge_entity::PledgeEntity;
-use lib_types::entity::reward_entity::RewardEntity;
+use lib_types::entity::reward_entity::{active_price_tier, RewardEntity};
 use lib_types::shared::api_error::ApiErrorCode;
-use lib_types::shared::project::{PaymentCurrency, ProjectStatus};
+use lib_types::shared::project::ProjectStatus;
+use lib_types::shared::terms::{PledgeTerms, ProjectTerms, RewardTerms};
 use lib_types::shared::user::RequestUser;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::api_context::ApiContext;
-use crate::app::helpers::get_request_user;
+use crate::app::helpers::{get_request_user, str_to_bigdecimal};
+use crate::app::notification::helpers::notify_project;
+use crate::app::pledge::helpers::encrypt_shipping_address;
+use crate::db::notification_repo::NotificationAudience;
 use crate::db::pledge_repo::{PledgeCreateProps, PledgeItemCreateProps};
 use crate::db::project_repo::ProjectUpdateProps;
 use crate::db::reward_repo::RewardUpdateProps;
@@ -32,17 +39,149 @@ fn to_api_response(result: PledgeEntity) -> Json<BackProjectResponse> {
         project_id: result.project_id,
         user_id: result.user_id,
         comment: result.comment,
+        total_amount: serialize_big(&result.total_amount),
+        paid_currency: result.paid_currency,
         created_at: result.created_at,
         updated_at: result.updated_at,
     });
 }
 
-fn reward_price(rewards: &Vec<RewardEntity>, reward_id: &str) -> Result<BigDecimal, ApiError> {
-    rewards
+// Price of a reward, or the selected variant if the reward has variants
+// The first active price tier overrides the reward price, unless the variant sets its own
+// A tier is only active if the whole quantity fits within its cap, otherwise the next tier applies
+// Returns the price and applied price tier
+fn reward_price(
+    rewards: &[RewardEntity],
+    reward_id: &str,
+    variant_id: Option<Uuid>,
+    quantity: i32,
+) -> Result<(BigDecimal, Option<Uuid>), ApiError> {
+    let reward = rewards
         .iter()
-        .find(|r| &r.id.to_string() == reward_id)
-        .and_then(|r| Some(r.price.clone()))
-        .ok_or(ApiError::bad_request().code(ApiErrorCode::UnknownReward))
+        .find(|r| r.id.to_string() == reward_id)
+        .ok_or(ApiError::bad_request().code(ApiErrorCode::UnknownReward))?;
+    let variant_err = || {
+        ApiError::bad_request()
+            .code(ApiErrorCode::RewardVariant)
+            .message("Invalid reward variant")
+    };
+    if reward.variants.is_empty() && variant_id.is_some() {
+        return Err(variant_err());
+    }
+    if !reward.variants.is_empty() {
+        let variant_id = variant_id.ok_or(variant_err())?;
+        let variant = reward
+            .variants
+            .iter()
+            .find(|v| v.id == variant_id)
+            .ok_or(variant_err())?;
+        if let Some(price) = &variant.price {
+            return Ok((price.clone(), None));
+        }
+    }
+    Ok(
+        match active_price_tier(&reward.price_tiers, Utc::now().timestamp(), quantity) {
+            Some(tier) => (tier.price.clone(), Some(tier.id)),
+            None => (reward.price.clone(), None),
+        },
+    )
+}
+
+// Counts a pledge line against its price tier, so later lines for the same reward
+// fall back to the next tier once the cap is reached
+fn claim_price_tier(rewards: &mut [RewardEntity], reward_id: Uuid, tier_id: Uuid, quantity: i32) {
+    if let Some(tier) = rewards
+        .iter_mut()
+        .filter(|r| r.id == reward_id)
+        .flat_map(|r| r.price_tiers.iter_mut())
+        .find(|t| t.id == tier_id)
+    {
+        tier.backer_count += quantity;
+    }
+}
+
+// Verifies pledged rewards are visible, and have enough backer slots left
+fn verify_rewards_available(
+    rewards: &[RewardEntity],
+    pledge_items: &[PledgeItemCreateProps],
+) -> Result<(), ApiError> {
+    for reward in rewards.iter() {
+        let quantity: i32 = pledge_items
+            .iter()
+            .filter(|item| item.reward_id == reward.id)
+            .map(|item| item.quantity)
+            .sum();
+        if quantity == 0 {
+            continue;
+        }
+        if !reward.visible {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::UnknownReward)
+                .message("Reward is not available"));
+        }
+        if reward.backer_count + quantity > reward.backer_limit {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::RewardSoldOut)
+                .message(format!("Reward {} is sold out", reward.name)));
+        }
+    }
+    Ok(())
+}
+
+// Add-ons may only be pledged alongside their parent reward
+fn verify_addons(
+    rewards: &[RewardEntity],
+    pledge_items: &[PledgeItemCreateProps],
+) -> Result<(), ApiError> {
+    for item in pledge_items.iter() {
+        let parent_reward_id = rewards
+            .iter()
+            .find(|r| r.id == item.reward_id)
+            .and_then(|r| r.parent_reward_id);
+        if let Some(parent_reward_id) = parent_reward_id {
+            if !pledge_items.iter().any(|i| i.reward_id == parent_reward_id) {
+                return Err(ApiError::bad_request()
+                    .code(ApiErrorCode::RewardAddon)
+                    .message("Add-on requires its parent reward"));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Per-unit shipping cost for a reward, based on the backer's country
+// Falls back to the `*` region, if the country isn't listed
+fn reward_shipping(
+    rewards: &[RewardEntity],
+    reward_id: &str,
+    country: Option<&str>,
+) -> Result<BigDecimal, ApiError> {
+    let reward = rewards
+        .iter()
+        .find(|r| r.id.to_string() == reward_id)
+        .ok_or(ApiError::bad_request().code(ApiErrorCode::UnknownReward))?;
+    if !reward.shipping_required {
+        return Ok(BigDecimal::from(0));
+    }
+    let country = country.ok_or(
+        ApiError::bad_request()
+            .code(ApiErrorCode::ShippingRequired)
+            .message("Shipping address required for reward"),
+    )?;
+    if reward.shipping_costs.is_empty() {
+        return Ok(BigDecimal::from(0));
+    }
+    reward
+        .shipping_costs
+        .iter()
+        .find(|c| c.region == country)
+        .or_else(|| reward.shipping_costs.iter().find(|c| c.region == "*"))
+        .map(|c| c.cost.clone())
+        .ok_or(
+            ApiError::bad_request()
+                .code(ApiErrorCode::ShippingRegion)
+                .message("Reward does not ship to this country"),
+        )
 }
 
 fn err_fail(e: DbError) -> ApiError {
@@ -67,28 +206,109 @@ pub async fn back_project(
             .code(ApiErrorCode::ProjectInactive)
             .message("Cannot back inactive project"));
     }
+    // Pledges close at the end time, before the funding outcome is recorded
+    if project.start_time + project.duration <= Utc::now().timestamp() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::ProjectInactive)
+            .message("Cannot back ended project"));
+    }
+    let country = dto.shipping_address.as_ref().map(|a| a.country.as_str());
+    let reward_ids = dto
+        .rewards
+        .iter()
+        .map(|reward| str_to_uuid(&reward.reward_id))
+        .collect::<Result<Vec<Uuid>, ApiError>>()?;
+
+    let mut tx = context.repo.start_transaction().await?;
+
+    // Availability and counters are checked against the locked rewards
+    let mut rewards = context
+        .repo
+        .reward
+        .lock_project_rewards_tx(&mut tx, project_id, reward_ids)
+        .await
+        .map_err(err_fail)?;
+
     let mut pledge_items: Vec<PledgeItemCreateProps> = vec![];
-    for reward in dto.rewards.into_iter() {
+    for reward in dto.rewards.iter() {
+        let variant_id = if let Some(variant_id) = &reward.variant_id {
+            Some(str_to_uuid(variant_id)?)
+        } else {
+            None
+        };
+        let (paid_price, price_tier_id) =
+            reward_price(&rewards, &reward.reward_id, variant_id, reward.quantity)?;
+        let reward_id = str_to_uuid(&reward.reward_id)?;
+        if let Some(price_tier_id) = price_tier_id {
+            claim_price_tier(&mut rewards, reward_id, price_tier_id, reward.quantity);
+        }
         pledge_items.push(PledgeItemCreateProps {
-            reward_id: str_to_uuid(&reward.reward_id)?,
+            reward_id,
+            variant_id,
+            price_tier_id,
             quantity: reward.quantity,
-            paid_price: reward_price(&project.rewards, &reward.reward_id)?,
-            paid_currency: PaymentCurrency::Ethereum,
+            paid_price,
+            paid_currency: project.base_currency,
+            paid_shipping: reward_shipping(&rewards, &reward.reward_id, country)?,
         })
     }
+    verify_rewards_available(&rewards, &pledge_items)?;
+    verify_addons(&rewards, &pledge_items)?;
+
+    let extra_amount = if let Some(extra_amount) = &dto.extra_amount {
+        str_to_bigdecimal(extra_amount, "extra_amount")?
+    } else {
+        BigDecimal::from(0)
+    };
+    if pledge_items.is_empty() && extra_amount == BigDecimal::from(0) {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::PledgeEmpty)
+            .message("Pledge requires a reward or an amount"));
+    }
+
     let pledged: BigDecimal = pledge_items
         .iter()
-        .map(|item| item.paid_price.clone() * item.quantity)
-        .sum();
+        .map(|item| (item.paid_price.clone() + item.paid_shipping.clone()) * item.quantity)
+        .sum::<BigDecimal>()
+        + extra_amount.clone();
+
+    if pledged < project.base_currency.min_pledge_amount() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::PledgeMinimum)
+            .message(format!(
+                "Pledge must be at least {} {}",
+                project.base_currency.min_pledge_amount(),
+                project.base_currency
+            )));
+    }
+
+    let shipping_address = if let Some(address) = &dto.shipping_address {
+        Some(encrypt_shipping_address(&context, address)?)
+    } else {
+        None
+    };
+
+    // Snapshot the terms the backer pledged against
+    let terms = PledgeTerms {
+        project: ProjectTerms::from(&project),
+        rewards: rewards
+            .iter()
+            .filter(|r| pledge_items.iter().any(|item| item.reward_id == r.id))
+            .map(RewardTerms::from)
+            .collect(),
+    };
 
     let props = PledgeCreateProps {
         user_id: user.id,
         project_id: project.id.clone(),
+        shipping_address,
+        extra_amount,
+        total_amount: pledged.clone(),
+        paid_currency: project.base_currency,
         pledge_items: pledge_items.clone(),
+        terms,
     };
 
-    let mut tx = context.repo.start_transaction().await?;
-
     let pledge_result = context
         .repo
         .pledge
@@ -96,7 +316,8 @@ pub async fn back_project(
         .await
         .map_err(err_fail)?;
 
-    context
+    let goal_reached = project.total_pledged >= project.funding_goal;
+    let project_result = context
         .repo
         .project
         .update_project_tx(
@@ -107,29 +328,63 @@ pub async fn back_project(
         .await
         .map_err(err_fail)?;
 
-    for pledge_item in pledge_items.into_iter() {
-        let count = project
-            .rewards
+    // Update reward and variant backer counts
+    for reward in rewards.iter() {
+        let items: Vec<&PledgeItemCreateProps> = pledge_items
             .iter()
-            .find(|r| r.id == pledge_item.reward_id)
-            .ok_or(
-                ApiError::bad_request()
-                    .code(ApiErrorCode::UnknownReward)
-                    .message("Pledge reward not found"),
-            )?
-            .backer_count;
+            .filter(|item| item.reward_id == reward.id)
+            .collect();
+        if items.is_empty() {
+            continue;
+        }
+        let quantity: i32 = items.iter().map(|item| item.quantity).sum();
+        let variants = if reward.variants.is_empty() {
+            None
+        } else {
+            let mut variants = reward.variants.clone();
+            for variant in variants.iter_mut() {
+                variant.backer_count += items
+                    .iter()
+                    .filter(|item| item.variant_id == Some(variant.id))
+                    .map(|item| item.quantity)
+                    .sum::<i32>();
+                if variant.backer_count > variant.backer_limit {
+                    return Err(ApiError::bad_request()
+                        .code(ApiErrorCode::RewardSoldOut)
+                        .message(format!("Reward variant {} is sold out", variant.name)));
+                }
+            }
+            Some(variants)
+        };
+        // Tier counts were claimed while pricing the pledge items
+        let price_tiers = if reward.price_tiers.is_empty() {
+            None
+        } else {
+            Some(reward.price_tiers.clone())
+        };
+        let props =
+            RewardUpdateProps::backed(reward.backer_count + quantity, variants, price_tiers);
         context
             .repo
             .reward
-            .update_reward(
-                pledge_item.reward_id,
-                RewardUpdateProps::backer_count(count + pledge_item.quantity),
-            )
+            .update_reward_tx(&mut tx, reward.id, props)
             .await
             .map_err(err_fail)?;
     }
 
     commit_or_rollback(tx, Ok(())).await?;
 
+    // Notify once, when this pledge crosses the funding goal
+    if !goal_reached && project_result.total_pledged >= project_result.funding_goal {
+        notify_project(
+            &context,
+            NotificationKind::GoalReached,
+            project_id,
+            NotificationData::default(),
+            NotificationAudience::FollowersAndBackers,
+        )
+        .await;
+    }
+
     Ok((StatusCode::CREATED, to_api_response(pledge_result)))
 }
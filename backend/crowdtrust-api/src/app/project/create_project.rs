@@ -11,13 +11,14 @@ use lib_types::dto::project::create_project_dto::{CreateProjectDto, CreateProjec
 use lib_types::entity::project_entity::ProjectEntity;
 use lib_types::shared::api_error::ApiErrorCode;
 use lib_types::shared::project::{
-    BlockchainStatus, PaymentCurrency, ProjectStatus, ACTIVE_PROJECT_CONTRACT,
+    BlockchainStatus, FundingModel, PaymentCurrency, ProjectStatus, ACTIVE_PROJECT_CONTRACT,
 };
 use lib_types::shared::user::RequestUser;
 use validator::Validate;
 
 use crate::api_context::ApiContext;
 use crate::app::helpers::{get_request_user, str_to_bigdecimal};
+use crate::app::project::helpers::available_slug;
 use crate::db::project_repo::ProjectCreateProps;
 
 fn to_api_response(result: ProjectEntity) -> Json<CreateProjectResponse> {
@@ -40,15 +41,19 @@ pub async fn create_project(
             .message("Start time must be after current time"));
     }
 
+    let slug = available_slug(&context, &dto.name, None).await?;
+
     let props = ProjectCreateProps {
         user_id: user.id,
         name: dto.name,
+        slug,
         description: dto.description,
         blurb: dto.blurb,
         contract_address: ACTIVE_PROJECT_CONTRACT.into(),
         payment_address: user.eth_address,
         category: dto.category,
         funding_goal: str_to_bigdecimal(&dto.funding_goal, "funding_goal")?,
+        funding_model: dto.funding_model.unwrap_or(FundingModel::AllOrNothing),
         start_time: dto.start_time,
         duration: dto.duration,
         total_pledged: str_to_bigdecimal("0", "total_pledged")?,
@@ -63,9 +68,9 @@ pub async fn create_project(
         .create_project(props)
         .await
         .map_err(|e| match e {
-            DbError::Unique(_) => ApiError::bad_request()
+            DbError::Unique(field) => ApiError::bad_request()
                 .code(ApiErrorCode::ProjectExists)
-                .message("Project with name already exists"),
+                .message(format!("Project with {} already exists", field)),
             _ => ApiError::internal_error().message(format!("Failed to create project: {}", e)),
         })?;
 
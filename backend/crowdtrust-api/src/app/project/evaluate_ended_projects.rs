@@ -0,0 +1,80 @@
+use axum::{extract::State, Json};
+use lib_api::{db::db_error::DbError, error::api_error::ApiError};
+use lib_types::{
+    dto::project::evaluate_ended_projects_dto::EvaluateEndedProjectsResponse,
+    shared::project::{FundingModel, FundingOutcome},
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, db::app_repo::start_transaction};
+
+// Completes the project with its funding outcome, and makes pledges refundable if needed.
+// The outcome is decided under the project lock, so backing can't change the total meanwhile
+async fn set_funding_outcome(
+    context: &ApiContext,
+    project_id: Uuid,
+) -> Result<FundingOutcome, DbError> {
+    let mut tx = start_transaction(&context.repo.db).await?;
+    let project = context
+        .repo
+        .project
+        .lock_active_project_tx(&mut tx, project_id)
+        .await?;
+    let outcome = if project.total_pledged >= project.funding_goal {
+        FundingOutcome::Funded
+    } else {
+        FundingOutcome::Unfunded
+    };
+
+    context
+        .repo
+        .project
+        .set_funding_outcome_tx(&mut tx, project.id, outcome)
+        .await?;
+    if outcome == FundingOutcome::Unfunded && project.funding_model == FundingModel::AllOrNothing {
+        context
+            .repo
+            .pledge
+            .set_project_pledges_refundable_tx(&mut tx, project.id)
+            .await?;
+    }
+    tx.commit().await.map_err(DbError::SqlxError)?;
+    Ok(outcome)
+}
+
+// Completes Active projects past their end time, and records whether they met the funding goal.
+// Pledges to unfunded all-or-nothing projects become refundable. Run periodically by a scheduled job
+pub async fn evaluate_ended_projects(
+    State(context): State<ApiContext>,
+) -> Result<Json<EvaluateEndedProjectsResponse>, ApiError> {
+    let projects = context
+        .repo
+        .project
+        .list_ended_projects()
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list ended projects: {}", e))
+        })?;
+
+    let mut funded = vec![];
+    let mut unfunded = vec![];
+    for project in projects.into_iter() {
+        // One failing project doesn't stop the rest of the batch
+        match set_funding_outcome(&context, project.id).await {
+            Ok(outcome) => match outcome {
+                FundingOutcome::Funded => funded.push(project.id),
+                FundingOutcome::Unfunded => unfunded.push(project.id),
+            },
+            // Evaluated by a concurrent run
+            Err(DbError::EntityNotFound()) => {}
+            Err(e) => error!(
+                err = e.to_string(),
+                project_id = project.id.to_string(),
+                "Failed to set funding outcome"
+            ),
+        }
+    }
+
+    Ok(Json(EvaluateEndedProjectsResponse { funded, unfunded }))
+}
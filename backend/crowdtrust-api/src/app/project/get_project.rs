@@ -1,46 +1,29 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Extension, Json,
 };
-use lib_api::error::api_error::ApiError;
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
 use lib_types::{
-    dto::project::get_project_dto::{to_api_response, GetProjectResponse},
-    shared::{
-        project::ProjectStatus,
-        user::{RequestUser, UserType},
-    },
+    dto::project::get_project_dto::{to_api_response, GetProjectQuery, GetProjectResponse},
+    shared::user::RequestUser,
 };
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::{api_context::ApiContext, app::helpers::not_found_or_internal};
+use crate::api_context::ApiContext;
+
+use super::helpers::get_visible_project;
 
 pub async fn get_project(
     Path(id): Path<Uuid>,
     State(context): State<ApiContext>,
     Extension(request_user): Extension<RequestUser>,
+    Query(query): Query<GetProjectQuery>,
 ) -> Result<Json<GetProjectResponse>, ApiError> {
-    // Get project from DB
-    let project = context
-        .repo
-        .project
-        .get_project_relations_by_id(id, false)
-        .await
-        .map_err(not_found_or_internal)?;
+    check_bad_form(query.validate())?;
 
-    // Verify user or admin, if the project is not published
-    let visible = matches!(
-        project.status,
-        ProjectStatus::Active | ProjectStatus::Complete | ProjectStatus::Prelaunch
-    );
-    if !visible && request_user.user_type != UserType::Admin {
-        if let Some(request_user_id) = request_user.user_id {
-            if request_user_id != project.user_id {
-                return Err(ApiError::forbidden());
-            }
-        } else {
-            return Err(ApiError::forbidden());
-        }
-    }
+    let project =
+        get_visible_project(&context, &request_user, id, query.preview_token.as_deref()).await?;
 
     Ok(Json(to_api_response(project)))
 }
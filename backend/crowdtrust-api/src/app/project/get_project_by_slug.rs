@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{IntoResponse, Redirect, Response},
+    Extension, Json,
+};
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_types::{
+    dto::project::get_project_dto::{to_api_response, GetProjectQuery},
+    shared::user::RequestUser,
+};
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::helpers::not_found_or_internal};
+
+use super::helpers::get_visible_project;
+
+pub async fn get_project_by_slug(
+    Path(slug): Path<String>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    Query(query): Query<GetProjectQuery>,
+) -> Result<Response, ApiError> {
+    check_bad_form(query.validate())?;
+
+    let project = context
+        .repo
+        .project
+        .get_project_by_slug(&slug)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    // Visibility is checked first, so redirects don't reveal hidden projects
+    let project = get_visible_project(
+        &context,
+        &request_user,
+        project.id,
+        query.preview_token.as_deref(),
+    )
+    .await?;
+
+    // Previous slugs of renamed projects redirect to the current slug
+    if project.slug != slug {
+        let mut location = format!("/api/projects/by-slug/{}", project.slug);
+        if let Some(preview_token) = &query.preview_token {
+            let params = form_urlencoded::Serializer::new(String::new())
+                .append_pair("preview_token", preview_token)
+                .finish();
+            location = format!("{}?{}", location, params);
+        }
+        return Ok(Redirect::temporary(&location).into_response());
+    }
+
+    Ok(Json(to_api_response(project)).into_response())
+}
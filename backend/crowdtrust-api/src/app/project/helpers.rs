@@ -1,8 +1,142 @@
 use lib_api::error::api_error::ApiError;
-use lib_types::entity::project_entity::{ProjectEntity, ProjectEntityRelations};
+use lib_types::{
+    entity::{
+        project_entity::{ProjectEntity, ProjectEntityRelations},
+        project_member_entity::ProjectPermission,
+    },
+    shared::{project::ProjectStatus, user::RequestUser},
+};
 use uuid::Uuid;
 
-use crate::api_context::ApiContext;
+use crate::{
+    api_context::ApiContext,
+    app::{
+        helpers::{is_admin_or_user, not_found_or_internal},
+        project_preview_token::helpers::verify_preview_access,
+    },
+};
+
+const MAX_SLUG_LENGTH: usize = 60;
+
+// Converts a project name to a URL slug, e.g. "Hi-Fi Sphere!" -> "hi-fi-sphere"
+// Keep in sync with the slug backfill migration
+pub fn to_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_SLUG_LENGTH);
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "project".into()
+    } else {
+        slug.into()
+    }
+}
+
+// Returns a slug for the project name that isn't used by another project, now or previously
+pub async fn available_slug(
+    context: &ApiContext,
+    name: &str,
+    project_id: Option<Uuid>,
+) -> Result<String, ApiError> {
+    context
+        .repo
+        .project
+        .get_available_slug(&to_slug(name), project_id)
+        .await
+        .map_err(|e| ApiError::internal_error().message(format!("Failed to create slug: {}", e)))
+}
+
+// Returns true if the requester is Admin, the project owner, or a collaborator whose role
+// allows the permission
+pub async fn has_project_permission(
+    context: &ApiContext,
+    request_user: &RequestUser,
+    project_id: Uuid,
+    owner_id: Uuid,
+    permission: ProjectPermission,
+) -> Result<bool, ApiError> {
+    if is_admin_or_user(request_user, owner_id) {
+        return Ok(true);
+    }
+    let Some(user_id) = request_user.user_id else {
+        return Ok(false);
+    };
+    let member = context
+        .repo
+        .project_member
+        .get_member(project_id, user_id)
+        .await
+        .map_err(|e| ApiError::internal_error().message(e))?;
+    Ok(member.is_some_and(|m| m.role.allows(permission)))
+}
+
+// Verifies the requester is Admin, the project owner, or a collaborator whose role
+// allows the permission
+pub async fn verify_project_permission(
+    context: &ApiContext,
+    request_user: &RequestUser,
+    project_id: Uuid,
+    owner_id: Uuid,
+    permission: ProjectPermission,
+) -> Result<(), ApiError> {
+    request_user.user_id.ok_or(ApiError::unauthorized())?;
+    if !has_project_permission(context, request_user, project_id, owner_id, permission).await? {
+        return Err(ApiError::forbidden());
+    }
+    Ok(())
+}
+
+// Gets a project with relations, if it's published or the requester is the owner, a collaborator
+// or Admin.
+// A preview token grants access to an unpublished project, and counts a view
+pub async fn get_visible_project(
+    context: &ApiContext,
+    request_user: &RequestUser,
+    id: Uuid,
+    preview_token: Option<&str>,
+) -> Result<ProjectEntityRelations, ApiError> {
+    // Get project from DB
+    let mut project = context
+        .repo
+        .project
+        .get_project_relations_by_id(id, false)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    // Verify user or admin, if the project is not published
+    let visible = matches!(
+        project.status,
+        ProjectStatus::Active | ProjectStatus::Complete | ProjectStatus::Prelaunch
+    );
+    let can_view = has_project_permission(
+        context,
+        request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::View,
+    )
+    .await?;
+    if !visible && !can_view {
+        if let Some(preview_token) = preview_token {
+            verify_preview_access(context, preview_token, project.id, true).await?;
+        } else {
+            return Err(ApiError::forbidden());
+        }
+    }
+
+    // Hidden rewards are only returned to the owner and collaborators
+    if !can_view {
+        project.rewards.retain(|r| r.visible);
+    }
+
+    Ok(project)
+}
 
 pub async fn verify_project_exist(
     context: &ApiContext,
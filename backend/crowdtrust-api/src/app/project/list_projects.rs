@@ -1,5 +1,6 @@
 use axum::{extract::State, Extension, Json};
 use lib_api::{
+    db::util::to_prefix_tsquery,
     error::{api_error::ApiError, helpers::check_bad_form},
     util::conversion::str_opt_to_uuid,
 };
@@ -16,7 +17,10 @@ use lib_types::{
 };
 use validator::Validate;
 
-use crate::{api_context::ApiContext, app::Qs};
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::to_cursor_page, Qs},
+};
 
 pub async fn list_projects(
     State(context): State<ApiContext>,
@@ -24,6 +28,16 @@ pub async fn list_projects(
     Extension(request_user): Extension<RequestUser>,
 ) -> Result<Json<ListProjectsResponse>, ApiError> {
     check_bad_form(query.validate())?;
+    if query
+        .q
+        .as_deref()
+        .is_some_and(|q| to_prefix_tsquery(q).is_none())
+    {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::InvalidFormData)
+            .message("Search must contain a word"));
+    }
+    let page = to_cursor_page(query.cursor.clone(), query.limit, query.include_total)?;
 
     let default_statuses = vec![
         ProjectStatus::Prelaunch,
@@ -63,9 +77,18 @@ pub async fn list_projects(
     let validated_query = ListProjectsQuery {
         from: query.from,
         to: query.to,
+        cursor: query.cursor,
+        limit: query.limit,
+        include_total: query.include_total,
         statuses,
         categories: query.categories,
         user_id: query.user_id,
+        q: query.q,
+        funded: query.funded,
+        live: query.live,
+        min_percent_funded: query.min_percent_funded,
+        max_percent_funded: query.max_percent_funded,
+        trending_days: query.trending_days,
         column: query.column,
         direction: query.direction,
     };
@@ -73,7 +96,7 @@ pub async fn list_projects(
     let projects = context
         .repo
         .project
-        .list_projects(validated_query)
+        .list_projects(validated_query, page)
         .await
         .map_err(|e| {
             ApiError::internal_error().message(format!("Failed to list projects: {}", e))
@@ -87,6 +110,7 @@ pub async fn list_projects(
 
     Ok(Json(ListProjectsResponse {
         total: projects.total,
+        next_cursor: projects.next_cursor,
         results: view_models,
     }))
 }
@@ -1,6 +1,8 @@
 pub mod back_project;
 pub mod create_project;
+pub mod evaluate_ended_projects;
 pub mod get_project;
+pub mod get_project_by_slug;
 pub mod helpers;
 pub mod list_projects;
 pub mod update_project;
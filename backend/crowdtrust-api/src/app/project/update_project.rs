@@ -3,14 +3,18 @@ use axum::http::StatusCode;
 use axum::{Extension, Json};
 use chrono::Utc;
 use lib_api::db::db_error::DbError;
+use lib_api::db::util::commit_or_rollback;
 use lib_api::error::api_error::ApiError;
 
 use lib_api::error::helpers::check_bad_form;
 use lib_api::util::json_extractor::CtJson;
 use lib_types::dto::project::project_view_model::{to_api_response, ProjectViewModel};
 use lib_types::dto::project::update_project_dto::UpdateProjectDto;
+use lib_types::entity::notification_entity::{NotificationData, NotificationKind};
+use lib_types::entity::project_member_entity::ProjectPermission;
 use lib_types::shared::api_error::ApiErrorCode;
 use lib_types::shared::project::ProjectStatus;
+use lib_types::shared::terms::ProjectTerms;
 use lib_types::shared::user::{RequestUser, UserType};
 use uuid::Uuid;
 use validator::Validate;
@@ -18,6 +22,11 @@ use validator::Validate;
 use crate::api_context::ApiContext;
 
 use crate::app::helpers::{str_to_bigdecimal, verify_admin_or_user};
+use crate::app::notification::helpers::notify_project;
+use crate::app::project::helpers::{available_slug, verify_project_permission};
+use crate::app::project_revision::helpers::record_revision_tx;
+use crate::app::project_signup::helpers::notify_project_signups;
+use crate::db::notification_repo::NotificationAudience;
 use crate::db::project_repo::ProjectUpdateProps;
 
 pub async fn update_project(
@@ -45,7 +54,18 @@ pub async fn update_project(
     );
 
     // Verify request
-    verify_admin_or_user(&request_user, project_to_be_updated.user_id.to_string())?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project_to_be_updated.id,
+        project_to_be_updated.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+    // Payout and lifecycle fields are owner-only, editors may only change content
+    if dto.payment_address.is_some() || dto.status.is_some() || dto.funding_model.is_some() {
+        verify_admin_or_user(&request_user, project_to_be_updated.user_id.to_string())?;
+    }
 
     let funding_goal = if let Some(funding_goal) = dto.funding_goal {
         Some(str_to_bigdecimal(&funding_goal, "funding_goal")?)
@@ -79,6 +99,14 @@ pub async fn update_project(
                 return Err(ApiError::bad_request().code(ApiErrorCode::ProjectActive));
             }
         }
+        // Verify active funding model can't change
+        if dto.funding_model.is_some() && is_active {
+            return Err(ApiError::bad_request().code(ApiErrorCode::ProjectActive));
+        }
+        // Verify active duration can't change, since the end time decides the funding outcome
+        if dto.duration.is_some() && is_active {
+            return Err(ApiError::bad_request().code(ApiErrorCode::ProjectActive));
+        }
         // Verify start_time is after now
         if let Some(start_time) = dto.start_time {
             if is_active || start_time < Utc::now().timestamp() {
@@ -98,13 +126,23 @@ pub async fn update_project(
         }
     }
 
+    // Renamed projects get a new slug, unique across current and previous slugs
+    let slug = match &dto.name {
+        Some(name) if *name != project_to_be_updated.name => {
+            Some(available_slug(&context, name, Some(project_id)).await?)
+        }
+        _ => None,
+    };
+
     let props = ProjectUpdateProps {
         name: dto.name,
+        slug,
         description: dto.description,
         blurb: dto.blurb,
         payment_address: dto.payment_address,
         category: dto.category,
         funding_goal,
+        funding_model: dto.funding_model,
         start_time: dto.start_time,
         duration: dto.duration,
         total_pledged: None,
@@ -117,17 +155,49 @@ pub async fn update_project(
         transaction_hash: dto.transaction_hash,
     };
 
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+    let mut tx = context.repo.start_transaction().await?;
+
     // Update project
     let project_result = context
         .repo
         .project
-        .update_project(project_id, props)
+        .update_project_tx(&mut tx, project_id, props)
         .await
         .map_err(|e| match e {
             DbError::Unique(_) => ApiError::bad_request().code(ApiErrorCode::ProjectExists),
             _ => ApiError::internal_error().message(format!("Failed to update project: {}", e)),
         })?;
 
+    // Record changed project terms
+    record_revision_tx(
+        &context,
+        &mut tx,
+        project_id,
+        None,
+        user_id,
+        Some(&ProjectTerms::from(&project_to_be_updated)),
+        Some(&ProjectTerms::from(&project_result)),
+    )
+    .await?;
+
+    commit_or_rollback(tx, Ok(())).await?;
+
+    // Notify followers and Prelaunch signups when the project goes live
+    if project_to_be_updated.status != ProjectStatus::Active
+        && project_result.status == ProjectStatus::Active
+    {
+        notify_project(
+            &context,
+            NotificationKind::ProjectLaunched,
+            project_id,
+            NotificationData::default(),
+            NotificationAudience::Followers,
+        )
+        .await;
+        notify_project_signups(&context, project_id).await;
+    }
+
     // Return response
     Ok((StatusCode::OK, Json(to_api_response(project_result))))
 }
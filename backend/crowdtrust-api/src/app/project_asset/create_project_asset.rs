@@ -9,13 +9,14 @@ use lib_types::{
     dto::project_asset::create_project_asset_dto::{
         to_api_response, CreateProjectAssetDto, CreateProjectAssetResponse,
     },
+    entity::project_member_entity::ProjectPermission,
     shared::{asset::AssetState, user::RequestUser},
 };
 use validator::Validate;
 
 use crate::{
     api_context::ApiContext,
-    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    app::project::helpers::{verify_project_exist, verify_project_permission},
     db::{project_asset_repo::ProjectAssetEntityProps, project_repo::ProjectUpdateProps},
 };
 
@@ -31,8 +32,15 @@ pub async fn create_project_asset(
     let project_id = str_to_uuid(&dto.project_id)?;
     let project = verify_project_exist(&context, project_id.clone()).await?;
 
-    // Check if the requester is the owner of the project or an admin
-    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     let content_type = dto.content_type.to_string();
 
@@ -4,12 +4,12 @@ use axum::{
 };
 
 use lib_api::error::api_error::ApiError;
-use lib_types::shared::user::RequestUser;
+use lib_types::{entity::project_member_entity::ProjectPermission, shared::user::RequestUser};
 use uuid::Uuid;
 
 use crate::{
     api_context::ApiContext,
-    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    app::project::helpers::{verify_project_exist, verify_project_permission},
     db::project_repo::ProjectUpdateProps,
 };
 
@@ -24,8 +24,15 @@ pub async fn delete_project_asset(
     let asset = verify_project_asset_exist(&context, asset_id).await?;
     let project = verify_project_exist(&context, asset.project_id).await?;
 
-    // Check if the requester is the owner of the asset or an admin
-    verify_admin_or_user(&request_user, asset.user_id.to_string())?;
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     // Call R2 endpoint to delete the asset
     context
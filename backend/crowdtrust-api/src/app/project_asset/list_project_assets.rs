@@ -2,17 +2,30 @@ use axum::{
     extract::{Query, State},
     Extension, Json,
 };
-use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_api::{
+    db::util::option_string_to_uuid,
+    error::{api_error::ApiError, helpers::check_bad_form},
+};
 use lib_types::{
     dto::project_asset::{
         list_project_assets_dto::{ListProjectAssetsQuery, ListProjectAssetsResponse},
         project_asset_viewmodel::to_api_response,
     },
-    shared::user::{RequestUser, UserType},
+    shared::{
+        api_error::ApiErrorCode,
+        asset::AssetState,
+        user::{RequestUser, UserType},
+    },
 };
 use validator::Validate;
 
-use crate::{api_context::ApiContext, app::helpers::verify_user};
+use crate::{
+    api_context::ApiContext,
+    app::{
+        helpers::{to_cursor_page, verify_user},
+        project_preview_token::helpers::verify_preview_access,
+    },
+};
 
 pub async fn list_project_assets(
     State(context): State<ApiContext>,
@@ -20,14 +33,15 @@ pub async fn list_project_assets(
     Query(query): Query<ListProjectAssetsQuery>,
 ) -> Result<Json<ListProjectAssetsResponse>, ApiError> {
     check_bad_form(query.validate())?;
+    let page = to_cursor_page(query.cursor.clone(), query.limit, query.include_total)?;
 
     // Check user role to determine the filters to apply
-    let q = make_list_project_assets_query(&request_user, query)?;
+    let q = make_list_project_assets_query(&context, &request_user, query).await?;
 
     let result = context
         .repo
         .project_asset
-        .list_project_assets(q)
+        .list_project_assets(q, page)
         .await
         .map_err(|e| {
             ApiError::internal_error().message(format!("Failed to list project assets: {}", e))
@@ -42,15 +56,34 @@ pub async fn list_project_assets(
         results,
         total_usage: result.total_usage,
         total: result.total,
+        next_cursor: result.next_cursor,
     }))
 }
 
-fn make_list_project_assets_query(
+async fn make_list_project_assets_query(
+    context: &ApiContext,
     request_user: &RequestUser,
     query: ListProjectAssetsQuery,
 ) -> Result<ListProjectAssetsQuery, ApiError> {
+    if request_user.user_type == UserType::Admin {
+        return Ok(query);
+    }
+    // Preview viewers can list uploaded assets of the previewed project
+    if let Some(preview_token) = &query.preview_token {
+        let project_id = option_string_to_uuid(query.project_id.clone()).ok_or(
+            ApiError::bad_request()
+                .code(ApiErrorCode::InvalidFormData)
+                .message("Preview token requires project_id"),
+        )?;
+        verify_preview_access(context, preview_token, project_id, false).await?;
+
+        return Ok(ListProjectAssetsQuery {
+            user_id: None,
+            state: Some(AssetState::Uploaded),
+            ..query
+        });
+    }
     match request_user.user_type {
-        UserType::Admin => Ok(query),
         UserType::User => {
             let user_id = verify_user(request_user, query.user_id.clone())?;
 
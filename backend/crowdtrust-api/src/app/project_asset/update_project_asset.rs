@@ -12,12 +12,16 @@ use lib_types::{
         project_asset_viewmodel::{to_api_response, ProjectAssetViewModel},
         update_project_asset_dto::UpdateProjectAssetDto,
     },
+    entity::project_member_entity::ProjectPermission,
     shared::user::{RequestUser, UserType},
 };
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::{api_context::ApiContext, app::helpers::verify_admin_or_user};
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
 
 use super::helpers::verify_project_asset_exist;
 
@@ -33,8 +37,17 @@ pub async fn update_project_asset(
     // Get Asset
     let project_asset = verify_project_asset_exist(&context, asset_id).await?;
 
-    // Check if the asset belongs to the current user
-    verify_admin_or_user(&request_user, project_asset.user_id.to_string())?;
+    let project = verify_project_exist(&context, project_asset.project_id).await?;
+
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     if user_type == UserType::User {
         if dto.state.is_some() {
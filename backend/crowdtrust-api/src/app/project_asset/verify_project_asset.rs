@@ -6,11 +6,15 @@ use chrono::Utc;
 use lib_api::error::api_error::ApiError;
 use lib_types::{
     dto::project_asset::verify_project_asset_dto::VerifyProjectAssetResponse,
+    entity::project_member_entity::ProjectPermission,
     shared::{asset::AssetState, user::RequestUser},
 };
 use uuid::Uuid;
 
-use crate::{api_context::ApiContext, app::helpers::verify_admin_or_user};
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
 
 use super::helpers::verify_project_asset_exist;
 
@@ -21,8 +25,17 @@ pub async fn verify_project_asset(
 ) -> Result<Json<VerifyProjectAssetResponse>, ApiError> {
     let project_asset = verify_project_asset_exist(&context, asset_id).await?;
 
-    // Check if the asset belongs to the current user
-    verify_admin_or_user(&request_user, project_asset.user_id.to_string())?;
+    let project = verify_project_exist(&context, project_asset.project_id).await?;
+
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     // Perform a HEAD request to check if the object exists
     let exists = context
@@ -0,0 +1,73 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use lib_api::{
+    db::util::option_string_to_uuid,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_comment::{
+        create_project_comment_dto::CreateProjectCommentDto,
+        project_comment_view_model::{to_api_response, ProjectCommentViewModel},
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext, app::project::helpers::get_visible_project,
+    db::project_comment_repo::ProjectCommentCreateProps,
+};
+
+use super::helpers::{
+    is_moderator, verify_comment_not_deleted, verify_comment_rate_limit,
+    verify_project_comment_exist,
+};
+
+pub async fn create_project_comment(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateProjectCommentDto>,
+) -> Result<(StatusCode, Json<ProjectCommentViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let project = get_visible_project(&context, &request_user, project_id, None).await?;
+
+    // Replies must be to a comment on the same project
+    let parent_id = option_string_to_uuid(dto.parent_id);
+    if let Some(parent_id) = parent_id {
+        let parent = verify_project_comment_exist(&context, parent_id).await?;
+        if parent.project_id != project_id {
+            return Err(ApiError::bad_request().message("Parent comment is not on this project"));
+        }
+        verify_comment_not_deleted(&parent)?;
+    }
+
+    verify_comment_rate_limit(&context, &request_user, user_id).await?;
+
+    let comment = context
+        .repo
+        .project_comment
+        .create_comment(ProjectCommentCreateProps {
+            project_id,
+            user_id,
+            parent_id,
+            body: dto.body,
+        })
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create comment: {}", e))
+        })?;
+
+    let is_moderator = is_moderator(&request_user, project.user_id);
+    Ok((
+        StatusCode::CREATED,
+        Json(to_api_response(comment, is_moderator)),
+    ))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::project::helpers::verify_project_exist};
+
+use super::helpers::{is_moderator, verify_project_comment_exist};
+
+pub async fn delete_project_comment(
+    Path(comment_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<(), ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let comment = verify_project_comment_exist(&context, comment_id).await?;
+    let project = verify_project_exist(&context, comment.project_id).await?;
+
+    // The author or a moderator can delete a comment
+    if comment.user_id != user_id && !is_moderator(&request_user, project.user_id) {
+        return Err(ApiError::forbidden());
+    }
+    if comment.deleted_at.is_some() {
+        return Ok(());
+    }
+
+    context
+        .repo
+        .project_comment
+        .delete_comment(comment_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to delete comment: {}", e))
+        })?;
+
+    Ok(())
+}
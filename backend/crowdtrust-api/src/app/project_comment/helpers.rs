@@ -0,0 +1,79 @@
+use chrono::{Duration, Utc};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    entity::project_comment_entity::ProjectCommentEntity,
+    shared::{
+        api_error::ApiErrorCode,
+        user::{RequestUser, UserType},
+    },
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::is_admin_or_user};
+
+// Comment limits per user, as (window in seconds, max comments)
+const COMMENT_RATE_LIMITS: [(i64, i64); 2] = [(60, 5), (3600, 30)];
+
+pub async fn verify_project_comment_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<ProjectCommentEntity, ApiError> {
+    let comment = context
+        .repo
+        .project_comment
+        .get_comment_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Comment not found"))?;
+    Ok(comment)
+}
+
+// Admins and the project owner moderate a project's comments
+pub fn is_moderator(request_user: &RequestUser, project_owner_id: Uuid) -> bool {
+    is_admin_or_user(request_user, project_owner_id)
+}
+
+pub fn verify_moderator(
+    request_user: &RequestUser,
+    project_owner_id: Uuid,
+) -> Result<(), ApiError> {
+    request_user.user_id.ok_or(ApiError::unauthorized())?;
+    if !is_moderator(request_user, project_owner_id) {
+        return Err(ApiError::forbidden());
+    }
+    Ok(())
+}
+
+// Deleted comments can't be edited, replied to or reported
+pub fn verify_comment_not_deleted(comment: &ProjectCommentEntity) -> Result<(), ApiError> {
+    if comment.deleted_at.is_some() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::CommentDeleted)
+            .message("Comment was deleted"));
+    }
+    Ok(())
+}
+
+// Limits how often a user can comment. Admins are exempt
+pub async fn verify_comment_rate_limit(
+    context: &ApiContext,
+    request_user: &RequestUser,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    if request_user.user_type == UserType::Admin {
+        return Ok(());
+    }
+    for (seconds, max) in COMMENT_RATE_LIMITS {
+        let count = context
+            .repo
+            .project_comment
+            .count_user_comments_since(user_id, Utc::now() - Duration::seconds(seconds))
+            .await
+            .map_err(|e| ApiError::internal_error().message(e))?;
+        if count >= max {
+            return Err(
+                ApiError::too_many_requests().message("Commenting too often, try again later")
+            );
+        }
+    }
+    Ok(())
+}
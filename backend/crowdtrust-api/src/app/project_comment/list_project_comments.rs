@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_types::{
+    dto::project_comment::{
+        list_project_comments_dto::{ListProjectCommentsQuery, ListProjectCommentsResponse},
+        project_comment_view_model::to_api_response,
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::to_cursor_page, project::helpers::get_visible_project},
+};
+
+use super::helpers::{is_moderator, verify_moderator};
+
+pub async fn list_project_comments(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    Query(query): Query<ListProjectCommentsQuery>,
+) -> Result<Json<ListProjectCommentsResponse>, ApiError> {
+    check_bad_form(query.validate())?;
+    let page = to_cursor_page(query.cursor.clone(), query.limit, query.include_total)?;
+
+    let project = get_visible_project(&context, &request_user, project_id, None).await?;
+
+    // Only moderators can list reported comments
+    if query.reported.unwrap_or(false) {
+        verify_moderator(&request_user, project.user_id)?;
+    }
+    let is_moderator = is_moderator(&request_user, project.user_id);
+
+    let comments = context
+        .repo
+        .project_comment
+        .list_comments(project_id, query, page)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list comments: {}", e))
+        })?;
+
+    Ok(Json(ListProjectCommentsResponse {
+        total: comments.total,
+        next_cursor: comments.next_cursor,
+        results: comments
+            .results
+            .into_iter()
+            .map(|comment| to_api_response(comment, is_moderator))
+            .collect(),
+    }))
+}
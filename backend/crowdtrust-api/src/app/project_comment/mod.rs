@@ -0,0 +1,8 @@
+pub mod create_project_comment;
+pub mod delete_project_comment;
+pub mod helpers;
+pub mod hide_project_comment;
+pub mod list_project_comments;
+pub mod report_project_comment;
+pub mod restore_project_comment;
+pub mod update_project_comment;
@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::{
+    db::db_error::DbError,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_comment::report_project_comment_dto::ReportProjectCommentDto,
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api_context::ApiContext;
+
+use super::helpers::{verify_comment_not_deleted, verify_project_comment_exist};
+
+pub async fn report_project_comment(
+    Path(comment_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<ReportProjectCommentDto>,
+) -> Result<StatusCode, ApiError> {
+    check_bad_form(dto.validate())?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let comment = verify_project_comment_exist(&context, comment_id).await?;
+    verify_comment_not_deleted(&comment)?;
+
+    context
+        .repo
+        .project_comment
+        .report_comment(comment_id, user_id, dto.reason)
+        .await
+        .map_err(|e| match e {
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::CommentReported)
+                .message("Comment already reported"),
+            _ => ApiError::internal_error().message(format!("Failed to report comment: {}", e)),
+        })?;
+
+    Ok(StatusCode::CREATED)
+}
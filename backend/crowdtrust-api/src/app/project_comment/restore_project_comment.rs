@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_comment::project_comment_view_model::{to_api_response, ProjectCommentViewModel},
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::project::helpers::verify_project_exist};
+
+use super::helpers::{verify_moderator, verify_project_comment_exist};
+
+// Makes a hidden comment readable again
+pub async fn restore_project_comment(
+    Path(comment_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<Json<ProjectCommentViewModel>, ApiError> {
+    let comment = verify_project_comment_exist(&context, comment_id).await?;
+    let project = verify_project_exist(&context, comment.project_id).await?;
+    verify_moderator(&request_user, project.user_id)?;
+
+    let comment = context
+        .repo
+        .project_comment
+        .set_comment_hidden(comment_id, None)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to restore comment: {}", e))
+        })?;
+
+    Ok(Json(to_api_response(comment, true)))
+}
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_comment::{
+        project_comment_view_model::{to_api_response, ProjectCommentViewModel},
+        update_project_comment_dto::UpdateProjectCommentDto,
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::project::helpers::verify_project_exist};
+
+use super::helpers::{is_moderator, verify_comment_not_deleted, verify_project_comment_exist};
+
+pub async fn update_project_comment(
+    Path(comment_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UpdateProjectCommentDto>,
+) -> Result<Json<ProjectCommentViewModel>, ApiError> {
+    check_bad_form(dto.validate())?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let comment = verify_project_comment_exist(&context, comment_id).await?;
+
+    // Only the author can edit a comment
+    if comment.user_id != user_id {
+        return Err(ApiError::forbidden());
+    }
+    verify_comment_not_deleted(&comment)?;
+
+    let project = verify_project_exist(&context, comment.project_id).await?;
+
+    let comment = context
+        .repo
+        .project_comment
+        .edit_comment(comment_id, dto.body)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to update comment: {}", e))
+        })?;
+
+    Ok(Json(to_api_response(
+        comment,
+        is_moderator(&request_user, project.user_id),
+    )))
+}
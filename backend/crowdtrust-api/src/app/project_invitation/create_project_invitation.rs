@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use lib_api::{
+    db::db_error::DbError,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_invitation::{
+        create_project_invitation_dto::CreateProjectInvitationDto,
+        project_invitation_view_model::{to_api_response, ProjectInvitationViewModel},
+    },
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    db::project_invitation_repo::ProjectInvitationCreateProps,
+};
+
+pub async fn create_project_invitation(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateProjectInvitationDto>,
+) -> Result<(StatusCode, Json<ProjectInvitationViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+    if dto.email.is_some() == dto.eth_address.is_some() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::InvalidFormData)
+            .message("Either email or eth_address is required"));
+    }
+
+    // Only the owner manages the project team
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    // Check if the invitee is already on the team
+    let invitee = if let Some(email) = &dto.email {
+        context.repo.user.find_user_by_email(email.clone()).await
+    } else {
+        let eth_address = dto.eth_address.clone().unwrap_or_default();
+        context
+            .repo
+            .user
+            .find_user_by_eth_address(eth_address)
+            .await
+    };
+    if let Ok(invitee) = invitee {
+        let is_member = invitee.id == project.user_id
+            || context
+                .repo
+                .project_member
+                .get_member(project_id, invitee.id)
+                .await
+                .map_err(|e| ApiError::internal_error().message(e))?
+                .is_some();
+        if is_member {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::MemberExists)
+                .message("User is already on the project team"));
+        }
+    }
+
+    let invitation = context
+        .repo
+        .project_invitation
+        .create_invitation(ProjectInvitationCreateProps {
+            project_id,
+            email: dto.email,
+            eth_address: dto.eth_address,
+            role: dto.role,
+            invited_by: user_id,
+        })
+        .await
+        .map_err(|e| match e {
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::InvitationExists)
+                .message("Invitation already pending"),
+            _ => ApiError::internal_error().message(format!("Failed to create invitation: {}", e)),
+        })?;
+
+    Ok((StatusCode::CREATED, Json(to_api_response(invitation))))
+}
@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_invitation::project_invitation_view_model::{
+        to_api_response, ProjectInvitationViewModel,
+    },
+    entity::project_member_entity::ProjectInvitationState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::get_request_user};
+
+use super::helpers::{map_invitation_closed, verify_project_invitation_exist};
+
+pub async fn decline_project_invitation(
+    Path(invitation_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectInvitationViewModel>, ApiError> {
+    let user = get_request_user(&context, &request_user).await?;
+    let invitation = verify_project_invitation_exist(&context, invitation_id).await?;
+
+    // Only the invitee can decline
+    if !invitation.is_for(&user.email, &user.eth_address) {
+        return Err(ApiError::forbidden());
+    }
+
+    let invitation = context
+        .repo
+        .project_invitation
+        .close_invitation(invitation_id, ProjectInvitationState::Declined)
+        .await
+        .map_err(map_invitation_closed)?;
+
+    Ok(Json(to_api_response(invitation)))
+}
@@ -0,0 +1,30 @@
+use lib_api::{db::db_error::DbError, error::api_error::ApiError};
+use lib_types::{
+    entity::project_member_entity::ProjectInvitationEntity, shared::api_error::ApiErrorCode,
+};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+pub async fn verify_project_invitation_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<ProjectInvitationEntity, ApiError> {
+    let invitation = context
+        .repo
+        .project_invitation
+        .get_invitation_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Invitation not found"))?;
+    Ok(invitation)
+}
+
+// Invitations can only be closed once
+pub fn map_invitation_closed(e: DbError) -> ApiError {
+    match e {
+        DbError::EntityNotFound() => ApiError::bad_request()
+            .code(ApiErrorCode::InvitationClosed)
+            .message("Invitation is no longer pending"),
+        _ => ApiError::internal_error().message(format!("Failed to update invitation: {}", e)),
+    }
+}
@@ -0,0 +1,32 @@
+use axum::{extract::State, Extension, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_invitation::{
+        list_project_invitations_dto::ListProjectInvitationsResponse,
+        project_invitation_view_model::to_api_response,
+    },
+    shared::user::RequestUser,
+};
+
+use crate::{api_context::ApiContext, app::helpers::get_request_user};
+
+// Pending invitations addressed to the request user's email or eth address
+pub async fn list_user_invitations(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ListProjectInvitationsResponse>, ApiError> {
+    let user = get_request_user(&context, &request_user).await?;
+
+    let invitations = context
+        .repo
+        .project_invitation
+        .list_pending_invitations(user.email, user.eth_address)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list invitations: {}", e))
+        })?;
+
+    Ok(Json(ListProjectInvitationsResponse {
+        results: invitations.into_iter().map(to_api_response).collect(),
+    }))
+}
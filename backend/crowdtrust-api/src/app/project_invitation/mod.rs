@@ -0,0 +1,7 @@
+pub mod accept_project_invitation;
+pub mod create_project_invitation;
+pub mod decline_project_invitation;
+pub mod helpers;
+pub mod list_project_invitations;
+pub mod list_user_invitations;
+pub mod revoke_project_invitation;
@@ -0,0 +1,39 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_invitation::project_invitation_view_model::{
+        to_api_response, ProjectInvitationViewModel,
+    },
+    entity::project_member_entity::ProjectInvitationState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+};
+
+use super::helpers::{map_invitation_closed, verify_project_invitation_exist};
+
+pub async fn revoke_project_invitation(
+    Path(invitation_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectInvitationViewModel>, ApiError> {
+    let invitation = verify_project_invitation_exist(&context, invitation_id).await?;
+    let project = verify_project_exist(&context, invitation.project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    let invitation = context
+        .repo
+        .project_invitation
+        .close_invitation(invitation_id, ProjectInvitationState::Revoked)
+        .await
+        .map_err(map_invitation_closed)?;
+
+    Ok(Json(to_api_response(invitation)))
+}
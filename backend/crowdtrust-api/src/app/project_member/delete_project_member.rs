@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::is_admin_or_user, project::helpers::verify_project_exist},
+};
+
+// Removes a collaborator. Members can remove themselves to leave the team
+pub async fn delete_project_member(
+    Path((project_id, user_id)): Path<(Uuid, Uuid)>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<(), ApiError> {
+    let project = verify_project_exist(&context, project_id).await?;
+    if request_user.user_id != Some(user_id) && !is_admin_or_user(&request_user, project.user_id) {
+        return Err(ApiError::forbidden());
+    }
+
+    context
+        .repo
+        .project_member
+        .delete_member(project_id, user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to remove member: {}", e))
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_member::{
+        list_project_members_dto::ListProjectMembersResponse,
+        project_member_view_model::to_api_response,
+    },
+    entity::project_member_entity::ProjectPermission,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
+
+pub async fn list_project_members(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ListProjectMembersResponse>, ApiError> {
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::View,
+    )
+    .await?;
+
+    let members = context
+        .repo
+        .project_member
+        .list_members(project_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list members: {}", e))
+        })?;
+
+    Ok(Json(ListProjectMembersResponse {
+        results: members.into_iter().map(to_api_response).collect(),
+    }))
+}
@@ -0,0 +1,3 @@
+pub mod delete_project_member;
+pub mod list_project_members;
+pub mod update_project_member;
@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_member::{
+        project_member_view_model::{to_api_response, ProjectMemberViewModel},
+        update_project_member_dto::UpdateProjectMemberDto,
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{
+        helpers::{not_found_or_internal, verify_admin_or_user},
+        project::helpers::verify_project_exist,
+    },
+};
+
+pub async fn update_project_member(
+    Path((project_id, user_id)): Path<(Uuid, Uuid)>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UpdateProjectMemberDto>,
+) -> Result<Json<ProjectMemberViewModel>, ApiError> {
+    check_bad_form(dto.validate())?;
+
+    // Only the owner manages the project team
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    let member = context
+        .repo
+        .project_member
+        .update_member_role(project_id, user_id, dto.role)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    Ok(Json(to_api_response(member)))
+}
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_milestone::{
+        create_project_milestone_dto::CreateProjectMilestoneDto,
+        project_milestone_view_model::{to_api_response, ProjectMilestoneViewModel},
+    },
+    entity::project_member_entity::ProjectPermission,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+    db::project_milestone_repo::ProjectMilestoneCreateProps,
+};
+
+use super::helpers::verify_release_percent;
+
+pub async fn create_project_milestone(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateProjectMilestoneDto>,
+) -> Result<(StatusCode, Json<ProjectMilestoneViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+    verify_release_percent(&context, project_id, None, dto.release_percent).await?;
+
+    let milestone = context
+        .repo
+        .project_milestone
+        .create_milestone(ProjectMilestoneCreateProps {
+            project_id,
+            title: dto.title,
+            description: dto.description,
+            target_date: dto.target_date,
+            release_percent: dto.release_percent,
+        })
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create milestone: {}", e))
+        })?;
+
+    Ok((StatusCode::CREATED, Json(to_api_response(milestone))))
+}
@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{entity::project_member_entity::ProjectPermission, shared::user::RequestUser};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
+
+use super::helpers::{map_milestone_locked, verify_project_milestone_exist};
+
+pub async fn delete_project_milestone(
+    Path(milestone_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<(), ApiError> {
+    let milestone = verify_project_milestone_exist(&context, milestone_id).await?;
+    let project = verify_project_exist(&context, milestone.project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+
+    context
+        .repo
+        .project_milestone
+        .delete_milestone(milestone_id)
+        .await
+        .map_err(map_milestone_locked)?;
+
+    Ok(())
+}
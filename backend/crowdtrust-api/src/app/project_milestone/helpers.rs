@@ -0,0 +1,63 @@
+use lib_api::{db::db_error::DbError, error::api_error::ApiError};
+use lib_types::{
+    entity::project_milestone_entity::ProjectMilestoneEntity, shared::api_error::ApiErrorCode,
+};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+// Backers can vote on a milestone for 7 days
+pub const MILESTONE_VOTING_DAYS: i64 = 7;
+
+pub async fn verify_project_milestone_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<ProjectMilestoneEntity, ApiError> {
+    let milestone = context
+        .repo
+        .project_milestone
+        .get_milestone_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Milestone not found"))?;
+    Ok(milestone)
+}
+
+pub fn milestone_locked() -> ApiError {
+    ApiError::bad_request()
+        .code(ApiErrorCode::MilestoneLocked)
+        .message("Milestone is no longer pending")
+}
+
+// Milestones can only be changed before voting starts
+pub fn map_milestone_locked(e: DbError) -> ApiError {
+    match e {
+        DbError::EntityNotFound() => milestone_locked(),
+        DbError::NoUpdate => ApiError::bad_request()
+            .code(ApiErrorCode::InvalidFormData)
+            .message("No fields to update"),
+        _ => ApiError::internal_error().message(format!("Failed to update milestone: {}", e)),
+    }
+}
+
+// Milestones of a project can release up to 100% of its funds
+pub async fn verify_release_percent(
+    context: &ApiContext,
+    project_id: Uuid,
+    exclude_id: Option<Uuid>,
+    release_percent: i32,
+) -> Result<(), ApiError> {
+    let total = context
+        .repo
+        .project_milestone
+        .sum_release_percent(project_id, exclude_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get milestones: {}", e))
+        })?;
+    if total + release_percent as i64 > 100 {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::MilestonePercent)
+            .message("Milestones can release up to 100% of funds"));
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use bigdecimal::BigDecimal;
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::{
+        project::get_project_dto::serialize_big,
+        project_milestone::{
+            list_project_milestones_dto::ListProjectMilestonesResponse,
+            project_milestone_view_model::to_api_response,
+        },
+    },
+    entity::project_milestone_entity::released_percent,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::project::helpers::get_visible_project};
+
+pub async fn list_project_milestones(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ListProjectMilestonesResponse>, ApiError> {
+    let project = get_visible_project(&context, &request_user, project_id, None).await?;
+
+    let milestones = context
+        .repo
+        .project_milestone
+        .list_milestones(project_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list milestones: {}", e))
+        })?;
+
+    let released_percent = released_percent(&milestones);
+    let released_amount =
+        project.total_pledged * BigDecimal::from(released_percent) / BigDecimal::from(100);
+
+    Ok(Json(ListProjectMilestonesResponse {
+        released_percent,
+        released_amount: serialize_big(&released_amount),
+        results: milestones.into_iter().map(to_api_response).collect(),
+    }))
+}
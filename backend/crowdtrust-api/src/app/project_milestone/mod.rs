@@ -0,0 +1,8 @@
+pub mod create_project_milestone;
+pub mod delete_project_milestone;
+pub mod helpers;
+pub mod list_project_milestones;
+pub mod open_milestone_voting;
+pub mod resolve_project_milestones;
+pub mod update_project_milestone;
+pub mod vote_project_milestone;
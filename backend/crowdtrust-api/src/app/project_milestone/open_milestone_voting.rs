@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use chrono::{Duration, Utc};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_milestone::project_milestone_view_model::{
+        to_api_response, ProjectMilestoneViewModel,
+    },
+    entity::project_member_entity::ProjectPermission,
+    shared::{api_error::ApiErrorCode, project::FundingOutcome, user::RequestUser},
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
+
+use super::helpers::{map_milestone_locked, verify_project_milestone_exist, MILESTONE_VOTING_DAYS};
+
+// The creator asks backers to approve a milestone, once the project is funded
+pub async fn open_milestone_voting(
+    Path(milestone_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectMilestoneViewModel>, ApiError> {
+    let milestone = verify_project_milestone_exist(&context, milestone_id).await?;
+    let project = verify_project_exist(&context, milestone.project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+    if project.funding_outcome != Some(FundingOutcome::Funded) {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::ProjectInactive)
+            .message("Project must be funded to vote on milestones"));
+    }
+
+    let ends_at = Utc::now() + Duration::days(MILESTONE_VOTING_DAYS);
+    let milestone = context
+        .repo
+        .project_milestone
+        .open_voting(milestone_id, ends_at)
+        .await
+        .map_err(map_milestone_locked)?;
+
+    Ok(Json(to_api_response(milestone)))
+}
@@ -0,0 +1,47 @@
+use axum::{extract::State, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_milestone::resolve_project_milestones_dto::ResolveProjectMilestonesResponse,
+    entity::project_milestone_entity::MilestoneState,
+};
+
+use crate::api_context::ApiContext;
+
+// Records the result of milestones with a voting window that ended.
+// Run periodically by a scheduled job
+pub async fn resolve_project_milestones(
+    State(context): State<ApiContext>,
+) -> Result<Json<ResolveProjectMilestonesResponse>, ApiError> {
+    let milestones = context
+        .repo
+        .project_milestone
+        .list_ended_voting()
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list milestones: {}", e))
+        })?;
+
+    let mut approved = vec![];
+    let mut rejected = vec![];
+    for milestone in milestones.into_iter() {
+        let state = milestone.outcome();
+        context
+            .repo
+            .project_milestone
+            .resolve_milestone(milestone.id, state)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to resolve milestone: {}", e))
+            })?;
+        if state == MilestoneState::Approved {
+            approved.push(milestone.id);
+        } else {
+            rejected.push(milestone.id);
+        }
+    }
+
+    Ok(Json(ResolveProjectMilestonesResponse {
+        approved,
+        rejected,
+    }))
+}
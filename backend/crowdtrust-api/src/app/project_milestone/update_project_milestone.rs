@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_milestone::{
+        project_milestone_view_model::{to_api_response, ProjectMilestoneViewModel},
+        update_project_milestone_dto::UpdateProjectMilestoneDto,
+    },
+    entity::{project_member_entity::ProjectPermission, project_milestone_entity::MilestoneState},
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+    db::project_milestone_repo::ProjectMilestoneUpdateProps,
+};
+
+use super::helpers::{
+    map_milestone_locked, milestone_locked, verify_project_milestone_exist, verify_release_percent,
+};
+
+pub async fn update_project_milestone(
+    Path(milestone_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UpdateProjectMilestoneDto>,
+) -> Result<Json<ProjectMilestoneViewModel>, ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let milestone = verify_project_milestone_exist(&context, milestone_id).await?;
+    let project = verify_project_exist(&context, milestone.project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+    if milestone.state != MilestoneState::Pending {
+        return Err(milestone_locked());
+    }
+    if let Some(release_percent) = dto.release_percent {
+        verify_release_percent(&context, project.id, Some(milestone_id), release_percent).await?;
+    }
+
+    let milestone = context
+        .repo
+        .project_milestone
+        .update_milestone(
+            milestone_id,
+            ProjectMilestoneUpdateProps {
+                title: dto.title,
+                description: dto.description,
+                target_date: dto.target_date,
+                release_percent: dto.release_percent,
+            },
+        )
+        .await
+        .map_err(map_milestone_locked)?;
+
+    Ok(Json(to_api_response(milestone)))
+}
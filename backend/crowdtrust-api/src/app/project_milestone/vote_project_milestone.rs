@@ -0,0 +1,98 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use bigdecimal::{BigDecimal, Zero};
+use chrono::Utc;
+use lib_api::{
+    db::db_error::DbError,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    eth::verify_signature::verify_signature,
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_milestone::{
+        project_milestone_view_model::{to_api_response, ProjectMilestoneViewModel},
+        vote_project_milestone_dto::VoteProjectMilestoneDto,
+    },
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext, app::helpers::get_request_user,
+    db::project_milestone_repo::MilestoneVoteCreateProps,
+};
+
+use super::helpers::verify_project_milestone_exist;
+
+fn voting_closed() -> ApiError {
+    ApiError::bad_request()
+        .code(ApiErrorCode::VotingClosed)
+        .message("Milestone is not open for voting")
+}
+
+// Backers vote with a signature from their wallet. Votes are weighted by the amount pledged
+pub async fn vote_project_milestone(
+    Path(milestone_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<VoteProjectMilestoneDto>,
+) -> Result<Json<ProjectMilestoneViewModel>, ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let user = get_request_user(&context, &request_user).await?;
+    let milestone = verify_project_milestone_exist(&context, milestone_id).await?;
+    if !milestone.is_voting(Utc::now()) {
+        return Err(voting_closed());
+    }
+
+    let weight: BigDecimal = context
+        .repo
+        .pledge
+        .get_backed_amount(user.id, milestone.project_id)
+        .await
+        .map_err(|e| ApiError::internal_error().message(format!("Failed to get pledges: {}", e)))?;
+    if weight.is_zero() {
+        return Err(ApiError::forbidden().message("Only backers can vote"));
+    }
+
+    let message = format!(
+        "{} CrowdTrust milestone {} with {}",
+        if dto.approve { "Approve" } else { "Reject" },
+        milestone.id,
+        user.eth_address
+    );
+    if !verify_signature(
+        dto.eth_address_signature.clone(),
+        message,
+        user.eth_address.clone(),
+    )? {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::InvalidSignature)
+            .message("Failed to verify signature"));
+    }
+
+    let milestone = context
+        .repo
+        .project_milestone
+        .create_vote(MilestoneVoteCreateProps {
+            milestone_id,
+            user_id: user.id,
+            approve: dto.approve,
+            weight,
+            eth_address: user.eth_address,
+            signature: dto.eth_address_signature,
+        })
+        .await
+        .map_err(|e| match e {
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::VoteExists)
+                .message("Already voted on this milestone"),
+            DbError::EntityNotFound() => voting_closed(),
+            _ => ApiError::internal_error().message(format!("Failed to vote: {}", e)),
+        })?;
+
+    Ok(Json(to_api_response(milestone)))
+}
@@ -0,0 +1,63 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::{Duration, Utc};
+use lib_api::auth::generate_jwt::generate_preview_token;
+use lib_api::error::api_error::ApiError;
+use lib_api::error::helpers::check_bad_form;
+use lib_api::util::json_extractor::CtJson;
+use lib_types::dto::project_preview_token::create_project_preview_token_dto::{
+    CreateProjectPreviewTokenDto, CreateProjectPreviewTokenResponse,
+};
+use lib_types::dto::project_preview_token::project_preview_token_view_model::to_api_response;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api_context::ApiContext;
+use crate::app::helpers::verify_admin_or_user;
+use crate::app::project::helpers::verify_project_exist;
+use crate::db::project_preview_token_repo::ProjectPreviewTokenCreateProps;
+
+// Creates a link token for sharing an unpublished project
+pub async fn create_project_preview_token(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateProjectPreviewTokenDto>,
+) -> Result<(StatusCode, Json<CreateProjectPreviewTokenResponse>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let props = ProjectPreviewTokenCreateProps {
+        project_id,
+        user_id,
+        expires_at: Utc::now() + Duration::minutes(dto.ttl),
+    };
+    let preview_token = context
+        .repo
+        .project_preview_token
+        .create_preview_token(props)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create preview token: {}", e))
+        })?;
+
+    let token = generate_preview_token(
+        preview_token.id,
+        project_id,
+        preview_token.expires_at.timestamp(),
+        &context.config.confirm_shared_secret,
+    )?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateProjectPreviewTokenResponse {
+            token,
+            preview_token: to_api_response(preview_token),
+        }),
+    ))
+}
@@ -0,0 +1,36 @@
+use chrono::Utc;
+use lib_api::{
+    auth::verify_jwt::{invalid_preview, verify_preview_token},
+    error::api_error::ApiError,
+};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+// Verifies a preview token grants access to the project. The signature and expiry are checked
+// first, then the token is checked for revocation. `count_view` increments the token view count
+pub async fn verify_preview_access(
+    context: &ApiContext,
+    token: &str,
+    project_id: Uuid,
+    count_view: bool,
+) -> Result<(), ApiError> {
+    let (token_id, token_project_id) =
+        verify_preview_token(&context.config.confirm_shared_secret, token)?;
+    if token_project_id != project_id {
+        return Err(invalid_preview());
+    }
+
+    let repo = &context.repo.project_preview_token;
+    let preview_token = if count_view {
+        repo.add_preview_token_view(token_id).await
+    } else {
+        repo.get_preview_token_by_id(token_id).await
+    }
+    .map_err(|_| invalid_preview())?;
+
+    if preview_token.project_id != project_id || !preview_token.is_active(Utc::now()) {
+        return Err(invalid_preview());
+    }
+    Ok(())
+}
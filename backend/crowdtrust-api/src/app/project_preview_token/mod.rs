@@ -0,0 +1,4 @@
+pub mod create_project_preview_token;
+pub mod helpers;
+pub mod list_project_preview_tokens;
+pub mod revoke_project_preview_token;
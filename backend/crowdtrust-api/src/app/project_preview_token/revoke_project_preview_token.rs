@@ -0,0 +1,37 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::dto::project_preview_token::project_preview_token_view_model::{
+    to_api_response, ProjectPreviewTokenViewModel,
+};
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+use crate::app::helpers::{not_found_or_internal, verify_admin_or_user};
+use crate::app::project::helpers::verify_project_exist;
+
+pub async fn revoke_project_preview_token(
+    Path(preview_token_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<(StatusCode, Json<ProjectPreviewTokenViewModel>), ApiError> {
+    let preview_token = context
+        .repo
+        .project_preview_token
+        .get_preview_token_by_id(preview_token_id)
+        .await
+        .map_err(not_found_or_internal)?;
+    let project = verify_project_exist(&context, preview_token.project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    let result = context
+        .repo
+        .project_preview_token
+        .revoke_preview_token(preview_token_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    Ok((StatusCode::OK, Json(to_api_response(result))))
+}
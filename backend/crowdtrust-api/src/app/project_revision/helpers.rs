@@ -0,0 +1,57 @@
+use lib_api::error::api_error::ApiError;
+use lib_types::entity::project_revision_entity::{revision_changes, RevisionAction};
+use serde::Serialize;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, db::project_revision_repo::ProjectRevisionCreateProps};
+
+// Field level diff of two versions of project or reward terms. None if no field changed
+fn to_revision_props<T: Serialize>(
+    project_id: Uuid,
+    reward_id: Option<Uuid>,
+    user_id: Uuid,
+    old: Option<&T>,
+    new: Option<&T>,
+) -> Option<ProjectRevisionCreateProps> {
+    let changes = revision_changes(old, new);
+    if changes.is_empty() {
+        return None;
+    }
+    let action = match (old, new) {
+        (None, _) => RevisionAction::Create,
+        (_, None) => RevisionAction::Delete,
+        _ => RevisionAction::Update,
+    };
+    Some(ProjectRevisionCreateProps {
+        project_id,
+        reward_id,
+        user_id,
+        action,
+        changes,
+    })
+}
+
+fn revision_error(e: impl std::fmt::Display) -> ApiError {
+    ApiError::internal_error().message(format!("Failed to create revision: {}", e))
+}
+
+pub async fn record_revision_tx<T: Serialize>(
+    context: &ApiContext,
+    tx: &mut Transaction<'_, Postgres>,
+    project_id: Uuid,
+    reward_id: Option<Uuid>,
+    user_id: Uuid,
+    old: Option<&T>,
+    new: Option<&T>,
+) -> Result<(), ApiError> {
+    if let Some(props) = to_revision_props(project_id, reward_id, user_id, old, new) {
+        context
+            .repo
+            .project_revision
+            .create_revision_tx(tx, props)
+            .await
+            .map_err(revision_error)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_types::{
+    dto::project_revision::{
+        list_project_revisions_dto::{ListProjectRevisionsQuery, ListProjectRevisionsResponse},
+        project_revision_view_model::to_api_response,
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::project::helpers::get_visible_project};
+
+pub async fn list_project_revisions(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    Query(query): Query<ListProjectRevisionsQuery>,
+) -> Result<Json<ListProjectRevisionsResponse>, ApiError> {
+    check_bad_form(query.validate())?;
+
+    // Revisions are visible to anyone who can view the project
+    get_visible_project(&context, &request_user, project_id, None).await?;
+
+    let revisions = context
+        .repo
+        .project_revision
+        .list_revisions(project_id, query)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list revisions: {}", e))
+        })?;
+
+    Ok(Json(ListProjectRevisionsResponse {
+        total: revisions.total,
+        results: revisions.results.into_iter().map(to_api_response).collect(),
+    }))
+}
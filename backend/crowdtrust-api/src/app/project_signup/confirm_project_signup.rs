@@ -0,0 +1,29 @@
+use axum::extract::State;
+use lib_api::{
+    auth::verify_jwt::verify_confirm_token, db::db_error::DbError, error::api_error::ApiError,
+    util::json_extractor::CtJson,
+};
+use lib_types::dto::project_signup::confirm_project_signup_dto::ConfirmProjectSignupDto;
+
+use crate::api_context::ApiContext;
+
+pub async fn confirm_project_signup(
+    State(context): State<ApiContext>,
+    CtJson(dto): CtJson<ConfirmProjectSignupDto>,
+) -> Result<(), ApiError> {
+    let secret = &context.config.confirm_shared_secret;
+
+    let signup_id = verify_confirm_token(secret, &dto.code)?;
+
+    context
+        .repo
+        .project_signup
+        .confirm_signup(signup_id)
+        .await
+        .map_err(|e| match e {
+            DbError::EntityNotFound() => ApiError::bad_request().message("Signup not found"),
+            _ => ApiError::internal_error().message(format!("Failed to confirm signup: {}", e)),
+        })?;
+
+    Ok(())
+}
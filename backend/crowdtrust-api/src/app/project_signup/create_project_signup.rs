@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_signup::create_project_signup_dto::CreateProjectSignupDto,
+    shared::{api_error::ApiErrorCode, project::ProjectStatus},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::project::helpers::verify_project_exist};
+
+use super::helpers::{send_signup_confirm_email, verify_signup_rate_limit};
+
+// Anonymous visitors can sign up to be notified when a Prelaunch project goes live.
+// The response doesn't reveal whether the email already signed up
+pub async fn create_project_signup(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    CtJson(dto): CtJson<CreateProjectSignupDto>,
+) -> Result<StatusCode, ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let project = verify_project_exist(&context, project_id).await?;
+    if project.status != ProjectStatus::Prelaunch {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::SignupClosed)
+            .message("Project is not accepting signups"));
+    }
+
+    let email = dto.email.trim().to_lowercase();
+    verify_signup_rate_limit(&context, &email).await?;
+
+    let signup = context
+        .repo
+        .project_signup
+        .create_signup(project_id, email)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create signup: {}", e))
+        })?;
+
+    if signup.confirmed_at.is_none() {
+        send_signup_confirm_email(&context, &signup).await?;
+    }
+
+    Ok(StatusCode::ACCEPTED)
+}
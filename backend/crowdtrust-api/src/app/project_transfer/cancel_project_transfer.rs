@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_transfer::project_transfer_view_model::{
+        to_api_response, ProjectTransferViewModel,
+    },
+    entity::project_transfer_entity::ProjectTransferState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+};
+
+use super::helpers::{map_transfer_closed, verify_project_transfer_exist};
+
+pub async fn cancel_project_transfer(
+    Path(transfer_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectTransferViewModel>, ApiError> {
+    let transfer = verify_project_transfer_exist(&context, transfer_id).await?;
+
+    // Only the owner can cancel
+    let project = verify_project_exist(&context, transfer.project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    let transfer = context
+        .repo
+        .project_transfer
+        .close_transfer(transfer_id, ProjectTransferState::Cancelled)
+        .await
+        .map_err(map_transfer_closed)?;
+
+    Ok(Json(to_api_response(transfer)))
+}
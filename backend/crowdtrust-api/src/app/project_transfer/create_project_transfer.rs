@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{Duration, Utc};
+use lib_api::{
+    db::db_error::DbError,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_transfer::{
+        create_project_transfer_dto::CreateProjectTransferDto,
+        project_transfer_view_model::{to_api_response, ProjectTransferViewModel},
+    },
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    db::project_transfer_repo::ProjectTransferCreateProps,
+};
+
+use super::helpers::TRANSFER_TTL_DAYS;
+
+// Starts a transfer of the project to another account, which the recipient must accept
+pub async fn create_project_transfer(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateProjectTransferDto>,
+) -> Result<(StatusCode, Json<ProjectTransferViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    let recipient = context
+        .repo
+        .user
+        .find_user_by_eth_address(dto.eth_address)
+        .await
+        .map_err(|_| ApiError::not_found().message("Recipient not found"))?;
+    if recipient.id == project.user_id {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::InvalidFormData)
+            .message("Recipient already owns the project"));
+    }
+
+    let transfer = context
+        .repo
+        .project_transfer
+        .create_transfer(ProjectTransferCreateProps {
+            project_id,
+            from_user_id: project.user_id,
+            to_user_id: recipient.id,
+            expires_at: Utc::now() + Duration::days(TRANSFER_TTL_DAYS),
+        })
+        .await
+        .map_err(|e| match e {
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::TransferExists)
+                .message("Project already has a pending transfer"),
+            _ => ApiError::internal_error().message(format!("Failed to create transfer: {}", e)),
+        })?;
+
+    Ok((StatusCode::CREATED, Json(to_api_response(transfer))))
+}
@@ -0,0 +1,39 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_transfer::project_transfer_view_model::{
+        to_api_response, ProjectTransferViewModel,
+    },
+    entity::project_transfer_entity::ProjectTransferState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+use super::helpers::{map_transfer_closed, verify_project_transfer_exist};
+
+pub async fn decline_project_transfer(
+    Path(transfer_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectTransferViewModel>, ApiError> {
+    let transfer = verify_project_transfer_exist(&context, transfer_id).await?;
+
+    // Only the recipient can decline
+    if request_user.user_id != Some(transfer.to_user_id) {
+        return Err(ApiError::forbidden());
+    }
+
+    let transfer = context
+        .repo
+        .project_transfer
+        .close_transfer(transfer_id, ProjectTransferState::Declined)
+        .await
+        .map_err(map_transfer_closed)?;
+
+    Ok(Json(to_api_response(transfer)))
+}
@@ -0,0 +1,37 @@
+use lib_api::{db::db_error::DbError, error::api_error::ApiError};
+use lib_types::{
+    entity::project_transfer_entity::ProjectTransferEntity, shared::api_error::ApiErrorCode,
+};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+// Transfer requests expire after 7 days
+pub const TRANSFER_TTL_DAYS: i64 = 7;
+
+pub async fn verify_project_transfer_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<ProjectTransferEntity, ApiError> {
+    let transfer = context
+        .repo
+        .project_transfer
+        .get_transfer_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Transfer not found"))?;
+    Ok(transfer)
+}
+
+pub fn transfer_closed() -> ApiError {
+    ApiError::bad_request()
+        .code(ApiErrorCode::TransferClosed)
+        .message("Transfer is no longer pending")
+}
+
+// Transfers can only be closed once
+pub fn map_transfer_closed(e: DbError) -> ApiError {
+    match e {
+        DbError::EntityNotFound() => transfer_closed(),
+        _ => ApiError::internal_error().message(format!("Failed to update transfer: {}", e)),
+    }
+}
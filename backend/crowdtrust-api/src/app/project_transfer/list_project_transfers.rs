@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_transfer::{
+        list_project_transfers_dto::ListProjectTransfersResponse,
+        project_transfer_view_model::to_api_response,
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+};
+
+pub async fn list_project_transfers(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ListProjectTransfersResponse>, ApiError> {
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    let transfers = context
+        .repo
+        .project_transfer
+        .list_project_transfers(project_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list transfers: {}", e))
+        })?;
+
+    Ok(Json(ListProjectTransfersResponse {
+        results: transfers.into_iter().map(to_api_response).collect(),
+    }))
+}
@@ -0,0 +1,32 @@
+use axum::{extract::State, Extension, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_transfer::{
+        list_project_transfers_dto::ListProjectTransfersResponse,
+        project_transfer_view_model::to_api_response,
+    },
+    shared::user::RequestUser,
+};
+
+use crate::api_context::ApiContext;
+
+// Pending transfers to the request user
+pub async fn list_user_transfers(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ListProjectTransfersResponse>, ApiError> {
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let transfers = context
+        .repo
+        .project_transfer
+        .list_pending_transfers(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list transfers: {}", e))
+        })?;
+
+    Ok(Json(ListProjectTransfersResponse {
+        results: transfers.into_iter().map(to_api_response).collect(),
+    }))
+}
@@ -0,0 +1,7 @@
+pub mod accept_project_transfer;
+pub mod cancel_project_transfer;
+pub mod create_project_transfer;
+pub mod decline_project_transfer;
+pub mod helpers;
+pub mod list_project_transfers;
+pub mod list_user_transfers;
@@ -0,0 +1,74 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_update::{
+        create_project_update_dto::CreateProjectUpdateDto,
+        project_update_view_model::{to_api_response, ProjectUpdateViewModel},
+    },
+    entity::project_update_entity::ProjectUpdateState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    db::project_update_repo::ProjectUpdateCreateProps,
+};
+
+use super::helpers::notify_update_posted;
+
+pub async fn create_project_update(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateProjectUpdateDto>,
+) -> Result<(StatusCode, Json<ProjectUpdateViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let project = verify_project_exist(&context, project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    let state = dto.state.unwrap_or(ProjectUpdateState::Draft);
+    let published_at = if state == ProjectUpdateState::Published {
+        Some(Utc::now())
+    } else {
+        None
+    };
+
+    let update = context
+        .repo
+        .project_update
+        .create_update(ProjectUpdateCreateProps {
+            project_id,
+            user_id,
+            title: dto.title,
+            body: dto.body,
+            state,
+            backers_only: dto.backers_only.unwrap_or(false),
+            published_at,
+        })
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create project update: {}", e))
+        })?;
+
+    if update.state == ProjectUpdateState::Published {
+        notify_update_posted(&context, &update).await;
+    }
+
+    Ok((
+        StatusCode::CREATED,
+        Json(to_api_response(update, false, true)),
+    ))
+}
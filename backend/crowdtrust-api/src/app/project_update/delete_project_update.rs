@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    Extension,
+};
+
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+};
+
+use super::helpers::verify_project_update_exist;
+
+pub async fn delete_project_update(
+    Path(update_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<(), ApiError> {
+    let update = verify_project_update_exist(&context, update_id).await?;
+    let project = verify_project_exist(&context, update.project_id).await?;
+
+    // Check if the requester is the owner of the project or an admin
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    // Call R2 endpoint to delete attachments. DB entries are removed with the update
+    for asset in update.assets.iter() {
+        context
+            .s3_client
+            .delete_project_asset(&asset.relative_url(project.id))
+            .await?;
+    }
+
+    context
+        .repo
+        .project_update
+        .delete_update_by_id(update_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to delete project update: {}", e))
+        })?;
+
+    Ok(())
+}
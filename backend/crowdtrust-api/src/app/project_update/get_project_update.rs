@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_update::project_update_view_model::{to_api_response, ProjectUpdateViewModel},
+    entity::project_update_entity::ProjectUpdateState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::project::helpers::get_visible_project};
+
+use super::helpers::{get_update_access, verify_project_update_exist};
+
+pub async fn get_project_update(
+    Path(update_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ProjectUpdateViewModel>, ApiError> {
+    let update = verify_project_update_exist(&context, update_id).await?;
+    let project = get_visible_project(&context, &request_user, update.project_id, None).await?;
+    let access = get_update_access(&context, &request_user, &project).await?;
+
+    // Drafts are only visible to the owner
+    if update.state == ProjectUpdateState::Draft && !access.is_owner {
+        return Err(ApiError::not_found().message("Project update not found"));
+    }
+
+    let locked = access.is_locked(&update);
+    Ok(Json(to_api_response(update, locked, access.is_owner)))
+}
@@ -0,0 +1,83 @@
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    entity::{
+        notification_entity::{NotificationData, NotificationKind},
+        project_entity::ProjectEntityRelations,
+        project_update_entity::ProjectUpdateEntity,
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::is_admin_or_user, notification::helpers::notify_project},
+    db::notification_repo::NotificationAudience,
+};
+
+pub async fn verify_project_update_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<ProjectUpdateEntity, ApiError> {
+    let update = context
+        .repo
+        .project_update
+        .get_update_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Project update not found"))?;
+    Ok(update)
+}
+
+// Followers only hear about backer-only updates if they're also backers
+pub async fn notify_update_posted(context: &ApiContext, update: &ProjectUpdateEntity) {
+    let audience = if update.backers_only {
+        NotificationAudience::Backers
+    } else {
+        NotificationAudience::FollowersAndBackers
+    };
+    notify_project(
+        context,
+        NotificationKind::UpdatePosted,
+        update.project_id,
+        NotificationData {
+            update_id: Some(update.id),
+            ..Default::default()
+        },
+        audience,
+    )
+    .await;
+}
+
+// Who can read project updates. Owners and Admins can read drafts,
+// owners, Admins and backers can read backer-only updates
+pub struct UpdateAccess {
+    pub is_owner: bool,
+    pub is_backer: bool,
+}
+
+impl UpdateAccess {
+    pub fn is_locked(&self, update: &ProjectUpdateEntity) -> bool {
+        update.backers_only && !self.is_owner && !self.is_backer
+    }
+}
+
+pub async fn get_update_access(
+    context: &ApiContext,
+    request_user: &RequestUser,
+    project: &ProjectEntityRelations,
+) -> Result<UpdateAccess, ApiError> {
+    let is_owner = is_admin_or_user(request_user, project.user_id);
+    let is_backer = match request_user.user_id {
+        Some(user_id) if !is_owner => context
+            .repo
+            .pledge
+            .has_backed_project(user_id, project.id)
+            .await
+            .map_err(|e| ApiError::internal_error().message(e))?,
+        _ => false,
+    };
+    Ok(UpdateAccess {
+        is_owner,
+        is_backer,
+    })
+}
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_types::{
+    dto::project_update::{
+        list_project_updates_dto::{ListProjectUpdatesQuery, ListProjectUpdatesResponse},
+        project_update_view_model::to_api_response,
+    },
+    entity::project_update_entity::ProjectUpdateState,
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::project::helpers::get_visible_project};
+
+use super::helpers::get_update_access;
+
+pub async fn list_project_updates(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    Query(query): Query<ListProjectUpdatesQuery>,
+) -> Result<Json<ListProjectUpdatesResponse>, ApiError> {
+    check_bad_form(query.validate())?;
+
+    let project = get_visible_project(&context, &request_user, project_id, None).await?;
+    let access = get_update_access(&context, &request_user, &project).await?;
+
+    // Only owners can list drafts
+    let state = if access.is_owner {
+        query.state
+    } else {
+        Some(ProjectUpdateState::Published)
+    };
+
+    let updates = context
+        .repo
+        .project_update
+        .list_updates(project_id, state, query.from, query.to)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list project updates: {}", e))
+        })?;
+
+    Ok(Json(ListProjectUpdatesResponse {
+        total: updates.total,
+        results: updates
+            .results
+            .into_iter()
+            .map(|update| {
+                let locked = access.is_locked(&update);
+                to_api_response(update, locked, access.is_owner)
+            })
+            .collect(),
+    }))
+}
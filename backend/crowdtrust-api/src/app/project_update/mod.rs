@@ -0,0 +1,6 @@
+pub mod create_project_update;
+pub mod delete_project_update;
+pub mod get_project_update;
+pub mod helpers;
+pub mod list_project_updates;
+pub mod update_project_update;
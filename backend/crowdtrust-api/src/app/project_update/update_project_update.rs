@@ -0,0 +1,78 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use lib_api::{
+    db::db_error::DbError,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::project_update::{
+        project_update_view_model::{to_api_response, ProjectUpdateViewModel},
+        update_project_update_dto::UpdateProjectUpdateDto,
+    },
+    entity::project_update_entity::ProjectUpdateState,
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    db::project_update_repo::ProjectUpdateUpdateProps,
+};
+
+use super::helpers::{notify_update_posted, verify_project_update_exist};
+
+pub async fn update_project_update(
+    Path(update_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UpdateProjectUpdateDto>,
+) -> Result<(StatusCode, Json<ProjectUpdateViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let update = verify_project_update_exist(&context, update_id).await?;
+    let project = verify_project_exist(&context, update.project_id).await?;
+    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+
+    // The publish date is kept if an update is unpublished and published again
+    let published_at =
+        if dto.state == Some(ProjectUpdateState::Published) && update.published_at.is_none() {
+            Some(Utc::now())
+        } else {
+            None
+        };
+
+    let first_publish = published_at.is_some();
+    let props = ProjectUpdateUpdateProps {
+        title: dto.title,
+        body: dto.body,
+        state: dto.state,
+        backers_only: dto.backers_only,
+        published_at,
+    };
+
+    let result = context
+        .repo
+        .project_update
+        .update_update(update_id, props)
+        .await
+        .map_err(|e| match e {
+            DbError::NoUpdate => ApiError::bad_request()
+                .code(ApiErrorCode::InvalidFormData)
+                .message("No fields to update"),
+            _ => ApiError::internal_error()
+                .message(format!("Failed to update project update: {}", e)),
+        })?;
+
+    if first_publish {
+        notify_update_posted(&context, &result).await;
+    }
+
+    Ok((StatusCode::OK, Json(to_api_response(result, false, true))))
+}
@@ -0,0 +1,18 @@
+use lib_api::error::api_error::ApiError;
+use lib_types::entity::project_update_entity::ProjectUpdateAssetEntity;
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+pub async fn verify_project_update_asset_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<ProjectUpdateAssetEntity, ApiError> {
+    let asset = context
+        .repo
+        .project_update_asset
+        .get_update_asset_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Project update asset not found"))?;
+    Ok(asset)
+}
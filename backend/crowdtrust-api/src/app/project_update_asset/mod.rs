@@ -0,0 +1,4 @@
+pub mod create_project_update_asset;
+pub mod delete_project_update_asset;
+pub mod helpers;
+pub mod verify_project_update_asset;
@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use chrono::Utc;
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::project_update_asset::verify_project_update_asset_dto::VerifyProjectUpdateAssetResponse,
+    shared::{asset::AssetState, user::RequestUser},
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, project_update::helpers::verify_project_update_exist},
+};
+
+use super::helpers::verify_project_update_asset_exist;
+
+pub async fn verify_project_update_asset(
+    Path(asset_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<Json<VerifyProjectUpdateAssetResponse>, ApiError> {
+    let update_asset = verify_project_update_asset_exist(&context, asset_id).await?;
+    let update = verify_project_update_exist(&context, update_asset.update_id).await?;
+
+    // Check if the asset belongs to the current user
+    verify_admin_or_user(&request_user, update_asset.user_id.to_string())?;
+
+    // Perform a HEAD request to check if the object exists
+    let exists = context
+        .s3_client
+        .verify_project_asset(&update_asset.relative_url(update.project_id))
+        .await?;
+
+    // Determine the state based on the verification result and expiration time
+    let now = Utc::now();
+    let state = if exists {
+        AssetState::Uploaded
+    } else if now > update_asset.upload_expires_at {
+        AssetState::Expired
+    } else {
+        AssetState::Created
+    };
+
+    context
+        .repo
+        .project_update_asset
+        .update_update_asset_state(asset_id, state)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error()
+                .message(format!("Failed to update project update asset: {}", e))
+        })?;
+
+    Ok(Json(VerifyProjectUpdateAssetResponse { verified: exists }))
+}
@@ -3,21 +3,28 @@ use axum::http::StatusCode;
 use axum::{Extension, Json};
 use axum_macros::debug_handler;
 use chrono::Utc;
+use lib_api::db::util::commit_or_rollback;
 use lib_api::error::api_error::ApiError;
 use lib_api::error::helpers::check_bad_form;
+use lib_api::util::conversion::str_to_uuid;
 use lib_api::util::json_extractor::CtJson;
 use lib_types::dto::reward::create_reward_dto::{CreateRewardDto, CreateRewardResponse};
+use lib_types::entity::project_member_entity::ProjectPermission;
 use lib_types::entity::reward_entity::RewardEntity;
 use lib_types::shared::api_error::ApiErrorCode;
+use lib_types::shared::terms::RewardTerms;
 use lib_types::shared::user::RequestUser;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::api_context::ApiContext;
-use crate::app::helpers::{get_request_user, str_to_bigdecimal, verify_admin_or_user};
-use crate::app::project::helpers::verify_project_exist;
+use crate::app::helpers::{get_request_user, str_to_bigdecimal};
+use crate::app::project::helpers::{verify_project_exist, verify_project_permission};
+use crate::app::project_revision::helpers::record_revision_tx;
 use crate::db::reward_repo::RewardCreateProps;
 
+use super::helpers::{to_price_tiers, to_reward_variants, to_shipping_costs, verify_reward_exist};
+
 fn to_api_response(result: RewardEntity) -> Json<CreateRewardResponse> {
     return Json(CreateRewardResponse { id: result.id });
 }
@@ -32,8 +39,16 @@ pub async fn create_reward(
     check_bad_form(dto.validate())?;
     let project = verify_project_exist(&context, project_id).await?;
 
-    // Check if the requester is the owner of the reward or an admin
-    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
 
     // Verify delivery_time is after now
     if dto.delivery_time < Utc::now().timestamp() {
@@ -42,6 +57,22 @@ pub async fn create_reward(
             .message("Delivery time must be after current time"));
     }
     let price = str_to_bigdecimal(&dto.price, "price")?;
+    let shipping_costs = to_shipping_costs(dto.shipping_costs)?;
+    let variants = to_reward_variants(dto.variants, &[])?;
+    let price_tiers = to_price_tiers(dto.price_tiers, &[])?;
+
+    // Verify add-on parent is a base reward in the same project
+    let parent_reward_id = if let Some(parent_reward_id) = dto.parent_reward_id {
+        let parent = verify_reward_exist(&context, str_to_uuid(&parent_reward_id)?).await?;
+        if parent.project_id != project_id || parent.parent_reward_id.is_some() {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::RewardAddon)
+                .message("Add-on parent must be a base reward in the same project"));
+        }
+        Some(parent.id)
+    } else {
+        None
+    };
 
     let props = RewardCreateProps {
         project_id,
@@ -50,19 +81,37 @@ pub async fn create_reward(
         price,
         delivery_time: dto.delivery_time,
         backer_limit: dto.backer_limit,
+        shipping_required: dto.shipping_required,
+        shipping_costs,
+        variants,
+        price_tiers,
+        parent_reward_id,
     };
 
-    let reward_id = context
+    let mut tx = context.repo.start_transaction().await?;
+
+    let reward = context
         .repo
         .reward
-        .create_reward(props)
+        .create_reward_tx(&mut tx, props)
         .await
         .map_err(|e| match e {
             _ => ApiError::internal_error().message(format!("Failed to create reward: {}", e)),
         })?;
 
-    Ok((
-        StatusCode::CREATED,
-        Json(CreateRewardResponse { id: reward_id }),
-    ))
+    // Record the initial reward terms
+    record_revision_tx(
+        &context,
+        &mut tx,
+        project_id,
+        Some(reward.id),
+        user_id,
+        None,
+        Some(&RewardTerms::from(&reward)),
+    )
+    .await?;
+
+    commit_or_rollback(tx, Ok(())).await?;
+
+    Ok((StatusCode::CREATED, to_api_response(reward)))
 }
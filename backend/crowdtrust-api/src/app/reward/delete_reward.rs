@@ -3,13 +3,20 @@ use axum::{
     Extension,
 };
 
-use lib_api::error::api_error::ApiError;
-use lib_types::shared::user::RequestUser;
+use lib_api::{db::util::commit_or_rollback, error::api_error::ApiError};
+use lib_types::{
+    dto::reward::list_rewards_dto::ListRewardsQuery,
+    entity::project_member_entity::ProjectPermission,
+    shared::{api_error::ApiErrorCode, terms::RewardTerms, user::RequestUser},
+};
 use uuid::Uuid;
 
 use crate::{
     api_context::ApiContext,
-    app::{helpers::verify_admin_or_user, project::helpers::verify_project_exist},
+    app::{
+        project::helpers::{verify_project_exist, verify_project_permission},
+        project_revision::helpers::record_revision_tx,
+    },
 };
 
 use super::helpers::verify_reward_exist;
@@ -23,18 +30,67 @@ pub async fn delete_reward(
     let reward = verify_reward_exist(&context, reward_id).await?;
     let project = verify_project_exist(&context, reward.project_id).await?;
 
-    // Check if the requester is the owner of the reward or an admin
-    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+
+    // Add-ons must be deleted before their parent reward
+    let addons = context
+        .repo
+        .reward
+        .list_rewards(
+            project.id,
+            vec![],
+            ListRewardsQuery {
+                visible: None,
+                available: None,
+                addons: Some(true),
+                column: None,
+                direction: None,
+            },
+        )
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list rewards: {}", e))
+        })?;
+    if addons.iter().any(|r| r.parent_reward_id == Some(reward_id)) {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::RewardAddon)
+            .message("Cannot delete a reward with add-ons"));
+    }
+
+    let mut tx = context.repo.start_transaction().await?;
 
     // Remove the entry from the database
     context
         .repo
         .reward
-        .delete_reward_by_id(reward_id)
+        .delete_reward_by_id_tx(&mut tx, reward_id)
         .await
         .map_err(|e| {
             ApiError::internal_error().message(format!("Failed to delete reward: {}", e))
         })?;
 
+    // Record the deleted reward terms
+    record_revision_tx(
+        &context,
+        &mut tx,
+        project.id,
+        Some(reward_id),
+        user_id,
+        Some(&RewardTerms::from(&reward)),
+        None,
+    )
+    .await?;
+
+    commit_or_rollback(tx, Ok(())).await?;
+
     Ok(())
 }
@@ -1,8 +1,12 @@
-use lib_api::error::api_error::ApiError;
-use lib_types::entity::reward_entity::RewardEntity;
+use lib_api::{error::api_error::ApiError, util::conversion::str_to_uuid};
+use lib_types::{
+    dto::reward::create_reward_dto::{PriceTierDto, RewardVariantDto, ShippingCostDto},
+    entity::reward_entity::{PriceTier, RewardEntity, RewardVariant, ShippingCost},
+    shared::api_error::ApiErrorCode,
+};
 use uuid::Uuid;
 
-use crate::api_context::ApiContext;
+use crate::{api_context::ApiContext, app::helpers::str_to_bigdecimal};
 
 pub async fn verify_reward_exist(context: &ApiContext, id: Uuid) -> Result<RewardEntity, ApiError> {
     let asset = context
@@ -13,3 +17,93 @@ pub async fn verify_reward_exist(context: &ApiContext, id: Uuid) -> Result<Rewar
         .map_err(|_| ApiError::not_found().message("Reward not found"))?;
     Ok(asset)
 }
+
+pub fn to_shipping_costs(costs: Vec<ShippingCostDto>) -> Result<Vec<ShippingCost>, ApiError> {
+    costs
+        .into_iter()
+        .map(|c| {
+            Ok(ShippingCost {
+                region: c.region,
+                cost: str_to_bigdecimal(&c.cost, "shipping cost")?,
+            })
+        })
+        .collect()
+}
+
+// Converts variant DTOs, keeping the backer count of existing variants
+pub fn to_reward_variants(
+    variants: Vec<RewardVariantDto>,
+    existing: &[RewardVariant],
+) -> Result<Vec<RewardVariant>, ApiError> {
+    variants
+        .into_iter()
+        .map(|v| {
+            let price = if let Some(price) = v.price {
+                Some(str_to_bigdecimal(&price, "variant price")?)
+            } else {
+                None
+            };
+            let (id, backer_count) = if let Some(id) = v.id {
+                let id = str_to_uuid(&id)?;
+                let current = existing.iter().find(|e| e.id == id).ok_or(
+                    ApiError::bad_request()
+                        .code(ApiErrorCode::RewardVariant)
+                        .message("Reward variant not found"),
+                )?;
+                (id, current.backer_count)
+            } else {
+                (Uuid::new_v4(), 0)
+            };
+            if v.backer_limit < backer_count {
+                return Err(ApiError::bad_request()
+                    .code(ApiErrorCode::RewardVariant)
+                    .message("Variant backer limit is below backer count"));
+            }
+            Ok(RewardVariant {
+                id,
+                name: v.name,
+                price,
+                backer_limit: v.backer_limit,
+                backer_count,
+            })
+        })
+        .collect()
+}
+
+// Converts price tier DTOs, keeping the backer count of existing tiers
+pub fn to_price_tiers(
+    tiers: Vec<PriceTierDto>,
+    existing: &[PriceTier],
+) -> Result<Vec<PriceTier>, ApiError> {
+    tiers
+        .into_iter()
+        .map(|t| {
+            if let (Some(starts_at), Some(ends_at)) = (t.starts_at, t.ends_at) {
+                if ends_at <= starts_at {
+                    return Err(ApiError::bad_request()
+                        .code(ApiErrorCode::RewardPriceTier)
+                        .message("Price tier must end after it starts"));
+                }
+            }
+            let (id, backer_count) = if let Some(id) = t.id {
+                let id = str_to_uuid(&id)?;
+                let current = existing.iter().find(|e| e.id == id).ok_or(
+                    ApiError::bad_request()
+                        .code(ApiErrorCode::RewardPriceTier)
+                        .message("Price tier not found"),
+                )?;
+                (id, current.backer_count)
+            } else {
+                (Uuid::new_v4(), 0)
+            };
+            Ok(PriceTier {
+                id,
+                price: str_to_bigdecimal(&t.price, "price tier")?,
+                starts_at: t.starts_at,
+                ends_at: t.ends_at,
+                backer_limit: t.backer_limit,
+                backer_count,
+            })
+        })
+        .collect()
+}
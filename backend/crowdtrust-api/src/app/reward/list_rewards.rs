@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
+use lib_types::{
+    dto::reward::{
+        list_rewards_dto::{ListRewardsQuery, ListRewardsResponse},
+        reward_view_model::to_api_response,
+    },
+    entity::project_member_entity::ProjectPermission,
+    shared::{project::ProjectStatus, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::{
+        project::helpers::{has_project_permission, verify_project_exist},
+        Qs,
+    },
+};
+
+pub async fn list_rewards(
+    Path(project_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Qs(query): Qs<ListRewardsQuery>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<ListRewardsResponse>, ApiError> {
+    check_bad_form(query.validate())?;
+    let project = verify_project_exist(&context, project_id).await?;
+
+    let can_view = has_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::View,
+    )
+    .await?;
+    let published = matches!(
+        project.status,
+        ProjectStatus::Active | ProjectStatus::Complete | ProjectStatus::Prelaunch
+    );
+    if !published && !can_view {
+        return Err(ApiError::forbidden());
+    }
+
+    // Only the owner and collaborators can see hidden rewards
+    let validated_query = ListRewardsQuery {
+        visible: if can_view { query.visible } else { Some(true) },
+        available: query.available,
+        addons: query.addons,
+        column: query.column,
+        direction: query.direction,
+    };
+
+    let rewards = context
+        .repo
+        .reward
+        .list_rewards(project_id, project.rewards_order, validated_query)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list rewards: {}", e))
+        })?;
+
+    Ok(Json(ListRewardsResponse {
+        results: rewards.into_iter().map(to_api_response).collect(),
+    }))
+}
@@ -1,4 +1,5 @@
 pub mod create_reward;
 pub mod delete_reward;
 pub mod helpers;
+pub mod list_rewards;
 pub mod update_reward;
@@ -2,23 +2,29 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Extension;
 use chrono::Utc;
+use lib_api::db::util::commit_or_rollback;
 use lib_api::error::api_error::ApiError;
 
 use lib_api::error::helpers::check_bad_form;
 use lib_api::util::json_extractor::CtJson;
 use lib_types::dto::reward::update_reward_dto::UpdateRewardDto;
+use lib_types::entity::project_member_entity::ProjectPermission;
 use lib_types::shared::api_error::ApiErrorCode;
 use lib_types::shared::project::ProjectStatus;
+use lib_types::shared::terms::RewardTerms;
 use lib_types::shared::user::{RequestUser, UserType};
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::api_context::ApiContext;
 
-use crate::app::helpers::{str_to_bigdecimal, verify_admin_or_user};
-use crate::app::project::helpers::verify_project_exist;
+use crate::app::helpers::str_to_bigdecimal;
+use crate::app::project::helpers::{verify_project_exist, verify_project_permission};
+use crate::app::project_revision::helpers::record_revision_tx;
 use crate::db::reward_repo::RewardUpdateProps;
 
+use super::helpers::{to_price_tiers, to_reward_variants, to_shipping_costs};
+
 pub async fn update_reward(
     Path(reward_id): Path<Uuid>,
     State(context): State<ApiContext>,
@@ -46,14 +52,54 @@ pub async fn update_reward(
     );
 
     // Verify request
-    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     let price = if let Some(price) = dto.price {
         Some(str_to_bigdecimal(&price, "price")?)
     } else {
         None
     };
+    let shipping_costs = if let Some(costs) = dto.shipping_costs {
+        Some(to_shipping_costs(costs)?)
+    } else {
+        None
+    };
+    let variants = if let Some(variants) = dto.variants {
+        Some(to_reward_variants(
+            variants,
+            &reward_to_be_updated.variants,
+        )?)
+    } else {
+        None
+    };
+    let price_tiers = if let Some(price_tiers) = dto.price_tiers {
+        Some(to_price_tiers(
+            price_tiers,
+            &reward_to_be_updated.price_tiers,
+        )?)
+    } else {
+        None
+    };
     if !is_admin {
+        // Verify active reward price tiers can't change
+        if price_tiers.is_some() && is_active {
+            return Err(ApiError::bad_request().code(ApiErrorCode::ProjectActive));
+        }
+        // Verify active reward variants can't change
+        if variants.is_some() && is_active {
+            return Err(ApiError::bad_request().code(ApiErrorCode::ProjectActive));
+        }
+        // Verify active reward shipping can't change
+        if (dto.shipping_required.is_some() || shipping_costs.is_some()) && is_active {
+            return Err(ApiError::bad_request().code(ApiErrorCode::ProjectActive));
+        }
         // Verify active reward name can't change
         if let Some(_) = dto.name {
             if is_active {
@@ -82,6 +128,30 @@ pub async fn update_reward(
         }
     }
 
+    // Reward terms after the update, for the revision history
+    let old_terms = RewardTerms::from(&reward_to_be_updated);
+    let new_terms = RewardTerms {
+        name: dto.name.clone().unwrap_or(old_terms.name.clone()),
+        description: dto
+            .description
+            .clone()
+            .unwrap_or(old_terms.description.clone()),
+        delivery_time: dto.delivery_time.unwrap_or(old_terms.delivery_time),
+        price: price.clone().unwrap_or(old_terms.price.clone()),
+        backer_limit: dto
+            .backer_limit
+            .map(|limit| limit as i32)
+            .unwrap_or(old_terms.backer_limit),
+        visible: dto.visible.unwrap_or(old_terms.visible),
+        shipping_required: dto.shipping_required.unwrap_or(old_terms.shipping_required),
+        shipping_costs: shipping_costs
+            .clone()
+            .unwrap_or(old_terms.shipping_costs.clone()),
+        variants: variants.clone().unwrap_or(old_terms.variants.clone()),
+        price_tiers: price_tiers.clone().unwrap_or(old_terms.price_tiers.clone()),
+        ..old_terms.clone()
+    };
+
     let props = RewardUpdateProps {
         name: dto.name,
         description: dto.description,
@@ -90,18 +160,39 @@ pub async fn update_reward(
         backer_count: None,
         backer_limit: dto.backer_limit,
         visible: dto.visible,
+        shipping_required: dto.shipping_required,
+        shipping_costs,
+        variants,
+        price_tiers,
     };
 
+    let user_id = request_user.user_id.ok_or(ApiError::unauthorized())?;
+    let mut tx = context.repo.start_transaction().await?;
+
     // Update reward
     context
         .repo
         .reward
-        .update_reward(reward_id, props)
+        .update_reward_tx(&mut tx, reward_id, props)
         .await
         .map_err(|e| match e {
             _ => ApiError::internal_error().message(format!("Failed to update reward: {}", e)),
         })?;
 
+    // Record changed reward terms
+    record_revision_tx(
+        &context,
+        &mut tx,
+        project_id,
+        Some(reward_id),
+        user_id,
+        Some(&old_terms),
+        Some(&new_terms),
+    )
+    .await?;
+
+    commit_or_rollback(tx, Ok(())).await?;
+
     // Return response
     Ok((StatusCode::OK, ()))
 }
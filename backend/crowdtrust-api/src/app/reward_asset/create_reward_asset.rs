@@ -10,6 +10,7 @@ use lib_types::{
         project_asset::create_project_asset_dto::CreateProjectAssetResponse,
         reward_asset::create_reward_asset_dto::{to_api_response, CreateRewardAssetDto},
     },
+    entity::project_member_entity::ProjectPermission,
     shared::{asset::AssetState, user::RequestUser},
 };
 use validator::Validate;
@@ -17,7 +18,7 @@ use validator::Validate;
 use crate::{
     api_context::ApiContext,
     app::{
-        helpers::verify_admin_or_user, project::helpers::verify_project_exist,
+        project::helpers::{verify_project_exist, verify_project_permission},
         reward::helpers::verify_reward_exist,
     },
     db::reward_asset_repo::RewardAssetEntityProps,
@@ -36,8 +37,15 @@ pub async fn create_reward_asset(
     let reward = verify_reward_exist(&context, reward_id.clone()).await?;
     let project = verify_project_exist(&context, reward.project_id).await?;
 
-    // Check if the requester is the owner of the reward or an admin
-    verify_admin_or_user(&request_user, project.user_id.to_string())?;
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     let content_type = dto.content_type.to_string();
 
@@ -4,12 +4,15 @@ use axum::{
 };
 
 use lib_api::error::api_error::ApiError;
-use lib_types::shared::user::RequestUser;
+use lib_types::{entity::project_member_entity::ProjectPermission, shared::user::RequestUser};
 use uuid::Uuid;
 
 use crate::{
     api_context::ApiContext,
-    app::{helpers::verify_admin_or_user, reward::helpers::verify_reward_exist},
+    app::{
+        project::helpers::{verify_project_exist, verify_project_permission},
+        reward::helpers::verify_reward_exist,
+    },
 };
 
 use super::helpers::verify_reward_asset_exist;
@@ -23,8 +26,17 @@ pub async fn delete_reward_asset(
     let asset = verify_reward_asset_exist(&context, asset_id).await?;
     let reward = verify_reward_exist(&context, asset.reward_id).await?;
 
-    // Check if the requester is the owner of the asset or an admin
-    verify_admin_or_user(&request_user, asset.user_id.to_string())?;
+    let project = verify_project_exist(&context, reward.project_id).await?;
+
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     // Call R2 endpoint to delete the asset
     context
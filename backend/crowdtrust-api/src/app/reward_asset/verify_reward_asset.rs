@@ -6,13 +6,17 @@ use chrono::Utc;
 use lib_api::error::api_error::ApiError;
 use lib_types::{
     dto::reward_asset::verify_reward_asset_dto::VerifyRewardAssetResponse,
+    entity::project_member_entity::ProjectPermission,
     shared::{asset::AssetState, user::RequestUser},
 };
 use uuid::Uuid;
 
 use crate::{
     api_context::ApiContext,
-    app::{helpers::verify_admin_or_user, reward::helpers::verify_reward_exist},
+    app::{
+        project::helpers::{verify_project_exist, verify_project_permission},
+        reward::helpers::verify_reward_exist,
+    },
 };
 
 use super::helpers::verify_reward_asset_exist;
@@ -25,8 +29,17 @@ pub async fn verify_reward_asset(
     let reward_asset = verify_reward_asset_exist(&context, asset_id).await?;
     let reward = verify_reward_exist(&context, reward_asset.reward_id).await?;
 
-    // Check if the asset belongs to the current user
-    verify_admin_or_user(&request_user, reward_asset.user_id.to_string())?;
+    let project = verify_project_exist(&context, reward.project_id).await?;
+
+    // Check if the requester can edit the project
+    verify_project_permission(
+        &context,
+        &request_user,
+        project.id,
+        project.user_id,
+        ProjectPermission::Edit,
+    )
+    .await?;
 
     // Perform a HEAD request to check if the object exists
     let exists = context
@@ -0,0 +1,31 @@
+use axum::{extract::State, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::dto::user::compute_user_reputations_dto::ComputeUserReputationsResponse;
+
+use crate::api_context::ApiContext;
+
+// Stores a new reputation snapshot for each creator with a completed project.
+// Run periodically by a scheduled job
+pub async fn compute_user_reputations(
+    State(context): State<ApiContext>,
+) -> Result<Json<ComputeUserReputationsResponse>, ApiError> {
+    let stats = context
+        .repo
+        .user_reputation
+        .list_reputation_stats()
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to compute reputations: {}", e))
+        })?;
+
+    let computed = context
+        .repo
+        .user_reputation
+        .create_reputations(stats)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to save reputations: {}", e))
+        })?;
+
+    Ok(Json(ComputeUserReputationsResponse { computed }))
+}
@@ -0,0 +1,105 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::{
+    db::password::verify,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::user::delete_user_dto::DeleteUserDto,
+    shared::{
+        api_error::ApiErrorCode,
+        user::{RequestUser, UserStatus, UserType},
+    },
+};
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::helpers::{not_found_or_internal, verify_admin_or_user},
+};
+
+// Deletes an account by anonymizing it. Pledges and projects are kept for project accounting
+pub async fn delete_user(
+    Path(user_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<DeleteUserDto>,
+) -> Result<StatusCode, ApiError> {
+    check_bad_form(dto.validate())?;
+    verify_admin_or_user(&request_user, user_id.to_string())?;
+
+    let user = context
+        .repo
+        .user
+        .get_user_by_id(user_id)
+        .await
+        .map_err(not_found_or_internal)?;
+    if user.user_status == UserStatus::Removed {
+        return Err(ApiError::not_found().message(format!("User with ID {} not found", user_id)));
+    }
+
+    // Users confirm with their password
+    if request_user.user_type == UserType::User {
+        let password = dto.password.ok_or(
+            ApiError::bad_request()
+                .code(ApiErrorCode::InvalidFormData)
+                .message("Password required"),
+        )?;
+        let verified = verify(password, &user.password_hash)
+            .map_err(|_| ApiError::internal_error().message("Password verification failed"))?;
+        if !verified {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::InvalidOldPassword)
+                .message("Invalid password"));
+        }
+    }
+
+    // Backers of open projects still depend on the creator
+    let open_projects = context
+        .repo
+        .project
+        .has_open_projects(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get projects: {}", e))
+        })?;
+    if open_projects {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::ProjectActive)
+            .message("Complete or transfer open projects before deleting the account"));
+    }
+
+    let assets = context
+        .repo
+        .user_asset
+        .list_user_assets(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get user assets: {}", e))
+        })?;
+
+    context
+        .repo
+        .user
+        .anonymize_user(user_id)
+        .await
+        .map_err(|e| ApiError::internal_error().message(format!("Failed to delete user: {}", e)))?;
+
+    for asset in assets.iter() {
+        if let Err(e) = context
+            .s3_client
+            .delete_project_asset(&asset.relative_url())
+            .await
+        {
+            error!("Failed to delete user asset {}: {}", asset.id, e);
+        }
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
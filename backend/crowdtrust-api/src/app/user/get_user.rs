@@ -23,18 +23,26 @@ pub async fn get_user(
         .get_user_by_id(id)
         .await
         .map_err(not_found_or_internal)?;
+    let reputation = context
+        .repo
+        .user_reputation
+        .get_reputation(id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get reputation: {}", e))
+        })?;
 
     // Return full user object if requester is admin or current user
     if request_user.user_type == UserType::User || request_user.user_type == UserType::Anonymous {
         if let Some(request_user_id) = request_user.user_id {
             if request_user_id == id {
-                return Ok(Json(to_api_response_private(user)).into_response());
+                return Ok(Json(to_api_response_private(user, reputation)).into_response());
             }
         }
     } else if request_user.user_type == UserType::Admin || request_user.user_type == UserType::Cron
     {
-        return Ok(Json(to_api_response_private(user)).into_response());
+        return Ok(Json(to_api_response_private(user, reputation)).into_response());
     }
 
-    Ok(Json(to_api_response(user)).into_response())
+    Ok(Json(to_api_response(user, reputation)).into_response())
 }
@@ -0,0 +1,100 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::{
+        project::list_projects_dto::{ListProjectsQuery, ProjectSortColumn},
+        sort_direction::SortDirection,
+        user::user_profile_dto::{to_api_response, UserProfileResponse},
+    },
+    shared::{project::ProjectStatus, user::UserStatus},
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::not_found_or_internal};
+
+const PROFILE_PROJECTS_LIMIT: i32 = 50;
+
+// Public profile of a creator. Only lists projects in visible statuses
+pub async fn get_user_profile(
+    Path(id): Path<Uuid>,
+    State(context): State<ApiContext>,
+) -> Result<Json<UserProfileResponse>, ApiError> {
+    let user = context
+        .repo
+        .user
+        .get_user_by_id(id)
+        .await
+        .map_err(not_found_or_internal)?;
+    if user.user_status != UserStatus::Active {
+        return Err(ApiError::not_found().message(format!("User with ID {} not found", id)));
+    }
+
+    let projects = context
+        .repo
+        .project
+        .list_projects(
+            ListProjectsQuery {
+                from: 1,
+                to: PROFILE_PROJECTS_LIMIT,
+                cursor: None,
+                limit: None,
+                include_total: None,
+                statuses: Some(vec![
+                    ProjectStatus::Prelaunch,
+                    ProjectStatus::Active,
+                    ProjectStatus::Complete,
+                ]),
+                categories: None,
+                user_id: Some(id.to_string()),
+                q: None,
+                funded: None,
+                live: None,
+                min_percent_funded: None,
+                max_percent_funded: None,
+                trending_days: None,
+                column: Some(ProjectSortColumn::CreatedAt),
+                direction: Some(SortDirection::Desc),
+            },
+            None,
+        )
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list projects: {}", e))
+        })?;
+
+    let backed_count = if user.show_backed_count {
+        Some(
+            context
+                .repo
+                .pledge
+                .count_backed_projects(id)
+                .await
+                .map_err(|e| {
+                    ApiError::internal_error().message(format!("Failed to count pledges: {}", e))
+                })?,
+        )
+    } else {
+        None
+    };
+
+    let reputation = context
+        .repo
+        .user_reputation
+        .get_reputation(id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to get reputation: {}", e))
+        })?;
+
+    let project_count = projects.total.unwrap_or(projects.results.len() as i64);
+    Ok(Json(to_api_response(
+        user,
+        projects.results,
+        project_count,
+        backed_count,
+        reputation,
+    )))
+}
@@ -2,22 +2,29 @@ use axum::{
     extract::{Query, State},
     Json,
 };
-use lib_api::error::api_error::ApiError;
+use lib_api::error::{api_error::ApiError, helpers::check_bad_form};
 use lib_types::dto::user::{
     list_users_dto::{ListUsersQuery, ListUsersResponse},
     user_view_model::{to_api_response, UserViewModel},
 };
 
-use crate::api_context::ApiContext;
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::helpers::to_cursor_page};
 
 pub async fn list_users(
     State(context): State<ApiContext>,
     Query(query): Query<ListUsersQuery>,
 ) -> Result<Json<ListUsersResponse>, ApiError> {
-    let users =
-        context.repo.user.list_users(query).await.map_err(|e| {
-            ApiError::internal_error().message(format!("Failed to list users: {}", e))
-        })?;
+    check_bad_form(query.validate())?;
+    let page = to_cursor_page(query.cursor.clone(), query.limit, query.include_total)?;
+
+    let users = context
+        .repo
+        .user
+        .list_users(query, page)
+        .await
+        .map_err(|e| ApiError::internal_error().message(format!("Failed to list users: {}", e)))?;
 
     let view_models: Vec<UserViewModel> = users
         .results
@@ -27,6 +34,7 @@ pub async fn list_users(
 
     Ok(Json(ListUsersResponse {
         total: users.total,
+        next_cursor: users.next_cursor,
         results: view_models,
     }))
 }
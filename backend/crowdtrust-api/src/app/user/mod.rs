@@ -1,4 +1,7 @@
+pub mod compute_user_reputations;
+pub mod delete_user;
 pub mod get_user;
+pub mod get_user_profile;
 pub mod list_users;
 pub mod register_user;
 pub mod update_user;
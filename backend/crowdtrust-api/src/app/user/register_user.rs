@@ -14,6 +14,16 @@ use validator::Validate;
 
 use crate::api_context::ApiContext;
 use crate::app::auth::helpers::send_welcome_email;
+use crate::db::user_wallet_repo::UserWalletProps;
+
+fn map_create_user_err(e: DbError) -> ApiError {
+    match e {
+        DbError::Unique(_) => ApiError::bad_request()
+            .code(ApiErrorCode::UserExists)
+            .message("User with email or eth_address already exists"),
+        _ => ApiError::internal_error().message(format!("Failed to create user: {}", e)),
+    }
+}
 
 fn to_api_response(result: UserCreateResult) -> Json<RegisterUserResponse> {
     return Json(RegisterUserResponse { id: result.id });
@@ -47,6 +57,9 @@ pub async fn register_user(
             .message("Failed to verify signature"));
     }
 
+    let wallet_address = dto.eth_address.clone();
+    let wallet_signature = dto.eth_address_signature.clone();
+
     // Start an sqlx transaction
     let mut tx = context.repo.start_transaction().await?;
 
@@ -55,12 +68,23 @@ pub async fn register_user(
         .user
         .create_user(&mut tx, dto)
         .await
-        .map_err(|e| match e {
-            DbError::Unique(_) => ApiError::bad_request()
-                .code(ApiErrorCode::UserExists)
-                .message("User with email or eth_address already exists"),
-            _ => ApiError::internal_error().message(format!("Failed to create user: {}", e)),
-        })?;
+        .map_err(map_create_user_err)?;
+
+    // The registration address is the primary wallet
+    context
+        .repo
+        .user_wallet
+        .create_primary_wallet_tx(
+            &mut tx,
+            UserWalletProps {
+                user_id: user_result.id,
+                eth_address: wallet_address,
+                signature: wallet_signature,
+                is_primary: true,
+            },
+        )
+        .await
+        .map_err(map_create_user_err)?;
 
     commit_or_rollback(tx, Ok(())).await?;
 
@@ -1,7 +1,10 @@
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::{Extension, Json};
+use chrono::Utc;
+use lib_api::db::db_error::DbError;
 use lib_api::db::password::verify;
+use lib_api::db::util::commit_or_rollback;
 use lib_api::error::api_error::ApiError;
 
 use lib_api::error::helpers::check_bad_form;
@@ -17,8 +20,11 @@ use validator::Validate;
 
 use crate::api_context::ApiContext;
 
-use crate::app::auth::helpers::resend_confirm_email;
 use crate::app::helpers::verify_admin_or_user;
+use crate::db::user_wallet_repo::UserWalletProps;
+
+// Max lifetime of a signed address switch, in seconds
+pub const ETH_ADDRESS_SIGNATURE_TTL: i64 = 600;
 
 pub async fn update_user(
     Path(user_id): Path<Uuid>,
@@ -27,7 +33,6 @@ pub async fn update_user(
     CtJson(dto): CtJson<UpdateUserDto>,
 ) -> Result<(StatusCode, Json<UserViewModel>), ApiError> {
     check_bad_form(dto.validate())?;
-    let has_new_email = dto.email.is_some();
 
     // Get user to be updated
     let user_to_be_updated = context
@@ -49,28 +54,14 @@ pub async fn update_user(
     }
 
     // Verify request
-    // Verify new email is unique
-    if let Some(new_email) = &dto.email {
-        if context
-            .repo
-            .user
-            .find_user_by_email(new_email.to_string())
-            .await
-            .is_ok()
-        {
-            return Err(ApiError::bad_request()
-                .code(ApiErrorCode::UserExists)
-                .message(format!("Email {} already exists", new_email)));
-        }
-    }
-    // Verify eth_address is unique and matches signature
+    // Verify eth_address isn't linked to another user and matches signature
     if let Some(new_eth_address) = &dto.eth_address {
         if context
             .repo
-            .user
-            .find_user_by_eth_address(new_eth_address.to_string())
+            .user_wallet
+            .find_wallet_by_eth_address(new_eth_address.to_string())
             .await
-            .is_ok()
+            .is_ok_and(|wallet| wallet.user_id != user_id)
         {
             return Err(ApiError::bad_request()
                 .code(ApiErrorCode::UserExists)
@@ -78,7 +69,21 @@ pub async fn update_user(
         }
         // Verify signature
         if let Some(signature) = &dto.eth_address_signature {
-            let message = format!("Switch CrowdTrust address to {}", new_eth_address);
+            let expires_at = dto.signature_expires_at.ok_or(
+                ApiError::bad_request()
+                    .code(ApiErrorCode::InvalidFormData)
+                    .message("Signature expiry required"),
+            )?;
+            let now = Utc::now().timestamp();
+            if expires_at < now || expires_at > now + ETH_ADDRESS_SIGNATURE_TTL {
+                return Err(ApiError::bad_request()
+                    .code(ApiErrorCode::ConfirmExpired)
+                    .message("Signature expired"));
+            }
+            let message = format!(
+                "Switch CrowdTrust account {} address to {}, valid until {}",
+                user_id, new_eth_address, expires_at
+            );
             if !verify_signature(signature.clone(), message, new_eth_address.clone())? {
                 return Err(ApiError::bad_request()
                     .code(ApiErrorCode::InvalidSignature)
@@ -107,26 +112,49 @@ pub async fn update_user(
         }
     }
 
+    // The wallet and the user update are saved together
+    let mut tx = context.repo.start_transaction().await?;
+
+    // The new address becomes the primary wallet, and the previous one stays linked
+    if let (Some(eth_address), Some(signature)) = (&dto.eth_address, &dto.eth_address_signature) {
+        context
+            .repo
+            .user_wallet
+            .link_wallet_tx(
+                &mut tx,
+                UserWalletProps {
+                    user_id,
+                    eth_address: eth_address.clone(),
+                    signature: signature.clone(),
+                    is_primary: true,
+                },
+            )
+            .await
+            .map_err(|e| match e {
+                DbError::Unique(_) => ApiError::bad_request()
+                    .code(ApiErrorCode::UserExists)
+                    .message(format!("Eth address {} already exists", eth_address)),
+                _ => ApiError::internal_error().message(format!("Failed to link wallet: {}", e)),
+            })?;
+    }
+
     // Update user
     let user_result = context
         .repo
         .user
-        .update_user(user_id, to_api_params(dto, has_new_email))
+        .update_user_tx(&mut tx, user_id, to_api_params(dto))
         .await
         .map_err(|e| ApiError::internal_error().message(format!("Failed to update user: {}", e)))?;
-
-    // Resend email confirmation if needed
-    if has_new_email {
-        resend_confirm_email(&context, user_result.id.clone(), &user_result.email).await?;
-    }
+    commit_or_rollback(tx, Ok(())).await?;
 
     // Return response
     Ok((StatusCode::OK, Json(to_api_response(user_result))))
 }
 
-fn to_api_params(dto: UpdateUserDto, has_new_email: bool) -> UserUpdateParams {
+// Email changes go through `create_user_email_change`
+fn to_api_params(dto: UpdateUserDto) -> UserUpdateParams {
     return UserUpdateParams {
-        email: dto.email,
+        email: None,
         name: dto.name,
         description: dto.description,
         link: dto.link,
@@ -135,6 +163,7 @@ fn to_api_params(dto: UpdateUserDto, has_new_email: bool) -> UserUpdateParams {
         eth_address: dto.eth_address,
         user_type: dto.user_type,
         user_status: dto.user_status,
-        email_confirmed: if has_new_email { Some(false) } else { None },
+        email_confirmed: None,
+        show_backed_count: dto.show_backed_count,
     };
 }
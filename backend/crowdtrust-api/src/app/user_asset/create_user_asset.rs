@@ -0,0 +1,73 @@
+use axum::{extract::State, http::StatusCode, Extension, Json};
+
+use chrono::{Duration, Utc};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::user_asset::create_user_asset_dto::{
+        to_api_response, CreateUserAssetDto, CreateUserAssetResponse,
+    },
+    shared::{api_error::ApiErrorCode, asset::AssetState, user::RequestUser},
+};
+use validator::Validate;
+
+use crate::{api_context::ApiContext, db::user_asset_repo::UserAssetEntityProps};
+
+pub async fn create_user_asset(
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateUserAssetDto>,
+) -> Result<(StatusCode, Json<CreateUserAssetResponse>), ApiError> {
+    check_bad_form(dto.validate())?;
+
+    let user_id = request_user.user_id.ok_or(ApiError::forbidden())?;
+
+    // Profile images are displayed inline, so only raster images are allowed
+    if !dto.content_type.is_image() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::AssetContentType)
+            .message(format!("Unsupported content type: {}", dto.content_type)));
+    }
+    if dto.content_size > dto.kind.max_size() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::AssetSize)
+            .message(format!(
+                "{} must be at most {} bytes",
+                dto.kind,
+                dto.kind.max_size()
+            )));
+    }
+
+    let content_type = dto.content_type.to_string();
+
+    let expires_seconds: i64 = 600;
+    let entity_props = UserAssetEntityProps {
+        user_id,
+        kind: dto.kind,
+        size: dto.content_size,
+        content_type: content_type.clone(),
+        state: AssetState::Created,
+        upload_expires_at: Utc::now() + Duration::seconds(expires_seconds),
+    };
+
+    let user_asset = context
+        .repo
+        .user_asset
+        .create_user_asset(entity_props)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create user asset: {}", e))
+        })?;
+
+    let signed_url = context.s3_client.presign_put_project_asset(
+        &user_asset.relative_url(),
+        expires_seconds as u64,
+        &content_type,
+        dto.content_size,
+    )?;
+
+    let response = Json(to_api_response(user_asset, signed_url.to_string()));
+    Ok((StatusCode::CREATED, response))
+}
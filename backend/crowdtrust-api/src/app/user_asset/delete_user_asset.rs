@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    Extension,
+};
+
+use lib_api::error::api_error::ApiError;
+use lib_types::shared::user::RequestUser;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::verify_admin_or_user};
+
+use super::helpers::verify_user_asset_exist;
+
+pub async fn delete_user_asset(
+    Path(asset_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<(), ApiError> {
+    let asset = verify_user_asset_exist(&context, asset_id).await?;
+
+    // Check if the requester is the owner of the asset or an admin
+    verify_admin_or_user(&request_user, asset.user_id.to_string())?;
+
+    // Call R2 endpoint to delete the asset
+    context
+        .s3_client
+        .delete_project_asset(&asset.relative_url())
+        .await?;
+
+    context
+        .repo
+        .user_asset
+        .delete_user_asset(&asset)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to delete user asset: {}", e))
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use lib_api::error::api_error::ApiError;
+use lib_types::entity::user_asset_entity::UserAssetEntity;
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+pub async fn verify_user_asset_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<UserAssetEntity, ApiError> {
+    let asset = context
+        .repo
+        .user_asset
+        .get_user_asset_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("User asset not found"))?;
+    Ok(asset)
+}
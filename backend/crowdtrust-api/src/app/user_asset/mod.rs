@@ -0,0 +1,4 @@
+pub mod create_user_asset;
+pub mod delete_user_asset;
+pub mod helpers;
+pub mod verify_user_asset;
@@ -0,0 +1,77 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use chrono::Utc;
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::user_asset::verify_user_asset_dto::VerifyUserAssetResponse,
+    shared::{asset::AssetState, user::RequestUser},
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::verify_admin_or_user};
+
+use super::helpers::verify_user_asset_exist;
+
+pub async fn verify_user_asset(
+    Path(asset_id): Path<Uuid>,
+    Extension(request_user): Extension<RequestUser>,
+    State(context): State<ApiContext>,
+) -> Result<Json<VerifyUserAssetResponse>, ApiError> {
+    let user_asset = verify_user_asset_exist(&context, asset_id).await?;
+
+    // Check if the asset belongs to the current user
+    verify_admin_or_user(&request_user, user_asset.user_id.to_string())?;
+
+    // Perform a HEAD request to check if the object exists
+    let exists = context
+        .s3_client
+        .verify_project_asset(&user_asset.relative_url())
+        .await?;
+
+    if !exists {
+        let state = if Utc::now() > user_asset.upload_expires_at {
+            AssetState::Expired
+        } else {
+            AssetState::Created
+        };
+        context
+            .repo
+            .user_asset
+            .update_user_asset_state(asset_id, state)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to update user asset: {}", e))
+            })?;
+        return Ok(Json(VerifyUserAssetResponse {
+            verified: false,
+            url: None,
+        }));
+    }
+
+    // The uploaded asset replaces the user's previous avatar or banner
+    let replaced = context
+        .repo
+        .user_asset
+        .activate_user_asset(&user_asset)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to update user asset: {}", e))
+        })?;
+    for asset in replaced.iter() {
+        if let Err(e) = context
+            .s3_client
+            .delete_project_asset(&asset.relative_url())
+            .await
+        {
+            error!("Failed to delete replaced user asset {}: {}", asset.id, e);
+        }
+    }
+
+    Ok(Json(VerifyUserAssetResponse {
+        verified: true,
+        url: Some(user_asset.relative_url()),
+    }))
+}
@@ -0,0 +1,65 @@
+use axum::{extract::State, Json};
+use chrono::Utc;
+use lib_api::{
+    auth::verify_jwt::verify_email_change_token, db::db_error::DbError, error::api_error::ApiError,
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::{
+        auth::confirm_email_dto::ConfirmEmailDto,
+        user_email_change::user_email_change_view_model::{
+            to_api_response, UserEmailChangeViewModel,
+        },
+    },
+    entity::user_email_change_entity::EmailChangeState,
+    shared::api_error::ApiErrorCode,
+};
+
+use crate::api_context::ApiContext;
+
+use super::helpers::{send_email_change_notice, verify_email_change_exist, EMAIL_CHANGE_CONFIRM};
+
+// Applies a pending email change with the code sent to the new address
+pub async fn confirm_user_email_change(
+    State(context): State<ApiContext>,
+    CtJson(dto): CtJson<ConfirmEmailDto>,
+) -> Result<Json<UserEmailChangeViewModel>, ApiError> {
+    let change_id = verify_email_change_token(
+        &context.config.confirm_shared_secret,
+        &dto.code,
+        EMAIL_CHANGE_CONFIRM,
+    )?;
+    let change = verify_email_change_exist(&context, change_id).await?;
+
+    let email_change_closed = || {
+        ApiError::bad_request()
+            .code(ApiErrorCode::EmailChangeClosed)
+            .message("Email change is no longer pending")
+    };
+    if change.state != EmailChangeState::Pending {
+        return Err(email_change_closed());
+    }
+    if change.expires_at < Utc::now() {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::ConfirmExpired)
+            .message("Email change expired"));
+    }
+
+    // Not found when the change was confirmed concurrently
+    let change = context
+        .repo
+        .user_email_change
+        .confirm_email_change(&change)
+        .await
+        .map_err(|e| match e {
+            DbError::EntityNotFound() => email_change_closed(),
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::UserExists)
+                .message(format!("Email {} already exists", change.new_email)),
+            _ => ApiError::internal_error().message(format!("Failed to confirm email: {}", e)),
+        })?;
+
+    send_email_change_notice(&context, &change).await;
+
+    Ok(Json(to_api_response(change)))
+}
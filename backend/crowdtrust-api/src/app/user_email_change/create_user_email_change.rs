@@ -0,0 +1,121 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{Duration, Utc};
+use lib_api::{
+    db::password::verify,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    eth::verify_signature::verify_signature,
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::user_email_change::{
+        create_user_email_change_dto::CreateUserEmailChangeDto,
+        user_email_change_view_model::{to_api_response, UserEmailChangeViewModel},
+    },
+    shared::{
+        api_error::ApiErrorCode,
+        user::{RequestUser, UserType},
+    },
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::helpers::{not_found_or_internal, verify_admin_or_user},
+    db::user_email_change_repo::UserEmailChangeProps,
+};
+
+use super::helpers::{
+    send_email_change_confirm, EMAIL_CHANGE_CONFIRM_TTL, EMAIL_CHANGE_SIGNATURE_TTL,
+};
+
+// The new email is applied once confirmed from the new address
+pub async fn create_user_email_change(
+    Path(user_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<CreateUserEmailChangeDto>,
+) -> Result<(StatusCode, Json<UserEmailChangeViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+    verify_admin_or_user(&request_user, user_id.to_string())?;
+
+    let user = context
+        .repo
+        .user
+        .get_user_by_id(user_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    // Users confirm with their password or primary wallet
+    if request_user.user_type == UserType::User {
+        if let Some(password) = dto.password {
+            let verified = verify(password, &user.password_hash)
+                .map_err(|_| ApiError::internal_error().message("Password verification failed"))?;
+            if !verified {
+                return Err(ApiError::bad_request()
+                    .code(ApiErrorCode::InvalidOldPassword)
+                    .message("Invalid password"));
+            }
+        } else if let Some(signature) = dto.eth_address_signature {
+            let expires_at = dto.signature_expires_at.ok_or(
+                ApiError::bad_request()
+                    .code(ApiErrorCode::InvalidFormData)
+                    .message("Signature expiry required"),
+            )?;
+            let now = Utc::now().timestamp();
+            if expires_at < now || expires_at > now + EMAIL_CHANGE_SIGNATURE_TTL {
+                return Err(ApiError::bad_request()
+                    .code(ApiErrorCode::ConfirmExpired)
+                    .message("Signature expired"));
+            }
+            let message = format!(
+                "Change CrowdTrust account {} email to {}, valid until {}",
+                user_id, dto.email, expires_at
+            );
+            if !verify_signature(signature, message, user.eth_address.clone())? {
+                return Err(ApiError::bad_request()
+                    .code(ApiErrorCode::InvalidSignature)
+                    .message("Failed to verify signature"));
+            }
+        } else {
+            return Err(ApiError::bad_request()
+                .code(ApiErrorCode::SignatureRequired)
+                .message("Password or signature required when changing email"));
+        }
+    }
+
+    // Verify new email is unique
+    if context
+        .repo
+        .user
+        .find_user_by_email(dto.email.clone())
+        .await
+        .is_ok()
+    {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::UserExists)
+            .message(format!("Email {} already exists", dto.email)));
+    }
+
+    let change = context
+        .repo
+        .user_email_change
+        .create_email_change(UserEmailChangeProps {
+            user_id,
+            old_email: user.email,
+            new_email: dto.email,
+            expires_at: Utc::now() + Duration::minutes(EMAIL_CHANGE_CONFIRM_TTL),
+        })
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create email change: {}", e))
+        })?;
+
+    send_email_change_confirm(&context, &change).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(to_api_response(change))))
+}
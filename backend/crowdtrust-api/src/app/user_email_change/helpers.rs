@@ -0,0 +1,95 @@
+use chrono::{Duration, Utc};
+use lib_api::{auth::generate_jwt::generate_email_change_token, error::api_error::ApiError};
+use lib_types::entity::user_email_change_entity::UserEmailChangeEntity;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+// Confirm link TTL in minutes
+pub const EMAIL_CHANGE_CONFIRM_TTL: i64 = 1440;
+// Max lifetime of a signed email change request, in seconds
+pub const EMAIL_CHANGE_SIGNATURE_TTL: i64 = 600;
+// The old address can revert the change for this many days after it's confirmed
+const EMAIL_CHANGE_REVERT_DAYS: i64 = 7;
+
+pub const EMAIL_CHANGE_CONFIRM: &str = "confirm";
+pub const EMAIL_CHANGE_REVERT: &str = "revert";
+
+pub async fn verify_email_change_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<UserEmailChangeEntity, ApiError> {
+    let change = context
+        .repo
+        .user_email_change
+        .get_email_change_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Email change not found"))?;
+    Ok(change)
+}
+
+// Sends the confirm link to the new address
+pub async fn send_email_change_confirm(
+    context: &ApiContext,
+    change: &UserEmailChangeEntity,
+) -> Result<(), ApiError> {
+    let _token = generate_email_change_token(
+        change.id,
+        EMAIL_CHANGE_CONFIRM,
+        change.expires_at.timestamp(),
+        &context.config.confirm_shared_secret,
+    )?;
+    /*
+    match send_confirm_email_change(
+        &context.config.sendgrid_api_key,
+        &change.new_email,
+        &context.config.app_web_url,
+        &_token,
+    )
+    .await
+    {
+        Ok(_) => info!("Sent email change confirm mail to: {}", change.new_email),
+        Err(e) => error!(err = e.to_string(), "Failed to send email change confirm mail"),
+    }
+    */
+    Ok(())
+}
+
+// Notifies the old address of a confirmed change, with a revert link. Failures are logged
+pub async fn send_email_change_notice(context: &ApiContext, change: &UserEmailChangeEntity) {
+    let exp = (Utc::now() + Duration::days(EMAIL_CHANGE_REVERT_DAYS)).timestamp();
+    let _token = match generate_email_change_token(
+        change.id,
+        EMAIL_CHANGE_REVERT,
+        exp,
+        &context.config.confirm_shared_secret,
+    ) {
+        Ok(token) => token,
+        Err(e) => {
+            error!(
+                err = e.to_string(),
+                "Failed to create email change revert token"
+            );
+            return;
+        }
+    };
+    info!(
+        user_id = change.user_id.to_string(),
+        "Sending email change notice"
+    );
+    /*
+    match send_email_changed(
+        &context.config.sendgrid_api_key,
+        &change.old_email,
+        &change.new_email,
+        &context.config.app_web_url,
+        &_token,
+    )
+    .await
+    {
+        Ok(_) => info!("Sent email change notice to: {}", change.old_email),
+        Err(e) => error!(err = e.to_string(), "Failed to send email change notice"),
+    }
+    */
+}
@@ -0,0 +1,4 @@
+pub mod confirm_user_email_change;
+pub mod create_user_email_change;
+pub mod helpers;
+pub mod revert_user_email_change;
@@ -0,0 +1,57 @@
+use axum::{extract::State, Json};
+use lib_api::{
+    auth::verify_jwt::verify_email_change_token, db::db_error::DbError, error::api_error::ApiError,
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::{
+        auth::confirm_email_dto::ConfirmEmailDto,
+        user_email_change::user_email_change_view_model::{
+            to_api_response, UserEmailChangeViewModel,
+        },
+    },
+    entity::user_email_change_entity::EmailChangeState,
+    shared::api_error::ApiErrorCode,
+};
+
+use crate::api_context::ApiContext;
+
+use super::helpers::{verify_email_change_exist, EMAIL_CHANGE_REVERT};
+
+// Restores the old email with the code sent to the old address
+pub async fn revert_user_email_change(
+    State(context): State<ApiContext>,
+    CtJson(dto): CtJson<ConfirmEmailDto>,
+) -> Result<Json<UserEmailChangeViewModel>, ApiError> {
+    let change_id = verify_email_change_token(
+        &context.config.confirm_shared_secret,
+        &dto.code,
+        EMAIL_CHANGE_REVERT,
+    )?;
+    let change = verify_email_change_exist(&context, change_id).await?;
+
+    let email_change_closed = || {
+        ApiError::bad_request()
+            .code(ApiErrorCode::EmailChangeClosed)
+            .message("Email change can no longer be reverted")
+    };
+    if change.state != EmailChangeState::Confirmed {
+        return Err(email_change_closed());
+    }
+
+    // Not found when the email changed again since
+    let change = context
+        .repo
+        .user_email_change
+        .revert_email_change(&change)
+        .await
+        .map_err(|e| match e {
+            DbError::EntityNotFound() => email_change_closed(),
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::UserExists)
+                .message(format!("Email {} already exists", change.old_email)),
+            _ => ApiError::internal_error().message(format!("Failed to revert email: {}", e)),
+        })?;
+
+    Ok(Json(to_api_response(change)))
+}
@@ -0,0 +1,45 @@
+use axum::{extract::State, Json};
+use lib_api::error::api_error::ApiError;
+use lib_types::dto::user_export::build_user_exports_dto::BuildUserExportsResponse;
+
+use crate::api_context::ApiContext;
+
+use super::helpers::decrypt_export_shipping_addresses;
+
+// Builds the archive of pending exports. Run periodically by a scheduled job
+pub async fn build_user_exports(
+    State(context): State<ApiContext>,
+) -> Result<Json<BuildUserExportsResponse>, ApiError> {
+    let exports = context
+        .repo
+        .user_export
+        .list_pending_exports()
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list exports: {}", e))
+        })?;
+
+    let mut built = vec![];
+    for export in exports.into_iter() {
+        let mut data = context
+            .repo
+            .user_export
+            .collect_export_data(export.id)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to build export: {}", e))
+            })?;
+        decrypt_export_shipping_addresses(&context, &mut data)?;
+        context
+            .repo
+            .user_export
+            .complete_export(export.id, data)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to build export: {}", e))
+            })?;
+        built.push(export.id);
+    }
+
+    Ok(Json(BuildUserExportsResponse { built }))
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::user_export::user_export_view_model::{to_api_response, UserExportViewModel},
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::helpers::{not_found_or_internal, verify_admin_or_user},
+};
+
+// Requests a personal data export. It's built by a scheduled job
+pub async fn create_user_export(
+    Path(user_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<(StatusCode, Json<UserExportViewModel>), ApiError> {
+    verify_admin_or_user(&request_user, user_id.to_string())?;
+    context
+        .repo
+        .user
+        .get_user_by_id(user_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    let export = context
+        .repo
+        .user_export
+        .create_export(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to create export: {}", e))
+        })?;
+
+    Ok((StatusCode::ACCEPTED, Json(to_api_response(export))))
+}
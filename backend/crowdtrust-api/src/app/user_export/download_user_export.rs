@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    Extension,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    entity::user_export_entity::UserExportState,
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::not_found_or_internal};
+
+use super::helpers::verify_user_export;
+
+// Downloads the JSON archive of a Ready export
+pub async fn download_user_export(
+    Path(export_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<impl IntoResponse, ApiError> {
+    let export = verify_user_export(&context, &request_user, export_id).await?;
+    if export.state != UserExportState::Ready {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::ExportPending)
+            .message("Export is not ready"));
+    }
+
+    let data = context
+        .repo
+        .user_export
+        .get_export_data(export_id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "application/json; charset=utf-8".to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"crowdtrust-export-{}.json\"",
+                    export.id
+                ),
+            ),
+        ],
+        data.to_string(),
+    ))
+}
@@ -0,0 +1,24 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::user_export::user_export_view_model::{to_api_response, UserExportViewModel},
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+use super::helpers::verify_user_export;
+
+pub async fn get_user_export(
+    Path(export_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<UserExportViewModel>, ApiError> {
+    let export = verify_user_export(&context, &request_user, export_id).await?;
+
+    Ok(Json(to_api_response(export)))
+}
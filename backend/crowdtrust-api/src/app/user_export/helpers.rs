@@ -0,0 +1,46 @@
+use lib_api::error::api_error::ApiError;
+use lib_types::{entity::user_export_entity::UserExportEntity, shared::user::RequestUser};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    api_context::ApiContext,
+    app::{helpers::verify_admin_or_user, pledge::helpers::decrypt_shipping_address},
+};
+
+// Exports can only be read by their user or an admin
+pub async fn verify_user_export(
+    context: &ApiContext,
+    request_user: &RequestUser,
+    id: Uuid,
+) -> Result<UserExportEntity, ApiError> {
+    let export = context
+        .repo
+        .user_export
+        .get_export_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("Export not found"))?;
+    verify_admin_or_user(request_user, export.user_id.to_string())?;
+    Ok(export)
+}
+
+// Pledge shipping addresses are stored encrypted, the archive gets the plain address
+pub fn decrypt_export_shipping_addresses(
+    context: &ApiContext,
+    data: &mut Value,
+) -> Result<(), ApiError> {
+    let Some(pledges) = data.get_mut("pledges").and_then(Value::as_array_mut) else {
+        return Ok(());
+    };
+    for pledge in pledges.iter_mut() {
+        let Some(address) = pledge.get_mut("shipping_address") else {
+            continue;
+        };
+        let encrypted = address.as_str().map(|a| a.to_string());
+        let decrypted = decrypt_shipping_address(context, encrypted)?;
+        *address = serde_json::to_value(decrypted).map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to serialize address: {}", e))
+        })?;
+    }
+    Ok(())
+}
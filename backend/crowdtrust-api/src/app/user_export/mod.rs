@@ -0,0 +1,5 @@
+pub mod build_user_exports;
+pub mod create_user_export;
+pub mod download_user_export;
+pub mod get_user_export;
+pub mod helpers;
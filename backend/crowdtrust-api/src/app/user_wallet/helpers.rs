@@ -0,0 +1,34 @@
+use lib_api::{error::api_error::ApiError, eth::verify_signature::verify_signature};
+use lib_types::{entity::user_wallet_entity::UserWalletEntity, shared::api_error::ApiErrorCode};
+use uuid::Uuid;
+
+use crate::api_context::ApiContext;
+
+pub async fn verify_user_wallet_exist(
+    context: &ApiContext,
+    id: Uuid,
+) -> Result<UserWalletEntity, ApiError> {
+    let wallet = context
+        .repo
+        .user_wallet
+        .get_wallet_by_id(id)
+        .await
+        .map_err(|_| ApiError::not_found().message("User wallet not found"))?;
+    Ok(wallet)
+}
+
+// Verifies the message was signed by any of the addresses
+pub fn verify_wallet_signature(
+    signature: &str,
+    message: String,
+    addresses: &[&str],
+) -> Result<(), ApiError> {
+    for address in addresses {
+        if verify_signature(signature.to_string(), message.clone(), address.to_string())? {
+            return Ok(());
+        }
+    }
+    Err(ApiError::bad_request()
+        .code(ApiErrorCode::InvalidSignature)
+        .message("Failed to verify signature"))
+}
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use lib_api::{
+    db::db_error::DbError,
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::user_wallet::{
+        link_user_wallet_dto::LinkUserWalletDto,
+        user_wallet_view_model::{to_api_response, UserWalletViewModel},
+    },
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext, app::helpers::verify_admin_or_user,
+    db::user_wallet_repo::UserWalletProps,
+};
+
+use super::helpers::verify_wallet_signature;
+
+pub async fn link_user_wallet(
+    Path(user_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<LinkUserWalletDto>,
+) -> Result<(StatusCode, Json<UserWalletViewModel>), ApiError> {
+    check_bad_form(dto.validate())?;
+    verify_admin_or_user(&request_user, user_id.to_string())?;
+
+    // The new wallet proves ownership by signing the account it's linked to
+    let message = format!("Link {} to CrowdTrust account {}", dto.eth_address, user_id);
+    verify_wallet_signature(&dto.eth_address_signature, message, &[&dto.eth_address])?;
+
+    let wallet = context
+        .repo
+        .user_wallet
+        .link_wallet(UserWalletProps {
+            user_id,
+            eth_address: dto.eth_address.clone(),
+            signature: dto.eth_address_signature,
+            is_primary: dto.primary.unwrap_or(false),
+        })
+        .await
+        .map_err(|e| match e {
+            DbError::Unique(_) => ApiError::bad_request()
+                .code(ApiErrorCode::UserExists)
+                .message(format!("Eth address {} already exists", dto.eth_address)),
+            _ => ApiError::internal_error().message(format!("Failed to link wallet: {}", e)),
+        })?;
+
+    Ok((StatusCode::CREATED, Json(to_api_response(wallet))))
+}
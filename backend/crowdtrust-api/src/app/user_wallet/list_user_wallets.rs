@@ -0,0 +1,31 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::error::api_error::ApiError;
+use lib_types::{
+    dto::user_wallet::user_wallet_view_model::{to_api_response, UserWalletViewModel},
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+
+use crate::{api_context::ApiContext, app::helpers::verify_admin_or_user};
+
+pub async fn list_user_wallets(
+    Path(user_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+) -> Result<Json<Vec<UserWalletViewModel>>, ApiError> {
+    verify_admin_or_user(&request_user, user_id.to_string())?;
+
+    let wallets = context
+        .repo
+        .user_wallet
+        .list_wallets(user_id)
+        .await
+        .map_err(|e| {
+            ApiError::internal_error().message(format!("Failed to list wallets: {}", e))
+        })?;
+
+    Ok(Json(wallets.into_iter().map(to_api_response).collect()))
+}
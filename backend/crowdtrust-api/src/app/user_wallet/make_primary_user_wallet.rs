@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::user_wallet::{
+        user_wallet_signature_dto::UserWalletSignatureDto,
+        user_wallet_view_model::{to_api_response, UserWalletViewModel},
+    },
+    shared::user::RequestUser,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{api_context::ApiContext, app::helpers::verify_admin_or_user};
+
+use super::helpers::{verify_user_wallet_exist, verify_wallet_signature};
+
+// The primary wallet is used for login
+pub async fn make_primary_user_wallet(
+    Path(wallet_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UserWalletSignatureDto>,
+) -> Result<Json<UserWalletViewModel>, ApiError> {
+    check_bad_form(dto.validate())?;
+    let mut wallet = verify_user_wallet_exist(&context, wallet_id).await?;
+    verify_admin_or_user(&request_user, wallet.user_id.to_string())?;
+
+    if !wallet.is_primary {
+        let message = format!("Use {} as primary CrowdTrust wallet", wallet.eth_address);
+        verify_wallet_signature(&dto.eth_address_signature, message, &[&wallet.eth_address])?;
+
+        context
+            .repo
+            .user_wallet
+            .set_primary_wallet(&wallet)
+            .await
+            .map_err(|e| {
+                ApiError::internal_error().message(format!("Failed to set primary wallet: {}", e))
+            })?;
+        wallet.is_primary = true;
+    }
+
+    Ok(Json(to_api_response(wallet)))
+}
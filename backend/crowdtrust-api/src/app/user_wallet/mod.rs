@@ -0,0 +1,5 @@
+pub mod helpers;
+pub mod link_user_wallet;
+pub mod list_user_wallets;
+pub mod make_primary_user_wallet;
+pub mod unlink_user_wallet;
@@ -0,0 +1,65 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension,
+};
+use lib_api::{
+    error::{api_error::ApiError, helpers::check_bad_form},
+    util::json_extractor::CtJson,
+};
+use lib_types::{
+    dto::user_wallet::user_wallet_signature_dto::UserWalletSignatureDto,
+    shared::{api_error::ApiErrorCode, user::RequestUser},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api_context::ApiContext,
+    app::helpers::{not_found_or_internal, verify_admin_or_user},
+};
+
+use super::helpers::{verify_user_wallet_exist, verify_wallet_signature};
+
+pub async fn unlink_user_wallet(
+    Path(wallet_id): Path<Uuid>,
+    State(context): State<ApiContext>,
+    Extension(request_user): Extension<RequestUser>,
+    CtJson(dto): CtJson<UserWalletSignatureDto>,
+) -> Result<StatusCode, ApiError> {
+    check_bad_form(dto.validate())?;
+    let wallet = verify_user_wallet_exist(&context, wallet_id).await?;
+    verify_admin_or_user(&request_user, wallet.user_id.to_string())?;
+
+    if wallet.is_primary {
+        return Err(ApiError::bad_request()
+            .code(ApiErrorCode::WalletPrimary)
+            .message("Primary wallet can't be unlinked"));
+    }
+
+    // Signed by the wallet itself, or by the primary wallet if it was lost
+    let user = context
+        .repo
+        .user
+        .get_user_by_id(wallet.user_id)
+        .await
+        .map_err(not_found_or_internal)?;
+    let message = format!(
+        "Unlink {} from CrowdTrust account {}",
+        wallet.eth_address, wallet.user_id
+    );
+    verify_wallet_signature(
+        &dto.eth_address_signature,
+        message,
+        &[&wallet.eth_address, &user.eth_address],
+    )?;
+
+    context
+        .repo
+        .user_wallet
+        .delete_wallet(wallet.id)
+        .await
+        .map_err(not_found_or_internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
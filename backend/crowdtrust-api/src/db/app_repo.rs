@@ -7,12 +7,29 @@ use lib_api::{
 use sqlx::{PgPool, Postgres, Transaction};
 
 use super::{
+    follow_repo::{DynFollowRepo, FollowRepo},
+    notification_repo::{DynNotificationRepo, NotificationRepo},
     pledge_repo::{DynPledgeRepo, PledgeRepo},
     project_asset_repo::{DynProjectAssetRepo, ProjectAssetRepo},
+    project_comment_repo::{DynProjectCommentRepo, ProjectCommentRepo},
+    project_invitation_repo::{DynProjectInvitationRepo, ProjectInvitationRepo},
+    project_member_repo::{DynProjectMemberRepo, ProjectMemberRepo},
+    project_milestone_repo::{DynProjectMilestoneRepo, ProjectMilestoneRepo},
+    project_preview_token_repo::{DynProjectPreviewTokenRepo, ProjectPreviewTokenRepo},
     project_repo::{DynProjectRepo, ProjectRepo},
+    project_revision_repo::{DynProjectRevisionRepo, ProjectRevisionRepo},
+    project_signup_repo::{DynProjectSignupRepo, ProjectSignupRepo},
+    project_transfer_repo::{DynProjectTransferRepo, ProjectTransferRepo},
+    project_update_asset_repo::{DynProjectUpdateAssetRepo, ProjectUpdateAssetRepo},
+    project_update_repo::{DynProjectUpdateRepo, ProjectUpdateRepo},
     reward_asset_repo::{DynRewardAssetRepo, RewardAssetRepo},
     reward_repo::{DynRewardRepo, RewardRepo},
+    user_asset_repo::{DynUserAssetRepo, UserAssetRepo},
+    user_email_change_repo::{DynUserEmailChangeRepo, UserEmailChangeRepo},
+    user_export_repo::{DynUserExportRepo, UserExportRepo},
     user_repo::{DynUserRepo, UserRepo},
+    user_reputation_repo::{DynUserReputationRepo, UserReputationRepo},
+    user_wallet_repo::{DynUserWalletRepo, UserWalletRepo},
 };
 
 #[derive(Clone)]
@@ -21,9 +38,26 @@ pub struct AppRepo {
     pub user: DynUserRepo,
     pub project: DynProjectRepo,
     pub project_asset: DynProjectAssetRepo,
+    pub project_comment: DynProjectCommentRepo,
+    pub project_invitation: DynProjectInvitationRepo,
+    pub project_member: DynProjectMemberRepo,
+    pub project_milestone: DynProjectMilestoneRepo,
+    pub project_preview_token: DynProjectPreviewTokenRepo,
+    pub project_revision: DynProjectRevisionRepo,
+    pub project_signup: DynProjectSignupRepo,
+    pub project_transfer: DynProjectTransferRepo,
+    pub project_update: DynProjectUpdateRepo,
+    pub project_update_asset: DynProjectUpdateAssetRepo,
     pub reward: DynRewardRepo,
     pub reward_asset: DynRewardAssetRepo,
     pub pledge: DynPledgeRepo,
+    pub follow: DynFollowRepo,
+    pub notification: DynNotificationRepo,
+    pub user_reputation: DynUserReputationRepo,
+    pub user_asset: DynUserAssetRepo,
+    pub user_export: DynUserExportRepo,
+    pub user_wallet: DynUserWalletRepo,
+    pub user_email_change: DynUserEmailChangeRepo,
 }
 
 pub async fn start_transaction(db: &PgPool) -> Result<Transaction<'_, Postgres>, DbError> {
@@ -41,9 +75,35 @@ impl AppRepo {
             user: Arc::new(UserRepo { db: db.clone() }) as DynUserRepo,
             project: Arc::new(ProjectRepo { db: db.clone() }) as DynProjectRepo,
             project_asset: Arc::new(ProjectAssetRepo { db: db.clone() }) as DynProjectAssetRepo,
+            project_comment: Arc::new(ProjectCommentRepo { db: db.clone() })
+                as DynProjectCommentRepo,
+            project_invitation: Arc::new(ProjectInvitationRepo { db: db.clone() })
+                as DynProjectInvitationRepo,
+            project_member: Arc::new(ProjectMemberRepo { db: db.clone() }) as DynProjectMemberRepo,
+            project_milestone: Arc::new(ProjectMilestoneRepo { db: db.clone() })
+                as DynProjectMilestoneRepo,
+            project_preview_token: Arc::new(ProjectPreviewTokenRepo { db: db.clone() })
+                as DynProjectPreviewTokenRepo,
+            project_revision: Arc::new(ProjectRevisionRepo { db: db.clone() })
+                as DynProjectRevisionRepo,
+            project_signup: Arc::new(ProjectSignupRepo { db: db.clone() }) as DynProjectSignupRepo,
+            project_transfer: Arc::new(ProjectTransferRepo { db: db.clone() })
+                as DynProjectTransferRepo,
+            project_update: Arc::new(ProjectUpdateRepo { db: db.clone() }) as DynProjectUpdateRepo,
+            project_update_asset: Arc::new(ProjectUpdateAssetRepo { db: db.clone() })
+                as DynProjectUpdateAssetRepo,
             reward: Arc::new(RewardRepo { db: db.clone() }) as DynRewardRepo,
             reward_asset: Arc::new(RewardAssetRepo { db: db.clone() }) as DynRewardAssetRepo,
             pledge: Arc::new(PledgeRepo { db: db.clone() }) as DynPledgeRepo,
+            follow: Arc::new(FollowRepo { db: db.clone() }) as DynFollowRepo,
+            notification: Arc::new(NotificationRepo { db: db.clone() }) as DynNotificationRepo,
+            user_reputation: Arc::new(UserReputationRepo { db: db.clone() })
+                as DynUserReputationRepo,
+            user_asset: Arc::new(UserAssetRepo { db: db.clone() }) as DynUserAssetRepo,
+            user_export: Arc::new(UserExportRepo { db: db.clone() }) as DynUserExportRepo,
+            user_wallet: Arc::new(UserWalletRepo { db: db.clone() }) as DynUserWalletRepo,
+            user_email_change: Arc::new(UserEmailChangeRepo { db: db.clone() })
+                as DynUserEmailChangeRepo,
         })
     }
 
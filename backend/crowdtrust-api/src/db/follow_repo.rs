@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use lib_api::db::db_error::DbError;
+use lib_types::entity::notification_entity::FollowsEntity;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub type DynFollowRepo = Arc<dyn FollowRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait FollowRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Following is idempotent
+    async fn follow_project(&self, user_id: Uuid, project_id: Uuid) -> Result<(), DbError>;
+    async fn unfollow_project(&self, user_id: Uuid, project_id: Uuid) -> Result<(), DbError>;
+    async fn follow_creator(&self, follower_id: Uuid, creator_id: Uuid) -> Result<(), DbError>;
+    async fn unfollow_creator(&self, follower_id: Uuid, creator_id: Uuid) -> Result<(), DbError>;
+    async fn list_follows(&self, user_id: Uuid) -> Result<FollowsEntity, DbError>;
+}
+
+pub struct FollowRepo {
+    pub db: PgPool,
+}
+
+#[async_trait]
+impl FollowRepoTrait for FollowRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn follow_project(&self, user_id: Uuid, project_id: Uuid) -> Result<(), DbError> {
+        sqlx::query(
+            r#"INSERT INTO "project_follows" (user_id, project_id) values ($1, $2)
+            ON CONFLICT DO NOTHING"#,
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn unfollow_project(&self, user_id: Uuid, project_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM project_follows WHERE user_id = $1 AND project_id = $2")
+            .bind(user_id)
+            .bind(project_id)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn follow_creator(&self, follower_id: Uuid, creator_id: Uuid) -> Result<(), DbError> {
+        sqlx::query(
+            r#"INSERT INTO "user_follows" (follower_id, creator_id) values ($1, $2)
+            ON CONFLICT DO NOTHING"#,
+        )
+        .bind(follower_id)
+        .bind(creator_id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn unfollow_creator(&self, follower_id: Uuid, creator_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM user_follows WHERE follower_id = $1 AND creator_id = $2")
+            .bind(follower_id)
+            .bind(creator_id)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn list_follows(&self, user_id: Uuid) -> Result<FollowsEntity, DbError> {
+        let project_ids = sqlx::query_scalar(
+            "SELECT project_id FROM project_follows WHERE user_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db)
+        .await?;
+        let creator_ids = sqlx::query_scalar(
+            "SELECT creator_id FROM user_follows WHERE follower_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(FollowsEntity {
+            project_ids,
+            creator_ids,
+        })
+    }
+}
@@ -1,7 +1,24 @@
 pub mod app_repo;
+pub mod follow_repo;
+pub mod notification_repo;
 pub mod pledge_repo;
 pub mod project_asset_repo;
+pub mod project_comment_repo;
+pub mod project_invitation_repo;
+pub mod project_member_repo;
+pub mod project_milestone_repo;
+pub mod project_preview_token_repo;
 pub mod project_repo;
+pub mod project_revision_repo;
+pub mod project_signup_repo;
+pub mod project_transfer_repo;
+pub mod project_update_asset_repo;
+pub mod project_update_repo;
 pub mod reward_asset_repo;
 pub mod reward_repo;
+pub mod user_asset_repo;
+pub mod user_email_change_repo;
+pub mod user_export_repo;
 pub mod user_repo;
+pub mod user_reputation_repo;
+pub mod user_wallet_repo;
@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::{
+    db_error::DbError,
+    db_result::list_result,
+    util::{
+        append_cursor, append_cursor_order_limit, append_limit_offset, cursor_count_column,
+        cursor_include_total, cursor_result, CursorPage, DbCursor,
+    },
+};
+use lib_types::{
+    dto::notification::list_notifications_dto::ListNotificationsQuery,
+    entity::notification_entity::{
+        NotificationData, NotificationEntity, NotificationKind, NotificationListResults,
+        NotificationPreferenceEntity,
+    },
+};
+use sqlx::{postgres::PgRow, types::Json, PgPool, Postgres, QueryBuilder, Row};
+use uuid::Uuid;
+
+use super::{app_repo::start_transaction, pledge_repo::CONFIRMED_PLEDGE};
+
+pub type DynNotificationRepo = Arc<dyn NotificationRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait NotificationRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Creates a notification for each recipient in the audience, following their preferences.
+    // The project owner is never notified. Returns the number of notifications created
+    async fn create_notifications(&self, props: NotificationCreateProps) -> Result<u64, DbError>;
+    async fn list_notifications(
+        &self,
+        user_id: Uuid,
+        query: ListNotificationsQuery,
+        page: Option<CursorPage>,
+    ) -> Result<NotificationListResults, DbError>;
+    // Returns EntityNotFound if the notification doesn't belong to the user
+    async fn mark_read(&self, user_id: Uuid, id: Uuid) -> Result<(), DbError>;
+    async fn mark_all_read(&self, user_id: Uuid) -> Result<u64, DbError>;
+    // Active projects ending within `hours`, which haven't sent an EndingSoon notification
+    async fn list_ending_soon_projects(&self, hours: i64) -> Result<Vec<Uuid>, DbError>;
+    // Saved preferences. Kinds with no saved preference use the default
+    async fn get_preferences(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<NotificationPreferenceEntity>, DbError>;
+    async fn set_preferences(
+        &self,
+        user_id: Uuid,
+        preferences: Vec<NotificationPreferenceEntity>,
+    ) -> Result<(), DbError>;
+}
+
+pub struct NotificationRepo {
+    pub db: PgPool,
+}
+
+// Who receives a project notification
+pub enum NotificationAudience {
+    // Followers of the project or its creator
+    Followers,
+    Backers,
+    FollowersAndBackers,
+    // The backer of a pledge
+    PledgeBacker(Uuid),
+}
+
+pub struct NotificationCreateProps {
+    pub kind: NotificationKind,
+    pub project_id: Uuid,
+    pub data: NotificationData,
+    pub audience: NotificationAudience,
+}
+
+const NOTIFICATION_COLUMNS: &str =
+    r#"id, user_id, kind, project_id, data, in_app, email, read_at, emailed_at, created_at"#;
+
+fn map_notification_entity(row: PgRow) -> Result<NotificationEntity, sqlx::Error> {
+    Ok(NotificationEntity {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        kind: row.try_get_unchecked("kind")?,
+        project_id: row.try_get("project_id")?,
+        data: row.try_get::<Json<NotificationData>, &str>("data")?.0,
+        in_app: row.try_get("in_app")?,
+        email: row.try_get("email")?,
+        read_at: row.try_get("read_at")?,
+        emailed_at: row.try_get("emailed_at")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+fn map_notification_list_entity(
+    row: PgRow,
+) -> Result<(NotificationEntity, Option<i64>), sqlx::Error> {
+    let count = row.try_get("count")?;
+    let entity = map_notification_entity(row)?;
+    Ok((entity, count))
+}
+
+fn map_preference_entity(row: PgRow) -> Result<NotificationPreferenceEntity, sqlx::Error> {
+    Ok(NotificationPreferenceEntity {
+        kind: row.try_get_unchecked("kind")?,
+        in_app: row.try_get("in_app")?,
+        email: row.try_get("email")?,
+    })
+}
+
+const FOLLOWERS_QUERY: &str = r#"SELECT pf.user_id FROM project_follows pf WHERE pf.project_id = p.id
+    UNION SELECT uf.follower_id FROM user_follows uf WHERE uf.creator_id = p.user_id"#;
+
+const BACKERS_QUERY: &str = formatcp!(
+    r#"SELECT DISTINCT pl.user_id FROM pledges pl WHERE pl.project_id = p.id AND {}"#,
+    CONFIRMED_PLEDGE
+);
+
+// Appends recipient user IDs for the audience, as a subquery over project `p`
+fn append_audience<'a>(
+    mut query: QueryBuilder<'a, Postgres>,
+    audience: &NotificationAudience,
+) -> QueryBuilder<'a, Postgres> {
+    match audience {
+        NotificationAudience::Followers => {
+            query.push(FOLLOWERS_QUERY);
+        }
+        NotificationAudience::Backers => {
+            query.push(BACKERS_QUERY);
+        }
+        NotificationAudience::FollowersAndBackers => {
+            query.push(formatcp!("{} UNION {}", FOLLOWERS_QUERY, BACKERS_QUERY));
+        }
+        NotificationAudience::PledgeBacker(pledge_id) => {
+            query.push("SELECT pl.user_id FROM pledges pl WHERE pl.id = ");
+            query.push_bind(*pledge_id);
+        }
+    }
+    query
+}
+
+#[async_trait]
+impl NotificationRepoTrait for NotificationRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_notifications(&self, props: NotificationCreateProps) -> Result<u64, DbError> {
+        let kind = props.kind.to_string();
+        let mut query = QueryBuilder::new(
+            r#"INSERT INTO "notifications" (user_id, kind, project_id, data, in_app, email)
+            SELECT r.user_id, "#,
+        );
+        query.push_bind(kind.clone());
+        query.push(", p.id, ");
+        query.push_bind(Json(props.data));
+        query.push(
+            r#", COALESCE(np.in_app, true), COALESCE(np.email, false)
+            FROM projects p
+            CROSS JOIN LATERAL ("#,
+        );
+        let mut query = append_audience(query, &props.audience);
+        query.push(
+            r#") as r
+            LEFT JOIN notification_preferences np ON np.user_id = r.user_id AND np.kind = "#,
+        );
+        query.push_bind(kind);
+        query.push(" WHERE p.id = ");
+        query.push_bind(props.project_id);
+        query.push(
+            r#" AND r.user_id != p.user_id
+            AND (COALESCE(np.in_app, true) OR COALESCE(np.email, false))"#,
+        );
+
+        let result = query.build().execute(&self.db).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_notifications(
+        &self,
+        user_id: Uuid,
+        query: ListNotificationsQuery,
+        page: Option<CursorPage>,
+    ) -> Result<NotificationListResults, DbError> {
+        let mut filtered_query = QueryBuilder::new("");
+        // Cursor pagination filters the count subquery, so the total isn't affected
+        if page.is_some() {
+            filtered_query.push("SELECT * FROM (");
+        }
+        filtered_query.push(format!(
+            "SELECT {}, {} FROM notifications WHERE in_app AND user_id = ",
+            NOTIFICATION_COLUMNS,
+            cursor_count_column(&page)
+        ));
+        filtered_query.push_bind(user_id);
+        if query.unread.unwrap_or(false) {
+            filtered_query.push(" AND read_at IS NULL");
+        }
+
+        if let Some(page) = &page {
+            filtered_query.push(") as filtered");
+            if page.cursor.is_some() {
+                filtered_query.push(" WHERE");
+            }
+            let (q, _) = append_cursor(
+                filtered_query,
+                page,
+                "filtered.created_at",
+                "timestamptz",
+                "filtered.id",
+                true,
+                0,
+            );
+            filtered_query =
+                append_cursor_order_limit(q, page, "filtered.created_at", "filtered.id", true);
+        } else {
+            filtered_query.push(" ORDER BY created_at DESC, id DESC");
+            filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        }
+
+        let results = filtered_query
+            .build()
+            .try_map(map_notification_list_entity)
+            .fetch_all(&self.db)
+            .await?;
+
+        let unread_count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM notifications WHERE in_app AND user_id = $1 AND read_at IS NULL",
+        )
+        .bind(user_id)
+        .fetch_one(&self.db)
+        .await?;
+
+        let (results, total) = list_result(results, cursor_include_total(&page));
+        let (results, next_cursor) = if let Some(page) = &page {
+            cursor_result(results, page, |notification: &NotificationEntity| {
+                DbCursor {
+                    value: notification.created_at.to_rfc3339(),
+                    id: notification.id,
+                }
+            })
+        } else {
+            (results, None)
+        };
+
+        Ok(NotificationListResults {
+            total,
+            next_cursor,
+            unread_count,
+            results,
+        })
+    }
+
+    async fn mark_read(&self, user_id: Uuid, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query(
+            "UPDATE notifications SET read_at = COALESCE(read_at, now()) WHERE id = $1 AND user_id = $2",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.db)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::EntityNotFound());
+        }
+        Ok(())
+    }
+
+    async fn mark_all_read(&self, user_id: Uuid) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            "UPDATE notifications SET read_at = now() WHERE user_id = $1 AND read_at IS NULL",
+        )
+        .bind(user_id)
+        .execute(&self.db)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_ending_soon_projects(&self, hours: i64) -> Result<Vec<Uuid>, DbError> {
+        Ok(sqlx::query_scalar(formatcp!(
+            r#"SELECT p.id FROM projects p
+            WHERE p.status = '{active}'
+            AND p.start_time + p.duration > extract(epoch from now())
+            AND p.start_time + p.duration <= extract(epoch from now()) + $1 * 3600
+            AND NOT EXISTS (
+              SELECT 1 FROM notifications n WHERE n.project_id = p.id AND n.kind = '{kind}'
+            )"#,
+            active = "Active",
+            kind = "EndingSoon"
+        ))
+        .bind(hours)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn get_preferences(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<NotificationPreferenceEntity>, DbError> {
+        Ok(sqlx::query(
+            "SELECT kind, in_app, email FROM notification_preferences WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .try_map(map_preference_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn set_preferences(
+        &self,
+        user_id: Uuid,
+        preferences: Vec<NotificationPreferenceEntity>,
+    ) -> Result<(), DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        for preference in preferences.into_iter() {
+            sqlx::query(
+                r#"INSERT INTO "notification_preferences" (user_id, kind, in_app, email)
+                values ($1, $2, $3, $4)
+                ON CONFLICT (user_id, kind) DO UPDATE SET in_app = EXCLUDED.in_app, email = EXCLUDED.email"#,
+            )
+            .bind(user_id)
+            .bind(preference.kind.to_string())
+            .bind(preference.in_app)
+            .bind(preference.email)
+            .execute(tx.as_mut())
+            .await?;
+        }
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(())
+    }
+}
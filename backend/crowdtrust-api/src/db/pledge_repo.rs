@@ -2,11 +2,14 @@ use std::sync::Arc;
 
 use axum::async_trait;
 use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
 use const_format::formatcp;
 use lib_api::db::{
     db_error::{map_sqlx_err, DbError},
     util::{
-        append_and_eq, append_comma, append_limit_offset, append_order_by, option_string_to_uuid,
+        append_and_eq, append_comma, append_cursor, append_cursor_order_limit, append_limit_offset,
+        append_order_by, cursor_count_column, cursor_include_total, cursor_result,
+        option_string_to_uuid, CursorPage, DbCursor,
     },
 };
 use lib_types::{
@@ -17,35 +20,62 @@ use lib_types::{
     entity::pledge_entity::{
         PledgeEntity, PledgeEntityRelations, PledgeItemEntity, PledgeListResults,
     },
-    shared::project::{BlockchainStatus, PaymentCurrency},
+    shared::{
+        pledge::FulfillmentStatus,
+        project::{BlockchainStatus, PaymentCurrency},
+        terms::PledgeTerms,
+    },
 };
 use serde::{Deserialize, Serialize};
 use serde_json::to_string;
-use sqlx::{postgres::PgRow, PgPool, Postgres, QueryBuilder, Row, Transaction};
+use sqlx::{postgres::PgRow, types::Json, PgPool, Postgres, QueryBuilder, Row, Transaction};
 use uuid::Uuid;
 
+use super::app_repo::start_transaction;
+
 pub type DynPledgeRepo = Arc<dyn PledgeRepoTrait + Send + Sync>;
 
 #[derive(Debug, Deserialize, Serialize, sqlx::Type)]
 pub struct PledgeCreateProps {
     pub user_id: Uuid,
     pub project_id: Uuid,
+    // Encrypted shipping address
+    pub shipping_address: Option<String>,
+    pub extra_amount: BigDecimal,
+    pub total_amount: BigDecimal,
+    pub paid_currency: PaymentCurrency,
     pub pledge_items: Vec<PledgeItemCreateProps>,
+    pub terms: PledgeTerms,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
 pub struct PledgeItemCreateProps {
     pub reward_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub price_tier_id: Option<Uuid>,
     pub quantity: i32,
     pub paid_price: BigDecimal,
     pub paid_currency: PaymentCurrency,
+    pub paid_shipping: BigDecimal,
 }
 
 #[derive(Debug, Deserialize, Serialize, sqlx::Type)]
 pub struct PledgeUpdateProps {
     pub comment: Option<String>,
+    // Encrypted shipping address
+    pub shipping_address: Option<String>,
     pub blockchain_status: Option<BlockchainStatus>,
     pub transaction_hash: Option<String>,
+    pub eth_address: Option<String>,
+}
+
+// Full fulfillment state of the item. None clears the column
+#[derive(Debug, Deserialize, Serialize, sqlx::Type)]
+pub struct PledgeItemFulfillmentProps {
+    pub fulfillment_status: FulfillmentStatus,
+    pub tracking_number: Option<String>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
 }
 
 #[async_trait]
@@ -63,7 +93,44 @@ pub trait PledgeRepoTrait {
         tx: &mut Transaction<'_, Postgres>,
         props: PledgeCreateProps,
     ) -> Result<PledgeEntity, DbError>;
-    async fn list_pledges(&self, query: ListPledgesQuery) -> Result<PledgeListResults, DbError>;
+    async fn list_pledges(
+        &self,
+        query: ListPledgesQuery,
+        page: Option<CursorPage>,
+    ) -> Result<PledgeListResults, DbError>;
+    // True if the user has a pledge for the project that succeeded on-chain
+    async fn has_backed_project(&self, user_id: Uuid, project_id: Uuid) -> Result<bool, DbError>;
+    // Total amount of the user's confirmed, non-refundable pledges to the project
+    async fn get_backed_amount(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<BigDecimal, DbError>;
+    // Number of distinct projects the user has a valid pledge to
+    async fn count_backed_projects(&self, user_id: Uuid) -> Result<i64, DbError>;
+    async fn get_pledge_item_by_id(&self, id: Uuid) -> Result<PledgeItemEntity, DbError>;
+    async fn get_project_pledge_items(
+        &self,
+        project_id: Uuid,
+        ids: Vec<Uuid>,
+    ) -> Result<Vec<PledgeItemEntity>, DbError>;
+    async fn update_pledge_item_fulfillment(
+        &self,
+        id: Uuid,
+        props: PledgeItemFulfillmentProps,
+    ) -> Result<PledgeItemEntity, DbError>;
+    async fn update_pledge_item_fulfillment_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        props: PledgeItemFulfillmentProps,
+    ) -> Result<PledgeItemEntity, DbError>;
+    // Returns the number of pledges marked refundable
+    async fn set_project_pledges_refundable_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        project_id: Uuid,
+    ) -> Result<u64, DbError>;
 }
 
 pub struct PledgeRepo {
@@ -71,29 +138,41 @@ pub struct PledgeRepo {
 }
 
 const PLEDGE_COLUMNS: &str = formatcp!(
-    r#"{p}.id, {p}.user_id, {p}.project_id, {p}.comment, {p}.transaction_hash, {p}.blockchain_status, {p}.created_at, {p}.updated_at"#,
+    r#"{p}.id, {p}.user_id, {p}.project_id, {p}.comment, {p}.shipping_address, {p}.extra_amount, {p}.total_amount, {p}.paid_currency, {p}.transaction_hash, {p}.eth_address, {p}.blockchain_status, {p}.terms, {p}.refundable, {p}.created_at, {p}.updated_at"#,
     p = "pledges"
 );
 
 const PLEDGE_ITEM_COLUMNS: &str = formatcp!(
-    r#"{p}.id, {p}.pledge_id, {p}.reward_id, {p}.quantity, {p}.paid_price, {p}.paid_currency, {p}.created_at, {p}.updated_at"#,
+    r#"{p}.id, {p}.pledge_id, {p}.reward_id, {p}.variant_id, {p}.price_tier_id, {p}.quantity, {p}.paid_price, {p}.paid_currency, {p}.paid_shipping, {p}.fulfillment_status, {p}.tracking_number, {p}.shipped_at, {p}.delivered_at, {p}.created_at, {p}.updated_at"#,
     p = "pledge_items"
 );
 
 const PLEDGE_RELATION_COLUMNS: &str = formatcp!(
-    r#"{p}, {pi}.id as pi_id, {pi}.pledge_id as pi_pledge_id, {pi}.reward_id as pi_reward_id, {pi}.quantity as pi_quantity, {pi}.paid_price as pi_paid_price, {pi}.paid_currency as pi_paid_currency, {pi}.created_at as pi_created_at, {pi}.updated_at as pi_updated_at"#,
+    r#"{p}, {pi}.id as pi_id, {pi}.pledge_id as pi_pledge_id, {pi}.reward_id as pi_reward_id, {pi}.variant_id as pi_variant_id, {pi}.price_tier_id as pi_price_tier_id, {pi}.quantity as pi_quantity, {pi}.paid_price as pi_paid_price, {pi}.paid_currency as pi_paid_currency, {pi}.paid_shipping as pi_paid_shipping, {pi}.fulfillment_status as pi_fulfillment_status, {pi}.tracking_number as pi_tracking_number, {pi}.shipped_at as pi_shipped_at, {pi}.delivered_at as pi_delivered_at, {pi}.created_at as pi_created_at, {pi}.updated_at as pi_updated_at"#,
     p = PLEDGE_COLUMNS,
     pi = "pi"
 );
 
+// Pledge `pl` succeeded on-chain, which makes its user a confirmed backer of the project
+pub const CONFIRMED_PLEDGE: &str = "pl.blockchain_status = 'Success'";
+
 fn map_pledge_entity(row: PgRow) -> Result<PledgeEntity, sqlx::Error> {
     Ok(PledgeEntity {
         id: row.try_get("id")?,
         user_id: row.try_get("user_id")?,
         project_id: row.try_get("project_id")?,
         comment: row.try_get("comment")?,
+        shipping_address: row.try_get("shipping_address")?,
+        extra_amount: row.try_get("extra_amount")?,
+        total_amount: row.try_get("total_amount")?,
+        paid_currency: row.try_get_unchecked("paid_currency")?,
         blockchain_status: row.try_get_unchecked("blockchain_status")?,
         transaction_hash: row.try_get("transaction_hash")?,
+        eth_address: row.try_get("eth_address")?,
+        terms: row
+            .try_get::<Option<Json<PledgeTerms>>, &str>("terms")?
+            .map(|terms| terms.0),
+        refundable: row.try_get("refundable")?,
         created_at: row.try_get("created_at")?,
         updated_at: row.try_get("updated_at")?,
     })
@@ -104,9 +183,16 @@ fn map_pledge_item_entity(row: PgRow) -> Result<PledgeItemEntity, sqlx::Error> {
         id: row.try_get("id")?,
         pledge_id: row.try_get("pledge_id")?,
         reward_id: row.try_get("reward_id")?,
+        variant_id: row.try_get("variant_id")?,
+        price_tier_id: row.try_get("price_tier_id")?,
         quantity: row.try_get("quantity")?,
         paid_price: row.try_get("paid_price")?,
         paid_currency: row.try_get_unchecked("paid_currency")?,
+        paid_shipping: row.try_get("paid_shipping")?,
+        fulfillment_status: row.try_get_unchecked("fulfillment_status")?,
+        tracking_number: row.try_get("tracking_number")?,
+        shipped_at: row.try_get("shipped_at")?,
+        delivered_at: row.try_get("delivered_at")?,
         created_at: row.try_get("created_at")?,
         updated_at: row.try_get("updated_at")?,
     })
@@ -117,9 +203,16 @@ fn map_pledge_item_entity_relation(row: &PgRow) -> Result<PledgeItemEntity, sqlx
         id: row.try_get("pi_id")?,
         pledge_id: row.try_get("pi_pledge_id")?,
         reward_id: row.try_get("pi_reward_id")?,
+        variant_id: row.try_get("pi_variant_id")?,
+        price_tier_id: row.try_get("pi_price_tier_id")?,
         quantity: row.try_get("pi_quantity")?,
         paid_price: row.try_get("pi_paid_price")?,
         paid_currency: row.try_get_unchecked("pi_paid_currency")?,
+        paid_shipping: row.try_get("pi_paid_shipping")?,
+        fulfillment_status: row.try_get_unchecked("pi_fulfillment_status")?,
+        tracking_number: row.try_get("pi_tracking_number")?,
+        shipped_at: row.try_get("pi_shipped_at")?,
+        delivered_at: row.try_get("pi_delivered_at")?,
         created_at: row.try_get("pi_created_at")?,
         updated_at: row.try_get("pi_updated_at")?,
     })
@@ -137,9 +230,18 @@ fn map_pledge_relation_entity(row: PgRow) -> Result<PledgeEntityRelations, sqlx:
         user_id: row.try_get("user_id")?,
         project_id: row.try_get("project_id")?,
         comment: row.try_get("comment")?,
+        shipping_address: row.try_get("shipping_address")?,
+        extra_amount: row.try_get("extra_amount")?,
+        total_amount: row.try_get("total_amount")?,
+        paid_currency: row.try_get_unchecked("paid_currency")?,
         pledge_items,
         blockchain_status: row.try_get_unchecked("blockchain_status")?,
         transaction_hash: row.try_get("transaction_hash")?,
+        eth_address: row.try_get("eth_address")?,
+        terms: row
+            .try_get::<Option<Json<PledgeTerms>>, &str>("terms")?
+            .map(|terms| terms.0),
+        refundable: row.try_get("refundable")?,
         created_at: row.try_get("created_at")?,
         updated_at: row.try_get("updated_at")?,
     })
@@ -198,6 +300,12 @@ impl PledgeRepoTrait for PledgeRepo {
         let update_count = 0;
 
         let (query, update_count) = append_comma(query, "comment", props.comment, update_count);
+        let (query, update_count) = append_comma(
+            query,
+            "shipping_address",
+            props.shipping_address,
+            update_count,
+        );
 
         let (query, update_count) = append_comma(
             query,
@@ -205,6 +313,8 @@ impl PledgeRepoTrait for PledgeRepo {
             props.transaction_hash,
             update_count,
         );
+        let (query, update_count) =
+            append_comma(query, "eth_address", props.eth_address, update_count);
         let (mut query, update_count) = append_comma(
             query,
             "blockchain_status",
@@ -239,15 +349,20 @@ impl PledgeRepoTrait for PledgeRepo {
         let pledge = sqlx::query(formatcp!(
             // language=PostgreSQL
             r#"
-              INSERT INTO "pledges" (user_id, project_id, blockchain_status)
-              values ($1, $2, $3)
+              INSERT INTO "pledges" (user_id, project_id, shipping_address, extra_amount, total_amount, paid_currency, blockchain_status, terms)
+              values ($1, $2, $3, $4, $5, $6, $7, $8)
               RETURNING {}
             "#,
             PLEDGE_COLUMNS
         ))
         .bind(props.user_id)
         .bind(props.project_id)
+        .bind(props.shipping_address)
+        .bind(props.extra_amount)
+        .bind(props.total_amount)
+        .bind(props.paid_currency.to_string())
         .bind(BlockchainStatus::None.to_string())
+        .bind(Json(props.terms))
         .try_map(map_pledge_entity)
         .fetch_one(tx.as_mut())
         .await
@@ -259,17 +374,20 @@ impl PledgeRepoTrait for PledgeRepo {
             sqlx::query(formatcp!(
                 // language=PostgreSQL
                 r#"
-                  INSERT INTO "pledge_items" (pledge_id, reward_id, quantity, paid_price, paid_currency)
-                  values ($1, $2, $3, $4, $5)
+                  INSERT INTO "pledge_items" (pledge_id, reward_id, variant_id, price_tier_id, quantity, paid_price, paid_currency, paid_shipping)
+                  values ($1, $2, $3, $4, $5, $6, $7, $8)
                   RETURNING {}
                 "#,
                 PLEDGE_ITEM_COLUMNS
             ))
             .bind(pledge.id.clone())
             .bind(item.reward_id)
+            .bind(item.variant_id)
+            .bind(item.price_tier_id)
             .bind(item.quantity)
             .bind(item.paid_price)
             .bind(item.paid_currency.to_string())
+            .bind(item.paid_shipping)
             .try_map(map_pledge_item_entity)
             .fetch_one(tx.as_mut())
             .await
@@ -280,10 +398,20 @@ impl PledgeRepoTrait for PledgeRepo {
         Ok(pledge)
     }
 
-    async fn list_pledges(&self, query: ListPledgesQuery) -> Result<PledgeListResults, DbError> {
-        let mut filtered_query = QueryBuilder::new(format!(
-            "SELECT {}, count FROM (SELECT *, COUNT(*) OVER () as count from \"pledges\"",
-            PLEDGE_RELATION_COLUMNS
+    async fn list_pledges(
+        &self,
+        query: ListPledgesQuery,
+        page: Option<CursorPage>,
+    ) -> Result<PledgeListResults, DbError> {
+        let mut filtered_query =
+            QueryBuilder::new(format!("SELECT {}, count FROM (", PLEDGE_RELATION_COLUMNS));
+        // Cursor pagination filters the count subquery, so the total isn't affected
+        if page.is_some() {
+            filtered_query.push("SELECT * FROM (");
+        }
+        filtered_query.push(format!(
+            "SELECT *, {} from \"pledges\"",
+            cursor_count_column(&page)
         ));
 
         if query.user_id.is_some() || query.project_id.is_some() {
@@ -305,17 +433,46 @@ impl PledgeRepoTrait for PledgeRepo {
             count,
         );
         // ORDER BY
-        let column = to_string(&query.column.unwrap_or(PledgeSortColumn::CreatedAt))
-            .map_err(|e| DbError::Serialize(e.to_string()))?;
+        let sort_column = query.column.unwrap_or(PledgeSortColumn::CreatedAt);
+        let column = to_string(&sort_column).map_err(|e| DbError::Serialize(e.to_string()))?;
         let direction = query.direction.unwrap_or(SortDirection::Desc);
 
+        // Pledge items are joined after paging, so the limit applies to pledges
+        if let Some(page) = &page {
+            let descending = direction == SortDirection::Desc;
+            let cursor_column = format!("filtered.{}", column);
+            filtered_query.push(") as filtered");
+            if page.cursor.is_some() {
+                filtered_query.push(" WHERE");
+            }
+            let (q, _) = append_cursor(
+                filtered_query,
+                page,
+                &cursor_column,
+                "timestamptz",
+                "filtered.id",
+                descending,
+                0,
+            );
+            filtered_query =
+                append_cursor_order_limit(q, page, &cursor_column, "filtered.id", descending);
+        }
+
         filtered_query
             .push(") as pledges LEFT OUTER JOIN \"pledge_items\" pi on pi.pledge_id = pledges.id");
 
         filtered_query
-            .push(" GROUP BY pledges.id, pledges.user_id, pledges.project_id, pledges.comment, pledges.blockchain_status, pledges.transaction_hash, pledges.created_at, pledges.count, pledges.updated_at, pi.id");
-        filtered_query = append_order_by(filtered_query, column, direction.to_string());
-        filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+            .push(" GROUP BY pledges.id, pledges.user_id, pledges.project_id, pledges.comment, pledges.shipping_address, pledges.extra_amount, pledges.total_amount, pledges.paid_currency, pledges.blockchain_status, pledges.transaction_hash, pledges.eth_address, pledges.terms, pledges.refundable, pledges.created_at, pledges.count, pledges.updated_at, pi.id");
+        if page.is_some() {
+            // Already limited, the id keeps each pledge's items together
+            filtered_query.push(format!(
+                " ORDER BY pledges.{} {}, pledges.id {}",
+                column, direction, direction
+            ));
+        } else {
+            filtered_query = append_order_by(filtered_query, column, direction.to_string());
+            filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        }
 
         let rows = filtered_query.build().fetch_all(&self.db).await?;
         let mut pledges: Vec<PledgeEntityRelations> = vec![];
@@ -324,7 +481,7 @@ impl PledgeRepoTrait for PledgeRepo {
 
         for row in rows.into_iter() {
             if count.is_none() {
-                count = Some(row.try_get("count")?);
+                count = row.try_get("count")?;
             }
             let id = row.try_get::<Uuid, &str>("id")?;
             if let Some(mut prev) = pledge {
@@ -344,9 +501,144 @@ impl PledgeRepoTrait for PledgeRepo {
             pledges.push(last_pledge);
         }
 
+        let total = if cursor_include_total(&page) {
+            Some(count.unwrap_or(pledges.len() as i64))
+        } else {
+            None
+        };
+        let (results, next_cursor) = if let Some(page) = &page {
+            cursor_result(pledges, page, |pledge: &PledgeEntityRelations| DbCursor {
+                value: match sort_column {
+                    PledgeSortColumn::CreatedAt => pledge.created_at.to_rfc3339(),
+                    PledgeSortColumn::UpdatedAt => pledge.updated_at.to_rfc3339(),
+                },
+                id: pledge.id,
+            })
+        } else {
+            (pledges, None)
+        };
+
         Ok(PledgeListResults {
-            total: count.unwrap_or(pledges.len() as i64),
-            results: pledges,
+            total,
+            next_cursor,
+            results,
         })
     }
+
+    async fn has_backed_project(&self, user_id: Uuid, project_id: Uuid) -> Result<bool, DbError> {
+        Ok(sqlx::query_scalar(formatcp!(
+            r#"SELECT EXISTS (
+              SELECT 1 FROM "pledges" pl WHERE pl.user_id = $1 AND pl.project_id = $2 AND {}
+            )"#,
+            CONFIRMED_PLEDGE
+        ))
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn get_backed_amount(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<BigDecimal, DbError> {
+        Ok(sqlx::query_scalar(
+            r#"SELECT COALESCE(SUM(total_amount), 0) FROM "pledges"
+            WHERE user_id = $1 AND project_id = $2 AND blockchain_status = $3 AND NOT refundable"#,
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .bind(BlockchainStatus::Success.to_string())
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn count_backed_projects(&self, user_id: Uuid) -> Result<i64, DbError> {
+        Ok(sqlx::query_scalar(
+            r#"SELECT COUNT(DISTINCT project_id) FROM "pledges" WHERE user_id = $1 AND blockchain_status != $2"#,
+        )
+        .bind(user_id)
+        .bind(BlockchainStatus::Error.to_string())
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_pledge_item_by_id(&self, id: Uuid) -> Result<PledgeItemEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM \"pledge_items\" WHERE id = $1",
+            PLEDGE_ITEM_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_pledge_item_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn get_project_pledge_items(
+        &self,
+        project_id: Uuid,
+        ids: Vec<Uuid>,
+    ) -> Result<Vec<PledgeItemEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"SELECT {} FROM "pledge_items"
+            INNER JOIN pledges p on p.id = pledge_items.pledge_id
+            WHERE p.project_id = $1 AND pledge_items.id = ANY($2)"#,
+            PLEDGE_ITEM_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(ids)
+        .try_map(map_pledge_item_entity)
+        .fetch_all(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn update_pledge_item_fulfillment(
+        &self,
+        id: Uuid,
+        props: PledgeItemFulfillmentProps,
+    ) -> Result<PledgeItemEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        let result = self
+            .update_pledge_item_fulfillment_tx(&mut tx, id, props)
+            .await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(result)
+    }
+
+    async fn update_pledge_item_fulfillment_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        props: PledgeItemFulfillmentProps,
+    ) -> Result<PledgeItemEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE pledge_items SET fulfillment_status = $1, tracking_number = $2, shipped_at = $3, delivered_at = $4 WHERE id = $5 RETURNING {}",
+            PLEDGE_ITEM_COLUMNS
+        ))
+        .bind(props.fulfillment_status.to_string())
+        .bind(props.tracking_number)
+        .bind(props.shipped_at)
+        .bind(props.delivered_at)
+        .bind(id)
+        .try_map(map_pledge_item_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn set_project_pledges_refundable_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        project_id: Uuid,
+    ) -> Result<u64, DbError> {
+        let result = sqlx::query("UPDATE pledges SET refundable = true WHERE project_id = $1")
+            .bind(project_id)
+            .execute(tx.as_mut())
+            .await?;
+        Ok(result.rows_affected())
+    }
 }
@@ -7,7 +7,9 @@ use lib_api::db::util::append_limit_offset;
 use lib_api::db::{
     db_error::{map_sqlx_err, DbError},
     util::{
-        append_and_eq, append_comma, append_order_by, option_enum_to_string, option_string_to_uuid,
+        append_and_eq, append_comma, append_cursor, append_cursor_order_limit, append_order_by,
+        cursor_count_column, cursor_include_total, cursor_result, option_enum_to_string,
+        option_string_to_uuid, CursorPage, DbCursor,
     },
 };
 use lib_types::dto::project_asset::list_project_assets_dto::{
@@ -37,6 +39,7 @@ pub trait ProjectAssetRepoTrait {
     async fn list_project_assets(
         &self,
         query: ListProjectAssetsQuery,
+        page: Option<CursorPage>,
     ) -> Result<ProjectAssetListResults, DbError>;
 
     async fn update_project_asset_state(&self, id: Uuid, state: AssetState) -> Result<(), DbError>;
@@ -79,11 +82,19 @@ fn map_project_asset_entity(row: PgRow) -> Result<ProjectAssetEntity, sqlx::Erro
     })
 }
 
-fn project_asset_list_result<T>(results: Vec<(T, i64, u64)>) -> (Vec<T>, i64, u64) {
-    let mut total = 0;
+fn project_asset_list_result<T>(
+    results: Vec<(T, Option<i64>, u64)>,
+    include_total: bool,
+) -> (Vec<T>, Option<i64>, u64) {
+    let mut total = None;
     let mut total_usage = 0;
+    if include_total {
+        total = Some(0);
+    }
     if let Some(item) = results.first() {
-        total = item.1;
+        if include_total {
+            total = Some(item.1.unwrap_or(0));
+        }
         total_usage = item.2
     }
     let items = results.into_iter().map(|t| t.0).collect();
@@ -92,7 +103,7 @@ fn project_asset_list_result<T>(results: Vec<(T, i64, u64)>) -> (Vec<T>, i64, u6
 
 fn map_project_asset_list_entity(
     row: PgRow,
-) -> Result<(ProjectAssetEntity, i64, u64), sqlx::Error> {
+) -> Result<(ProjectAssetEntity, Option<i64>, u64), sqlx::Error> {
     let count = row.try_get("count")?;
     let total_usage: i64 = row.try_get("total_usage")?;
     let entity = map_project_asset_entity(row)?;
@@ -184,27 +195,44 @@ impl ProjectAssetRepoTrait for ProjectAssetRepo {
     async fn list_project_assets(
         &self,
         query: ListProjectAssetsQuery,
+        page: Option<CursorPage>,
     ) -> Result<ProjectAssetListResults, DbError> {
+        let mut filtered_query = QueryBuilder::new("");
+        // Cursor pagination filters the count subquery, so the totals aren't affected
+        if page.is_some() {
+            filtered_query.push("SELECT * FROM (");
+        }
+        let count_column = cursor_count_column(&page);
         let filtered_query = if query.user_id.is_none()
             && query.project_id.is_none()
             && query.state.is_none()
             && query.content_type.is_none()
         {
-            QueryBuilder::new(
-                "SELECT *, COUNT(*) OVER (), CAST(SUM(size) OVER() as INT8) as total_usage FROM project_assets",
-            )
+            filtered_query.push(format!(
+                "SELECT *, {}, CAST(SUM(size) OVER() as INT8) as total_usage FROM project_assets",
+                count_column
+            ));
+            filtered_query
         } else if let Some(user_id) = query.user_id.clone() {
             // Filter user_id for SUM, so we can return the correct asset usage for a user
-            let q = QueryBuilder::new(
-                "SELECT *, COUNT(*) OVER (), CAST((SELECT SUM(size) FROM project_assets WHERE",
+            filtered_query.push(format!(
+                "SELECT *, {}, CAST((SELECT SUM(size) FROM project_assets WHERE",
+                count_column
+            ));
+            let (mut q, _c) = append_and_eq(
+                filtered_query,
+                "user_id",
+                option_string_to_uuid(Some(user_id)),
+                0,
             );
-            let (mut q, _c) = append_and_eq(q, "user_id", option_string_to_uuid(Some(user_id)), 0);
             q.push(") as INT8) as total_usage FROM project_assets WHERE");
             q
         } else {
-            QueryBuilder::new(
-                "SELECT *, COUNT(*) OVER (), CAST(SUM(size) OVER() as INT8) as total_usage FROM project_assets WHERE",
-            )
+            filtered_query.push(format!(
+                "SELECT *, {}, CAST(SUM(size) OVER() as INT8) as total_usage FROM project_assets WHERE",
+                count_column
+            ));
+            filtered_query
         };
 
         // Filter user_id
@@ -236,22 +264,60 @@ impl ProjectAssetRepoTrait for ProjectAssetRepo {
         );
 
         // ORDER BY
-        let column = to_string(&query.column.unwrap_or(ProjectAssetSortColumn::CreatedAt))
-            .map_err(|e| DbError::Serialize(e.to_string()))?;
+        let sort_column = query.column.unwrap_or(ProjectAssetSortColumn::CreatedAt);
+        let column = to_string(&sort_column).map_err(|e| DbError::Serialize(e.to_string()))?;
         let direction = query.direction.unwrap_or(SortDirection::Desc);
 
-        filtered_query = append_order_by(filtered_query, column, direction.to_string());
-        filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        if let Some(page) = &page {
+            let cast = match sort_column {
+                ProjectAssetSortColumn::Size => "bigint",
+                _ => "timestamptz",
+            };
+            let column = format!("project_assets.{}", column);
+            let descending = direction == SortDirection::Desc;
+            filtered_query.push(") as project_assets");
+            if page.cursor.is_some() {
+                filtered_query.push(" WHERE");
+            }
+            let (q, _) = append_cursor(
+                filtered_query,
+                page,
+                &column,
+                cast,
+                "project_assets.id",
+                descending,
+                0,
+            );
+            filtered_query =
+                append_cursor_order_limit(q, page, &column, "project_assets.id", descending);
+        } else {
+            filtered_query = append_order_by(filtered_query, column, direction.to_string());
+            filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        }
 
         let results = filtered_query
             .build()
             .try_map(map_project_asset_list_entity)
             .fetch_all(&self.db)
             .await?;
-        let (results, total, total_usage) = project_asset_list_result(results);
+        let (results, total, total_usage) =
+            project_asset_list_result(results, cursor_include_total(&page));
+        let (results, next_cursor) = if let Some(page) = &page {
+            cursor_result(results, page, |asset: &ProjectAssetEntity| DbCursor {
+                value: match sort_column {
+                    ProjectAssetSortColumn::Size => asset.size.to_string(),
+                    ProjectAssetSortColumn::CreatedAt => asset.created_at.to_rfc3339(),
+                    ProjectAssetSortColumn::UpdatedAt => asset.updated_at.to_rfc3339(),
+                },
+                id: asset.id,
+            })
+        } else {
+            (results, None)
+        };
 
         Ok(ProjectAssetListResults {
             total,
+            next_cursor,
             total_usage,
             results,
         })
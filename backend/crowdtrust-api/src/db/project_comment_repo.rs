@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::{
+    db_error::{map_sqlx_err, DbError},
+    db_result::list_result,
+    util::{
+        append_cursor, append_cursor_order_limit, append_limit_offset, append_order_by,
+        cursor_count_column, cursor_include_total, cursor_result, option_string_to_uuid,
+        CursorPage, DbCursor,
+    },
+};
+use lib_types::{
+    dto::{
+        project_comment::list_project_comments_dto::ListProjectCommentsQuery,
+        sort_direction::SortDirection,
+    },
+    entity::project_comment_entity::{ProjectCommentEntity, ProjectCommentListResults},
+};
+use sqlx::{postgres::PgRow, PgPool, QueryBuilder, Row};
+use uuid::Uuid;
+
+use super::{app_repo::start_transaction, pledge_repo::CONFIRMED_PLEDGE};
+
+pub type DynProjectCommentRepo = Arc<dyn ProjectCommentRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectCommentRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_comment(
+        &self,
+        props: ProjectCommentCreateProps,
+    ) -> Result<ProjectCommentEntity, DbError>;
+    async fn get_comment_by_id(&self, id: Uuid) -> Result<ProjectCommentEntity, DbError>;
+    async fn edit_comment(&self, id: Uuid, body: String) -> Result<ProjectCommentEntity, DbError>;
+    // Soft delete, the comment is kept so replies stay in the thread
+    async fn delete_comment(&self, id: Uuid) -> Result<ProjectCommentEntity, DbError>;
+    // Hides the comment if `hidden_by` is set, otherwise restores it
+    async fn set_comment_hidden(
+        &self,
+        id: Uuid,
+        hidden_by: Option<Uuid>,
+    ) -> Result<ProjectCommentEntity, DbError>;
+    // Returns Unique if the user already reported the comment
+    async fn report_comment(&self, id: Uuid, user_id: Uuid, reason: String) -> Result<(), DbError>;
+    async fn count_user_comments_since(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DbError>;
+    async fn list_comments(
+        &self,
+        project_id: Uuid,
+        query: ListProjectCommentsQuery,
+        page: Option<CursorPage>,
+    ) -> Result<ProjectCommentListResults, DbError>;
+}
+
+pub struct ProjectCommentRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectCommentCreateProps {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub body: String,
+}
+
+const COMMENT_COLUMNS: &str = formatcp!(
+    r#"{c}.id, {c}.project_id, {c}.user_id, {c}.parent_id, {c}.body, {c}.report_count, {c}.edited_at, {c}.deleted_at, {c}.hidden_at, {c}.hidden_by, {c}.created_at, {c}.updated_at"#,
+    c = "c"
+);
+
+// Comment columns, with the author badge and reply count
+const COMMENT_SELECT: &str = formatcp!(
+    r#"SELECT {columns},
+      CASE
+        WHEN c.user_id = p.user_id THEN 'Creator'
+        WHEN EXISTS (
+          SELECT 1 FROM pledges pl
+          WHERE pl.user_id = c.user_id AND pl.project_id = c.project_id AND {confirmed}
+        ) THEN 'Backer'
+      END as badge,
+      (SELECT COUNT(*) FROM project_comments r WHERE r.parent_id = c.id) as reply_count
+    FROM project_comments c
+    INNER JOIN projects p ON p.id = c.project_id"#,
+    columns = COMMENT_COLUMNS,
+    confirmed = CONFIRMED_PLEDGE
+);
+
+fn map_comment_entity(row: PgRow) -> Result<ProjectCommentEntity, sqlx::Error> {
+    let badge: Option<String> = row.try_get("badge")?;
+    Ok(ProjectCommentEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        user_id: row.try_get("user_id")?,
+        parent_id: row.try_get("parent_id")?,
+        body: row.try_get("body")?,
+        badge: badge.and_then(|b| b.parse().ok()),
+        reply_count: row.try_get("reply_count")?,
+        report_count: row.try_get("report_count")?,
+        edited_at: row.try_get("edited_at")?,
+        deleted_at: row.try_get("deleted_at")?,
+        hidden_at: row.try_get("hidden_at")?,
+        hidden_by: row.try_get("hidden_by")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn map_comment_list_entity(row: PgRow) -> Result<(ProjectCommentEntity, Option<i64>), sqlx::Error> {
+    let count = row.try_get("count")?;
+    let entity = map_comment_entity(row)?;
+    Ok((entity, count))
+}
+
+#[async_trait]
+impl ProjectCommentRepoTrait for ProjectCommentRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_comment(
+        &self,
+        props: ProjectCommentCreateProps,
+    ) -> Result<ProjectCommentEntity, DbError> {
+        let id: Uuid = sqlx::query_scalar(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_comments" (project_id, user_id, parent_id, body)
+              values ($1, $2, $3, $4)
+              RETURNING id
+            "#,
+        )
+        .bind(props.project_id)
+        .bind(props.user_id)
+        .bind(props.parent_id)
+        .bind(props.body)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?;
+
+        self.get_comment_by_id(id).await
+    }
+
+    async fn get_comment_by_id(&self, id: Uuid) -> Result<ProjectCommentEntity, DbError> {
+        Ok(sqlx::query(formatcp!("{} WHERE c.id = $1", COMMENT_SELECT))
+            .bind(id)
+            .try_map(map_comment_entity)
+            .fetch_one(&self.db)
+            .await
+            .map_err(map_sqlx_err)?)
+    }
+
+    async fn edit_comment(&self, id: Uuid, body: String) -> Result<ProjectCommentEntity, DbError> {
+        sqlx::query("UPDATE project_comments SET body = $1, edited_at = now() WHERE id = $2")
+            .bind(body)
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+        self.get_comment_by_id(id).await
+    }
+
+    async fn delete_comment(&self, id: Uuid) -> Result<ProjectCommentEntity, DbError> {
+        sqlx::query("UPDATE project_comments SET deleted_at = now() WHERE id = $1")
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+        self.get_comment_by_id(id).await
+    }
+
+    async fn set_comment_hidden(
+        &self,
+        id: Uuid,
+        hidden_by: Option<Uuid>,
+    ) -> Result<ProjectCommentEntity, DbError> {
+        sqlx::query(
+            r#"UPDATE project_comments
+            SET hidden_at = CASE WHEN $1::uuid IS NULL THEN NULL ELSE now() END, hidden_by = $1
+            WHERE id = $2"#,
+        )
+        .bind(hidden_by)
+        .bind(id)
+        .execute(&self.db)
+        .await?;
+        self.get_comment_by_id(id).await
+    }
+
+    async fn report_comment(&self, id: Uuid, user_id: Uuid, reason: String) -> Result<(), DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        sqlx::query(
+            r#"INSERT INTO "project_comment_reports" (comment_id, user_id, reason) values ($1, $2, $3)"#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(reason)
+        .execute(tx.as_mut())
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(dbe)
+                if dbe.constraint() == Some("project_comment_reports_pkey") =>
+            {
+                DbError::Unique("report".into())
+            }
+            _ => DbError::Query(e.to_string()),
+        })?;
+        sqlx::query("UPDATE project_comments SET report_count = report_count + 1 WHERE id = $1")
+            .bind(id)
+            .execute(tx.as_mut())
+            .await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(())
+    }
+
+    async fn count_user_comments_since(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DbError> {
+        Ok(sqlx::query_scalar(
+            "SELECT COUNT(*) FROM project_comments WHERE user_id = $1 AND created_at > $2",
+        )
+        .bind(user_id)
+        .bind(since)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_comments(
+        &self,
+        project_id: Uuid,
+        query: ListProjectCommentsQuery,
+        page: Option<CursorPage>,
+    ) -> Result<ProjectCommentListResults, DbError> {
+        let parent_id = option_string_to_uuid(query.parent_id);
+        let reported = query.reported.unwrap_or(false);
+        // Replies are read oldest first
+        let default_direction = if parent_id.is_some() {
+            SortDirection::Asc
+        } else {
+            SortDirection::Desc
+        };
+        let direction = query.direction.unwrap_or(default_direction);
+        let descending = direction == SortDirection::Desc;
+
+        let mut filtered_query = QueryBuilder::new("");
+        // Cursor pagination filters the count subquery, so the total isn't affected
+        if page.is_some() {
+            filtered_query.push("SELECT * FROM (");
+        }
+        filtered_query.push(format!(
+            "SELECT *, {} FROM ({} WHERE c.project_id = ",
+            cursor_count_column(&page),
+            COMMENT_SELECT
+        ));
+        filtered_query.push_bind(project_id);
+
+        // Filter parent_id. Reported comments are listed at any level
+        if reported {
+            filtered_query.push(" AND c.report_count > 0");
+        } else if let Some(parent_id) = parent_id {
+            filtered_query.push(" AND c.parent_id = ");
+            filtered_query.push_bind(parent_id);
+        } else {
+            filtered_query.push(" AND c.parent_id IS NULL");
+        }
+        filtered_query.push(") as comments");
+
+        if let Some(page) = &page {
+            filtered_query.push(") as filtered");
+            if page.cursor.is_some() {
+                filtered_query.push(" WHERE");
+            }
+            let (q, _) = append_cursor(
+                filtered_query,
+                page,
+                "filtered.created_at",
+                "timestamptz",
+                "filtered.id",
+                descending,
+                0,
+            );
+            filtered_query = append_cursor_order_limit(
+                q,
+                page,
+                "filtered.created_at",
+                "filtered.id",
+                descending,
+            );
+        } else {
+            filtered_query =
+                append_order_by(filtered_query, "created_at".into(), direction.to_string());
+            filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        }
+
+        let results = filtered_query
+            .build()
+            .try_map(map_comment_list_entity)
+            .fetch_all(&self.db)
+            .await?;
+
+        let (results, total) = list_result(results, cursor_include_total(&page));
+        let (results, next_cursor) = if let Some(page) = &page {
+            cursor_result(results, page, |comment: &ProjectCommentEntity| DbCursor {
+                value: comment.created_at.to_rfc3339(),
+                id: comment.id,
+            })
+        } else {
+            (results, None)
+        };
+
+        Ok(ProjectCommentListResults {
+            total,
+            next_cursor,
+            results,
+        })
+    }
+}
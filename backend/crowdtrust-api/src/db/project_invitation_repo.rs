@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::project_member_entity::{
+    ProjectInvitationEntity, ProjectInvitationState, ProjectMemberEntity, ProjectMemberRole,
+};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+use super::{
+    app_repo::start_transaction,
+    project_member_repo::{map_project_member_entity, PROJECT_MEMBER_COLUMNS},
+};
+
+pub type DynProjectInvitationRepo = Arc<dyn ProjectInvitationRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectInvitationRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Returns Unique if the invitee already has a pending invitation to the project
+    async fn create_invitation(
+        &self,
+        props: ProjectInvitationCreateProps,
+    ) -> Result<ProjectInvitationEntity, DbError>;
+    async fn get_invitation_by_id(&self, id: Uuid) -> Result<ProjectInvitationEntity, DbError>;
+    async fn list_project_invitations(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectInvitationEntity>, DbError>;
+    // Pending invitations addressed to the email or eth address
+    async fn list_pending_invitations(
+        &self,
+        email: String,
+        eth_address: String,
+    ) -> Result<Vec<ProjectInvitationEntity>, DbError>;
+    // Closes a pending invitation. Returns EntityNotFound if it's not pending
+    async fn close_invitation(
+        &self,
+        id: Uuid,
+        state: ProjectInvitationState,
+    ) -> Result<ProjectInvitationEntity, DbError>;
+    // Closes the invitation and adds the user to the project, replacing any existing role
+    async fn accept_invitation(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ProjectMemberEntity, DbError>;
+}
+
+pub struct ProjectInvitationRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectInvitationCreateProps {
+    pub project_id: Uuid,
+    pub email: Option<String>,
+    pub eth_address: Option<String>,
+    pub role: ProjectMemberRole,
+    pub invited_by: Uuid,
+}
+
+const PROJECT_INVITATION_COLUMNS: &str =
+    r#"id, project_id, email, eth_address, role, state, invited_by, created_at, updated_at"#;
+
+fn map_project_invitation_entity(row: PgRow) -> Result<ProjectInvitationEntity, sqlx::Error> {
+    Ok(ProjectInvitationEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        email: row.try_get("email")?,
+        eth_address: row.try_get("eth_address")?,
+        role: row.try_get_unchecked("role")?,
+        state: row.try_get_unchecked("state")?,
+        invited_by: row.try_get("invited_by")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectInvitationRepoTrait for ProjectInvitationRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_invitation(
+        &self,
+        props: ProjectInvitationCreateProps,
+    ) -> Result<ProjectInvitationEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_invitations" (project_id, email, eth_address, role, invited_by)
+              values ($1, $2, $3, $4, $5)
+              RETURNING {}
+            "#,
+            PROJECT_INVITATION_COLUMNS
+        ))
+        .bind(props.project_id)
+        .bind(props.email)
+        .bind(props.eth_address)
+        .bind(props.role.to_string())
+        .bind(props.invited_by)
+        .try_map(map_project_invitation_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(dbe)
+                if dbe.constraint() == Some("project_invitations_pending_email_idx")
+                    || dbe.constraint() == Some("project_invitations_pending_eth_address_idx") =>
+            {
+                DbError::Unique("invitation".into())
+            }
+            _ => DbError::Query(e.to_string()),
+        })?)
+    }
+
+    async fn get_invitation_by_id(&self, id: Uuid) -> Result<ProjectInvitationEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_invitations WHERE id = $1",
+            PROJECT_INVITATION_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_invitation_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_project_invitations(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectInvitationEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_invitations WHERE project_id = $1 ORDER BY created_at DESC",
+            PROJECT_INVITATION_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_project_invitation_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn list_pending_invitations(
+        &self,
+        email: String,
+        eth_address: String,
+    ) -> Result<Vec<ProjectInvitationEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"SELECT {} FROM project_invitations
+            WHERE state = 'Pending' AND (lower(email) = lower($1) OR lower(eth_address) = lower($2))
+            ORDER BY created_at DESC"#,
+            PROJECT_INVITATION_COLUMNS
+        ))
+        .bind(email)
+        .bind(eth_address)
+        .try_map(map_project_invitation_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn close_invitation(
+        &self,
+        id: Uuid,
+        state: ProjectInvitationState,
+    ) -> Result<ProjectInvitationEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE project_invitations SET state = $1 WHERE id = $2 AND state = 'Pending' RETURNING {}",
+            PROJECT_INVITATION_COLUMNS
+        ))
+        .bind(state.to_string())
+        .bind(id)
+        .try_map(map_project_invitation_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn accept_invitation(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ProjectMemberEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        let invitation = sqlx::query(formatcp!(
+            "UPDATE project_invitations SET state = 'Accepted' WHERE id = $1 AND state = 'Pending' RETURNING {}",
+            PROJECT_INVITATION_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_invitation_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?;
+
+        let member = sqlx::query(formatcp!(
+            r#"INSERT INTO "project_members" (project_id, user_id, role, invited_by)
+            values ($1, $2, $3, $4)
+            ON CONFLICT (project_id, user_id) DO UPDATE SET role = EXCLUDED.role, invited_by = EXCLUDED.invited_by
+            RETURNING {}"#,
+            PROJECT_MEMBER_COLUMNS
+        ))
+        .bind(invitation.project_id)
+        .bind(user_id)
+        .bind(invitation.role.to_string())
+        .bind(invitation.invited_by)
+        .try_map(map_project_member_entity)
+        .fetch_one(tx.as_mut())
+        .await?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(member)
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::project_member_entity::{ProjectMemberEntity, ProjectMemberRole};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+pub type DynProjectMemberRepo = Arc<dyn ProjectMemberRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectMemberRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Returns None if the user is not a member of the project
+    async fn get_member(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<ProjectMemberEntity>, DbError>;
+    async fn list_members(&self, project_id: Uuid) -> Result<Vec<ProjectMemberEntity>, DbError>;
+    async fn update_member_role(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        role: ProjectMemberRole,
+    ) -> Result<ProjectMemberEntity, DbError>;
+    async fn delete_member(&self, project_id: Uuid, user_id: Uuid) -> Result<(), DbError>;
+}
+
+pub struct ProjectMemberRepo {
+    pub db: PgPool,
+}
+
+pub const PROJECT_MEMBER_COLUMNS: &str =
+    r#"project_id, user_id, role, invited_by, created_at, updated_at"#;
+
+pub fn map_project_member_entity(row: PgRow) -> Result<ProjectMemberEntity, sqlx::Error> {
+    Ok(ProjectMemberEntity {
+        project_id: row.try_get("project_id")?,
+        user_id: row.try_get("user_id")?,
+        role: row.try_get_unchecked("role")?,
+        invited_by: row.try_get("invited_by")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectMemberRepoTrait for ProjectMemberRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn get_member(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<ProjectMemberEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_members WHERE project_id = $1 AND user_id = $2",
+            PROJECT_MEMBER_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(user_id)
+        .try_map(map_project_member_entity)
+        .fetch_optional(&self.db)
+        .await?)
+    }
+
+    async fn list_members(&self, project_id: Uuid) -> Result<Vec<ProjectMemberEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_members WHERE project_id = $1 ORDER BY created_at ASC",
+            PROJECT_MEMBER_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_project_member_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn update_member_role(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        role: ProjectMemberRole,
+    ) -> Result<ProjectMemberEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE project_members SET role = $1 WHERE project_id = $2 AND user_id = $3 RETURNING {}",
+            PROJECT_MEMBER_COLUMNS
+        ))
+        .bind(role.to_string())
+        .bind(project_id)
+        .bind(user_id)
+        .try_map(map_project_member_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn delete_member(&self, project_id: Uuid, user_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM project_members WHERE project_id = $1 AND user_id = $2")
+            .bind(project_id)
+            .bind(user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+}
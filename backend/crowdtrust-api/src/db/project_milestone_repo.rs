@@ -0,0 +1,335 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::{
+    db_error::{map_sqlx_err, DbError},
+    util::append_comma,
+};
+use lib_types::entity::project_milestone_entity::{MilestoneState, ProjectMilestoneEntity};
+use sqlx::{postgres::PgRow, PgPool, QueryBuilder, Row};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynProjectMilestoneRepo = Arc<dyn ProjectMilestoneRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectMilestoneRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_milestone(
+        &self,
+        props: ProjectMilestoneCreateProps,
+    ) -> Result<ProjectMilestoneEntity, DbError>;
+    async fn get_milestone_by_id(&self, id: Uuid) -> Result<ProjectMilestoneEntity, DbError>;
+    // Ordered by target date
+    async fn list_milestones(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectMilestoneEntity>, DbError>;
+    // Total release percent of the project's milestones, except `exclude_id`
+    async fn sum_release_percent(
+        &self,
+        project_id: Uuid,
+        exclude_id: Option<Uuid>,
+    ) -> Result<i64, DbError>;
+    // Updates a Pending milestone. Returns EntityNotFound if it's not Pending
+    async fn update_milestone(
+        &self,
+        id: Uuid,
+        props: ProjectMilestoneUpdateProps,
+    ) -> Result<ProjectMilestoneEntity, DbError>;
+    // Deletes a Pending milestone. Returns EntityNotFound if it's not Pending
+    async fn delete_milestone(&self, id: Uuid) -> Result<(), DbError>;
+    // Starts voting on a Pending milestone. Returns EntityNotFound if it's not Pending
+    async fn open_voting(
+        &self,
+        id: Uuid,
+        ends_at: DateTime<Utc>,
+    ) -> Result<ProjectMilestoneEntity, DbError>;
+    // Records a vote and adds its weight to the tally. Returns Unique if the user already voted,
+    // and EntityNotFound if voting is closed
+    async fn create_vote(
+        &self,
+        props: MilestoneVoteCreateProps,
+    ) -> Result<ProjectMilestoneEntity, DbError>;
+    // Milestones with a voting window that ended
+    async fn list_ended_voting(&self) -> Result<Vec<ProjectMilestoneEntity>, DbError>;
+    // Records the result of a milestone in Voting
+    async fn resolve_milestone(
+        &self,
+        id: Uuid,
+        state: MilestoneState,
+    ) -> Result<ProjectMilestoneEntity, DbError>;
+}
+
+pub struct ProjectMilestoneRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectMilestoneCreateProps {
+    pub project_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub target_date: i64,
+    pub release_percent: i32,
+}
+
+pub struct ProjectMilestoneUpdateProps {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub target_date: Option<i64>,
+    pub release_percent: Option<i32>,
+}
+
+pub struct MilestoneVoteCreateProps {
+    pub milestone_id: Uuid,
+    pub user_id: Uuid,
+    pub approve: bool,
+    pub weight: BigDecimal,
+    pub eth_address: String,
+    pub signature: String,
+}
+
+const PROJECT_MILESTONE_COLUMNS: &str = r#"id, project_id, title, description, target_date, release_percent, state, voting_starts_at, voting_ends_at, approve_weight, reject_weight, vote_count, resolved_at, created_at, updated_at"#;
+
+fn map_project_milestone_entity(row: PgRow) -> Result<ProjectMilestoneEntity, sqlx::Error> {
+    Ok(ProjectMilestoneEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        title: row.try_get("title")?,
+        description: row.try_get("description")?,
+        target_date: row.try_get("target_date")?,
+        release_percent: row.try_get("release_percent")?,
+        state: row.try_get_unchecked("state")?,
+        voting_starts_at: row.try_get("voting_starts_at")?,
+        voting_ends_at: row.try_get("voting_ends_at")?,
+        approve_weight: row.try_get("approve_weight")?,
+        reject_weight: row.try_get("reject_weight")?,
+        vote_count: row.try_get("vote_count")?,
+        resolved_at: row.try_get("resolved_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectMilestoneRepoTrait for ProjectMilestoneRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_milestone(
+        &self,
+        props: ProjectMilestoneCreateProps,
+    ) -> Result<ProjectMilestoneEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_milestones" (project_id, title, description, target_date, release_percent)
+              values ($1, $2, $3, $4, $5)
+              RETURNING {}
+            "#,
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(props.project_id)
+        .bind(props.title)
+        .bind(props.description)
+        .bind(props.target_date)
+        .bind(props.release_percent)
+        .try_map(map_project_milestone_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_milestone_by_id(&self, id: Uuid) -> Result<ProjectMilestoneEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_milestones WHERE id = $1",
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_milestone_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_milestones(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectMilestoneEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_milestones WHERE project_id = $1 ORDER BY target_date, created_at",
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_project_milestone_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn sum_release_percent(
+        &self,
+        project_id: Uuid,
+        exclude_id: Option<Uuid>,
+    ) -> Result<i64, DbError> {
+        Ok(sqlx::query_scalar(
+            r#"SELECT COALESCE(SUM(release_percent), 0) FROM project_milestones
+            WHERE project_id = $1 AND ($2::uuid IS NULL OR id != $2)"#,
+        )
+        .bind(project_id)
+        .bind(exclude_id)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn update_milestone(
+        &self,
+        id: Uuid,
+        props: ProjectMilestoneUpdateProps,
+    ) -> Result<ProjectMilestoneEntity, DbError> {
+        let query = QueryBuilder::new("UPDATE project_milestones SET");
+        let update_count = 0;
+
+        let (query, update_count) = append_comma(query, "title", props.title, update_count);
+        let (query, update_count) =
+            append_comma(query, "description", props.description, update_count);
+        let (query, update_count) =
+            append_comma(query, "target_date", props.target_date, update_count);
+        let (mut query, update_count) = append_comma(
+            query,
+            "release_percent",
+            props.release_percent,
+            update_count,
+        );
+
+        if update_count == 0 {
+            return Err(DbError::NoUpdate);
+        }
+
+        query.push(" WHERE id = ");
+        query.push_bind(id);
+        query.push(" AND state = ");
+        query.push_bind(MilestoneState::Pending.to_string());
+        query.push(formatcp!(" RETURNING {}", PROJECT_MILESTONE_COLUMNS));
+
+        Ok(query
+            .build()
+            .try_map(map_project_milestone_entity)
+            .fetch_one(&self.db)
+            .await
+            .map_err(map_sqlx_err)?)
+    }
+
+    async fn delete_milestone(&self, id: Uuid) -> Result<(), DbError> {
+        let result =
+            sqlx::query(r#"DELETE FROM "project_milestones" WHERE id = $1 AND state = $2"#)
+                .bind(id)
+                .bind(MilestoneState::Pending.to_string())
+                .execute(&self.db)
+                .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::EntityNotFound());
+        }
+        Ok(())
+    }
+
+    async fn open_voting(
+        &self,
+        id: Uuid,
+        ends_at: DateTime<Utc>,
+    ) -> Result<ProjectMilestoneEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"UPDATE project_milestones SET state = $1, voting_starts_at = now(), voting_ends_at = $2
+            WHERE id = $3 AND state = $4 RETURNING {}"#,
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(MilestoneState::Voting.to_string())
+        .bind(ends_at)
+        .bind(id)
+        .bind(MilestoneState::Pending.to_string())
+        .try_map(map_project_milestone_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn create_vote(
+        &self,
+        props: MilestoneVoteCreateProps,
+    ) -> Result<ProjectMilestoneEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        sqlx::query(
+            r#"INSERT INTO "milestone_votes" (milestone_id, user_id, approve, weight, eth_address, signature)
+            values ($1, $2, $3, $4, $5, $6)"#,
+        )
+        .bind(props.milestone_id)
+        .bind(props.user_id)
+        .bind(props.approve)
+        .bind(&props.weight)
+        .bind(props.eth_address)
+        .bind(props.signature)
+        .execute(tx.as_mut())
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(dbe) if dbe.constraint() == Some("milestone_votes_pkey") => {
+                DbError::Unique("vote".into())
+            }
+            _ => DbError::Query(e.to_string()),
+        })?;
+
+        // The window is checked with the tally update, so late votes are rolled back
+        let milestone = sqlx::query(formatcp!(
+            r#"UPDATE project_milestones SET
+              approve_weight = approve_weight + CASE WHEN $1 THEN $2 ELSE 0 END,
+              reject_weight = reject_weight + CASE WHEN $1 THEN 0 ELSE $2 END,
+              vote_count = vote_count + 1
+            WHERE id = $3 AND state = $4 AND voting_ends_at > now()
+            RETURNING {}"#,
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(props.approve)
+        .bind(props.weight)
+        .bind(props.milestone_id)
+        .bind(MilestoneState::Voting.to_string())
+        .try_map(map_project_milestone_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(milestone)
+    }
+
+    async fn list_ended_voting(&self) -> Result<Vec<ProjectMilestoneEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_milestones WHERE state = $1 AND voting_ends_at <= now() ORDER BY voting_ends_at",
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(MilestoneState::Voting.to_string())
+        .try_map(map_project_milestone_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn resolve_milestone(
+        &self,
+        id: Uuid,
+        state: MilestoneState,
+    ) -> Result<ProjectMilestoneEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"UPDATE project_milestones SET state = $1, resolved_at = now()
+            WHERE id = $2 AND state = $3 RETURNING {}"#,
+            PROJECT_MILESTONE_COLUMNS
+        ))
+        .bind(state.to_string())
+        .bind(id)
+        .bind(MilestoneState::Voting.to_string())
+        .try_map(map_project_milestone_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::project_preview_token_entity::ProjectPreviewTokenEntity;
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+pub type DynProjectPreviewTokenRepo = Arc<dyn ProjectPreviewTokenRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectPreviewTokenRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_preview_token(
+        &self,
+        props: ProjectPreviewTokenCreateProps,
+    ) -> Result<ProjectPreviewTokenEntity, DbError>;
+    async fn get_preview_token_by_id(&self, id: Uuid)
+        -> Result<ProjectPreviewTokenEntity, DbError>;
+    async fn list_preview_tokens(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectPreviewTokenEntity>, DbError>;
+    async fn revoke_preview_token(&self, id: Uuid) -> Result<ProjectPreviewTokenEntity, DbError>;
+    // Increments the view count of an active token. Returns EntityNotFound if the token
+    // is revoked or expired
+    async fn add_preview_token_view(&self, id: Uuid) -> Result<ProjectPreviewTokenEntity, DbError>;
+}
+
+pub struct ProjectPreviewTokenRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectPreviewTokenCreateProps {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+const PREVIEW_TOKEN_COLUMNS: &str = r#"id, project_id, user_id, expires_at, revoked_at, view_count, last_viewed_at, created_at, updated_at"#;
+
+fn map_preview_token_entity(row: PgRow) -> Result<ProjectPreviewTokenEntity, sqlx::Error> {
+    Ok(ProjectPreviewTokenEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        user_id: row.try_get("user_id")?,
+        expires_at: row.try_get("expires_at")?,
+        revoked_at: row.try_get("revoked_at")?,
+        view_count: row.try_get("view_count")?,
+        last_viewed_at: row.try_get("last_viewed_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectPreviewTokenRepoTrait for ProjectPreviewTokenRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_preview_token(
+        &self,
+        props: ProjectPreviewTokenCreateProps,
+    ) -> Result<ProjectPreviewTokenEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_preview_tokens" (project_id, user_id, expires_at)
+              values ($1, $2, $3)
+              RETURNING {}
+            "#,
+            PREVIEW_TOKEN_COLUMNS
+        ))
+        .bind(props.project_id)
+        .bind(props.user_id)
+        .bind(props.expires_at)
+        .try_map(map_preview_token_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_preview_token_by_id(
+        &self,
+        id: Uuid,
+    ) -> Result<ProjectPreviewTokenEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_preview_tokens WHERE id = $1",
+            PREVIEW_TOKEN_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_preview_token_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_preview_tokens(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectPreviewTokenEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_preview_tokens WHERE project_id = $1 ORDER BY created_at DESC",
+            PREVIEW_TOKEN_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_preview_token_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn revoke_preview_token(&self, id: Uuid) -> Result<ProjectPreviewTokenEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE project_preview_tokens SET revoked_at = COALESCE(revoked_at, now()) WHERE id = $1 RETURNING {}",
+            PREVIEW_TOKEN_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_preview_token_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn add_preview_token_view(&self, id: Uuid) -> Result<ProjectPreviewTokenEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"UPDATE project_preview_tokens SET view_count = view_count + 1, last_viewed_at = now()
+            WHERE id = $1 AND revoked_at IS NULL AND expires_at > now()
+            RETURNING {}"#,
+            PREVIEW_TOKEN_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_preview_token_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+}
@@ -6,7 +6,11 @@ use const_format::formatcp;
 use lib_api::db::{
     db_error::{map_sqlx_err, DbError},
     db_result::list_result,
-    util::{append_comma, append_in, append_limit_offset, append_op, append_order_by, DbOp},
+    util::{
+        append_comma, append_cursor, append_cursor_order_limit, append_in, append_limit_offset,
+        append_op, append_order_by, cursor_count_column, cursor_include_total, cursor_result,
+        to_prefix_tsquery, CursorPage, DbCursor, DbOp,
+    },
 };
 use lib_types::{
     dto::{
@@ -17,13 +21,20 @@ use lib_types::{
         project_entity::{
             ProjectAssetEntityRelation, ProjectEntity, ProjectEntityRelations, ProjectListResults,
         },
-        reward_entity::{RewardAssetEntityRelation, RewardEntity},
+        reward_entity::{
+            PriceTier, RewardAssetEntityRelation, RewardEntity, RewardVariant, ShippingCost,
+        },
+    },
+    shared::project::{
+        BlockchainStatus, FundingModel, FundingOutcome, PaymentCurrency, ProjectCategory,
+        ProjectStatus,
     },
-    shared::project::{BlockchainStatus, PaymentCurrency, ProjectCategory, ProjectStatus},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::to_string;
-use sqlx::{postgres::PgRow, PgPool, Postgres, QueryBuilder, Row, Transaction};
+use sqlx::{
+    postgres::PgRow, types::Json, Decode, PgPool, Postgres, QueryBuilder, Row, Transaction, Type,
+};
 use uuid::Uuid;
 
 use super::app_repo::start_transaction;
@@ -34,12 +45,14 @@ pub type DynProjectRepo = Arc<dyn ProjectRepoTrait + Send + Sync>;
 pub struct ProjectCreateProps {
     pub user_id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub blurb: String,
     pub contract_address: String,
     pub payment_address: String,
     pub category: ProjectCategory,
     pub funding_goal: BigDecimal,
+    pub funding_model: FundingModel,
     pub start_time: i64,
     pub duration: i64,
     pub total_pledged: BigDecimal,
@@ -51,11 +64,14 @@ pub struct ProjectCreateProps {
 #[derive(Debug, Deserialize, Serialize, sqlx::Type)]
 pub struct ProjectUpdateProps {
     pub name: Option<String>,
+    // The previous slug is kept in history, so old links still resolve
+    pub slug: Option<String>,
     pub description: Option<String>,
     pub blurb: Option<String>,
     pub payment_address: Option<String>,
     pub category: Option<ProjectCategory>,
     pub funding_goal: Option<BigDecimal>,
+    pub funding_model: Option<FundingModel>,
     pub start_time: Option<i64>,
     pub duration: Option<i64>,
     pub backer_count: Option<i32>,
@@ -72,11 +88,13 @@ impl ProjectUpdateProps {
     pub fn asset_order(order: Vec<String>) -> Self {
         Self {
             name: None,
+            slug: None,
             description: None,
             blurb: None,
             payment_address: None,
             category: None,
             funding_goal: None,
+            funding_model: None,
             start_time: None,
             duration: None,
             total_pledged: None,
@@ -92,11 +110,13 @@ impl ProjectUpdateProps {
     pub fn backed(backer_count: i32, total_pledged: BigDecimal) -> Self {
         Self {
             name: None,
+            slug: None,
             description: None,
             blurb: None,
             payment_address: None,
             category: None,
             funding_goal: None,
+            funding_model: None,
             start_time: None,
             duration: None,
             total_pledged: Some(total_pledged),
@@ -126,13 +146,48 @@ pub trait ProjectRepoTrait {
         id: Uuid,
         props: ProjectUpdateProps,
     ) -> Result<ProjectEntity, DbError>;
+    // Moves the project and its assets to a new owner. The new owner is removed from the team
+    async fn transfer_project_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        user_id: Uuid,
+        payment_address: Option<String>,
+    ) -> Result<ProjectEntity, DbError>;
+    // Records the outcome of an Active project and completes it
+    async fn set_funding_outcome_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        outcome: FundingOutcome,
+    ) -> Result<ProjectEntity, DbError>;
+    // Locks an Active project until the transaction ends. Not found if it's no longer Active
+    async fn lock_active_project_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<ProjectEntity, DbError>;
+    // Active projects past their end time
+    async fn list_ended_projects(&self) -> Result<Vec<ProjectEntity>, DbError>;
+    // True if the user owns a Prelaunch or Active project
+    async fn has_open_projects(&self, user_id: Uuid) -> Result<bool, DbError>;
     async fn get_project_by_id(&self, id: Uuid) -> Result<ProjectEntity, DbError>;
+    async fn get_project_by_slug(&self, slug: &str) -> Result<ProjectEntity, DbError>;
+    async fn get_available_slug(
+        &self,
+        slug: &str,
+        project_id: Option<Uuid>,
+    ) -> Result<String, DbError>;
     async fn get_project_relations_by_id(
         &self,
         id: Uuid,
         all_assets: bool,
     ) -> Result<ProjectEntityRelations, DbError>;
-    async fn list_projects(&self, query: ListProjectsQuery) -> Result<ProjectListResults, DbError>;
+    async fn list_projects(
+        &self,
+        query: ListProjectsQuery,
+        page: Option<CursorPage>,
+    ) -> Result<ProjectListResults, DbError>;
 }
 
 pub struct ProjectRepo {
@@ -140,18 +195,36 @@ pub struct ProjectRepo {
 }
 
 const PROJECT_COLUMNS: &str = formatcp!(
-    r#"{p}.id, {p}.user_id, {p}.name, {p}.description, {p}.blurb, {p}.contract_address, {p}.payment_address, {p}.category, {p}.funding_goal, {p}.start_time, {p}.duration, {p}.total_pledged, {p}.backer_count, {p}.base_currency, {p}.status, {p}.blockchain_status, {p}.transaction_hash, {p}.rewards_order, {p}.assets_order, {p}.created_at, {p}.updated_at"#,
+    r#"{p}.id, {p}.user_id, {p}.name, {p}.slug, {p}.description, {p}.blurb, {p}.contract_address, {p}.payment_address, {p}.category, {p}.funding_goal, {p}.funding_model, {p}.funding_outcome, {p}.outcome_at, {p}.start_time, {p}.duration, {p}.total_pledged, {p}.backer_count, {p}.base_currency, {p}.status, {p}.blockchain_status, {p}.transaction_hash, {p}.rewards_order, {p}.assets_order, {p}.created_at, {p}.updated_at"#,
     p = "projects"
 );
 
 const PROJECT_RELATION_COLUMNS: &str = formatcp!(
-    r#"{projects}, {r}.id as r_id, {r}.name as r_name, {r}.description as r_description, {r}.delivery_time as r_delivery_time, {r}.price as r_price, {r}.backer_limit as r_backer_limit, {r}.backer_count as r_backer_count, {r}.created_at as r_created_at, {r}.updated_at as r_updated_at, {ri}.id as ri_id, {ri}.size as ri_size, {ri}.content_type as ri_content_type, {a}.id as a_id, {a}.size a_size, {a}.content_type as a_content_type"#,
+    r#"{projects}, {r}.id as r_id, {r}.name as r_name, {r}.description as r_description, {r}.delivery_time as r_delivery_time, {r}.price as r_price, {r}.backer_limit as r_backer_limit, {r}.backer_count as r_backer_count, {r}.visible as r_visible, {r}.shipping_required as r_shipping_required, {r}.shipping_costs as r_shipping_costs, {r}.variants as r_variants, {r}.price_tiers as r_price_tiers, {r}.parent_reward_id as r_parent_reward_id, {r}.created_at as r_created_at, {r}.updated_at as r_updated_at, {ri}.id as ri_id, {ri}.size as ri_size, {ri}.content_type as ri_content_type, {a}.id as a_id, {a}.size a_size, {a}.content_type as a_content_type"#,
     projects = PROJECT_COLUMNS,
     r = "r",
     ri = "ri",
     a = "a"
 );
 
+// Percent of the funding goal pledged, computed for list filters and sorting
+const PERCENT_FUNDED: &str = "CASE WHEN projects.funding_goal > 0 THEN projects.total_pledged * 100 / projects.funding_goal ELSE 0 END";
+
+// Score of the creator's latest reputation snapshot
+const CREATOR_REPUTATION: &str = "(SELECT score FROM user_reputations WHERE user_reputations.user_id = projects.user_id ORDER BY computed_at DESC LIMIT 1)";
+
+// Computed columns that only some queries select. Missing columns are None,
+// but decode errors are still returned
+fn try_get_computed<'r, T>(row: &'r PgRow, column: &str) -> Result<Option<T>, sqlx::Error>
+where
+    T: Decode<'r, Postgres> + Type<Postgres>,
+{
+    match row.try_get(column) {
+        Err(sqlx::Error::ColumnNotFound(_)) => Ok(None),
+        result => result,
+    }
+}
+
 fn map_project_entity(row: PgRow) -> Result<ProjectEntity, sqlx::Error> {
     let project_id: Uuid = row.try_get("id")?;
     let assets = if let Ok(asset_id) = row.try_get::<Uuid, &str>("a_id") {
@@ -168,12 +241,16 @@ fn map_project_entity(row: PgRow) -> Result<ProjectEntity, sqlx::Error> {
         id: project_id,
         user_id: row.try_get("user_id")?,
         name: row.try_get("name")?,
+        slug: row.try_get("slug")?,
         description: row.try_get("description")?,
         blurb: row.try_get("blurb")?,
         contract_address: row.try_get("contract_address")?,
         payment_address: row.try_get("payment_address")?,
         category: row.try_get_unchecked("category")?,
         funding_goal: row.try_get("funding_goal")?,
+        funding_model: row.try_get_unchecked("funding_model")?,
+        funding_outcome: row.try_get_unchecked("funding_outcome")?,
+        outcome_at: row.try_get("outcome_at")?,
         start_time: row.try_get("start_time")?,
         duration: row.try_get("duration")?,
         total_pledged: row.try_get("total_pledged")?,
@@ -185,6 +262,10 @@ fn map_project_entity(row: PgRow) -> Result<ProjectEntity, sqlx::Error> {
         rewards_order: row.try_get("rewards_order")?,
         assets,
         assets_order: row.try_get("assets_order")?,
+        search_snippet: try_get_computed(&row, "search_snippet")?,
+        percent_funded: try_get_computed(&row, "percent_funded")?,
+        trending_score: try_get_computed(&row, "trending_score")?,
+        creator_reputation: try_get_computed(&row, "creator_reputation")?,
         created_at: row.try_get("created_at")?,
         updated_at: row.try_get("updated_at")?,
     })
@@ -199,12 +280,16 @@ fn map_project_relation_entity(
         id: row.try_get("id")?,
         user_id: row.try_get("user_id")?,
         name: row.try_get("name")?,
+        slug: row.try_get("slug")?,
         description: row.try_get("description")?,
         blurb: row.try_get("blurb")?,
         contract_address: row.try_get("contract_address")?,
         payment_address: row.try_get("payment_address")?,
         category: row.try_get_unchecked("category")?,
         funding_goal: row.try_get("funding_goal")?,
+        funding_model: row.try_get_unchecked("funding_model")?,
+        funding_outcome: row.try_get_unchecked("funding_outcome")?,
+        outcome_at: row.try_get("outcome_at")?,
         start_time: row.try_get("start_time")?,
         duration: row.try_get("duration")?,
         total_pledged: row.try_get("total_pledged")?,
@@ -222,7 +307,7 @@ fn map_project_relation_entity(
     })
 }
 
-fn map_project_list_entity(row: PgRow) -> Result<(ProjectEntity, i64), sqlx::Error> {
+fn map_project_list_entity(row: PgRow) -> Result<(ProjectEntity, Option<i64>), sqlx::Error> {
     let count = row.try_get("count")?;
     let entity = map_project_entity(row)?;
     Ok((entity, count))
@@ -238,8 +323,8 @@ impl ProjectRepoTrait for ProjectRepo {
         Ok(sqlx::query(formatcp!(
             // language=PostgreSQL
             r#"
-              INSERT INTO "projects" (user_id, name, description, blurb, contract_address, payment_address, category, funding_goal, start_time, duration, total_pledged, base_currency, status, blockchain_status)
-              values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+              INSERT INTO "projects" (user_id, name, description, blurb, contract_address, payment_address, category, funding_goal, start_time, duration, total_pledged, base_currency, status, blockchain_status, slug, funding_model)
+              values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
               RETURNING {}
             "#,
             PROJECT_COLUMNS
@@ -258,12 +343,17 @@ impl ProjectRepoTrait for ProjectRepo {
         .bind(props.base_currency.to_string())
         .bind(props.status.to_string())
         .bind(props.blockchain_status.to_string())
+        .bind(props.slug)
+        .bind(props.funding_model.to_string())
         .try_map(map_project_entity)
         .fetch_one(&self.db)
         .await.map_err(|e| match e {
             sqlx::Error::Database(dbe) if dbe.constraint() == Some("projects_name_key") => {
                 DbError::Unique("name".into())
             }
+            sqlx::Error::Database(dbe) if dbe.constraint() == Some("projects_slug_key") => {
+                DbError::Unique("slug".into())
+            }
             _ => DbError::Query(e.to_string()),
         })?)
     }
@@ -285,10 +375,29 @@ impl ProjectRepoTrait for ProjectRepo {
         id: Uuid,
         props: ProjectUpdateProps,
     ) -> Result<ProjectEntity, DbError> {
+        // Keep the previous slug for lookups, and reclaim the new slug if it was used before
+        if let Some(slug) = &props.slug {
+            sqlx::query(
+                r#"INSERT INTO "project_slugs" (slug, project_id)
+                SELECT slug, id FROM projects WHERE id = $1 AND slug <> $2
+                ON CONFLICT (slug) DO NOTHING"#,
+            )
+            .bind(id)
+            .bind(slug)
+            .execute(tx.as_mut())
+            .await?;
+            sqlx::query(r#"DELETE FROM "project_slugs" WHERE project_id = $1 AND slug = $2"#)
+                .bind(id)
+                .bind(slug)
+                .execute(tx.as_mut())
+                .await?;
+        }
+
         let query = QueryBuilder::new("UPDATE projects SET");
         let update_count = 0;
 
         let (query, update_count) = append_comma(query, "name", props.name, update_count);
+        let (query, update_count) = append_comma(query, "slug", props.slug, update_count);
         let (query, update_count) =
             append_comma(query, "description", props.description, update_count);
         let (query, update_count) = append_comma(query, "blurb", props.blurb, update_count);
@@ -301,6 +410,12 @@ impl ProjectRepoTrait for ProjectRepo {
         );
         let (query, update_count) =
             append_comma(query, "funding_goal", props.funding_goal, update_count);
+        let (query, update_count) = append_comma(
+            query,
+            "funding_model",
+            props.funding_model.map(|m| m.to_string()),
+            update_count,
+        );
         let (query, update_count) = append_comma(
             query,
             "payment_address",
@@ -367,10 +482,125 @@ impl ProjectRepoTrait for ProjectRepo {
                 sqlx::Error::Database(dbe) if dbe.constraint() == Some("projects_name_key") => {
                     DbError::Unique("name".into())
                 }
+                sqlx::Error::Database(dbe) if dbe.constraint() == Some("projects_slug_key") => {
+                    DbError::Unique("slug".into())
+                }
                 _ => DbError::Query(e.to_string()),
             })?)
     }
 
+    async fn transfer_project_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        user_id: Uuid,
+        payment_address: Option<String>,
+    ) -> Result<ProjectEntity, DbError> {
+        let project = sqlx::query(formatcp!(
+            r#"UPDATE projects SET user_id = $1, payment_address = COALESCE($2, payment_address)
+            WHERE id = $3 RETURNING {}"#,
+            PROJECT_COLUMNS
+        ))
+        .bind(user_id)
+        .bind(payment_address)
+        .bind(id)
+        .try_map(map_project_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?;
+
+        sqlx::query("UPDATE project_assets SET user_id = $1 WHERE project_id = $2")
+            .bind(user_id)
+            .bind(id)
+            .execute(tx.as_mut())
+            .await?;
+        sqlx::query(
+            r#"UPDATE reward_assets ra SET user_id = $1
+            FROM rewards r WHERE r.id = ra.reward_id AND r.project_id = $2"#,
+        )
+        .bind(user_id)
+        .bind(id)
+        .execute(tx.as_mut())
+        .await?;
+        sqlx::query(
+            r#"UPDATE project_update_assets ua SET user_id = $1
+            FROM project_updates u WHERE u.id = ua.update_id AND u.project_id = $2"#,
+        )
+        .bind(user_id)
+        .bind(id)
+        .execute(tx.as_mut())
+        .await?;
+        sqlx::query("DELETE FROM project_members WHERE project_id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(tx.as_mut())
+            .await?;
+
+        Ok(project)
+    }
+
+    async fn set_funding_outcome_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        outcome: FundingOutcome,
+    ) -> Result<ProjectEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"UPDATE projects SET status = $1, funding_outcome = $2, outcome_at = now()
+            WHERE id = $3 AND status = $4 RETURNING {}"#,
+            PROJECT_COLUMNS
+        ))
+        .bind(ProjectStatus::Complete.to_string())
+        .bind(outcome.to_string())
+        .bind(id)
+        .bind(ProjectStatus::Active.to_string())
+        .try_map(map_project_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn lock_active_project_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<ProjectEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM projects WHERE id = $1 AND status = $2 FOR UPDATE",
+            PROJECT_COLUMNS
+        ))
+        .bind(id)
+        .bind(ProjectStatus::Active.to_string())
+        .try_map(map_project_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_ended_projects(&self) -> Result<Vec<ProjectEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"SELECT {} FROM projects
+            WHERE status = $1 AND start_time + duration <= extract(epoch from now())
+            ORDER BY start_time + duration"#,
+            PROJECT_COLUMNS
+        ))
+        .bind(ProjectStatus::Active.to_string())
+        .try_map(map_project_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn has_open_projects(&self, user_id: Uuid) -> Result<bool, DbError> {
+        Ok(sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE user_id = $1 AND status IN ($2, $3))",
+        )
+        .bind(user_id)
+        .bind(ProjectStatus::Prelaunch.to_string())
+        .bind(ProjectStatus::Active.to_string())
+        .fetch_one(&self.db)
+        .await?)
+    }
+
     async fn get_project_by_id(&self, id: Uuid) -> Result<ProjectEntity, DbError> {
         Ok(sqlx::query(formatcp!(
             "SELECT {} FROM \"projects\" WHERE id = $1",
@@ -383,6 +613,49 @@ impl ProjectRepoTrait for ProjectRepo {
         .map_err(map_sqlx_err)?)
     }
 
+    async fn get_project_by_slug(&self, slug: &str) -> Result<ProjectEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"SELECT {} FROM "projects"
+            LEFT OUTER JOIN project_slugs h on h.project_id = projects.id AND h.slug = $1
+            WHERE projects.slug = $1 OR h.slug IS NOT NULL
+            LIMIT 1"#,
+            PROJECT_COLUMNS
+        ))
+        .bind(slug)
+        .try_map(map_project_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn get_available_slug(
+        &self,
+        slug: &str,
+        project_id: Option<Uuid>,
+    ) -> Result<String, DbError> {
+        // Current and previous slugs of other projects, which match `slug` or `slug-N`
+        let taken: HashSet<String> = sqlx::query(
+            r#"SELECT slug FROM "projects" WHERE (slug = $1 OR slug LIKE $2) AND id IS DISTINCT FROM $3
+            UNION SELECT slug FROM "project_slugs" WHERE (slug = $1 OR slug LIKE $2) AND project_id IS DISTINCT FROM $3"#,
+        )
+        .bind(slug)
+        .bind(format!("{}-%", slug))
+        .bind(project_id)
+        .try_map(|row: PgRow| row.try_get("slug"))
+        .fetch_all(&self.db)
+        .await?
+        .into_iter()
+        .collect();
+
+        let mut candidate = slug.to_string();
+        let mut suffix = 1;
+        while taken.contains(&candidate) {
+            suffix += 1;
+            candidate = format!("{}-{}", slug, suffix);
+        }
+        Ok(candidate)
+    }
+
     async fn get_project_relations_by_id(
         &self,
         id: Uuid,
@@ -438,6 +711,18 @@ impl ProjectRepoTrait for ProjectRepo {
                         backer_limit: row.try_get("r_backer_limit")?,
                         image,
                         backer_count: row.try_get("r_backer_count")?,
+                        visible: row.try_get("r_visible")?,
+                        shipping_required: row.try_get("r_shipping_required")?,
+                        shipping_costs: row
+                            .try_get::<Json<Vec<ShippingCost>>, &str>("r_shipping_costs")?
+                            .0,
+                        variants: row
+                            .try_get::<Json<Vec<RewardVariant>>, &str>("r_variants")?
+                            .0,
+                        price_tiers: row
+                            .try_get::<Json<Vec<PriceTier>>, &str>("r_price_tiers")?
+                            .0,
+                        parent_reward_id: row.try_get("r_parent_reward_id")?,
                         created_at: row.try_get("r_created_at")?,
                         updated_at: row.try_get("r_updated_at")?,
                     });
@@ -462,10 +747,61 @@ impl ProjectRepoTrait for ProjectRepo {
         }
     }
 
-    async fn list_projects(&self, query: ListProjectsQuery) -> Result<ProjectListResults, DbError> {
-        let mut filtered_query = QueryBuilder::new(format!("SELECT {}, a.id as a_id, a.size a_size, a.content_type as a_content_type, COUNT(projects.id) OVER () FROM \"projects\" LEFT OUTER JOIN project_assets a on a.project_id = projects.id AND projects.assets_order[1] = a.id::text", PROJECT_COLUMNS));
+    async fn list_projects(
+        &self,
+        query: ListProjectsQuery,
+        page: Option<CursorPage>,
+    ) -> Result<ProjectListResults, DbError> {
+        let search = query.q.as_deref().and_then(to_prefix_tsquery);
+
+        let mut filtered_query = QueryBuilder::new("");
+        // Cursor pagination filters the count subquery, so the total isn't affected
+        if page.is_some() {
+            filtered_query.push("SELECT * FROM (");
+        }
+        filtered_query.push(format!(
+            "SELECT {}, a.id as a_id, a.size a_size, a.content_type as a_content_type, {}, projects.start_time + projects.duration as end_time, {} as percent_funded, {} as creator_reputation",
+            PROJECT_COLUMNS,
+            cursor_count_column(&page),
+            PERCENT_FUNDED,
+            CREATOR_REPUTATION
+        ));
+        let trending = query.column == Some(ProjectSortColumn::Trending);
+        if trending {
+            filtered_query.push(", COALESCE(t.trending_score, 0) as trending_score");
+        }
+        // Search rank and highlighted snippet
+        if let Some(search) = &search {
+            filtered_query.push(", ts_rank(projects.search_vector, to_tsquery('english', ");
+            filtered_query.push_bind(search.clone());
+            filtered_query.push(")) as relevance, ts_headline('english', projects.description, to_tsquery('english', ");
+            filtered_query.push_bind(search.clone());
+            filtered_query.push(
+                "), 'MaxFragments=2, MinWords=5, MaxWords=20, StartSel=<mark>, StopSel=</mark>') as search_snippet",
+            );
+        }
+        filtered_query.push(" FROM \"projects\" LEFT OUTER JOIN project_assets a on a.project_id = projects.id AND projects.assets_order[1] = a.id::text");
+        // Pledge velocity, only counts pledges in the trending window
+        if trending {
+            let days = query.trending_days.unwrap_or(7);
+            filtered_query.push(" LEFT OUTER JOIN (SELECT project_id, COUNT(*)::numeric / ");
+            filtered_query.push_bind(days);
+            filtered_query.push(" as trending_score FROM pledges WHERE blockchain_status <> ");
+            filtered_query.push_bind(BlockchainStatus::Error.to_string());
+            filtered_query.push(" AND created_at > NOW() - make_interval(days => ");
+            filtered_query.push_bind(days);
+            filtered_query.push(") GROUP BY project_id) t on t.project_id = projects.id");
+        }
 
-        if query.categories.is_some() || query.statuses.is_some() || query.user_id.is_some() {
+        if query.categories.is_some()
+            || query.statuses.is_some()
+            || query.user_id.is_some()
+            || search.is_some()
+            || query.funded.is_some()
+            || query.live.is_some()
+            || query.min_percent_funded.is_some()
+            || query.max_percent_funded.is_some()
+        {
             filtered_query.push(" WHERE");
         }
 
@@ -474,7 +810,7 @@ impl ProjectRepoTrait for ProjectRepo {
         // Filter statuses
         let (filtered_query, count) = append_in(filtered_query, "status", query.statuses, count);
         // Filter user_id
-        let (mut filtered_query, _) = if let Some(user_id) = query.user_id {
+        let (filtered_query, count) = if let Some(user_id) = query.user_id {
             let (mut q, c) = append_op(filtered_query, DbOp::And, count);
             q.push(" projects.user_id::text = ");
             q.push_bind(user_id);
@@ -482,13 +818,104 @@ impl ProjectRepoTrait for ProjectRepo {
         } else {
             (filtered_query, count)
         };
+        // Filter search
+        let (filtered_query, count) = if let Some(search) = &search {
+            let (mut q, c) = append_op(filtered_query, DbOp::And, count);
+            q.push(" projects.search_vector @@ to_tsquery('english', ");
+            q.push_bind(search.clone());
+            q.push(")");
+            (q, c)
+        } else {
+            (filtered_query, count)
+        };
+        // Filter funded
+        let (filtered_query, count) = if let Some(funded) = query.funded {
+            let (mut q, c) = append_op(filtered_query, DbOp::And, count);
+            if funded {
+                q.push(" projects.total_pledged >= projects.funding_goal");
+            } else {
+                q.push(" projects.total_pledged < projects.funding_goal");
+            }
+            (q, c)
+        } else {
+            (filtered_query, count)
+        };
+        // Filter live, active and between the start and end time
+        let (filtered_query, count) = if let Some(live) = query.live {
+            let (mut q, c) = append_op(filtered_query, DbOp::And, count);
+            q.push(if live { " (" } else { " NOT (" });
+            q.push("projects.status = ");
+            q.push_bind(ProjectStatus::Active.to_string());
+            q.push(" AND projects.start_time <= EXTRACT(EPOCH FROM NOW())::bigint AND projects.start_time + projects.duration > EXTRACT(EPOCH FROM NOW())::bigint)");
+            (q, c)
+        } else {
+            (filtered_query, count)
+        };
+        // Filter funding percentage range
+        let (filtered_query, count) = if let Some(min) = query.min_percent_funded {
+            let (mut q, c) = append_op(filtered_query, DbOp::And, count);
+            q.push(format!(" {} >= ", PERCENT_FUNDED));
+            q.push_bind(min);
+            (q, c)
+        } else {
+            (filtered_query, count)
+        };
+        let (mut filtered_query, _) = if let Some(max) = query.max_percent_funded {
+            let (mut q, c) = append_op(filtered_query, DbOp::And, count);
+            q.push(format!(" {} <= ", PERCENT_FUNDED));
+            q.push_bind(max);
+            (q, c)
+        } else {
+            (filtered_query, count)
+        };
         // ORDER BY
-        let column = to_string(&query.column.unwrap_or(ProjectSortColumn::CreatedAt))
-            .map_err(|e| DbError::Serialize(e.to_string()))?;
+        // Search results are sorted by relevance by default, which requires a search.
+        // Relevance isn't a stored column, so cursor pages fall back to creation order
+        let sort_column = match (query.column, &search) {
+            (None, Some(_)) if page.is_none() => ProjectSortColumn::Relevance,
+            (Some(ProjectSortColumn::Relevance), Some(_)) if page.is_none() => {
+                ProjectSortColumn::Relevance
+            }
+            (Some(ProjectSortColumn::Relevance), _) | (None, _) => ProjectSortColumn::CreatedAt,
+            (Some(column), _) => column,
+        };
+        let column = if sort_column == ProjectSortColumn::Trending {
+            "trending_score".to_string()
+        } else {
+            to_string(&sort_column).map_err(|e| DbError::Serialize(e.to_string()))?
+        };
         let direction = query.direction.unwrap_or(SortDirection::Desc);
 
-        filtered_query = append_order_by(filtered_query, column, direction.to_string());
-        filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        if let Some(page) = &page {
+            let cast = match sort_column {
+                ProjectSortColumn::TotalPledged
+                | ProjectSortColumn::FundingGoal
+                | ProjectSortColumn::PercentFunded
+                | ProjectSortColumn::Trending => "numeric",
+                ProjectSortColumn::EndTime => "bigint",
+                ProjectSortColumn::BackerCount => "integer",
+                _ => "timestamptz",
+            };
+            let column = format!("projects.{}", column);
+            let descending = direction == SortDirection::Desc;
+            filtered_query.push(") as projects");
+            if page.cursor.is_some() {
+                filtered_query.push(" WHERE");
+            }
+            let (q, _) = append_cursor(
+                filtered_query,
+                page,
+                &column,
+                cast,
+                "projects.id",
+                descending,
+                0,
+            );
+            filtered_query = append_cursor_order_limit(q, page, &column, "projects.id", descending);
+        } else {
+            filtered_query = append_order_by(filtered_query, column, direction.to_string());
+            filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        }
 
         let results = filtered_query
             .build()
@@ -496,8 +923,39 @@ impl ProjectRepoTrait for ProjectRepo {
             .fetch_all(&self.db)
             .await?;
 
-        let (results, total) = list_result(results);
+        let (results, total) = list_result(results, cursor_include_total(&page));
+        let (results, next_cursor) = if let Some(page) = &page {
+            cursor_result(results, page, |project: &ProjectEntity| DbCursor {
+                value: match sort_column {
+                    ProjectSortColumn::TotalPledged => project.total_pledged.to_string(),
+                    ProjectSortColumn::FundingGoal => project.funding_goal.to_string(),
+                    ProjectSortColumn::EndTime => {
+                        (project.start_time + project.duration).to_string()
+                    }
+                    ProjectSortColumn::PercentFunded => project
+                        .percent_funded
+                        .as_ref()
+                        .map(|p| p.to_string())
+                        .unwrap_or_default(),
+                    ProjectSortColumn::BackerCount => project.backer_count.to_string(),
+                    ProjectSortColumn::Trending => project
+                        .trending_score
+                        .as_ref()
+                        .map(|t| t.to_string())
+                        .unwrap_or_default(),
+                    ProjectSortColumn::UpdatedAt => project.updated_at.to_rfc3339(),
+                    _ => project.created_at.to_rfc3339(),
+                },
+                id: project.id,
+            })
+        } else {
+            (results, None)
+        };
 
-        Ok(ProjectListResults { total, results })
+        Ok(ProjectListResults {
+            total,
+            next_cursor,
+            results,
+        })
     }
 }
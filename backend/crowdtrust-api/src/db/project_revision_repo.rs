@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::{
+    db_error::DbError,
+    db_result::list_result,
+    util::{append_limit_offset, option_string_to_uuid},
+};
+use lib_types::{
+    dto::project_revision::list_project_revisions_dto::ListProjectRevisionsQuery,
+    entity::project_revision_entity::{
+        ProjectRevisionEntity, ProjectRevisionListResults, RevisionAction, RevisionChange,
+    },
+};
+use sqlx::{postgres::PgRow, types::Json, PgPool, Postgres, QueryBuilder, Row, Transaction};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynProjectRevisionRepo = Arc<dyn ProjectRevisionRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectRevisionRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_revision(
+        &self,
+        props: ProjectRevisionCreateProps,
+    ) -> Result<ProjectRevisionEntity, DbError>;
+    async fn create_revision_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: ProjectRevisionCreateProps,
+    ) -> Result<ProjectRevisionEntity, DbError>;
+    async fn list_revisions(
+        &self,
+        project_id: Uuid,
+        query: ListProjectRevisionsQuery,
+    ) -> Result<ProjectRevisionListResults, DbError>;
+}
+
+pub struct ProjectRevisionRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectRevisionCreateProps {
+    pub project_id: Uuid,
+    pub reward_id: Option<Uuid>,
+    pub user_id: Uuid,
+    pub action: RevisionAction,
+    pub changes: Vec<RevisionChange>,
+}
+
+const REVISION_COLUMNS: &str = r#"id, project_id, reward_id, user_id, action, changes, created_at"#;
+
+fn map_revision_entity(row: PgRow) -> Result<ProjectRevisionEntity, sqlx::Error> {
+    let changes: Json<Vec<RevisionChange>> = row.try_get("changes")?;
+    Ok(ProjectRevisionEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        reward_id: row.try_get("reward_id")?,
+        user_id: row.try_get("user_id")?,
+        action: row.try_get_unchecked("action")?,
+        changes: changes.0,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+fn map_revision_list_entity(
+    row: PgRow,
+) -> Result<(ProjectRevisionEntity, Option<i64>), sqlx::Error> {
+    let count = row.try_get("count")?;
+    let entity = map_revision_entity(row)?;
+    Ok((entity, count))
+}
+
+#[async_trait]
+impl ProjectRevisionRepoTrait for ProjectRevisionRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_revision(
+        &self,
+        props: ProjectRevisionCreateProps,
+    ) -> Result<ProjectRevisionEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        let result = self.create_revision_tx(&mut tx, props).await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(result)
+    }
+
+    async fn create_revision_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: ProjectRevisionCreateProps,
+    ) -> Result<ProjectRevisionEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_revisions" (project_id, reward_id, user_id, action, changes)
+              values ($1, $2, $3, $4, $5)
+              RETURNING {}
+            "#,
+            REVISION_COLUMNS
+        ))
+        .bind(props.project_id)
+        .bind(props.reward_id)
+        .bind(props.user_id)
+        .bind(props.action.to_string())
+        .bind(Json(props.changes))
+        .try_map(map_revision_entity)
+        .fetch_one(tx.as_mut())
+        .await?)
+    }
+
+    async fn list_revisions(
+        &self,
+        project_id: Uuid,
+        query: ListProjectRevisionsQuery,
+    ) -> Result<ProjectRevisionListResults, DbError> {
+        let mut filtered_query = QueryBuilder::new(formatcp!(
+            "SELECT {}, COUNT(*) OVER () as count FROM project_revisions WHERE project_id = ",
+            REVISION_COLUMNS
+        ));
+        filtered_query.push_bind(project_id);
+
+        // Filter reward_id
+        if let Some(reward_id) = option_string_to_uuid(query.reward_id) {
+            filtered_query.push(" AND reward_id = ");
+            filtered_query.push_bind(reward_id);
+        }
+        filtered_query.push(" ORDER BY created_at DESC");
+        filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+
+        let results = filtered_query
+            .build()
+            .try_map(map_revision_list_entity)
+            .fetch_all(&self.db)
+            .await?;
+
+        let (results, total) = list_result(results, true);
+
+        Ok(ProjectRevisionListResults {
+            total: total.unwrap_or(0),
+            results,
+        })
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::project_signup_entity::{ProjectSignupCounts, ProjectSignupEntity};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+pub type DynProjectSignupRepo = Arc<dyn ProjectSignupRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectSignupRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Returns the existing signup if the email already signed up
+    async fn create_signup(
+        &self,
+        project_id: Uuid,
+        email: String,
+    ) -> Result<ProjectSignupEntity, DbError>;
+    async fn confirm_signup(&self, id: Uuid) -> Result<ProjectSignupEntity, DbError>;
+    // Signups for the email created or repeated since the given time, across projects
+    async fn count_email_signups_since(
+        &self,
+        email: &str,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DbError>;
+    async fn get_signup_counts(&self, project_id: Uuid) -> Result<ProjectSignupCounts, DbError>;
+    async fn list_confirmed_signups(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectSignupEntity>, DbError>;
+    // Marks confirmed signups as notified, and returns the ones that weren't notified yet
+    async fn mark_signups_notified(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectSignupEntity>, DbError>;
+}
+
+pub struct ProjectSignupRepo {
+    pub db: PgPool,
+}
+
+const SIGNUP_COLUMNS: &str =
+    r#"id, project_id, email, confirmed_at, notified_at, created_at, updated_at"#;
+
+fn map_signup_entity(row: PgRow) -> Result<ProjectSignupEntity, sqlx::Error> {
+    Ok(ProjectSignupEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        email: row.try_get("email")?,
+        confirmed_at: row.try_get("confirmed_at")?,
+        notified_at: row.try_get("notified_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectSignupRepoTrait for ProjectSignupRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_signup(
+        &self,
+        project_id: Uuid,
+        email: String,
+    ) -> Result<ProjectSignupEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_signups" (project_id, email)
+              values ($1, $2)
+              ON CONFLICT (project_id, email) DO UPDATE SET email = EXCLUDED.email
+              RETURNING {}
+            "#,
+            SIGNUP_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(email)
+        .try_map(map_signup_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn count_email_signups_since(
+        &self,
+        email: &str,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DbError> {
+        Ok(sqlx::query_scalar(
+            "SELECT COUNT(*) FROM project_signups WHERE email = $1 AND updated_at > $2",
+        )
+        .bind(email)
+        .bind(since)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn confirm_signup(&self, id: Uuid) -> Result<ProjectSignupEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE project_signups SET confirmed_at = COALESCE(confirmed_at, now()) WHERE id = $1 RETURNING {}",
+            SIGNUP_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_signup_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn get_signup_counts(&self, project_id: Uuid) -> Result<ProjectSignupCounts, DbError> {
+        Ok(sqlx::query(
+            r#"SELECT COUNT(*) as total,
+              COUNT(confirmed_at) as confirmed,
+              COUNT(notified_at) as notified
+            FROM project_signups WHERE project_id = $1"#,
+        )
+        .bind(project_id)
+        .try_map(|row: PgRow| {
+            Ok(ProjectSignupCounts {
+                total: row.try_get("total")?,
+                confirmed: row.try_get("confirmed")?,
+                notified: row.try_get("notified")?,
+            })
+        })
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn list_confirmed_signups(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectSignupEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_signups WHERE project_id = $1 AND confirmed_at IS NOT NULL ORDER BY created_at",
+            SIGNUP_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_signup_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn mark_signups_notified(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectSignupEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"UPDATE project_signups SET notified_at = now()
+            WHERE project_id = $1 AND confirmed_at IS NOT NULL AND notified_at IS NULL
+            RETURNING {}"#,
+            SIGNUP_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_signup_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+}
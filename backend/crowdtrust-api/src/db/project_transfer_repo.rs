@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::project_transfer_entity::{ProjectTransferEntity, ProjectTransferState};
+use sqlx::{postgres::PgRow, PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynProjectTransferRepo = Arc<dyn ProjectTransferRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectTransferRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Returns Unique if the project already has a pending transfer. Expired transfers
+    // are cancelled first
+    async fn create_transfer(
+        &self,
+        props: ProjectTransferCreateProps,
+    ) -> Result<ProjectTransferEntity, DbError>;
+    async fn get_transfer_by_id(&self, id: Uuid) -> Result<ProjectTransferEntity, DbError>;
+    async fn list_project_transfers(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectTransferEntity>, DbError>;
+    // Unexpired pending transfers to the user
+    async fn list_pending_transfers(
+        &self,
+        to_user_id: Uuid,
+    ) -> Result<Vec<ProjectTransferEntity>, DbError>;
+    // Closes a pending transfer. Returns EntityNotFound if it's not pending
+    async fn close_transfer(
+        &self,
+        id: Uuid,
+        state: ProjectTransferState,
+    ) -> Result<ProjectTransferEntity, DbError>;
+    // Marks a pending, unexpired transfer accepted. Returns EntityNotFound otherwise
+    async fn accept_transfer_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<ProjectTransferEntity, DbError>;
+}
+
+pub struct ProjectTransferRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectTransferCreateProps {
+    pub project_id: Uuid,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+const PROJECT_TRANSFER_COLUMNS: &str =
+    r#"id, project_id, from_user_id, to_user_id, state, expires_at, created_at, updated_at"#;
+
+fn map_project_transfer_entity(row: PgRow) -> Result<ProjectTransferEntity, sqlx::Error> {
+    Ok(ProjectTransferEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        from_user_id: row.try_get("from_user_id")?,
+        to_user_id: row.try_get("to_user_id")?,
+        state: row.try_get_unchecked("state")?,
+        expires_at: row.try_get("expires_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectTransferRepoTrait for ProjectTransferRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_transfer(
+        &self,
+        props: ProjectTransferCreateProps,
+    ) -> Result<ProjectTransferEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        sqlx::query(
+            r#"UPDATE project_transfers SET state = 'Cancelled'
+            WHERE project_id = $1 AND state = 'Pending' AND expires_at <= now()"#,
+        )
+        .bind(props.project_id)
+        .execute(tx.as_mut())
+        .await?;
+
+        let transfer = sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_transfers" (project_id, from_user_id, to_user_id, expires_at)
+              values ($1, $2, $3, $4)
+              RETURNING {}
+            "#,
+            PROJECT_TRANSFER_COLUMNS
+        ))
+        .bind(props.project_id)
+        .bind(props.from_user_id)
+        .bind(props.to_user_id)
+        .bind(props.expires_at)
+        .try_map(map_project_transfer_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(dbe)
+                if dbe.constraint() == Some("project_transfers_pending_idx") =>
+            {
+                DbError::Unique("transfer".into())
+            }
+            _ => DbError::Query(e.to_string()),
+        })?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(transfer)
+    }
+
+    async fn get_transfer_by_id(&self, id: Uuid) -> Result<ProjectTransferEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_transfers WHERE id = $1",
+            PROJECT_TRANSFER_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_transfer_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_project_transfers(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectTransferEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_transfers WHERE project_id = $1 ORDER BY created_at DESC",
+            PROJECT_TRANSFER_COLUMNS
+        ))
+        .bind(project_id)
+        .try_map(map_project_transfer_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn list_pending_transfers(
+        &self,
+        to_user_id: Uuid,
+    ) -> Result<Vec<ProjectTransferEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"SELECT {} FROM project_transfers
+            WHERE to_user_id = $1 AND state = 'Pending' AND expires_at > now()
+            ORDER BY created_at DESC"#,
+            PROJECT_TRANSFER_COLUMNS
+        ))
+        .bind(to_user_id)
+        .try_map(map_project_transfer_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn close_transfer(
+        &self,
+        id: Uuid,
+        state: ProjectTransferState,
+    ) -> Result<ProjectTransferEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE project_transfers SET state = $1 WHERE id = $2 AND state = 'Pending' RETURNING {}",
+            PROJECT_TRANSFER_COLUMNS
+        ))
+        .bind(state.to_string())
+        .bind(id)
+        .try_map(map_project_transfer_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn accept_transfer_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<ProjectTransferEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            r#"UPDATE project_transfers SET state = 'Accepted'
+            WHERE id = $1 AND state = 'Pending' AND expires_at > now()
+            RETURNING {}"#,
+            PROJECT_TRANSFER_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_transfer_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::project_update_entity::ProjectUpdateAssetEntity;
+use lib_types::shared::asset::AssetState;
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+pub type DynProjectUpdateAssetRepo = Arc<dyn ProjectUpdateAssetRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectUpdateAssetRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_update_asset(
+        &self,
+        props: ProjectUpdateAssetEntityProps,
+    ) -> Result<ProjectUpdateAssetEntity, DbError>;
+
+    async fn get_update_asset_by_id(&self, id: Uuid) -> Result<ProjectUpdateAssetEntity, DbError>;
+
+    async fn update_update_asset_state(&self, id: Uuid, state: AssetState) -> Result<(), DbError>;
+
+    async fn delete_update_asset_by_id(&self, id: Uuid) -> Result<(), DbError>;
+}
+
+pub struct ProjectUpdateAssetRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectUpdateAssetEntityProps {
+    pub size: i64,
+    pub content_type: String,
+    pub state: AssetState,
+    pub user_id: Uuid,
+    pub update_id: Uuid,
+    pub upload_expires_at: DateTime<Utc>,
+}
+
+pub const PROJECT_UPDATE_ASSET_COLUMNS: &str = r#"id, update_id, user_id, size, content_type, state, upload_expires_at, created_at, updated_at"#;
+
+pub fn map_project_update_asset_entity(
+    row: PgRow,
+) -> Result<ProjectUpdateAssetEntity, sqlx::Error> {
+    Ok(ProjectUpdateAssetEntity {
+        id: row.try_get("id")?,
+        update_id: row.try_get("update_id")?,
+        user_id: row.try_get("user_id")?,
+        size: row.try_get("size")?,
+        content_type: row.try_get_unchecked("content_type")?,
+        state: row.try_get_unchecked("state")?,
+        upload_expires_at: row.try_get("upload_expires_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl ProjectUpdateAssetRepoTrait for ProjectUpdateAssetRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_update_asset(
+        &self,
+        props: ProjectUpdateAssetEntityProps,
+    ) -> Result<ProjectUpdateAssetEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_update_assets" (update_id, user_id, size, content_type, state, upload_expires_at)
+              values ($1, $2, $3, $4, $5, $6)
+              RETURNING {}
+            "#,
+            PROJECT_UPDATE_ASSET_COLUMNS
+        ))
+        .bind(props.update_id)
+        .bind(props.user_id)
+        .bind(props.size)
+        .bind(props.content_type)
+        .bind(props.state.to_string())
+        .bind(props.upload_expires_at)
+        .try_map(map_project_update_asset_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_update_asset_by_id(&self, id: Uuid) -> Result<ProjectUpdateAssetEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM project_update_assets WHERE id = $1",
+            PROJECT_UPDATE_ASSET_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_update_asset_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn update_update_asset_state(&self, id: Uuid, state: AssetState) -> Result<(), DbError> {
+        sqlx::query("UPDATE project_update_assets SET state = $1 WHERE id = $2")
+            .bind(state.to_string())
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_update_asset_by_id(&self, id: Uuid) -> Result<(), DbError> {
+        sqlx::query(r#"DELETE FROM "project_update_assets" WHERE id = $1"#)
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
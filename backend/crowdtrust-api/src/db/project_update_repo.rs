@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::{
+    db_error::{map_sqlx_err, DbError},
+    db_result::list_result,
+    util::{append_comma, append_limit_offset},
+};
+use lib_types::entity::project_update_entity::{
+    ProjectUpdateEntity, ProjectUpdateListResults, ProjectUpdateState,
+};
+use sqlx::{postgres::PgRow, PgPool, QueryBuilder, Row};
+use uuid::Uuid;
+
+use super::project_update_asset_repo::{
+    map_project_update_asset_entity, PROJECT_UPDATE_ASSET_COLUMNS,
+};
+
+pub type DynProjectUpdateRepo = Arc<dyn ProjectUpdateRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ProjectUpdateRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_update(
+        &self,
+        props: ProjectUpdateCreateProps,
+    ) -> Result<ProjectUpdateEntity, DbError>;
+    async fn get_update_by_id(&self, id: Uuid) -> Result<ProjectUpdateEntity, DbError>;
+    async fn update_update(
+        &self,
+        id: Uuid,
+        props: ProjectUpdateUpdateProps,
+    ) -> Result<ProjectUpdateEntity, DbError>;
+    async fn delete_update_by_id(&self, id: Uuid) -> Result<(), DbError>;
+    // Published updates are ordered by publish date, newest first. Drafts are listed first
+    async fn list_updates(
+        &self,
+        project_id: Uuid,
+        state: Option<ProjectUpdateState>,
+        from: i32,
+        to: i32,
+    ) -> Result<ProjectUpdateListResults, DbError>;
+}
+
+pub struct ProjectUpdateRepo {
+    pub db: PgPool,
+}
+
+pub struct ProjectUpdateCreateProps {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub state: ProjectUpdateState,
+    pub backers_only: bool,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+pub struct ProjectUpdateUpdateProps {
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub state: Option<ProjectUpdateState>,
+    pub backers_only: Option<bool>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+const PROJECT_UPDATE_COLUMNS: &str = r#"id, project_id, user_id, title, body, state, backers_only, published_at, created_at, updated_at"#;
+
+fn map_project_update_entity(row: PgRow) -> Result<ProjectUpdateEntity, sqlx::Error> {
+    Ok(ProjectUpdateEntity {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        user_id: row.try_get("user_id")?,
+        title: row.try_get("title")?,
+        body: row.try_get("body")?,
+        state: row.try_get_unchecked("state")?,
+        backers_only: row.try_get("backers_only")?,
+        published_at: row.try_get("published_at")?,
+        assets: vec![],
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn map_project_update_list_entity(
+    row: PgRow,
+) -> Result<(ProjectUpdateEntity, Option<i64>), sqlx::Error> {
+    let count = row.try_get("count")?;
+    let entity = map_project_update_entity(row)?;
+    Ok((entity, count))
+}
+
+impl ProjectUpdateRepo {
+    // Attaches assets to each update, with a single query
+    async fn with_assets(
+        &self,
+        mut updates: Vec<ProjectUpdateEntity>,
+    ) -> Result<Vec<ProjectUpdateEntity>, DbError> {
+        if updates.is_empty() {
+            return Ok(updates);
+        }
+        let ids: Vec<Uuid> = updates.iter().map(|u| u.id).collect();
+        let assets = sqlx::query(formatcp!(
+            "SELECT {} FROM project_update_assets WHERE update_id = ANY($1) ORDER BY created_at",
+            PROJECT_UPDATE_ASSET_COLUMNS
+        ))
+        .bind(ids)
+        .try_map(map_project_update_asset_entity)
+        .fetch_all(&self.db)
+        .await
+        .map_err(map_sqlx_err)?;
+
+        for asset in assets.into_iter() {
+            if let Some(update) = updates.iter_mut().find(|u| u.id == asset.update_id) {
+                update.assets.push(asset);
+            }
+        }
+        Ok(updates)
+    }
+}
+
+#[async_trait]
+impl ProjectUpdateRepoTrait for ProjectUpdateRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_update(
+        &self,
+        props: ProjectUpdateCreateProps,
+    ) -> Result<ProjectUpdateEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "project_updates" (project_id, user_id, title, body, state, backers_only, published_at)
+              values ($1, $2, $3, $4, $5, $6, $7)
+              RETURNING {}
+            "#,
+            PROJECT_UPDATE_COLUMNS
+        ))
+        .bind(props.project_id)
+        .bind(props.user_id)
+        .bind(props.title)
+        .bind(props.body)
+        .bind(props.state.to_string())
+        .bind(props.backers_only)
+        .bind(props.published_at)
+        .try_map(map_project_update_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_update_by_id(&self, id: Uuid) -> Result<ProjectUpdateEntity, DbError> {
+        let update = sqlx::query(formatcp!(
+            "SELECT {} FROM project_updates WHERE id = $1",
+            PROJECT_UPDATE_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_project_update_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?;
+
+        let mut updates = self.with_assets(vec![update]).await?;
+        updates.pop().ok_or(DbError::EntityNotFound())
+    }
+
+    async fn update_update(
+        &self,
+        id: Uuid,
+        props: ProjectUpdateUpdateProps,
+    ) -> Result<ProjectUpdateEntity, DbError> {
+        let query = QueryBuilder::new("UPDATE project_updates SET");
+        let update_count = 0;
+
+        let (query, update_count) = append_comma(query, "title", props.title, update_count);
+        let (query, update_count) = append_comma(query, "body", props.body, update_count);
+        let (query, update_count) = append_comma(
+            query,
+            "state",
+            props.state.map(|s| s.to_string()),
+            update_count,
+        );
+        let (query, update_count) =
+            append_comma(query, "backers_only", props.backers_only, update_count);
+        let (mut query, update_count) =
+            append_comma(query, "published_at", props.published_at, update_count);
+
+        if update_count == 0 {
+            return Err(DbError::NoUpdate);
+        }
+
+        query.push(" WHERE id = ");
+        query.push_bind(id);
+        query.push(formatcp!(" RETURNING {}", PROJECT_UPDATE_COLUMNS));
+
+        let update = query
+            .build()
+            .try_map(map_project_update_entity)
+            .fetch_one(&self.db)
+            .await
+            .map_err(map_sqlx_err)?;
+
+        let mut updates = self.with_assets(vec![update]).await?;
+        updates.pop().ok_or(DbError::EntityNotFound())
+    }
+
+    async fn delete_update_by_id(&self, id: Uuid) -> Result<(), DbError> {
+        sqlx::query(r#"DELETE FROM "project_updates" WHERE id = $1"#)
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_updates(
+        &self,
+        project_id: Uuid,
+        state: Option<ProjectUpdateState>,
+        from: i32,
+        to: i32,
+    ) -> Result<ProjectUpdateListResults, DbError> {
+        let mut filtered_query = QueryBuilder::new(formatcp!(
+            "SELECT {}, COUNT(*) OVER () as count FROM project_updates WHERE project_id = ",
+            PROJECT_UPDATE_COLUMNS
+        ));
+        filtered_query.push_bind(project_id);
+
+        // Filter state
+        if let Some(state) = state {
+            filtered_query.push(" AND state = ");
+            filtered_query.push_bind(state.to_string());
+        }
+        filtered_query.push(" ORDER BY published_at DESC NULLS FIRST, created_at DESC");
+        filtered_query = append_limit_offset(filtered_query, from, to);
+
+        let results = filtered_query
+            .build()
+            .try_map(map_project_update_list_entity)
+            .fetch_all(&self.db)
+            .await?;
+
+        let (results, total) = list_result(results, true);
+
+        Ok(ProjectUpdateListResults {
+            total: total.unwrap_or(0),
+            results: self.with_assets(results).await?,
+        })
+    }
+}
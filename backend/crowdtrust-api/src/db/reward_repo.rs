@@ -3,10 +3,22 @@ use std::sync::Arc;
 use axum::async_trait;
 use bigdecimal::BigDecimal;
 use const_format::formatcp;
-use lib_api::db::{db_error::DbError, util::append_comma};
-use lib_types::entity::reward_entity::RewardEntity;
+use lib_api::db::{
+    db_error::DbError,
+    util::{append_comma, append_op, append_order_by, DbOp},
+};
+use lib_types::{
+    dto::{
+        reward::list_rewards_dto::{ListRewardsQuery, RewardSortColumn},
+        sort_direction::SortDirection,
+    },
+    entity::reward_entity::{
+        PriceTier, RewardAssetEntityRelation, RewardEntity, RewardVariant, ShippingCost,
+    },
+};
 use serde::{Deserialize, Serialize};
-use sqlx::{postgres::PgRow, PgPool, Postgres, QueryBuilder, Row, Transaction};
+use serde_json::to_string;
+use sqlx::{postgres::PgRow, types::Json, PgPool, Postgres, QueryBuilder, Row, Transaction};
 use uuid::Uuid;
 
 use super::app_repo::start_transaction;
@@ -21,6 +33,11 @@ pub struct RewardCreateProps {
     pub price: BigDecimal,
     pub delivery_time: i64,
     pub backer_limit: i64,
+    pub shipping_required: bool,
+    pub shipping_costs: Vec<ShippingCost>,
+    pub variants: Vec<RewardVariant>,
+    pub price_tiers: Vec<PriceTier>,
+    pub parent_reward_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, Serialize, sqlx::Type)]
@@ -32,6 +49,10 @@ pub struct RewardUpdateProps {
     pub backer_count: Option<i32>,
     pub backer_limit: Option<i64>,
     pub visible: Option<bool>,
+    pub shipping_required: Option<bool>,
+    pub shipping_costs: Option<Vec<ShippingCost>>,
+    pub variants: Option<Vec<RewardVariant>>,
+    pub price_tiers: Option<Vec<PriceTier>>,
 }
 
 impl RewardUpdateProps {
@@ -44,6 +65,23 @@ impl RewardUpdateProps {
             backer_count: Some(backer_count),
             backer_limit: None,
             visible: None,
+            shipping_required: None,
+            shipping_costs: None,
+            variants: None,
+            price_tiers: None,
+        }
+    }
+
+    // Backer counts after a pledge, including variant and price tier counts
+    pub fn backed(
+        backer_count: i32,
+        variants: Option<Vec<RewardVariant>>,
+        price_tiers: Option<Vec<PriceTier>>,
+    ) -> Self {
+        Self {
+            variants,
+            price_tiers,
+            ..Self::backer_count(backer_count)
         }
     }
 }
@@ -52,6 +90,11 @@ impl RewardUpdateProps {
 pub trait RewardRepoTrait {
     fn get_db(&self) -> &PgPool;
     async fn create_reward(&self, props: RewardCreateProps) -> Result<Uuid, DbError>;
+    async fn create_reward_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: RewardCreateProps,
+    ) -> Result<RewardEntity, DbError>;
     async fn update_reward(&self, id: Uuid, props: RewardUpdateProps) -> Result<(), DbError>;
     async fn update_reward_tx(
         &self,
@@ -60,14 +103,34 @@ pub trait RewardRepoTrait {
         props: RewardUpdateProps,
     ) -> Result<(), DbError>;
     async fn get_reward_by_id(&self, id: Uuid) -> Result<RewardEntity, DbError>;
+    async fn lock_project_rewards_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        project_id: Uuid,
+        ids: Vec<Uuid>,
+    ) -> Result<Vec<RewardEntity>, DbError>;
     async fn delete_reward_by_id(&self, id: Uuid) -> Result<(), DbError>;
+    async fn delete_reward_by_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<(), DbError>;
+    async fn list_rewards(
+        &self,
+        project_id: Uuid,
+        rewards_order: Vec<String>,
+        query: ListRewardsQuery,
+    ) -> Result<Vec<RewardEntity>, DbError>;
 }
 
 pub struct RewardRepo {
     pub db: PgPool,
 }
 
-const REWARD_COLUMNS: &str = r#"id, project_id, name, description, delivery_time, price, backer_limit, backer_count, created_at, updated_at"#;
+const REWARD_COLUMNS: &str = formatcp!(
+    r#"{r}.id, {r}.project_id, {r}.name, {r}.description, {r}.delivery_time, {r}.price, {r}.backer_limit, {r}.backer_count, {r}.visible, {r}.shipping_required, {r}.shipping_costs, {r}.variants, {r}.price_tiers, {r}.parent_reward_id, {r}.created_at, {r}.updated_at"#,
+    r = "rewards"
+);
 
 fn map_reward_entity(row: PgRow) -> Result<RewardEntity, sqlx::Error> {
     Ok(RewardEntity {
@@ -79,6 +142,14 @@ fn map_reward_entity(row: PgRow) -> Result<RewardEntity, sqlx::Error> {
         price: row.try_get_unchecked("price")?,
         backer_limit: row.try_get("backer_limit")?,
         backer_count: row.try_get("backer_count")?,
+        visible: row.try_get("visible")?,
+        shipping_required: row.try_get("shipping_required")?,
+        shipping_costs: row
+            .try_get::<Json<Vec<ShippingCost>>, &str>("shipping_costs")?
+            .0,
+        variants: row.try_get::<Json<Vec<RewardVariant>>, &str>("variants")?.0,
+        price_tiers: row.try_get::<Json<Vec<PriceTier>>, &str>("price_tiers")?.0,
+        parent_reward_id: row.try_get("parent_reward_id")?,
         image: None,
         created_at: row.try_get("created_at")?,
         updated_at: row.try_get("updated_at")?,
@@ -92,13 +163,25 @@ impl RewardRepoTrait for RewardRepo {
     }
 
     async fn create_reward(&self, props: RewardCreateProps) -> Result<Uuid, DbError> {
-        let row = sqlx::query(formatcp!(
+        let mut tx = start_transaction(&self.db).await?;
+        let reward = self.create_reward_tx(&mut tx, props).await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(reward.id)
+    }
+
+    async fn create_reward_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: RewardCreateProps,
+    ) -> Result<RewardEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
             // language=PostgreSQL
             r#"
-              INSERT INTO "rewards" (project_id, name, description, price, delivery_time, backer_limit)
-              values ($1, $2, $3, $4, $5, $6)
-              RETURNING id
-            "#
+              INSERT INTO "rewards" (project_id, name, description, price, delivery_time, backer_limit, shipping_required, shipping_costs, variants, price_tiers, parent_reward_id)
+              values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+              RETURNING {}
+            "#,
+            REWARD_COLUMNS
         ))
         .bind(props.project_id)
         .bind(props.name)
@@ -106,14 +189,19 @@ impl RewardRepoTrait for RewardRepo {
         .bind(props.price)
         .bind(props.delivery_time)
         .bind(props.backer_limit)
-        .fetch_one(&self.db)
+        .bind(props.shipping_required)
+        .bind(Json(props.shipping_costs))
+        .bind(Json(props.variants))
+        .bind(Json(props.price_tiers))
+        .bind(props.parent_reward_id)
+        .try_map(map_reward_entity)
+        .fetch_one(tx.as_mut())
         .await.map_err(|e| match e {
             sqlx::Error::Database(dbe) if dbe.constraint() == Some("rewards_name_key") => {
                 DbError::Unique("name".into())
             }
             _ => DbError::Query(e.to_string()),
-        })?;
-        Ok(row.try_get("id")?)
+        })?)
     }
 
     async fn update_reward(&self, id: Uuid, props: RewardUpdateProps) -> Result<(), DbError> {
@@ -145,7 +233,27 @@ impl RewardRepoTrait for RewardRepo {
         let (query, update_count) =
             append_comma(query, "backer_limit", props.backer_limit, update_count);
 
-        let (mut query, update_count) = append_comma(query, "visible", props.visible, update_count);
+        let (query, update_count) = append_comma(query, "visible", props.visible, update_count);
+        let (query, update_count) = append_comma(
+            query,
+            "shipping_required",
+            props.shipping_required,
+            update_count,
+        );
+        let (query, update_count) = append_comma(
+            query,
+            "shipping_costs",
+            props.shipping_costs.map(Json),
+            update_count,
+        );
+        let (query, update_count) =
+            append_comma(query, "variants", props.variants.map(Json), update_count);
+        let (mut query, update_count) = append_comma(
+            query,
+            "price_tiers",
+            props.price_tiers.map(Json),
+            update_count,
+        );
 
         if update_count == 0 {
             return Err(DbError::NoUpdate);
@@ -177,12 +285,132 @@ impl RewardRepoTrait for RewardRepo {
         .await?)
     }
 
+    // Locks the project's rewards until the transaction ends, so concurrent pledges
+    // see each other's backer counts. Locked in id order to avoid deadlocks
+    async fn lock_project_rewards_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        project_id: Uuid,
+        ids: Vec<Uuid>,
+    ) -> Result<Vec<RewardEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM rewards WHERE project_id = $1 AND id = ANY($2) ORDER BY id FOR UPDATE",
+            REWARD_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(ids)
+        .try_map(map_reward_entity)
+        .fetch_all(tx.as_mut())
+        .await?)
+    }
+
     async fn delete_reward_by_id(&self, id: Uuid) -> Result<(), DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        self.delete_reward_by_id_tx(&mut tx, id).await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(())
+    }
+
+    async fn delete_reward_by_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<(), DbError> {
         sqlx::query(r#"DELETE FROM "rewards" WHERE id = $1"#)
             .bind(id)
-            .execute(&self.db)
+            .execute(tx.as_mut())
             .await?;
 
         Ok(())
     }
+
+    async fn list_rewards(
+        &self,
+        project_id: Uuid,
+        rewards_order: Vec<String>,
+        query: ListRewardsQuery,
+    ) -> Result<Vec<RewardEntity>, DbError> {
+        let mut filtered_query = QueryBuilder::new(format!(
+            "SELECT {}, ri.id as ri_id, ri.size as ri_size, ri.content_type as ri_content_type FROM rewards LEFT OUTER JOIN reward_assets ri on ri.reward_id = rewards.id WHERE",
+            REWARD_COLUMNS
+        ));
+        filtered_query.push(" rewards.project_id = ");
+        filtered_query.push_bind(project_id);
+
+        // Filter visible
+        if let Some(visible) = query.visible {
+            let (mut q, _) = append_op(filtered_query, DbOp::And, 1);
+            q.push(" rewards.visible = ");
+            q.push_bind(visible);
+            filtered_query = q;
+        }
+        // Filter available
+        if let Some(available) = query.available {
+            let (mut q, _) = append_op(filtered_query, DbOp::And, 1);
+            q.push(if available {
+                " rewards.backer_count < rewards.backer_limit"
+            } else {
+                " rewards.backer_count >= rewards.backer_limit"
+            });
+            filtered_query = q;
+        }
+        // Filter add-ons
+        if let Some(addons) = query.addons {
+            let (mut q, _) = append_op(filtered_query, DbOp::And, 1);
+            q.push(if addons {
+                " rewards.parent_reward_id IS NOT NULL"
+            } else {
+                " rewards.parent_reward_id IS NULL"
+            });
+            filtered_query = q;
+        }
+
+        // ORDER BY
+        let column = query.column.unwrap_or(RewardSortColumn::Order);
+        let direction = query.direction.unwrap_or(SortDirection::Asc);
+        if column == RewardSortColumn::Order {
+            // Rewards missing from `rewards_order` are sorted last, by creation date
+            filtered_query.push(" ORDER BY array_position(");
+            filtered_query.push_bind(rewards_order);
+            filtered_query.push(format!(
+                "::text[], rewards.id::text) {} NULLS LAST, rewards.created_at",
+                direction
+            ));
+        } else {
+            let column = to_string(&column).map_err(|e| DbError::Serialize(e.to_string()))?;
+            filtered_query = append_order_by(
+                filtered_query,
+                format!("rewards.{}", column),
+                direction.to_string(),
+            );
+        }
+
+        let rows = filtered_query
+            .build()
+            .fetch_all(&self.db)
+            .await
+            .map_err(|e| DbError::Query(e.to_string()))?;
+
+        let mut rewards: Vec<RewardEntity> = vec![];
+        for row in rows.into_iter() {
+            let image = if let Ok(image_id) = row.try_get("ri_id") {
+                Some(RewardAssetEntityRelation {
+                    id: image_id,
+                    project_id,
+                    size: row.try_get("ri_size")?,
+                    content_type: row.try_get_unchecked("ri_content_type")?,
+                })
+            } else {
+                None
+            };
+            let reward_id: Uuid = row.try_get("id")?;
+            if rewards.iter().any(|r| r.id == reward_id) {
+                continue;
+            }
+            let mut reward = map_reward_entity(row)?;
+            reward.image = image;
+            rewards.push(reward);
+        }
+        Ok(rewards)
+    }
 }
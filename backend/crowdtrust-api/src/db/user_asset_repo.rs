@@ -0,0 +1,194 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::{
+    entity::user_asset_entity::UserAssetEntity,
+    shared::asset::{AssetState, UserAssetKind},
+};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynUserAssetRepo = Arc<dyn UserAssetRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserAssetRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    async fn create_user_asset(
+        &self,
+        props: UserAssetEntityProps,
+    ) -> Result<UserAssetEntity, DbError>;
+    async fn get_user_asset_by_id(&self, id: Uuid) -> Result<UserAssetEntity, DbError>;
+    async fn list_user_assets(&self, user_id: Uuid) -> Result<Vec<UserAssetEntity>, DbError>;
+    async fn update_user_asset_state(&self, id: Uuid, state: AssetState) -> Result<(), DbError>;
+    // Marks the asset uploaded and sets it on the user. Returns the assets it replaced
+    async fn activate_user_asset(
+        &self,
+        asset: &UserAssetEntity,
+    ) -> Result<Vec<UserAssetEntity>, DbError>;
+    // Deletes the asset, and removes it from the user if active
+    async fn delete_user_asset(&self, asset: &UserAssetEntity) -> Result<(), DbError>;
+}
+
+pub struct UserAssetRepo {
+    pub db: PgPool,
+}
+
+pub struct UserAssetEntityProps {
+    pub user_id: Uuid,
+    pub kind: UserAssetKind,
+    pub size: i64,
+    pub content_type: String,
+    pub state: AssetState,
+    pub upload_expires_at: DateTime<Utc>,
+}
+
+const USER_ASSET_COLUMNS: &str =
+    r#"id, user_id, kind, size, content_type, state, upload_expires_at, created_at, updated_at"#;
+
+fn map_user_asset_entity(row: PgRow) -> Result<UserAssetEntity, sqlx::Error> {
+    Ok(UserAssetEntity {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        kind: row.try_get_unchecked("kind")?,
+        size: row.try_get("size")?,
+        content_type: row.try_get_unchecked("content_type")?,
+        state: row.try_get_unchecked("state")?,
+        upload_expires_at: row.try_get("upload_expires_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+// Column on users for the active asset of each kind
+fn user_url_column(kind: UserAssetKind) -> &'static str {
+    match kind {
+        UserAssetKind::Avatar => "avatar_url",
+        UserAssetKind::Banner => "banner_url",
+    }
+}
+
+#[async_trait]
+impl UserAssetRepoTrait for UserAssetRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_user_asset(
+        &self,
+        props: UserAssetEntityProps,
+    ) -> Result<UserAssetEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "user_assets" (user_id, kind, size, content_type, state, upload_expires_at)
+              values ($1, $2, $3, $4, $5, $6)
+              RETURNING {}
+            "#,
+            USER_ASSET_COLUMNS
+        ))
+        .bind(props.user_id)
+        .bind(props.kind.to_string())
+        .bind(props.size)
+        .bind(props.content_type)
+        .bind(props.state.to_string())
+        .bind(props.upload_expires_at)
+        .try_map(map_user_asset_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_user_asset_by_id(&self, id: Uuid) -> Result<UserAssetEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_assets WHERE id = $1",
+            USER_ASSET_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_user_asset_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_user_assets(&self, user_id: Uuid) -> Result<Vec<UserAssetEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_assets WHERE user_id = $1 ORDER BY created_at",
+            USER_ASSET_COLUMNS
+        ))
+        .bind(user_id)
+        .try_map(map_user_asset_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn update_user_asset_state(&self, id: Uuid, state: AssetState) -> Result<(), DbError> {
+        sqlx::query("UPDATE user_assets SET state = $1 WHERE id = $2")
+            .bind(state.to_string())
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn activate_user_asset(
+        &self,
+        asset: &UserAssetEntity,
+    ) -> Result<Vec<UserAssetEntity>, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        let replaced = sqlx::query(formatcp!(
+            "DELETE FROM user_assets WHERE user_id = $1 AND kind = $2 AND state = $3 AND id <> $4 RETURNING {}",
+            USER_ASSET_COLUMNS
+        ))
+        .bind(asset.user_id)
+        .bind(asset.kind.to_string())
+        .bind(AssetState::Uploaded.to_string())
+        .bind(asset.id)
+        .try_map(map_user_asset_entity)
+        .fetch_all(tx.as_mut())
+        .await?;
+
+        sqlx::query("UPDATE user_assets SET state = $1 WHERE id = $2")
+            .bind(AssetState::Uploaded.to_string())
+            .bind(asset.id)
+            .execute(tx.as_mut())
+            .await?;
+
+        sqlx::query(&format!(
+            "UPDATE users SET {} = $1 WHERE id = $2",
+            user_url_column(asset.kind)
+        ))
+        .bind(asset.relative_url())
+        .bind(asset.user_id)
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(replaced)
+    }
+
+    async fn delete_user_asset(&self, asset: &UserAssetEntity) -> Result<(), DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        sqlx::query(r#"DELETE FROM "user_assets" WHERE id = $1"#)
+            .bind(asset.id)
+            .execute(tx.as_mut())
+            .await?;
+
+        let column = user_url_column(asset.kind);
+        sqlx::query(&format!(
+            "UPDATE users SET {column} = NULL WHERE id = $1 AND {column} = $2"
+        ))
+        .bind(asset.user_id)
+        .bind(asset.relative_url())
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(())
+    }
+}
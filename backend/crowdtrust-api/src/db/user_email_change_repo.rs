@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::user_email_change_entity::{EmailChangeState, UserEmailChangeEntity};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynUserEmailChangeRepo = Arc<dyn UserEmailChangeRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserEmailChangeRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Cancels the user's pending change, if any
+    async fn create_email_change(
+        &self,
+        props: UserEmailChangeProps,
+    ) -> Result<UserEmailChangeEntity, DbError>;
+    async fn get_email_change_by_id(&self, id: Uuid) -> Result<UserEmailChangeEntity, DbError>;
+    // Sets the new email on the user, and marks the change Confirmed. Not found when it's no
+    // longer Pending
+    async fn confirm_email_change(
+        &self,
+        change: &UserEmailChangeEntity,
+    ) -> Result<UserEmailChangeEntity, DbError>;
+    // Restores the old email, if the user still has the new one. Marks the change Reverted
+    async fn revert_email_change(
+        &self,
+        change: &UserEmailChangeEntity,
+    ) -> Result<UserEmailChangeEntity, DbError>;
+}
+
+pub struct UserEmailChangeRepo {
+    pub db: PgPool,
+}
+
+pub struct UserEmailChangeProps {
+    pub user_id: Uuid,
+    pub old_email: String,
+    pub new_email: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+const USER_EMAIL_CHANGE_COLUMNS: &str = r#"id, user_id, old_email, new_email, state, expires_at, confirmed_at, reverted_at, created_at, updated_at"#;
+
+fn map_user_email_change_entity(row: PgRow) -> Result<UserEmailChangeEntity, sqlx::Error> {
+    Ok(UserEmailChangeEntity {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        old_email: row.try_get("old_email")?,
+        new_email: row.try_get("new_email")?,
+        state: row.try_get_unchecked("state")?,
+        expires_at: row.try_get("expires_at")?,
+        confirmed_at: row.try_get("confirmed_at")?,
+        reverted_at: row.try_get("reverted_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn map_email_unique_err(e: sqlx::Error) -> DbError {
+    match e {
+        sqlx::Error::Database(dbe) if dbe.constraint() == Some("users_email_key") => {
+            DbError::Unique("email".into())
+        }
+        _ => DbError::Query(e.to_string()),
+    }
+}
+
+#[async_trait]
+impl UserEmailChangeRepoTrait for UserEmailChangeRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_email_change(
+        &self,
+        props: UserEmailChangeProps,
+    ) -> Result<UserEmailChangeEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        sqlx::query("UPDATE user_email_changes SET state = $1 WHERE user_id = $2 AND state = $3")
+            .bind(EmailChangeState::Cancelled.to_string())
+            .bind(props.user_id)
+            .bind(EmailChangeState::Pending.to_string())
+            .execute(tx.as_mut())
+            .await?;
+
+        let change = sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "user_email_changes" (user_id, old_email, new_email, state, expires_at)
+              values ($1, $2, $3, $4, $5)
+              RETURNING {}
+            "#,
+            USER_EMAIL_CHANGE_COLUMNS
+        ))
+        .bind(props.user_id)
+        .bind(props.old_email)
+        .bind(props.new_email)
+        .bind(EmailChangeState::Pending.to_string())
+        .bind(props.expires_at)
+        .try_map(map_user_email_change_entity)
+        .fetch_one(tx.as_mut())
+        .await?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(change)
+    }
+
+    async fn get_email_change_by_id(&self, id: Uuid) -> Result<UserEmailChangeEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_email_changes WHERE id = $1",
+            USER_EMAIL_CHANGE_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_user_email_change_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn confirm_email_change(
+        &self,
+        change: &UserEmailChangeEntity,
+    ) -> Result<UserEmailChangeEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        // Not found when the change was already confirmed or cancelled
+        let confirmed = sqlx::query(formatcp!(
+            "UPDATE user_email_changes SET state = $1, confirmed_at = now() WHERE id = $2 AND state = $3 RETURNING {}",
+            USER_EMAIL_CHANGE_COLUMNS
+        ))
+        .bind(EmailChangeState::Confirmed.to_string())
+        .bind(change.id)
+        .bind(EmailChangeState::Pending.to_string())
+        .try_map(map_user_email_change_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?;
+
+        sqlx::query("UPDATE users SET email = $1, email_confirmed = true WHERE id = $2")
+            .bind(&change.new_email)
+            .bind(change.user_id)
+            .execute(tx.as_mut())
+            .await
+            .map_err(map_email_unique_err)?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(confirmed)
+    }
+
+    async fn revert_email_change(
+        &self,
+        change: &UserEmailChangeEntity,
+    ) -> Result<UserEmailChangeEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        let result = sqlx::query(
+            "UPDATE users SET email = $1, email_confirmed = true WHERE id = $2 AND email = $3",
+        )
+        .bind(&change.old_email)
+        .bind(change.user_id)
+        .bind(&change.new_email)
+        .execute(tx.as_mut())
+        .await
+        .map_err(map_email_unique_err)?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::EntityNotFound());
+        }
+
+        let change = sqlx::query(formatcp!(
+            "UPDATE user_email_changes SET state = $1, reverted_at = now() WHERE id = $2 RETURNING {}",
+            USER_EMAIL_CHANGE_COLUMNS
+        ))
+        .bind(EmailChangeState::Reverted.to_string())
+        .bind(change.id)
+        .try_map(map_user_email_change_entity)
+        .fetch_one(tx.as_mut())
+        .await?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(change)
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::user_export_entity::{UserExportEntity, UserExportState};
+use serde_json::Value;
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
+
+pub type DynUserExportRepo = Arc<dyn UserExportRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserExportRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Returns the pending export if the user already requested one
+    async fn create_export(&self, user_id: Uuid) -> Result<UserExportEntity, DbError>;
+    async fn get_export_by_id(&self, id: Uuid) -> Result<UserExportEntity, DbError>;
+    async fn get_export_data(&self, id: Uuid) -> Result<Value, DbError>;
+    async fn list_pending_exports(&self) -> Result<Vec<UserExportEntity>, DbError>;
+    // Collects the user's data for the export archive. Pledge shipping addresses are still encrypted
+    async fn collect_export_data(&self, id: Uuid) -> Result<Value, DbError>;
+    // Stores the export archive, and marks it Ready
+    async fn complete_export(&self, id: Uuid, data: Value) -> Result<UserExportEntity, DbError>;
+}
+
+pub struct UserExportRepo {
+    pub db: PgPool,
+}
+
+const USER_EXPORT_COLUMNS: &str = r#"id, user_id, state, completed_at, created_at, updated_at"#;
+
+// Archive of everything a user created. Credentials and generated columns are left out
+const USER_EXPORT_DATA: &str = r#"jsonb_build_object(
+  'profile', (SELECT to_jsonb(u) - 'password_hash' FROM users u WHERE u.id = e.user_id),
+  'wallets', COALESCE((SELECT jsonb_agg(to_jsonb(w) ORDER BY w.created_at) FROM user_wallets w WHERE w.user_id = e.user_id), '[]'),
+  'email_changes', COALESCE((SELECT jsonb_agg(to_jsonb(c) ORDER BY c.created_at) FROM user_email_changes c WHERE c.user_id = e.user_id), '[]'),
+  'projects', COALESCE((SELECT jsonb_agg(to_jsonb(p) - 'search_vector' ORDER BY p.created_at) FROM projects p WHERE p.user_id = e.user_id), '[]'),
+  'pledges', COALESCE((SELECT jsonb_agg(to_jsonb(pl) || jsonb_build_object('items', COALESCE((SELECT jsonb_agg(to_jsonb(pi) ORDER BY pi.created_at) FROM pledge_items pi WHERE pi.pledge_id = pl.id), '[]')) ORDER BY pl.created_at) FROM pledges pl WHERE pl.user_id = e.user_id), '[]'),
+  'comments', COALESCE((SELECT jsonb_agg(to_jsonb(c) ORDER BY c.created_at) FROM project_comments c WHERE c.user_id = e.user_id), '[]'),
+  'assets', jsonb_build_object(
+    'user', COALESCE((SELECT jsonb_agg(to_jsonb(a) ORDER BY a.created_at) FROM user_assets a WHERE a.user_id = e.user_id), '[]'),
+    'project', COALESCE((SELECT jsonb_agg(to_jsonb(a) ORDER BY a.created_at) FROM project_assets a WHERE a.user_id = e.user_id), '[]'),
+    'reward', COALESCE((SELECT jsonb_agg(to_jsonb(a) ORDER BY a.created_at) FROM reward_assets a WHERE a.user_id = e.user_id), '[]'),
+    'project_update', COALESCE((SELECT jsonb_agg(to_jsonb(a) ORDER BY a.created_at) FROM project_update_assets a WHERE a.user_id = e.user_id), '[]')
+  ),
+  'exported_at', now()
+)"#;
+
+fn map_user_export_entity(row: PgRow) -> Result<UserExportEntity, sqlx::Error> {
+    Ok(UserExportEntity {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        state: row.try_get_unchecked("state")?,
+        completed_at: row.try_get("completed_at")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl UserExportRepoTrait for UserExportRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_export(&self, user_id: Uuid) -> Result<UserExportEntity, DbError> {
+        let pending = sqlx::query(formatcp!(
+            "SELECT {} FROM user_exports WHERE user_id = $1 AND state = $2",
+            USER_EXPORT_COLUMNS
+        ))
+        .bind(user_id)
+        .bind(UserExportState::Pending.to_string())
+        .try_map(map_user_export_entity)
+        .fetch_optional(&self.db)
+        .await?;
+        if let Some(pending) = pending {
+            return Ok(pending);
+        }
+
+        Ok(sqlx::query(formatcp!(
+            "INSERT INTO user_exports (user_id, state) values ($1, $2) RETURNING {}",
+            USER_EXPORT_COLUMNS
+        ))
+        .bind(user_id)
+        .bind(UserExportState::Pending.to_string())
+        .try_map(map_user_export_entity)
+        .fetch_one(&self.db)
+        .await?)
+    }
+
+    async fn get_export_by_id(&self, id: Uuid) -> Result<UserExportEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_exports WHERE id = $1",
+            USER_EXPORT_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_user_export_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn get_export_data(&self, id: Uuid) -> Result<Value, DbError> {
+        Ok(
+            sqlx::query_scalar("SELECT data FROM user_exports WHERE id = $1 AND data IS NOT NULL")
+                .bind(id)
+                .fetch_one(&self.db)
+                .await
+                .map_err(map_sqlx_err)?,
+        )
+    }
+
+    async fn list_pending_exports(&self) -> Result<Vec<UserExportEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_exports WHERE state = $1 ORDER BY created_at",
+            USER_EXPORT_COLUMNS
+        ))
+        .bind(UserExportState::Pending.to_string())
+        .try_map(map_user_export_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn collect_export_data(&self, id: Uuid) -> Result<Value, DbError> {
+        Ok(sqlx::query_scalar(formatcp!(
+            "SELECT {} FROM user_exports e WHERE e.id = $1",
+            USER_EXPORT_DATA
+        ))
+        .bind(id)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn complete_export(&self, id: Uuid, data: Value) -> Result<UserExportEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "UPDATE user_exports SET data = $2, state = $3, completed_at = now() WHERE id = $1 RETURNING {}",
+            USER_EXPORT_COLUMNS
+        ))
+        .bind(id)
+        .bind(data)
+        .bind(UserExportState::Ready.to_string())
+        .try_map(map_user_export_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+}
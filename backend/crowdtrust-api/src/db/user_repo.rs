@@ -6,16 +6,29 @@ use lib_api::db::{
     db_error::{map_sqlx_err, DbError},
     db_result::list_result,
     password::hash,
-    util::{append_and_eq, append_comma, append_limit_offset, option_enum_to_string},
+    util::{
+        append_and_eq, append_comma, append_cursor, append_cursor_order_limit, append_limit_offset,
+        cursor_count_column, cursor_include_total, cursor_result, option_enum_to_string,
+        CursorPage, DbCursor,
+    },
 };
 use lib_types::{
     dto::user::{list_users_dto::ListUsersQuery, register_user_dto::RegisterUserDto},
-    entity::user_entity::{UserCreateResult, UserEntity, UserListResults, UserUpdateParams},
-    shared::user::{UserStatus, UserType},
+    entity::{
+        project_member_entity::ProjectInvitationState,
+        project_transfer_entity::ProjectTransferState,
+        user_entity::{UserCreateResult, UserEntity, UserListResults, UserUpdateParams},
+    },
+    shared::{
+        pledge::FulfillmentStatus,
+        user::{UserStatus, UserType},
+    },
 };
 use sqlx::{postgres::PgRow, PgPool, Postgres, QueryBuilder, Row, Transaction};
 use uuid::Uuid;
 
+use super::app_repo::start_transaction;
+
 pub type DynUserRepo = Arc<dyn UserRepoTrait + Send + Sync>;
 
 #[async_trait]
@@ -27,12 +40,25 @@ pub trait UserRepoTrait {
         dto: RegisterUserDto,
     ) -> Result<UserCreateResult, DbError>;
     async fn delete_user(&self, id: Uuid) -> Result<(), DbError>;
+    // Removes the user's personal data and marks them Removed. Pledges, projects and
+    // comments are kept for project accounting, and show as an anonymous user
+    async fn anonymize_user(&self, id: Uuid) -> Result<UserEntity, DbError>;
     async fn get_user_by_id(&self, id: Uuid) -> Result<UserEntity, DbError>;
     async fn get_user_by_eth_address(&self, id: String) -> Result<UserEntity, DbError>;
     async fn update_user(&self, id: Uuid, params: UserUpdateParams) -> Result<UserEntity, DbError>;
+    async fn update_user_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        params: UserUpdateParams,
+    ) -> Result<UserEntity, DbError>;
     async fn find_user_by_email(&self, email: String) -> Result<UserEntity, DbError>;
     async fn find_user_by_eth_address(&self, email: String) -> Result<UserEntity, DbError>;
-    async fn list_users(&self, query: ListUsersQuery) -> Result<UserListResults, DbError>;
+    async fn list_users(
+        &self,
+        query: ListUsersQuery,
+        page: Option<CursorPage>,
+    ) -> Result<UserListResults, DbError>;
 }
 
 pub struct UserRepo {
@@ -41,7 +67,7 @@ pub struct UserRepo {
 
 const USER_COLUMNS: &str = formatcp!(
     r#"{u}.id, {u}.name, {u}.description, {u}.link, {u}.location, {u}.email, {u}.password_hash, {u}.eth_address, {u}.created_at, {u}.updated_at,
-{u}.user_type, {u}.user_status, {u}.email_confirmed"#,
+{u}.user_type, {u}.user_status, {u}.email_confirmed, {u}.show_backed_count, {u}.avatar_url, {u}.banner_url"#,
     u = "users"
 );
 
@@ -60,6 +86,9 @@ fn map_user_entity(row: PgRow) -> Result<UserEntity, sqlx::Error> {
         user_type: row.try_get_unchecked("user_type")?,
         user_status: row.try_get_unchecked("user_status")?,
         email_confirmed: row.try_get("email_confirmed")?,
+        show_backed_count: row.try_get("show_backed_count")?,
+        avatar_url: row.try_get("avatar_url")?,
+        banner_url: row.try_get("banner_url")?,
     })
 }
 
@@ -78,10 +107,13 @@ fn map_user_entity_with_relations(row: PgRow) -> Result<UserEntity, sqlx::Error>
         user_type: row.try_get_unchecked("user_type")?,
         user_status: row.try_get_unchecked("user_status")?,
         email_confirmed: row.try_get("email_confirmed")?,
+        show_backed_count: row.try_get("show_backed_count")?,
+        avatar_url: row.try_get("avatar_url")?,
+        banner_url: row.try_get("banner_url")?,
     })
 }
 
-fn map_user_list_entity(row: PgRow) -> Result<(UserEntity, i64), sqlx::Error> {
+fn map_user_list_entity(row: PgRow) -> Result<(UserEntity, Option<i64>), sqlx::Error> {
     let count = row.try_get("count")?;
     let entity = map_user_entity(row)?;
     Ok((entity, count))
@@ -145,6 +177,78 @@ impl UserRepoTrait for UserRepo {
         Ok(())
     }
 
+    async fn anonymize_user(&self, id: Uuid) -> Result<UserEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        // Signups and invitations are addressed to the user's email or eth address
+        sqlx::query(
+            "DELETE FROM project_signups WHERE email = (SELECT email FROM users WHERE id = $1)",
+        )
+        .bind(id)
+        .execute(tx.as_mut())
+        .await?;
+        sqlx::query(
+            r#"UPDATE project_invitations i SET state = $2 FROM users u
+            WHERE u.id = $1 AND i.state = $3
+            AND (lower(i.email) = lower(u.email) OR lower(i.eth_address) = lower(u.eth_address))"#,
+        )
+        .bind(id)
+        .bind(ProjectInvitationState::Declined.to_string())
+        .bind(ProjectInvitationState::Pending.to_string())
+        .execute(tx.as_mut())
+        .await?;
+        sqlx::query(
+            "UPDATE project_transfers SET state = $2 WHERE state = $3 AND (from_user_id = $1 OR to_user_id = $1)",
+        )
+        .bind(id)
+        .bind(ProjectTransferState::Cancelled.to_string())
+        .bind(ProjectTransferState::Pending.to_string())
+        .execute(tx.as_mut())
+        .await?;
+
+        for query in [
+            "DELETE FROM project_follows WHERE user_id = $1",
+            "DELETE FROM user_follows WHERE follower_id = $1 OR creator_id = $1",
+            "DELETE FROM notifications WHERE user_id = $1",
+            "DELETE FROM notification_preferences WHERE user_id = $1",
+            "DELETE FROM project_members WHERE user_id = $1",
+            "DELETE FROM user_assets WHERE user_id = $1",
+            "DELETE FROM user_exports WHERE user_id = $1",
+            "DELETE FROM user_wallets WHERE user_id = $1",
+            "DELETE FROM user_email_changes WHERE user_id = $1",
+        ] {
+            sqlx::query(query).bind(id).execute(tx.as_mut()).await?;
+        }
+
+        // Shipping addresses are kept until every item of the pledge has shipped
+        sqlx::query(
+            r#"UPDATE pledges SET shipping_address = NULL WHERE user_id = $1 AND NOT EXISTS
+            (SELECT 1 FROM pledge_items WHERE pledge_id = pledges.id AND fulfillment_status = $2)"#,
+        )
+        .bind(id)
+        .bind(FulfillmentStatus::Pending.to_string())
+        .execute(tx.as_mut())
+        .await?;
+
+        // Email and eth address are unique, so they're replaced with placeholders
+        let user = sqlx::query(formatcp!(
+            r#"UPDATE users SET name = 'Deleted user', description = '', link = '', location = '',
+            email = id::text || '@deleted.invalid', password_hash = '', eth_address = 'deleted:' || id::text,
+            user_status = $2, email_confirmed = false, show_backed_count = false, avatar_url = NULL, banner_url = NULL
+            WHERE id = $1 RETURNING {}"#,
+            USER_COLUMNS
+        ))
+        .bind(id)
+        .bind(UserStatus::Removed.to_string())
+        .try_map(map_user_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_sqlx_err)?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(user)
+    }
+
     async fn get_user_by_id(&self, id: Uuid) -> Result<UserEntity, DbError> {
         Ok(sqlx::query(formatcp!(
             "SELECT {} FROM users WHERE id = $1",
@@ -170,6 +274,18 @@ impl UserRepoTrait for UserRepo {
     }
 
     async fn update_user(&self, id: Uuid, params: UserUpdateParams) -> Result<UserEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        let result = self.update_user_tx(&mut tx, id, params).await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(result)
+    }
+
+    async fn update_user_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        params: UserUpdateParams,
+    ) -> Result<UserEntity, DbError> {
         let query = QueryBuilder::new("UPDATE users SET");
         let update_count = 0;
 
@@ -207,6 +323,13 @@ impl UserRepoTrait for UserRepo {
             update_count,
         );
 
+        let (query, update_count) = append_comma(
+            query,
+            "show_backed_count",
+            params.show_backed_count,
+            update_count,
+        );
+
         let (mut query, update_count) = if let Some(user_status) = params.user_status {
             append_comma(
                 query,
@@ -229,7 +352,7 @@ impl UserRepoTrait for UserRepo {
         Ok(query
             .build()
             .try_map(map_user_entity)
-            .fetch_one(&self.db)
+            .fetch_one(tx.as_mut())
             .await
             .map_err(|e| match e {
                 sqlx::Error::RowNotFound => DbError::EntityNotFound(),
@@ -278,12 +401,23 @@ impl UserRepoTrait for UserRepo {
         Ok(user)
     }
 
-    async fn list_users(&self, query: ListUsersQuery) -> Result<UserListResults, DbError> {
-        let filtered_query = if query.user_type.is_none() && query.status.is_none() {
-            QueryBuilder::new("SELECT *, COUNT(*) OVER () FROM users")
-        } else {
-            QueryBuilder::new("SELECT *, COUNT(*) OVER () FROM users WHERE")
-        };
+    async fn list_users(
+        &self,
+        query: ListUsersQuery,
+        page: Option<CursorPage>,
+    ) -> Result<UserListResults, DbError> {
+        let mut filtered_query = QueryBuilder::new("");
+        // Cursor pagination filters the count subquery, so the total isn't affected
+        if page.is_some() {
+            filtered_query.push("SELECT * FROM (");
+        }
+        filtered_query.push(format!(
+            "SELECT *, {} FROM users",
+            cursor_count_column(&page)
+        ));
+        if query.user_type.is_some() || query.status.is_some() {
+            filtered_query.push(" WHERE");
+        }
 
         // Filter type
         let (filtered_query, count) = append_and_eq(
@@ -299,7 +433,25 @@ impl UserRepoTrait for UserRepo {
             option_enum_to_string(query.status),
             count,
         );
-        filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        if let Some(page) = &page {
+            filtered_query.push(") as users");
+            if page.cursor.is_some() {
+                filtered_query.push(" WHERE");
+            }
+            let (q, _) = append_cursor(
+                filtered_query,
+                page,
+                "users.created_at",
+                "timestamptz",
+                "users.id",
+                true,
+                0,
+            );
+            filtered_query =
+                append_cursor_order_limit(q, page, "users.created_at", "users.id", true);
+        } else {
+            filtered_query = append_limit_offset(filtered_query, query.from, query.to);
+        }
 
         let results = filtered_query
             .build()
@@ -307,8 +459,20 @@ impl UserRepoTrait for UserRepo {
             .fetch_all(&self.db)
             .await?;
 
-        let (results, total) = list_result(results);
+        let (results, total) = list_result(results, cursor_include_total(&page));
+        let (results, next_cursor) = if let Some(page) = &page {
+            cursor_result(results, page, |user: &UserEntity| DbCursor {
+                value: user.created_at.to_rfc3339(),
+                id: user.id,
+            })
+        } else {
+            (results, None)
+        };
 
-        Ok(UserListResults { total, results })
+        Ok(UserListResults {
+            total,
+            next_cursor,
+            results,
+        })
     }
 }
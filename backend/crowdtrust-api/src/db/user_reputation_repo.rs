@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::db_error::DbError;
+use lib_types::{
+    entity::user_reputation_entity::{UserReputationEntity, UserReputationStats},
+    shared::{
+        pledge::FulfillmentStatus,
+        project::{BlockchainStatus, FundingOutcome, ProjectStatus},
+    },
+};
+use sqlx::{postgres::PgRow, PgPool, QueryBuilder, Row};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynUserReputationRepo = Arc<dyn UserReputationRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserReputationRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Aggregates the delivery history of every user with a completed project
+    async fn list_reputation_stats(&self) -> Result<Vec<UserReputationStats>, DbError>;
+    // Stores a new snapshot for each user
+    async fn create_reputations(&self, stats: Vec<UserReputationStats>) -> Result<u64, DbError>;
+    // Latest snapshot of a user, if one has been computed
+    async fn get_reputation(&self, user_id: Uuid) -> Result<Option<UserReputationEntity>, DbError>;
+}
+
+pub struct UserReputationRepo {
+    pub db: PgPool,
+}
+
+// Rows per INSERT, so the 11 binds per row stay under Postgres' 65535 bind limit
+const REPUTATION_INSERT_CHUNK: usize = 5000;
+
+const REPUTATION_STATS_COLUMNS: &str = r#"user_id, projects_completed, projects_funded, items_due, items_on_time, items_shipped, items_delivered, items_disputed, pledges_total, pledges_refunded"#;
+
+fn map_reputation_stats(row: &PgRow) -> Result<UserReputationStats, sqlx::Error> {
+    Ok(UserReputationStats {
+        user_id: row.try_get("user_id")?,
+        projects_completed: row.try_get("projects_completed")?,
+        projects_funded: row.try_get("projects_funded")?,
+        items_due: row.try_get("items_due")?,
+        items_on_time: row.try_get("items_on_time")?,
+        items_shipped: row.try_get("items_shipped")?,
+        items_delivered: row.try_get("items_delivered")?,
+        items_disputed: row.try_get("items_disputed")?,
+        pledges_total: row.try_get("pledges_total")?,
+        pledges_refunded: row.try_get("pledges_refunded")?,
+    })
+}
+
+fn map_reputation_entity(row: PgRow) -> Result<UserReputationEntity, sqlx::Error> {
+    Ok(UserReputationEntity {
+        id: row.try_get("id")?,
+        score: row.try_get("score")?,
+        stats: map_reputation_stats(&row)?,
+        computed_at: row.try_get("computed_at")?,
+    })
+}
+
+#[async_trait]
+impl UserReputationRepoTrait for UserReputationRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn list_reputation_stats(&self) -> Result<Vec<UserReputationStats>, DbError> {
+        // Items are on time if shipped before the reward's promised delivery time.
+        // Refunded pledges don't count towards delivery
+        Ok(sqlx::query(
+            // language=PostgreSQL
+            r#"
+              SELECT projects.user_id,
+                COUNT(*)::int as projects_completed,
+                COUNT(*) FILTER (WHERE projects.funding_outcome = $2)::int as projects_funded,
+                COALESCE(SUM(i.items_due), 0)::int as items_due,
+                COALESCE(SUM(i.items_on_time), 0)::int as items_on_time,
+                COALESCE(SUM(i.items_shipped), 0)::int as items_shipped,
+                COALESCE(SUM(i.items_delivered), 0)::int as items_delivered,
+                COALESCE(SUM(i.items_disputed), 0)::int as items_disputed,
+                COALESCE(SUM(pl.pledges_total), 0)::int as pledges_total,
+                COALESCE(SUM(pl.pledges_refunded), 0)::int as pledges_refunded
+              FROM projects
+              CROSS JOIN LATERAL (
+                SELECT
+                  COUNT(*) FILTER (WHERE r.delivery_time < EXTRACT(EPOCH FROM NOW())::bigint OR pi.shipped_at IS NOT NULL) as items_due,
+                  COUNT(*) FILTER (WHERE pi.shipped_at IS NOT NULL AND (r.delivery_time IS NULL OR EXTRACT(EPOCH FROM pi.shipped_at)::bigint <= r.delivery_time)) as items_on_time,
+                  COUNT(*) FILTER (WHERE pi.fulfillment_status <> $4) as items_shipped,
+                  COUNT(*) FILTER (WHERE pi.fulfillment_status = $5) as items_delivered,
+                  COUNT(*) FILTER (WHERE pi.fulfillment_status = $6) as items_disputed
+                FROM pledge_items pi
+                JOIN pledges p on p.id = pi.pledge_id
+                JOIN rewards r on r.id = pi.reward_id
+                WHERE p.project_id = projects.id AND p.blockchain_status <> $3 AND NOT p.refundable
+              ) i
+              CROSS JOIN LATERAL (
+                SELECT COUNT(*) as pledges_total, COUNT(*) FILTER (WHERE refundable) as pledges_refunded
+                FROM pledges WHERE project_id = projects.id AND blockchain_status <> $3
+              ) pl
+              WHERE projects.status = $1
+              GROUP BY projects.user_id
+            "#,
+        )
+        .bind(ProjectStatus::Complete.to_string())
+        .bind(FundingOutcome::Funded.to_string())
+        .bind(BlockchainStatus::Error.to_string())
+        .bind(FulfillmentStatus::Pending.to_string())
+        .bind(FulfillmentStatus::Delivered.to_string())
+        .bind(FulfillmentStatus::Disputed.to_string())
+        .try_map(|row: PgRow| map_reputation_stats(&row))
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn create_reputations(&self, stats: Vec<UserReputationStats>) -> Result<u64, DbError> {
+        if stats.is_empty() {
+            return Ok(0);
+        }
+        // All snapshots are computed at the same time, or not at all
+        let mut tx = start_transaction(&self.db).await?;
+        let mut rows_affected = 0;
+        for chunk in stats.chunks(REPUTATION_INSERT_CHUNK) {
+            let mut query = QueryBuilder::new(format!(
+                "INSERT INTO user_reputations (score, {}) ",
+                REPUTATION_STATS_COLUMNS
+            ));
+            query.push_values(chunk, |mut b, s| {
+                b.push_bind(s.score())
+                    .push_bind(s.user_id)
+                    .push_bind(s.projects_completed)
+                    .push_bind(s.projects_funded)
+                    .push_bind(s.items_due)
+                    .push_bind(s.items_on_time)
+                    .push_bind(s.items_shipped)
+                    .push_bind(s.items_delivered)
+                    .push_bind(s.items_disputed)
+                    .push_bind(s.pledges_total)
+                    .push_bind(s.pledges_refunded);
+            });
+            rows_affected += query.build().execute(tx.as_mut()).await?.rows_affected();
+        }
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(rows_affected)
+    }
+
+    async fn get_reputation(&self, user_id: Uuid) -> Result<Option<UserReputationEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT id, score, {}, computed_at FROM user_reputations WHERE user_id = $1 ORDER BY computed_at DESC LIMIT 1",
+            REPUTATION_STATS_COLUMNS
+        ))
+        .bind(user_id)
+        .try_map(map_reputation_entity)
+        .fetch_optional(&self.db)
+        .await?)
+    }
+}
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use const_format::formatcp;
+use lib_api::db::db_error::{map_sqlx_err, DbError};
+use lib_types::entity::user_wallet_entity::UserWalletEntity;
+use sqlx::{postgres::PgRow, PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+use super::app_repo::start_transaction;
+
+pub type DynUserWalletRepo = Arc<dyn UserWalletRepoTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserWalletRepoTrait {
+    fn get_db(&self) -> &PgPool;
+    // Adds the primary wallet of a new user
+    async fn create_primary_wallet_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: UserWalletProps,
+    ) -> Result<UserWalletEntity, DbError>;
+    // Links the wallet, or updates it if already linked to the user. A primary wallet
+    // replaces the previous one, which stays linked
+    async fn link_wallet(&self, props: UserWalletProps) -> Result<UserWalletEntity, DbError>;
+    async fn link_wallet_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: UserWalletProps,
+    ) -> Result<UserWalletEntity, DbError>;
+    async fn get_wallet_by_id(&self, id: Uuid) -> Result<UserWalletEntity, DbError>;
+    async fn find_wallet_by_eth_address(
+        &self,
+        eth_address: String,
+    ) -> Result<UserWalletEntity, DbError>;
+    async fn list_wallets(&self, user_id: Uuid) -> Result<Vec<UserWalletEntity>, DbError>;
+    async fn set_primary_wallet(&self, wallet: &UserWalletEntity) -> Result<(), DbError>;
+    // Primary wallets can't be unlinked
+    async fn delete_wallet(&self, id: Uuid) -> Result<(), DbError>;
+    async fn is_user_wallet(&self, user_id: Uuid, eth_address: String) -> Result<bool, DbError>;
+}
+
+pub struct UserWalletRepo {
+    pub db: PgPool,
+}
+
+pub struct UserWalletProps {
+    pub user_id: Uuid,
+    pub eth_address: String,
+    pub signature: String,
+    pub is_primary: bool,
+}
+
+const USER_WALLET_COLUMNS: &str = r#"id, user_id, eth_address, is_primary, created_at, updated_at"#;
+
+fn map_user_wallet_entity(row: PgRow) -> Result<UserWalletEntity, sqlx::Error> {
+    Ok(UserWalletEntity {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        eth_address: row.try_get("eth_address")?,
+        is_primary: row.try_get("is_primary")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn map_wallet_unique_err(e: sqlx::Error) -> DbError {
+    match e {
+        sqlx::Error::Database(dbe)
+            if dbe.constraint() == Some("user_wallets_eth_address_idx")
+                || dbe.constraint() == Some("users_eth_address_key") =>
+        {
+            DbError::Unique("eth_address".into())
+        }
+        _ => DbError::Query(e.to_string()),
+    }
+}
+
+#[async_trait]
+impl UserWalletRepoTrait for UserWalletRepo {
+    fn get_db(&self) -> &PgPool {
+        &self.db
+    }
+
+    async fn create_primary_wallet_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: UserWalletProps,
+    ) -> Result<UserWalletEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "user_wallets" (user_id, eth_address, signature, is_primary)
+              values ($1, $2, $3, true)
+              RETURNING {}
+            "#,
+            USER_WALLET_COLUMNS
+        ))
+        .bind(props.user_id)
+        .bind(props.eth_address)
+        .bind(props.signature)
+        .try_map(map_user_wallet_entity)
+        .fetch_one(tx.as_mut())
+        .await
+        .map_err(map_wallet_unique_err)?)
+    }
+
+    async fn link_wallet(&self, props: UserWalletProps) -> Result<UserWalletEntity, DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+        let result = self.link_wallet_tx(&mut tx, props).await?;
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(result)
+    }
+
+    async fn link_wallet_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        props: UserWalletProps,
+    ) -> Result<UserWalletEntity, DbError> {
+        if props.is_primary {
+            sqlx::query(
+                "UPDATE user_wallets SET is_primary = false WHERE user_id = $1 AND is_primary",
+            )
+            .bind(props.user_id)
+            .execute(tx.as_mut())
+            .await?;
+        }
+
+        // Wallets linked to another user are left untouched, and return no row
+        let wallet = sqlx::query(formatcp!(
+            // language=PostgreSQL
+            r#"
+              INSERT INTO "user_wallets" (user_id, eth_address, signature, is_primary)
+              values ($1, $2, $3, $4)
+              ON CONFLICT ((lower(eth_address))) DO UPDATE
+                SET signature = EXCLUDED.signature,
+                    is_primary = user_wallets.is_primary OR EXCLUDED.is_primary
+                WHERE user_wallets.user_id = EXCLUDED.user_id
+              RETURNING {}
+            "#,
+            USER_WALLET_COLUMNS
+        ))
+        .bind(props.user_id)
+        .bind(&props.eth_address)
+        .bind(props.signature)
+        .bind(props.is_primary)
+        .try_map(map_user_wallet_entity)
+        .fetch_optional(tx.as_mut())
+        .await?
+        .ok_or(DbError::Unique("eth_address".into()))?;
+
+        if props.is_primary {
+            sqlx::query("UPDATE users SET eth_address = $1 WHERE id = $2")
+                .bind(&wallet.eth_address)
+                .bind(props.user_id)
+                .execute(tx.as_mut())
+                .await
+                .map_err(map_wallet_unique_err)?;
+        }
+
+        Ok(wallet)
+    }
+
+    async fn get_wallet_by_id(&self, id: Uuid) -> Result<UserWalletEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_wallets WHERE id = $1",
+            USER_WALLET_COLUMNS
+        ))
+        .bind(id)
+        .try_map(map_user_wallet_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn find_wallet_by_eth_address(
+        &self,
+        eth_address: String,
+    ) -> Result<UserWalletEntity, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_wallets WHERE lower(eth_address) = lower($1)",
+            USER_WALLET_COLUMNS
+        ))
+        .bind(eth_address)
+        .try_map(map_user_wallet_entity)
+        .fetch_one(&self.db)
+        .await
+        .map_err(map_sqlx_err)?)
+    }
+
+    async fn list_wallets(&self, user_id: Uuid) -> Result<Vec<UserWalletEntity>, DbError> {
+        Ok(sqlx::query(formatcp!(
+            "SELECT {} FROM user_wallets WHERE user_id = $1 ORDER BY is_primary DESC, created_at",
+            USER_WALLET_COLUMNS
+        ))
+        .bind(user_id)
+        .try_map(map_user_wallet_entity)
+        .fetch_all(&self.db)
+        .await?)
+    }
+
+    async fn set_primary_wallet(&self, wallet: &UserWalletEntity) -> Result<(), DbError> {
+        let mut tx = start_transaction(&self.db).await?;
+
+        sqlx::query("UPDATE user_wallets SET is_primary = false WHERE user_id = $1 AND is_primary")
+            .bind(wallet.user_id)
+            .execute(tx.as_mut())
+            .await?;
+
+        sqlx::query("UPDATE user_wallets SET is_primary = true WHERE id = $1")
+            .bind(wallet.id)
+            .execute(tx.as_mut())
+            .await?;
+
+        sqlx::query("UPDATE users SET eth_address = $1 WHERE id = $2")
+            .bind(&wallet.eth_address)
+            .bind(wallet.user_id)
+            .execute(tx.as_mut())
+            .await
+            .map_err(map_wallet_unique_err)?;
+
+        tx.commit().await.map_err(DbError::SqlxError)?;
+        Ok(())
+    }
+
+    async fn delete_wallet(&self, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query(r#"DELETE FROM "user_wallets" WHERE id = $1 AND NOT is_primary"#)
+            .bind(id)
+            .execute(&self.db)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::EntityNotFound());
+        }
+        Ok(())
+    }
+
+    async fn is_user_wallet(&self, user_id: Uuid, eth_address: String) -> Result<bool, DbError> {
+        Ok(sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM user_wallets WHERE user_id = $1 AND lower(eth_address) = lower($2))",
+        )
+        .bind(user_id)
+        .bind(eth_address)
+        .fetch_one(&self.db)
+        .await?)
+    }
+}
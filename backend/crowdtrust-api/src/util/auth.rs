@@ -9,18 +9,22 @@ use lib_api::{
     },
     error::api_error::ApiError,
 };
-use lib_types::shared::user::{RequestUser, UserType};
+use lib_types::shared::user::{RequestUser, UserStatus, UserType};
 
 use crate::api_context::ApiContext;
 
 pub async fn verify_user_exist(context: ApiContext, token: &UserToken) -> Result<(), ApiError> {
     if token.user_type.clone() != UserType::Anonymous {
-        let _ = context
+        let user = context
             .repo
             .user
             .get_user_by_id(token.user_id)
             .await
             .map_err(|_| ApiError::unauthorized())?;
+        // Deleted accounts can't use tokens issued before deletion
+        if user.user_status == UserStatus::Removed {
+            return Err(ApiError::unauthorized());
+        }
     }
     Ok(())
 }
@@ -0,0 +1,16 @@
+ALTER TABLE
+    rewards
+ADD COLUMN shipping_required BOOLEAN DEFAULT FALSE NOT NULL,
+ADD COLUMN shipping_costs JSONB DEFAULT '[]'::jsonb NOT NULL;
+
+ALTER TABLE
+    pledges
+ADD COLUMN shipping_address TEXT;
+
+ALTER TABLE
+    pledge_items
+ADD COLUMN paid_shipping NUMERIC(78, 0) NOT NULL DEFAULT 0,
+ADD COLUMN fulfillment_status TEXT NOT NULL DEFAULT 'Pending',
+ADD COLUMN tracking_number TEXT,
+ADD COLUMN shipped_at timestamp with time zone,
+ADD COLUMN delivered_at timestamp with time zone;
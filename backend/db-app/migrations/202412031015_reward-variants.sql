@@ -0,0 +1,8 @@
+ALTER TABLE
+    rewards
+ADD COLUMN variants JSONB DEFAULT '[]'::jsonb NOT NULL,
+ADD COLUMN parent_reward_id uuid REFERENCES rewards(id);
+
+ALTER TABLE
+    pledge_items
+ADD COLUMN variant_id uuid;
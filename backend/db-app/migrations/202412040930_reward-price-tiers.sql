@@ -0,0 +1,7 @@
+ALTER TABLE
+    rewards
+ADD COLUMN price_tiers JSONB DEFAULT '[]'::jsonb NOT NULL;
+
+ALTER TABLE
+    pledge_items
+ADD COLUMN price_tier_id uuid;
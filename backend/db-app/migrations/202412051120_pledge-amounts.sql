@@ -0,0 +1,20 @@
+ALTER TABLE
+    pledges
+ADD COLUMN extra_amount NUMERIC(78, 0) NOT NULL DEFAULT 0,
+ADD COLUMN total_amount NUMERIC(78, 0) NOT NULL DEFAULT 0,
+ADD COLUMN paid_currency TEXT NOT NULL DEFAULT 'Ethereum';
+
+UPDATE
+    pledges
+SET
+    total_amount = COALESCE(
+        (
+            SELECT
+                SUM((pi.paid_price + pi.paid_shipping) * pi.quantity)
+            FROM
+                pledge_items pi
+            WHERE
+                pi.pledge_id = pledges.id
+        ),
+        0
+    );
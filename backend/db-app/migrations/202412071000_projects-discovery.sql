@@ -0,0 +1,5 @@
+-- Ending soon sort and live filter
+CREATE INDEX projects_end_time_idx ON projects ((start_time + duration));
+
+-- Trending score, counts recent pledges per project
+CREATE INDEX pledges_created_at_project_id_idx ON pledges (created_at, project_id);
@@ -0,0 +1,41 @@
+ALTER TABLE
+    projects
+ADD COLUMN slug TEXT;
+
+-- Backfill from project names, suffixing duplicates
+UPDATE
+    projects
+SET
+    slug = s.slug
+FROM (
+    SELECT
+        id,
+        base || CASE WHEN n > 1 THEN '-' || n ELSE '' END as slug
+    FROM (
+        SELECT
+            id,
+            base,
+            ROW_NUMBER() OVER (PARTITION BY base ORDER BY created_at) as n
+        FROM (
+            SELECT
+                id,
+                created_at,
+                COALESCE(NULLIF(trim(both '-' from left(trim(both '-' from lower(regexp_replace(name, '[^a-zA-Z0-9]+', '-', 'g'))), 60)), ''), 'project') as base
+            FROM projects
+        ) b
+    ) n
+) s
+WHERE
+    projects.id = s.id;
+
+ALTER TABLE
+    projects
+ALTER COLUMN slug SET NOT NULL,
+ADD CONSTRAINT projects_slug_key UNIQUE (slug);
+
+-- Previous slugs of renamed projects
+CREATE TABLE project_slugs (
+    slug TEXT PRIMARY KEY,
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
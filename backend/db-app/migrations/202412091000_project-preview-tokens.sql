@@ -0,0 +1,17 @@
+CREATE TABLE project_preview_tokens (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    expires_at timestamp with time zone NOT NULL,
+    revoked_at timestamp with time zone,
+    view_count INTEGER NOT NULL DEFAULT 0,
+    last_viewed_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_preview_tokens_project_id_idx ON project_preview_tokens (project_id);
+
+CREATE TRIGGER project_preview_tokens_modified_column
+BEFORE UPDATE ON project_preview_tokens FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
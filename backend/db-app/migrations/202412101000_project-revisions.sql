@@ -0,0 +1,17 @@
+-- Project and reward content changes. `reward_id` isn't a foreign key, so revisions of deleted rewards are kept
+CREATE TABLE project_revisions (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    reward_id uuid,
+    user_id uuid NOT NULL REFERENCES users(id),
+    action TEXT NOT NULL,
+    changes JSONB NOT NULL DEFAULT '[]',
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_revisions_project_id_idx ON project_revisions (project_id, created_at);
+
+-- Project and reward terms when the pledge was made
+ALTER TABLE
+    pledges
+ADD COLUMN terms JSONB;
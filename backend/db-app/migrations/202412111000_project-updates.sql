@@ -0,0 +1,37 @@
+-- Creator announcements. `body` is markdown, `backers_only` updates are only readable by backers
+CREATE TABLE project_updates (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    state TEXT NOT NULL,
+    backers_only BOOLEAN NOT NULL DEFAULT false,
+    published_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_updates_project_id_idx ON project_updates (project_id, published_at);
+
+CREATE TRIGGER project_updates_modified_column
+BEFORE UPDATE ON project_updates FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
+
+CREATE TABLE project_update_assets (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    update_id uuid NOT NULL REFERENCES project_updates(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    size BIGINT NOT NULL DEFAULT 0,
+    content_type TEXT NOT NULL,
+    state TEXT NOT NULL,
+    upload_expires_at timestamp with time zone NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_update_assets_update_id_idx ON project_update_assets (update_id);
+
+CREATE TRIGGER project_update_assets_modified_column
+BEFORE UPDATE ON project_update_assets FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
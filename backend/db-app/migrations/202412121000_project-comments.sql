@@ -0,0 +1,32 @@
+-- Threaded project comments. Comments are soft deleted, so replies keep their parent
+CREATE TABLE project_comments (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    parent_id uuid REFERENCES project_comments(id),
+    body TEXT NOT NULL,
+    report_count INTEGER NOT NULL DEFAULT 0,
+    edited_at timestamp with time zone,
+    deleted_at timestamp with time zone,
+    hidden_at timestamp with time zone,
+    hidden_by uuid REFERENCES users(id),
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_comments_project_id_idx ON project_comments (project_id, created_at);
+CREATE INDEX project_comments_parent_id_idx ON project_comments (parent_id, created_at);
+CREATE INDEX project_comments_user_id_idx ON project_comments (user_id, created_at);
+
+CREATE TRIGGER project_comments_modified_column
+BEFORE UPDATE ON project_comments FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
+
+-- One report per user and comment
+CREATE TABLE project_comment_reports (
+    comment_id uuid NOT NULL REFERENCES project_comments(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    reason TEXT NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    PRIMARY KEY (comment_id, user_id)
+);
@@ -0,0 +1,40 @@
+-- Project collaborators. The project owner is not a member, and always has full access
+CREATE TABLE project_members (
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    role TEXT NOT NULL,
+    invited_by uuid REFERENCES users(id),
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE INDEX project_members_user_id_idx ON project_members (user_id);
+
+CREATE TRIGGER project_members_modified_column
+BEFORE UPDATE ON project_members FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
+
+-- Invitations are addressed to an email or eth address, and accepted by the matching user
+CREATE TABLE project_invitations (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    email TEXT,
+    eth_address TEXT,
+    role TEXT NOT NULL,
+    state TEXT NOT NULL DEFAULT 'Pending',
+    invited_by uuid NOT NULL REFERENCES users(id),
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    CHECK ((email IS NULL) != (eth_address IS NULL))
+);
+
+-- One pending invitation per project and invitee
+CREATE UNIQUE INDEX project_invitations_pending_email_idx
+ON project_invitations (project_id, lower(email)) WHERE state = 'Pending';
+CREATE UNIQUE INDEX project_invitations_pending_eth_address_idx
+ON project_invitations (project_id, lower(eth_address)) WHERE state = 'Pending';
+
+CREATE TRIGGER project_invitations_modified_column
+BEFORE UPDATE ON project_invitations FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
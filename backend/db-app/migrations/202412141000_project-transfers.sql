@@ -0,0 +1,21 @@
+-- Project ownership transfers. The recipient accepts with a wallet signature
+CREATE TABLE project_transfers (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    from_user_id uuid NOT NULL REFERENCES users(id),
+    to_user_id uuid NOT NULL REFERENCES users(id),
+    state TEXT NOT NULL DEFAULT 'Pending',
+    expires_at timestamp with time zone NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_transfers_project_id_idx ON project_transfers (project_id);
+CREATE INDEX project_transfers_to_user_id_idx ON project_transfers (to_user_id);
+
+-- One pending transfer per project
+CREATE UNIQUE INDEX project_transfers_pending_idx ON project_transfers (project_id) WHERE state = 'Pending';
+
+CREATE TRIGGER project_transfers_modified_column
+BEFORE UPDATE ON project_transfers FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
@@ -0,0 +1,51 @@
+CREATE TABLE project_follows (
+    user_id uuid NOT NULL REFERENCES users(id),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    PRIMARY KEY (user_id, project_id)
+);
+
+CREATE INDEX project_follows_project_id_idx ON project_follows (project_id);
+
+CREATE TABLE user_follows (
+    follower_id uuid NOT NULL REFERENCES users(id),
+    creator_id uuid NOT NULL REFERENCES users(id),
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    PRIMARY KEY (follower_id, creator_id),
+    CHECK (follower_id != creator_id)
+);
+
+CREATE INDEX user_follows_creator_id_idx ON user_follows (creator_id);
+
+-- Notifications are created for each recipient when a domain event occurs.
+-- Email notifications with no `emailed_at` are pending delivery
+CREATE TABLE notifications (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL REFERENCES users(id),
+    kind TEXT NOT NULL,
+    project_id uuid REFERENCES projects(id) ON DELETE CASCADE,
+    data JSONB NOT NULL DEFAULT '{}',
+    in_app BOOLEAN NOT NULL,
+    email BOOLEAN NOT NULL,
+    read_at timestamp with time zone,
+    emailed_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX notifications_user_id_idx ON notifications (user_id, created_at);
+CREATE INDEX notifications_project_id_idx ON notifications (project_id, kind);
+CREATE INDEX notifications_email_pending_idx ON notifications (created_at) WHERE email AND emailed_at IS NULL;
+
+-- Delivery preferences per kind. Defaults are applied if a kind has no row
+CREATE TABLE notification_preferences (
+    user_id uuid NOT NULL REFERENCES users(id),
+    kind TEXT NOT NULL,
+    in_app BOOLEAN NOT NULL,
+    email BOOLEAN NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    PRIMARY KEY (user_id, kind)
+);
+
+CREATE TRIGGER notification_preferences_modified_column
+BEFORE UPDATE ON notification_preferences FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
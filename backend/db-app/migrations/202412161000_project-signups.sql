@@ -0,0 +1,17 @@
+-- "Notify me" signups for Prelaunch projects. Emails are stored lowercase
+CREATE TABLE project_signups (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    -- Set when the email is confirmed (double opt-in)
+    confirmed_at timestamp with time zone,
+    -- Set when the launch notification is sent
+    notified_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    UNIQUE (project_id, email)
+);
+
+CREATE TRIGGER project_signups_modified_column
+BEFORE UPDATE ON project_signups FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
@@ -0,0 +1,8 @@
+ALTER TABLE projects
+    ADD COLUMN funding_model TEXT NOT NULL DEFAULT 'AllOrNothing',
+    -- Set when the campaign ends
+    ADD COLUMN funding_outcome TEXT,
+    ADD COLUMN outcome_at timestamp with time zone;
+
+-- Set when an all-or-nothing project ends unfunded
+ALTER TABLE pledges ADD COLUMN refundable BOOLEAN NOT NULL DEFAULT false;
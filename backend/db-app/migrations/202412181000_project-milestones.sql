@@ -0,0 +1,38 @@
+-- Milestones unlock a percentage of a funded project's funds when approved by backers
+CREATE TABLE project_milestones (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id uuid NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    -- Epoch seconds
+    target_date BIGINT NOT NULL,
+    release_percent INTEGER NOT NULL CHECK (release_percent > 0 AND release_percent <= 100),
+    state TEXT NOT NULL DEFAULT 'Pending',
+    voting_starts_at timestamp with time zone,
+    voting_ends_at timestamp with time zone,
+    -- Pledged amounts of backers who voted
+    approve_weight NUMERIC NOT NULL DEFAULT 0,
+    reject_weight NUMERIC NOT NULL DEFAULT 0,
+    vote_count INTEGER NOT NULL DEFAULT 0,
+    resolved_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX project_milestones_project_id_idx ON project_milestones (project_id, target_date);
+
+CREATE TRIGGER project_milestones_modified_column
+BEFORE UPDATE ON project_milestones FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
+
+-- One vote per backer, signed by their wallet
+CREATE TABLE milestone_votes (
+    milestone_id uuid NOT NULL REFERENCES project_milestones(id) ON DELETE CASCADE,
+    user_id uuid NOT NULL REFERENCES users(id),
+    approve BOOLEAN NOT NULL,
+    weight NUMERIC NOT NULL,
+    eth_address TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    PRIMARY KEY (milestone_id, user_id)
+);
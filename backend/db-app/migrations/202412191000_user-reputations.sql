@@ -0,0 +1,20 @@
+-- Snapshots of a creator's delivery history, recomputed periodically
+CREATE TABLE user_reputations (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL REFERENCES users(id),
+    -- Null until the creator has enough delivered items
+    score INT,
+    projects_completed INT NOT NULL DEFAULT 0,
+    projects_funded INT NOT NULL DEFAULT 0,
+    -- Pledged items past their promised delivery time, or already shipped
+    items_due INT NOT NULL DEFAULT 0,
+    items_on_time INT NOT NULL DEFAULT 0,
+    items_shipped INT NOT NULL DEFAULT 0,
+    items_delivered INT NOT NULL DEFAULT 0,
+    items_disputed INT NOT NULL DEFAULT 0,
+    pledges_total INT NOT NULL DEFAULT 0,
+    pledges_refunded INT NOT NULL DEFAULT 0,
+    computed_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX user_reputations_user_id_idx ON user_reputations (user_id, computed_at DESC);
@@ -0,0 +1,2 @@
+-- Users opt in to showing how many projects they backed on their public profile
+ALTER TABLE users ADD COLUMN show_backed_count BOOLEAN NOT NULL DEFAULT false;
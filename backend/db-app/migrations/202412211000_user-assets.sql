@@ -0,0 +1,24 @@
+CREATE TABLE user_assets (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL REFERENCES users(id),
+    -- Avatar or Banner
+    kind TEXT NOT NULL,
+    size BIGINT NOT NULL DEFAULT 0,
+    content_type TEXT NOT NULL,
+    state TEXT NOT NULL,
+    upload_expires_at timestamp with time zone NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+-- One active avatar and banner per user
+CREATE UNIQUE INDEX user_assets_active_idx ON user_assets (user_id, kind) WHERE state = 'Uploaded';
+
+CREATE TRIGGER user_assets_modified_column
+BEFORE UPDATE ON user_assets FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
+
+-- Object keys of the active avatar and banner
+ALTER TABLE users
+    ADD COLUMN avatar_url TEXT,
+    ADD COLUMN banner_url TEXT;
@@ -0,0 +1,19 @@
+-- Personal data exports, built asynchronously by a scheduled job
+CREATE TABLE user_exports (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL REFERENCES users(id),
+    state TEXT NOT NULL DEFAULT 'Pending',
+    -- JSON archive, set when the export is Ready
+    data JSONB,
+    completed_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX user_exports_user_id_idx ON user_exports (user_id);
+
+CREATE INDEX user_exports_pending_idx ON user_exports (created_at) WHERE state = 'Pending';
+
+CREATE TRIGGER user_exports_modified_column
+BEFORE UPDATE ON user_exports FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
@@ -0,0 +1,28 @@
+-- Wallets linked to a user. The primary wallet is used for login, and mirrored to users.eth_address
+CREATE TABLE user_wallets (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL REFERENCES users(id),
+    eth_address TEXT NOT NULL,
+    is_primary BOOLEAN NOT NULL DEFAULT false,
+    -- Link signature. NULL for wallets migrated from users.eth_address
+    signature TEXT,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE UNIQUE INDEX user_wallets_eth_address_idx ON user_wallets (lower(eth_address));
+
+CREATE INDEX user_wallets_user_id_idx ON user_wallets (user_id);
+
+-- One primary wallet per user
+CREATE UNIQUE INDEX user_wallets_primary_idx ON user_wallets (user_id) WHERE is_primary;
+
+CREATE TRIGGER user_wallets_modified_column
+BEFORE UPDATE ON user_wallets FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
+
+INSERT INTO user_wallets (user_id, eth_address, is_primary)
+SELECT id, eth_address, true FROM users;
+
+-- Linked wallet the pledge was paid from
+ALTER TABLE pledges ADD COLUMN eth_address TEXT;
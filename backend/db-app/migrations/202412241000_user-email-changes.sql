@@ -0,0 +1,23 @@
+-- Email changes are applied once the new address confirms, and can be reverted from the old address
+CREATE TABLE user_email_changes (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL REFERENCES users(id),
+    old_email TEXT NOT NULL,
+    new_email TEXT NOT NULL,
+    -- Pending, Confirmed, Reverted or Cancelled
+    state TEXT NOT NULL DEFAULT 'Pending',
+    expires_at timestamp with time zone NOT NULL,
+    confirmed_at timestamp with time zone,
+    reverted_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+CREATE INDEX user_email_changes_user_id_idx ON user_email_changes (user_id);
+
+-- One pending change per user
+CREATE UNIQUE INDEX user_email_changes_pending_idx ON user_email_changes (user_id) WHERE state = 'Pending';
+
+CREATE TRIGGER user_email_changes_modified_column
+BEFORE UPDATE ON user_email_changes FOR EACH ROW
+EXECUTE PROCEDURE update_modified_column();
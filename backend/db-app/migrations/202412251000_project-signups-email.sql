@@ -0,0 +1,2 @@
+-- Signup rate limits look up recent signups by email
+CREATE INDEX project_signups_email_idx ON project_signups (email, updated_at);
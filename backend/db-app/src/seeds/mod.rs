@@ -8,6 +8,8 @@ pub mod s030_rewards;
 pub mod s035_reward_assets;
 pub mod s040_pledges;
 pub mod s045_pledge_items;
+pub mod s050_project_milestones;
+pub mod s055_project_signups;
 
 pub async fn seed_all(db: &PgPool) -> Result<(), DbError> {
     s010_users::seed(db).await?;
@@ -17,5 +19,7 @@ pub async fn seed_all(db: &PgPool) -> Result<(), DbError> {
     s035_reward_assets::seed(db).await?;
     s040_pledges::seed(db).await?;
     s045_pledge_items::seed(db).await?;
+    s050_project_milestones::seed(db).await?;
+    s055_project_signups::seed(db).await?;
     Ok(())
 }
@@ -30,6 +30,9 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             user_type: UserType::Admin,
             user_status: UserStatus::Active,
             email_confirmed: true,
+            show_backed_count: false,
+            avatar_url: None,
+            banner_url: None,
         },
         UserEntity {
             id: Uuid::from_str("45013993-2a1a-4ee5-8dbd-b4b63d9af34f").unwrap(),
@@ -45,6 +48,9 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             user_type: UserType::User,
             user_status: UserStatus::Active,
             email_confirmed: true,
+            show_backed_count: false,
+            avatar_url: None,
+            banner_url: None,
         },
         UserEntity {
             id: Uuid::from_str("276168ed-9228-4d6b-aec2-ed53bb7c1901").unwrap(),
@@ -60,6 +66,9 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             user_type: UserType::User,
             user_status: UserStatus::Blocked,
             email_confirmed: true,
+            show_backed_count: false,
+            avatar_url: None,
+            banner_url: None,
         },
         UserEntity {
             id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
@@ -75,6 +84,9 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             user_type: UserType::User,
             user_status: UserStatus::Active,
             email_confirmed: false,
+            show_backed_count: false,
+            avatar_url: None,
+            banner_url: None,
         },
     ];
 
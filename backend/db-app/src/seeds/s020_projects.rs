@@ -17,6 +17,7 @@ pub struct ProjectEntityDbProps {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub blurb: String,
     pub contract_address: String,
@@ -45,6 +46,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("14bfe82a-1003-446b-b6bb-20a176e848e0").unwrap(),
             user_id: Uuid::from_str("45013993-2a1a-4ee5-8dbd-b4b63d9af34f").unwrap(),
             name: "Game Box".into(),
+            slug: "game-box".into(),
             description: "A revolutionary new handheld gaming device capable of emulating all previous systems as well as exciting new titles.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Revolutionizing handheld gaming!".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -73,6 +75,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("a3a2b1c4-a1ee-42d5-a729-bb6ff6fdfdfe").unwrap(),
             user_id: Uuid::from_str("45013993-2a1a-4ee5-8dbd-b4b63d9af34f").unwrap(),
             name: "Super Jetpack".into(),
+            slug: "super-jetpack".into(),
             description: "An actual real jetpack that really works.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Get your flight on!".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -96,6 +99,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("d13b990d-172e-4a01-aeea-43f6ef505a7c").unwrap(),
             user_id: Uuid::from_str("45013993-2a1a-4ee5-8dbd-b4b63d9af34f").unwrap(),
             name: "Donate to the needy".into(),
+            slug: "donate-to-the-needy".into(),
             description: "Donate a dollar or two to help those in need. Whatever you can provide helps!\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Collecting donations for the hungry and un-housed.".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -119,6 +123,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("bbe3791a-96af-4de6-8796-5d2f5c8ca144").unwrap(),
             user_id: Uuid::from_str("276168ed-9228-4d6b-aec2-ed53bb7c1901").unwrap(),
             name: "Dogpedia".into(),
+            slug: "dogpedia".into(),
             description: "Finally, a website that works for pets. Tired of explaining everything to your fluffy buddy? Dogpedia will change your life.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Wikipedia for Dogs".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -142,6 +147,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("f20c8b1a-16cc-4f2b-bb85-00b6fa00f4e9").unwrap(),
             user_id: Uuid::from_str("276168ed-9228-4d6b-aec2-ed53bb7c1901").unwrap(),
             name: "Some Thing".into(),
+            slug: "some-thing".into(),
             description: "A cool thing I guess.".into(),
             blurb: "Low Effort Post".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -165,6 +171,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("0c9d3f3e-8027-4582-b573-99b2d6f87ebc").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             name: "Good Boot".into(),
+            slug: "good-boot".into(),
             description: "We make custom designer boots for men, women, and children! Back now to get your own one of a kind footwear. Lifetime guarantee!\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Single run designer boots".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -188,6 +195,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("a9b3146a-1bb7-49fc-b4be-c25e103d899c").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             name: "Modern Classic Album".into(),
+            slug: "modern-classic-album".into(),
             description: "We're a new band from Saskatchewan making avant garde premium grass-fed steampunk rock for humans. Please give us money for our eponymous album.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Echoes of Yesterday: A Modern Classic Rock Revival".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -211,6 +219,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("3e42e273-546d-4989-a97c-f6eb173e8450").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             name: "Vintage Player".into(),
+            slug: "vintage-player".into(),
             description: "Rediscover Vinyl - Dive into the warm, rich sounds of vinyl with our elegantly designed, vintage-inspired record player. Perfect for audiophiles and casual listeners alike, this turntable is a bridge between classic aesthetics and contemporary functionality.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Revive the Classics: Vintage-Inspired Record Player with Modern Tech".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -234,6 +243,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("9e8f0c6f-1edf-4d68-a096-7a2bb4625c98").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             name: "Repair Shop".into(),
+            slug: "repair-shop".into(),
             description: "Join Our Mission - At Fix-It-Right, we believe in the power of repair to reduce waste and empower communities. Our shop specializes in fixing everything from electronics to furniture, breathing new life into items and reducing the need for replacements.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Fix-It-Right: Empowering Community Through Repair".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -257,6 +267,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("fa4d21c2-16a3-46cf-8162-98f4a82b59aa").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             name: "Hi-Fi Sphere".into(),
+            slug: "hi-fi-sphere".into(),
             description: "Elevate Your Listening - Introducing SoundSphere, the next generation of hi-fi music players. Designed as a sleek, elegant sphere, it offers unparalleled sound quality and a stunning visual addition to any space.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "SoundSphere: Revolutionize Your Music Experience".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
@@ -280,6 +291,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             id: Uuid::from_str("00df0e23-22af-4959-874c-aca385b54eed").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             name: "Community Event".into(),
+            slug: "community-event".into(),
             description: "Join us in bringing the annual Community Festival to life! This event is all about celebrating our local culture, arts, and the spirit of togetherness. Our festival features live music, art installations, local cuisine, and activities for all ages, creating a vibrant tapestry of our community's talents and interests.\n\nThis project is for demonstration purposes only, backing is disabled.".into(),
             blurb: "Celebrate Together: Community Festival Fundraiser".into(),
             contract_address: "0x0000000000000000000000000000000000000000".into(),
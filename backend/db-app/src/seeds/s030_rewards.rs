@@ -19,6 +19,7 @@ pub struct RewardEntityDbProps {
     pub price: BigDecimal,
     pub backer_limit: i32,
     pub backer_count: i32,
+    pub visible: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -36,6 +37,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             price: 50000000000000000i128.into(),
             backer_limit: 1000,
             backer_count: 0,
+            visible: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         },
@@ -48,6 +50,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             price: 100000000000000000i128.into(),
             backer_limit: 200,
             backer_count: 0,
+            visible: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         },
@@ -60,6 +63,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             price: 100000000000000000i128.into(),
             backer_limit: 400,
             backer_count: 97,
+            visible: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         },
@@ -72,6 +76,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             price: 200000000000000000i128.into(),
             backer_limit: 100,
             backer_count: 0,
+            visible: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         },
@@ -84,6 +89,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             price: 100000000000000000i128.into(),
             backer_limit: 2,
             backer_count: 1,
+            visible: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         },
@@ -96,6 +102,7 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             price: 150000000000000000i128.into(),
             backer_limit: 1000,
             backer_count: 0,
+            visible: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         },
@@ -2,7 +2,10 @@ use std::str::FromStr;
 
 use chrono::{Duration, Utc};
 use lib_api::db::db_error::DbError;
-use lib_types::{entity::pledge_entity::PledgeEntity, shared::project::BlockchainStatus};
+use lib_types::{
+    entity::pledge_entity::PledgeEntity,
+    shared::project::{BlockchainStatus, PaymentCurrency},
+};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -17,8 +20,15 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             project_id: Uuid::from_str("14bfe82a-1003-446b-b6bb-20a176e848e0").unwrap(),
             user_id: Uuid::from_str("276168ed-9228-4d6b-aec2-ed53bb7c1901").unwrap(),
             comment: "".into(),
+            shipping_address: None,
+            extra_amount: 0.into(),
+            total_amount: 200000000000000000i128.into(),
+            paid_currency: PaymentCurrency::Ethereum,
             blockchain_status: BlockchainStatus::None,
             transaction_hash: None,
+            terms: None,
+            eth_address: None,
+            refundable: false,
             created_at: Utc::now() - Duration::days(20),
             updated_at: Utc::now(),
         },
@@ -27,8 +37,15 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             project_id: Uuid::from_str("14bfe82a-1003-446b-b6bb-20a176e848e0").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             comment: "123 Fake Street, Nowhere CA".into(),
+            shipping_address: None,
+            extra_amount: 0.into(),
+            total_amount: 50000000000000000i128.into(),
+            paid_currency: PaymentCurrency::Ethereum,
             blockchain_status: BlockchainStatus::None,
             transaction_hash: None,
+            terms: None,
+            eth_address: None,
+            refundable: false,
             created_at: Utc::now() - Duration::days(18),
             updated_at: Utc::now(),
         },
@@ -37,10 +54,17 @@ pub async fn seed(db: &PgPool) -> Result<(), DbError> {
             project_id: Uuid::from_str("3e42e273-546d-4989-a97c-f6eb173e8450").unwrap(),
             user_id: Uuid::from_str("00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd").unwrap(),
             comment: "My Address".into(),
+            shipping_address: None,
+            extra_amount: 0.into(),
+            total_amount: 100000000000000000i128.into(),
+            paid_currency: PaymentCurrency::Ethereum,
             blockchain_status: BlockchainStatus::Success,
             transaction_hash: Some(
                 "0x123454292f1680730fe8803949c8ddf9fbe8256da1ff86bc9b304b35a3f00000".into(),
             ),
+            terms: None,
+            eth_address: None,
+            refundable: false,
             created_at: Utc::now() - Duration::days(16),
             updated_at: Utc::now(),
         },
@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use chrono::{Duration, Utc};
+use lib_api::db::db_error::DbError;
+use lib_types::entity::project_milestone_entity::{MilestoneState, ProjectMilestoneEntity};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::util::bulk_insert;
+
+pub async fn seed(db: &PgPool) -> Result<(), DbError> {
+    let table = "project_milestones";
+
+    let data = vec![
+        // Voting ended without any votes
+        ProjectMilestoneEntity {
+            id: Uuid::from_str("6f4c1d2e-8b3a-4e5f-9c7d-2a1b0e9f8d76").unwrap(),
+            project_id: Uuid::from_str("3e42e273-546d-4989-a97c-f6eb173e8450").unwrap(),
+            title: "Factory tooling".to_string(),
+            description: "Molds for the production run".to_string(),
+            target_date: (Utc::now() + Duration::days(30)).timestamp(),
+            release_percent: 30,
+            state: MilestoneState::Voting,
+            voting_starts_at: Some(Utc::now() - Duration::days(8)),
+            voting_ends_at: Some(Utc::now() - Duration::days(1)),
+            approve_weight: 0.into(),
+            reject_weight: 0.into(),
+            vote_count: 0,
+            resolved_at: None,
+            created_at: Utc::now() - Duration::days(10),
+            updated_at: Utc::now() - Duration::days(8),
+        },
+    ];
+
+    Ok(bulk_insert(&db, table, &data).await?)
+}
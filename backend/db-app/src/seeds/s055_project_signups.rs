@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use chrono::{Duration, Utc};
+use lib_api::db::db_error::DbError;
+use lib_types::entity::project_signup_entity::ProjectSignupEntity;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::util::bulk_insert;
+
+pub async fn seed(db: &PgPool) -> Result<(), DbError> {
+    let table = "project_signups";
+
+    let project_id = Uuid::from_str("d13b990d-172e-4a01-aeea-43f6ef505a7c").unwrap();
+    let data = vec![
+        ProjectSignupEntity {
+            id: Uuid::from_str("5b2e8c41-7d3f-4a96-b0e5-1c9f2a6d8e37").unwrap(),
+            project_id,
+            email: "fan1@example.com".to_string(),
+            confirmed_at: Some(Utc::now() - Duration::days(2)),
+            notified_at: None,
+            created_at: Utc::now() - Duration::days(3),
+            updated_at: Utc::now() - Duration::days(2),
+        },
+        // Not confirmed yet
+        ProjectSignupEntity {
+            id: Uuid::from_str("a47d0f92-3c61-4e8b-9d25-6f1b8e0c4a73").unwrap(),
+            project_id,
+            email: "fan2@example.com".to_string(),
+            confirmed_at: None,
+            notified_at: None,
+            created_at: Utc::now() - Duration::days(1),
+            updated_at: Utc::now() - Duration::days(1),
+        },
+    ];
+
+    bulk_insert(db, table, &data).await
+}
@@ -7,7 +7,7 @@ use uuid::Uuid;
 
 use crate::error::api_error::ApiError;
 
-use super::types::{ConfirmClaims, JwtClaims, UserToken};
+use super::types::{ConfirmClaims, EmailChangeClaims, JwtClaims, PreviewClaims, UserToken};
 
 pub fn generate_admin_jwt(private_key: &str) -> Result<UserToken, ApiError> {
     generate_jwt(
@@ -51,6 +51,28 @@ pub fn generate_jwt(
     Ok(token_details)
 }
 
+pub fn generate_preview_token(
+    token_id: Uuid,
+    project_id: Uuid,
+    // Expiry unix timestamp
+    exp: i64,
+    secret: &str,
+) -> Result<String, ApiError> {
+    let key = EncodingKey::from_base64_secret(secret)
+        .map_err(|_| ApiError::internal_error().message("Auth misconfiguration"))?;
+
+    let claims = PreviewClaims {
+        sub: token_id.to_string(),
+        project_id: project_id.to_string(),
+        exp,
+    };
+
+    let token = jsonwebtoken::encode(&Header::default(), &claims, &key)
+        .map_err(|_| ApiError::internal_error().message("Failed to encode preview token"))?;
+
+    Ok(token)
+}
+
 pub fn generate_confirm_token(
     user_id: Uuid,
     // TTL in minutes
@@ -69,3 +91,26 @@ pub fn generate_confirm_token(
 
     Ok(token)
 }
+
+pub fn generate_email_change_token(
+    change_id: Uuid,
+    // "confirm" for the new address, "revert" for the old address
+    action: &str,
+    // Expiry unix timestamp
+    exp: i64,
+    secret: &str,
+) -> Result<String, ApiError> {
+    let key = EncodingKey::from_base64_secret(secret)
+        .map_err(|_| ApiError::internal_error().message("Auth misconfiguration"))?;
+
+    let claims = EmailChangeClaims {
+        sub: change_id.to_string(),
+        action: action.to_string(),
+        exp,
+    };
+
+    let token = jsonwebtoken::encode(&Header::default(), &claims, &key)
+        .map_err(|_| ApiError::internal_error().message("Failed to encode email change token"))?;
+
+    Ok(token)
+}
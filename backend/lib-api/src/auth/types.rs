@@ -22,3 +22,19 @@ pub struct ConfirmClaims {
     pub sub: String,
     pub exp: i64,
 }
+
+// `sub` is the preview token ID, so it can be revoked
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreviewClaims {
+    pub sub: String,
+    pub project_id: String,
+    pub exp: i64,
+}
+
+// `sub` is the email change ID. `action` keeps confirm and revert tokens apart
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailChangeClaims {
+    pub sub: String,
+    pub action: String,
+    pub exp: i64,
+}
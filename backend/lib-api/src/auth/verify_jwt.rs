@@ -6,7 +6,7 @@ use uuid::Uuid;
 
 use crate::error::api_error::ApiError;
 
-use super::types::{ConfirmClaims, JwtClaims, UserToken};
+use super::types::{ConfirmClaims, EmailChangeClaims, JwtClaims, PreviewClaims, UserToken};
 
 fn unauthorized() -> ApiError {
     return ApiError::unauthorized().code(ApiErrorCode::InvalidAuth);
@@ -32,6 +32,25 @@ pub fn verify_jwt(secret: &str, token: &str) -> Result<UserToken, ApiError> {
     })
 }
 
+// Returns the preview token ID and project ID
+pub fn verify_preview_token(secret: &str, token: &str) -> Result<(Uuid, Uuid), ApiError> {
+    let key = &DecodingKey::from_base64_secret(secret)
+        .map_err(|_| ApiError::internal_error().message("Auth misconfiguration"))?;
+    let decoded = jsonwebtoken::decode::<PreviewClaims>(token, key, &Validation::default())
+        .map_err(|_| invalid_preview())?;
+
+    let token_id = Uuid::parse_str(&decoded.claims.sub).map_err(|_| invalid_preview())?;
+    let project_id = Uuid::parse_str(&decoded.claims.project_id).map_err(|_| invalid_preview())?;
+
+    Ok((token_id, project_id))
+}
+
+pub fn invalid_preview() -> ApiError {
+    ApiError::forbidden()
+        .code(ApiErrorCode::InvalidPreview)
+        .message("Invalid or expired preview token")
+}
+
 pub fn verify_confirm_token(secret: &str, token: &str) -> Result<Uuid, ApiError> {
     let key = &DecodingKey::from_base64_secret(secret)
         .map_err(|_| ApiError::internal_error().message("Auth misconfiguration"))?;
@@ -49,3 +68,28 @@ pub fn verify_confirm_token(secret: &str, token: &str) -> Result<Uuid, ApiError>
 
     Ok(user_id)
 }
+
+// Returns the email change ID, if the token was issued for `action`
+pub fn verify_email_change_token(
+    secret: &str,
+    token: &str,
+    action: &str,
+) -> Result<Uuid, ApiError> {
+    let key = &DecodingKey::from_base64_secret(secret)
+        .map_err(|_| ApiError::internal_error().message("Auth misconfiguration"))?;
+    let decoded = jsonwebtoken::decode::<EmailChangeClaims>(token, key, &Validation::default())
+        .map_err(|e| {
+            let mut err = ApiError::bad_request();
+            if e.to_string() == "ExpiredSignature" {
+                err = err.code(ApiErrorCode::ConfirmExpired)
+            }
+            err.message(format!("Email change token decode error: {}", e))
+        })?;
+    if decoded.claims.action != action {
+        return Err(ApiError::bad_request().message("Invalid email change token"));
+    }
+
+    let change_id = Uuid::parse_str(&decoded.claims.sub).map_err(|_| unauthorized())?;
+
+    Ok(change_id)
+}
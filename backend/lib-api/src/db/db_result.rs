@@ -1,7 +1,11 @@
-pub fn list_result<T>(results: Vec<(T, i64)>) -> (Vec<T>, i64) {
-    let mut total = 0;
-    if let Some(item) = results.first() {
-        total = item.1
+// `count` is None if the total was not selected
+pub fn list_result<T>(
+    results: Vec<(T, Option<i64>)>,
+    include_total: bool,
+) -> (Vec<T>, Option<i64>) {
+    let mut total = None;
+    if include_total {
+        total = Some(results.first().and_then(|item| item.1).unwrap_or(0));
     }
     let items = results.into_iter().map(|t| t.0).collect();
     return (items, total);
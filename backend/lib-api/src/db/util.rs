@@ -1,11 +1,15 @@
 use std::fmt::Display;
 
+use alloy::hex;
+use serde::{Deserialize, Serialize};
 use sqlx::{Database, Encode, Postgres, QueryBuilder, Transaction, Type};
 use strum::{Display, EnumString};
 use uuid::Uuid;
 
 use crate::error::api_error::ApiError;
 
+use super::db_error::DbError;
+
 pub fn append_limit_offset<'a>(
     mut query: QueryBuilder<'a, Postgres>,
     from: i32,
@@ -19,6 +23,127 @@ pub fn append_limit_offset<'a>(
     query
 }
 
+/// Position in a keyset paginated list. Encoded as an opaque string for clients
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DbCursor {
+    // Sort column value of the last returned row
+    pub value: String,
+    pub id: Uuid,
+}
+
+impl DbCursor {
+    pub fn encode(&self) -> String {
+        hex::encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, DbError> {
+        let bytes = hex::decode(cursor).map_err(|_| DbError::Parse("cursor".into()))?;
+        serde_json::from_slice(&bytes).map_err(|_| DbError::Parse("cursor".into()))
+    }
+}
+
+/// Cursor pagination options for list queries
+#[derive(Debug)]
+pub struct CursorPage {
+    pub cursor: Option<DbCursor>,
+    pub limit: i32,
+    pub include_total: bool,
+}
+
+impl CursorPage {
+    /// Returns None if neither `cursor` or `limit` are set, in which case offset pagination is used
+    pub fn from_query(
+        cursor: Option<String>,
+        limit: Option<i32>,
+        include_total: Option<bool>,
+    ) -> Result<Option<Self>, DbError> {
+        if cursor.is_none() && limit.is_none() {
+            return Ok(None);
+        }
+        let cursor = if let Some(cursor) = cursor {
+            Some(DbCursor::decode(&cursor)?)
+        } else {
+            None
+        };
+        Ok(Some(Self {
+            cursor,
+            limit: limit.unwrap_or(20),
+            include_total: include_total.unwrap_or(false),
+        }))
+    }
+}
+
+/// Window count column for list totals. Skipped in cursor mode unless requested
+pub fn cursor_count_column(page: &Option<CursorPage>) -> &'static str {
+    match page {
+        Some(page) if !page.include_total => "NULL::bigint as count",
+        _ => "COUNT(*) OVER () as count",
+    }
+}
+
+/// True if the list total should be returned
+pub fn cursor_include_total(page: &Option<CursorPage>) -> bool {
+    page.as_ref().map(|page| page.include_total).unwrap_or(true)
+}
+
+/// Appends a keyset condition " (column, id) < ($x, $y)" for rows after the cursor,
+/// prepended with " AND" if it's not the first call
+/// `cast` is the Postgres type of `column`, since the cursor value is stored as a string
+pub fn append_cursor<'a>(
+    query: QueryBuilder<'a, Postgres>,
+    page: &CursorPage,
+    column: &str,
+    cast: &str,
+    id_column: &str,
+    descending: bool,
+    count: u32,
+) -> (QueryBuilder<'a, Postgres>, u32) {
+    if let Some(cursor) = &page.cursor {
+        let (mut q, count) = append_op(query, DbOp::And, count);
+        let op = if descending { "<" } else { ">" };
+        q.push(format!(" ({}, {}) {} (CAST(", column, id_column, op));
+        q.push_bind(cursor.value.clone());
+        q.push(format!(" AS {}), ", cast));
+        q.push_bind(cursor.id);
+        q.push(")");
+        return (q, count);
+    }
+    (query, count)
+}
+
+/// Appends " ORDER BY column, id LIMIT $x", fetching one extra row to detect the next page
+pub fn append_cursor_order_limit<'a>(
+    mut query: QueryBuilder<'a, Postgres>,
+    page: &CursorPage,
+    column: &str,
+    id_column: &str,
+    descending: bool,
+) -> QueryBuilder<'a, Postgres> {
+    let direction = if descending { "DESC" } else { "ASC" };
+    query.push(format!(
+        " ORDER BY {} {}, {} {}",
+        column, direction, id_column, direction
+    ));
+    query.push(" LIMIT ");
+    query.push_bind(page.limit + 1);
+    query
+}
+
+/// Trims the extra row fetched by `append_cursor_order_limit`, and returns the next cursor if
+/// there are more results
+pub fn cursor_result<T>(
+    mut results: Vec<T>,
+    page: &CursorPage,
+    cursor_of: impl Fn(&T) -> DbCursor,
+) -> (Vec<T>, Option<String>) {
+    if results.len() as i32 <= page.limit {
+        return (results, None);
+    }
+    results.truncate(page.limit as usize);
+    let next_cursor = results.last().map(|last| cursor_of(last).encode());
+    (results, next_cursor)
+}
+
 /// Appends a " field LIKE %$x%" binding to the query builder,
 /// prepended with " AND" if it's not the first call
 /// Returns the input `count + 1` if a parameter was bound
@@ -40,6 +165,27 @@ pub fn append_like<
     (query, count)
 }
 
+/// Converts a user search string into a Postgres tsquery, with prefix matching on each word
+/// Non alphanumeric characters are stripped, so the result is safe to pass to `to_tsquery`
+/// Returns None if no searchable words remain
+pub fn to_prefix_tsquery(search: &str) -> Option<String> {
+    let terms: Vec<String> = search
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .map(|word| format!("{}:*", word.to_lowercase()))
+        .collect();
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" & "))
+    }
+}
+
 // Commits or rolls back an SQLx transaction based on success or failure of
 // an external APi call
 pub async fn commit_or_rollback<T>(
@@ -81,6 +81,14 @@ impl ApiError {
         }
     }
 
+    pub fn too_many_requests() -> ApiError {
+        Self {
+            code: ApiErrorCode::RateLimited,
+            message: "Too many requests".to_string(),
+            status: StatusCode::TOO_MANY_REQUESTS,
+        }
+    }
+
     // builder
     pub fn code(mut self, code: ApiErrorCode) -> Self {
         self.code = code;
@@ -45,6 +45,10 @@ pub struct Config {
     #[clap(long, env = "CONFIRM_SHARED_SECRET", value_parser = NonEmptyStringValueParser::new())]
     pub confirm_shared_secret: String,
 
+    /// Secret used to encrypt backer shipping addresses
+    #[clap(long, env = "SHIPPING_ENCRYPTION_KEY", value_parser = NonEmptyStringValueParser::new())]
+    pub shipping_encryption_key: String,
+
     /// API key for sending email via SendGrid
     #[clap(long, env = "SENDGRID_API_KEY", value_parser = NonEmptyStringValueParser::new())]
     pub sendgrid_api_key: String,
@@ -0,0 +1,53 @@
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use alloy::hex;
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use crate::error::api_error::ApiError;
+
+const NONCE_LEN: usize = 12;
+const KDF_INFO: &[u8] = b"crowdtrust-encryption-v1";
+
+// Derives a 256 bit key from an arbitrary length secret
+fn derive_key(secret: &str) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    Hkdf::<Sha256>::new(None, secret.as_bytes())
+        .expand(KDF_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// Encrypts a string with AES-256-GCM, using a random nonce
+/// Returns the hex encoded nonce and authenticated ciphertext
+pub fn encrypt_string(secret: &str, plaintext: &str) -> Result<String, ApiError> {
+    let cipher = Aes256Gcm::new(&derive_key(secret).into());
+    let nonce: [u8; NONCE_LEN] = rand::random();
+
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|_| ApiError::internal_error().message("Failed to encrypt value"))?;
+
+    let mut result = nonce.to_vec();
+    result.extend(ciphertext);
+    Ok(hex::encode(result))
+}
+
+/// Decrypts a string created by `encrypt_string`. Fails if the ciphertext was modified
+pub fn decrypt_string(secret: &str, encrypted: &str) -> Result<String, ApiError> {
+    let decrypt_error = || ApiError::internal_error().message("Failed to decrypt value");
+    let cipher = Aes256Gcm::new(&derive_key(secret).into());
+    let bytes = hex::decode(encrypted).map_err(|_| decrypt_error())?;
+    if bytes.len() <= NONCE_LEN {
+        return Err(decrypt_error());
+    }
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| decrypt_error())?;
+
+    String::from_utf8(plaintext).map_err(|_| decrypt_error())
+}
@@ -1,5 +1,6 @@
 pub mod config;
 pub mod conversion;
+pub mod encryption;
 pub mod json_extractor;
 pub mod log;
 pub mod log_format;
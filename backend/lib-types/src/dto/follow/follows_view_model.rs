@@ -0,0 +1,17 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::entity::notification_entity::FollowsEntity;
+
+#[derive(Serialize)]
+pub struct FollowsViewModel {
+    pub project_ids: Vec<Uuid>,
+    pub creator_ids: Vec<Uuid>,
+}
+
+pub fn to_api_response(entity: FollowsEntity) -> FollowsViewModel {
+    FollowsViewModel {
+        project_ids: entity.project_ids,
+        creator_ids: entity.creator_ids,
+    }
+}
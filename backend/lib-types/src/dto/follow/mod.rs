@@ -0,0 +1 @@
+pub mod follows_view_model;
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::notification_view_model::NotificationViewModel;
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ListNotificationsQuery {
+    // Only lists unread notifications
+    pub unread: Option<bool>,
+    #[serde(default = "default_from")]
+    #[validate(range(min = 1))]
+    pub from: i32,
+    #[serde(default = "default_to")]
+    #[validate(range(min = 1))]
+    pub to: i32,
+    // Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+    #[validate(length(max = 1000))]
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i32>,
+    // The total is always returned for offset pagination, and only on request with a cursor
+    pub include_total: Option<bool>,
+}
+
+fn default_from() -> i32 {
+    1
+}
+
+fn default_to() -> i32 {
+    20
+}
+
+#[derive(Serialize)]
+pub struct ListNotificationsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    pub unread_count: i64,
+    pub results: Vec<NotificationViewModel>,
+}
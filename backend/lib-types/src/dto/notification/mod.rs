@@ -0,0 +1,3 @@
+pub mod list_notifications_dto;
+pub mod notification_preferences_dto;
+pub mod notification_view_model;
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::entity::notification_entity::{NotificationKind, NotificationPreferenceEntity};
+
+#[derive(Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationPreferenceDto {
+    pub kind: NotificationKind,
+    // Listed in `GET /notifications`
+    pub in_app: bool,
+    pub email: bool,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateNotificationPreferencesDto {
+    // Kinds that aren't listed are unchanged
+    #[validate(length(min = 1, max = 20))]
+    pub preferences: Vec<NotificationPreferenceDto>,
+}
+
+#[derive(Serialize)]
+pub struct NotificationPreferencesResponse {
+    pub preferences: Vec<NotificationPreferenceDto>,
+}
+
+pub fn to_api_response(entity: NotificationPreferenceEntity) -> NotificationPreferenceDto {
+    NotificationPreferenceDto {
+        kind: entity.kind,
+        in_app: entity.in_app,
+        email: entity.email,
+    }
+}
@@ -0,0 +1,32 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::notification_entity::{NotificationData, NotificationEntity, NotificationKind},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct NotificationViewModel {
+    pub id: Uuid,
+    pub kind: NotificationKind,
+    pub project_id: Option<Uuid>,
+    pub data: NotificationData,
+    pub read: bool,
+    pub read_at: Option<JsDate>,
+    pub created_at: JsDate,
+}
+
+pub fn to_api_response(entity: NotificationEntity) -> NotificationViewModel {
+    NotificationViewModel {
+        id: entity.id,
+        kind: entity.kind,
+        project_id: entity.project_id,
+        data: entity.data,
+        read: entity.read_at.is_some(),
+        read_at: entity.read_at.map(|timestamp| JsDate { timestamp }),
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+// Backer confirmation of receipt. `received = false` opens a dispute
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfirmPledgeItemDto {
+    pub received: bool,
+}
@@ -23,6 +23,13 @@ pub struct ListPledgesQuery {
     #[serde(default = "default_to")]
     #[validate(range(min = 1))]
     pub to: i32,
+    // Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+    #[validate(length(max = 1000))]
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i32>,
+    // The total is always returned for offset pagination, and only on request with a cursor
+    pub include_total: Option<bool>,
     #[validate(regex(path = "*REGEX_UUID"))]
     pub user_id: Option<String>,
     #[validate(regex(path = "*REGEX_UUID"))]
@@ -41,6 +48,9 @@ fn default_to() -> i32 {
 
 #[derive(Serialize)]
 pub struct ListPledgesResponse {
-    pub total: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
     pub results: Vec<GetPledgeViewModel>,
 }
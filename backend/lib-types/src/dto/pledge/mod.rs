@@ -1,3 +1,6 @@
+pub mod confirm_pledge_item_dto;
 pub mod list_pledges_dto;
 pub mod pledge_view_model;
+pub mod shipping_address_dto;
 pub mod update_pledge_dto;
+pub mod update_pledge_items_dto;
@@ -5,17 +5,29 @@ use uuid::Uuid;
 use crate::{
     dto::project::get_project_dto::serialize_big,
     entity::pledge_entity::{PledgeEntity, PledgeEntityRelations, PledgeItemEntity},
-    shared::project::{BlockchainStatus, PaymentCurrency},
+    shared::{
+        pledge::FulfillmentStatus,
+        project::{BlockchainStatus, PaymentCurrency},
+        terms::PledgeTerms,
+    },
 };
 
+use super::shipping_address_dto::ShippingAddressDto;
+
 #[derive(Serialize)]
 pub struct PledgeViewModel {
     pub id: Uuid,
     pub project_id: Uuid,
     pub user_id: Uuid,
     pub comment: String,
+    pub extra_amount: String,
+    pub total_amount: String,
+    pub paid_currency: PaymentCurrency,
     pub blockchain_status: BlockchainStatus,
     pub transaction_hash: Option<String>,
+    pub eth_address: Option<String>,
+    // The project ended unfunded, and the pledge can be refunded
+    pub refundable: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -26,9 +38,17 @@ pub struct GetPledgeViewModel {
     pub project_id: Uuid,
     pub user_id: Uuid,
     pub comment: String,
+    pub shipping_address: Option<ShippingAddressDto>,
+    pub extra_amount: String,
+    pub total_amount: String,
+    pub paid_currency: PaymentCurrency,
     pub pledge_items: Vec<PledgeItemViewModel>,
     pub blockchain_status: BlockchainStatus,
     pub transaction_hash: Option<String>,
+    pub eth_address: Option<String>,
+    // Project and reward terms when the pledge was made
+    pub terms: Option<PledgeTerms>,
+    pub refundable: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -38,9 +58,16 @@ pub struct PledgeItemViewModel {
     pub id: Uuid,
     pub pledge_id: Uuid,
     pub reward_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub price_tier_id: Option<Uuid>,
     pub quantity: i32,
     pub paid_price: String,
     pub paid_currency: PaymentCurrency,
+    pub paid_shipping: String,
+    pub fulfillment_status: FulfillmentStatus,
+    pub tracking_number: Option<String>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -51,32 +78,52 @@ pub fn to_api_response(entity: PledgeEntity) -> PledgeViewModel {
         project_id: entity.project_id,
         user_id: entity.user_id,
         comment: entity.comment,
+        extra_amount: serialize_big(&entity.extra_amount),
+        total_amount: serialize_big(&entity.total_amount),
+        paid_currency: entity.paid_currency,
         blockchain_status: entity.blockchain_status,
         transaction_hash: entity.transaction_hash,
+        eth_address: entity.eth_address,
+        refundable: entity.refundable,
         created_at: entity.created_at,
         updated_at: entity.updated_at,
     };
 }
 
-fn pledge_item_api_response(entity: PledgeItemEntity) -> PledgeItemViewModel {
+pub fn pledge_item_api_response(entity: PledgeItemEntity) -> PledgeItemViewModel {
     PledgeItemViewModel {
         id: entity.id,
         pledge_id: entity.pledge_id,
         reward_id: entity.reward_id,
+        variant_id: entity.variant_id,
+        price_tier_id: entity.price_tier_id,
         quantity: entity.quantity,
         paid_price: serialize_big(&entity.paid_price),
         paid_currency: entity.paid_currency,
+        paid_shipping: serialize_big(&entity.paid_shipping),
+        fulfillment_status: entity.fulfillment_status,
+        tracking_number: entity.tracking_number,
+        shipped_at: entity.shipped_at,
+        delivered_at: entity.delivered_at,
         created_at: entity.created_at,
         updated_at: entity.updated_at,
     }
 }
 
-pub fn to_api_response_relations(entity: PledgeEntityRelations) -> GetPledgeViewModel {
+// `shipping_address` is decrypted by the caller
+pub fn to_api_response_relations(
+    entity: PledgeEntityRelations,
+    shipping_address: Option<ShippingAddressDto>,
+) -> GetPledgeViewModel {
     return GetPledgeViewModel {
         id: entity.id,
         project_id: entity.project_id,
         user_id: entity.user_id,
         comment: entity.comment,
+        shipping_address,
+        extra_amount: serialize_big(&entity.extra_amount),
+        total_amount: serialize_big(&entity.total_amount),
+        paid_currency: entity.paid_currency,
         pledge_items: entity
             .pledge_items
             .into_iter()
@@ -84,6 +131,9 @@ pub fn to_api_response_relations(entity: PledgeEntityRelations) -> GetPledgeView
             .collect(),
         blockchain_status: entity.blockchain_status,
         transaction_hash: entity.transaction_hash,
+        eth_address: entity.eth_address,
+        terms: entity.terms,
+        refundable: entity.refundable,
         created_at: entity.created_at,
         updated_at: entity.updated_at,
     };
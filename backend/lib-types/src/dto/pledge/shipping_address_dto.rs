@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::type_util::REGEX_COUNTRY_CODE;
+
+// Stored encrypted on the pledge, only visible to the backer and project owner
+#[derive(Debug, Clone, Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ShippingAddressDto {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(min = 1, max = 200))]
+    pub line1: String,
+    #[validate(length(min = 0, max = 200))]
+    pub line2: Option<String>,
+    #[validate(length(min = 1, max = 100))]
+    pub city: String,
+    #[validate(length(min = 0, max = 100))]
+    pub state: Option<String>,
+    #[validate(length(min = 1, max = 20))]
+    pub postal_code: String,
+    #[validate(regex(path = "*REGEX_COUNTRY_CODE"))]
+    pub country: String,
+    #[validate(length(min = 0, max = 30))]
+    pub phone: Option<String>,
+}
@@ -1,14 +1,24 @@
 use serde::Deserialize;
 use validator::Validate;
 
-use crate::{shared::project::BlockchainStatus, type_util::REGEX_ETH_TX};
+use crate::{
+    shared::project::BlockchainStatus,
+    type_util::{REGEX_ETH_ADDRESS, REGEX_ETH_TX},
+};
+
+use super::shipping_address_dto::ShippingAddressDto;
 
 #[derive(Deserialize, Validate)]
 #[serde(deny_unknown_fields)]
 pub struct UpdatePledgeDto {
     #[validate(length(min = 0, max = 200))]
     pub comment: Option<String>,
+    #[validate(nested)]
+    pub shipping_address: Option<ShippingAddressDto>,
     pub blockchain_status: Option<BlockchainStatus>,
     #[validate(regex(path = "*REGEX_ETH_TX"))]
     pub transaction_hash: Option<String>,
+    // Wallet the transaction was sent from. Any of the user's linked wallets
+    #[validate(regex(path = "*REGEX_ETH_ADDRESS"))]
+    pub eth_address: Option<String>,
 }
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{shared::pledge::FulfillmentStatus, type_util::REGEX_UUID};
+
+use super::pledge_view_model::PledgeItemViewModel;
+
+#[derive(Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdatePledgeItemDto {
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub id: String,
+    pub fulfillment_status: FulfillmentStatus,
+    #[validate(length(min = 1, max = 100))]
+    pub tracking_number: Option<String>,
+}
+
+// Bulk fulfillment update, used by project creators
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdatePledgeItemsDto {
+    #[validate(length(min = 1, max = 500), nested)]
+    pub items: Vec<UpdatePledgeItemDto>,
+}
+
+#[derive(Serialize)]
+pub struct UpdatePledgeItemsResponse {
+    pub results: Vec<PledgeItemViewModel>,
+}
@@ -3,12 +3,19 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::type_util::REGEX_UUID;
+use crate::{
+    dto::pledge::shipping_address_dto::ShippingAddressDto,
+    shared::project::PaymentCurrency,
+    type_util::{REGEX_POSITIVE_NUMBER, REGEX_UUID},
+};
 
-#[derive(Deserialize, Validate)]
+#[derive(Deserialize, Serialize, Validate)]
 pub struct PledgeItemDto {
     #[validate(regex(path = "*REGEX_UUID"))]
     pub reward_id: String,
+    // Required if the reward has variants
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub variant_id: Option<String>,
     #[validate(range(min = 1, max = 1000))]
     pub quantity: i32,
 }
@@ -16,8 +23,16 @@ pub struct PledgeItemDto {
 #[derive(Deserialize, Validate)]
 #[serde(deny_unknown_fields)]
 pub struct BackProjectDto {
-    #[validate(nested)]
+    // May be empty if `extra_amount` is set
+    #[serde(default)]
+    #[validate(length(max = 50), nested)]
     pub rewards: Vec<PledgeItemDto>,
+    // Pledged on top of rewards, or alone for a reward-less pledge
+    #[validate(length(min = 0, max = 100), regex(path = "*REGEX_POSITIVE_NUMBER"))]
+    pub extra_amount: Option<String>,
+    // Required if any reward needs shipping
+    #[validate(nested)]
+    pub shipping_address: Option<ShippingAddressDto>,
 }
 
 #[derive(Serialize)]
@@ -26,6 +41,9 @@ pub struct BackProjectResponse {
     pub project_id: Uuid,
     pub user_id: Uuid,
     pub comment: String,
+    // Amount to send on-chain
+    pub total_amount: String,
+    pub paid_currency: PaymentCurrency,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
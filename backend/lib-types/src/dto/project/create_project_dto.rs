@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::{shared::project::ProjectCategory, type_util::REGEX_POSITIVE_NUMBER};
+use crate::{
+    shared::project::{FundingModel, ProjectCategory},
+    type_util::REGEX_POSITIVE_NUMBER,
+};
 
 #[derive(Deserialize, Validate)]
 #[serde(deny_unknown_fields)]
@@ -16,6 +19,8 @@ pub struct CreateProjectDto {
     pub category: ProjectCategory,
     #[validate(length(min = 0, max = 100), regex(path = "*REGEX_POSITIVE_NUMBER"))]
     pub funding_goal: String,
+    // Defaults to AllOrNothing
+    pub funding_model: Option<FundingModel>,
     pub start_time: i64,
     #[validate(range(min = 86400, max = 7776000))]
     pub duration: i64,
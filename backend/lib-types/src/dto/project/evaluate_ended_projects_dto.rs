@@ -0,0 +1,9 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+// Projects completed by an evaluation run
+#[derive(Serialize)]
+pub struct EvaluateEndedProjectsResponse {
+    pub funded: Vec<Uuid>,
+    pub unfunded: Vec<Uuid>,
+}
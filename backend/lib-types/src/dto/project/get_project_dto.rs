@@ -1,27 +1,43 @@
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
     dto::reward::reward_view_model::{self, RewardViewModel},
     entity::project_entity::ProjectEntityRelations,
-    shared::project::{BlockchainStatus, PaymentCurrency, ProjectCategory, ProjectStatus},
+    shared::project::{
+        BlockchainStatus, FundingModel, FundingOutcome, PaymentCurrency, ProjectCategory,
+        ProjectStatus,
+    },
 };
 
 use super::project_view_model::ProjectAssetViewModelRelation;
 
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct GetProjectQuery {
+    // Grants access to an unpublished project
+    #[validate(length(max = 2000))]
+    pub preview_token: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct GetProjectResponse {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub blurb: String,
     pub contract_address: String,
     pub payment_address: String,
     pub category: ProjectCategory,
     pub funding_goal: String,
+    pub funding_model: FundingModel,
+    pub funding_outcome: Option<FundingOutcome>,
+    pub outcome_at: Option<DateTime<Utc>>,
     pub start_time: i64,
     pub duration: i64,
     pub total_pledged: String,
@@ -47,12 +63,16 @@ pub fn to_api_response(user_entity: ProjectEntityRelations) -> GetProjectRespons
         id: user_entity.id,
         user_id: user_entity.user_id,
         name: user_entity.name,
+        slug: user_entity.slug,
         description: user_entity.description,
         blurb: user_entity.blurb,
         contract_address: user_entity.contract_address,
         payment_address: user_entity.payment_address,
         category: user_entity.category,
         funding_goal: serialize_big(&user_entity.funding_goal),
+        funding_model: user_entity.funding_model,
+        funding_outcome: user_entity.funding_outcome,
+        outcome_at: user_entity.outcome_at,
         start_time: user_entity.start_time,
         duration: user_entity.duration,
         total_pledged: serialize_big(&user_entity.total_pledged),
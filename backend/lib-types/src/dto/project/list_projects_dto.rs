@@ -17,6 +17,14 @@ pub enum ProjectSortColumn {
     FundingGoal,
     CreatedAt,
     UpdatedAt,
+    // Search rank, only applies when `q` is set
+    Relevance,
+    // `start_time + duration`, sort ascending for projects ending soonest
+    EndTime,
+    PercentFunded,
+    BackerCount,
+    // Pledges per day over the last `trending_days`
+    Trending,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -28,10 +36,32 @@ pub struct ListProjectsQuery {
     #[serde(default = "default_to")]
     #[validate(range(min = 1))]
     pub to: i32,
+    // Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+    #[validate(length(max = 1000))]
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i32>,
+    // The total is always returned for offset pagination, and only on request with a cursor
+    pub include_total: Option<bool>,
     pub statuses: Option<Vec<ProjectStatus>>,
     pub categories: Option<Vec<ProjectCategory>>,
     #[validate(regex(path = "*REGEX_UUID"))]
     pub user_id: Option<String>,
+    // Keyword search over name, blurb and description. Every word is prefix matched, and
+    // punctuation is ignored. A search without any words is rejected
+    #[validate(length(min = 1, max = 100))]
+    pub q: Option<String>,
+    // True for projects that reached their funding goal, false for projects that haven't
+    pub funded: Option<bool>,
+    // True for active projects between their start and end time
+    pub live: Option<bool>,
+    #[validate(range(min = 0))]
+    pub min_percent_funded: Option<i32>,
+    #[validate(range(min = 0))]
+    pub max_percent_funded: Option<i32>,
+    // Window for the trending sort, defaults to 7 days
+    #[validate(range(min = 1, max = 90))]
+    pub trending_days: Option<i32>,
     pub column: Option<ProjectSortColumn>,
     pub direction: Option<SortDirection>,
 }
@@ -46,6 +76,9 @@ fn default_to() -> i32 {
 
 #[derive(Serialize)]
 pub struct ListProjectsResponse {
-    pub total: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
     pub results: Vec<ProjectViewModel>,
 }
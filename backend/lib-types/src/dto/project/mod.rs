@@ -1,5 +1,6 @@
 pub mod back_project_dto;
 pub mod create_project_dto;
+pub mod evaluate_ended_projects_dto;
 pub mod get_project_dto;
 pub mod list_projects_dto;
 pub mod project_view_model;
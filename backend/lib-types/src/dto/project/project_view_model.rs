@@ -6,7 +6,10 @@ use crate::{
     entity::project_entity::ProjectEntity,
     shared::{
         asset::AssetContentType,
-        project::{BlockchainStatus, PaymentCurrency, ProjectCategory, ProjectStatus},
+        project::{
+            BlockchainStatus, FundingModel, FundingOutcome, PaymentCurrency, ProjectCategory,
+            ProjectStatus,
+        },
     },
 };
 
@@ -17,12 +20,16 @@ pub struct ProjectViewModel {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub blurb: String,
     pub contract_address: String,
     pub payment_address: String,
     pub category: ProjectCategory,
     pub funding_goal: String,
+    pub funding_model: FundingModel,
+    pub funding_outcome: Option<FundingOutcome>,
+    pub outcome_at: Option<DateTime<Utc>>,
     pub start_time: i64,
     pub duration: i64,
     pub total_pledged: String,
@@ -34,6 +41,10 @@ pub struct ProjectViewModel {
     pub assets: Vec<ProjectAssetViewModelRelation>,
     pub assets_order: Vec<String>,
     pub rewards_order: Vec<String>,
+    pub search_snippet: Option<String>,
+    pub percent_funded: Option<String>,
+    pub trending_score: Option<String>,
+    pub creator_reputation: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -43,12 +54,16 @@ pub fn to_api_response(user_entity: ProjectEntity) -> ProjectViewModel {
         id: user_entity.id,
         user_id: user_entity.user_id,
         name: user_entity.name,
+        slug: user_entity.slug,
         description: user_entity.description,
         blurb: user_entity.blurb,
         contract_address: user_entity.contract_address,
         payment_address: user_entity.payment_address,
         category: user_entity.category,
         funding_goal: serialize_big(&user_entity.funding_goal),
+        funding_model: user_entity.funding_model,
+        funding_outcome: user_entity.funding_outcome,
+        outcome_at: user_entity.outcome_at,
         start_time: user_entity.start_time,
         duration: user_entity.duration,
         total_pledged: serialize_big(&user_entity.total_pledged),
@@ -64,6 +79,10 @@ pub fn to_api_response(user_entity: ProjectEntity) -> ProjectViewModel {
             .collect(),
         assets_order: user_entity.assets_order,
         rewards_order: user_entity.rewards_order,
+        search_snippet: user_entity.search_snippet,
+        percent_funded: user_entity.percent_funded.map(|p| p.round(2).to_string()),
+        trending_score: user_entity.trending_score.map(|t| t.round(2).to_string()),
+        creator_reputation: user_entity.creator_reputation,
         created_at: user_entity.created_at,
         updated_at: user_entity.updated_at,
     };
@@ -2,7 +2,7 @@ use serde::Deserialize;
 use validator::Validate;
 
 use crate::{
-    shared::project::{BlockchainStatus, ProjectCategory, ProjectStatus},
+    shared::project::{BlockchainStatus, FundingModel, ProjectCategory, ProjectStatus},
     type_util::{REGEX_ETH_ADDRESS, REGEX_ETH_TX, REGEX_POSITIVE_NUMBER},
 };
 
@@ -21,6 +21,7 @@ pub struct UpdateProjectDto {
     pub status: Option<ProjectStatus>,
     #[validate(length(min = 0, max = 100), regex(path = "*REGEX_POSITIVE_NUMBER"))]
     pub funding_goal: Option<String>,
+    pub funding_model: Option<FundingModel>,
     pub start_time: Option<i64>,
     #[validate(range(min = 86400, max = 7776000))]
     pub duration: Option<i64>,
@@ -33,8 +33,18 @@ pub struct ListProjectAssetsQuery {
     #[serde(default = "default_to")]
     #[validate(range(min = 1))]
     pub to: i32,
+    // Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+    #[validate(length(max = 1000))]
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i32>,
+    // The total is always returned for offset pagination, and only on request with a cursor
+    pub include_total: Option<bool>,
     pub column: Option<ProjectAssetSortColumn>,
     pub direction: Option<SortDirection>,
+    // Grants access to uploaded assets of an unpublished project, requires `project_id`
+    #[validate(length(max = 2000))]
+    pub preview_token: Option<String>,
 }
 
 impl ListProjectAssetsQuery {
@@ -47,7 +57,11 @@ impl ListProjectAssetsQuery {
             column: None,
             from: default_from(),
             to: default_to(),
+            cursor: None,
+            limit: None,
+            include_total: None,
             direction: None,
+            preview_token: None,
         }
     }
     pub fn project_id(mut self, project_id: String) -> ListProjectAssetsQuery {
@@ -66,7 +80,10 @@ fn default_to() -> i32 {
 
 #[derive(Serialize)]
 pub struct ListProjectAssetsResponse {
-    pub total: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
     pub total_usage: u64,
     pub results: Vec<ProjectAssetViewModel>,
 }
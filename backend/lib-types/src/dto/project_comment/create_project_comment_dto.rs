@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::type_util::REGEX_UUID;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectCommentDto {
+    #[validate(length(min = 1, max = 2000))]
+    pub body: String,
+    // Replies to another comment on the project
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub parent_id: Option<String>,
+}
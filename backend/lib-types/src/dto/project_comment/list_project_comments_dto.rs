@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{dto::sort_direction::SortDirection, type_util::REGEX_UUID};
+
+use super::project_comment_view_model::ProjectCommentViewModel;
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ListProjectCommentsQuery {
+    // Lists replies to a comment. Top level comments are listed if not set
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub parent_id: Option<String>,
+    // Only lists reported comments, at any level. Moderators only
+    pub reported: Option<bool>,
+    #[serde(default = "default_from")]
+    #[validate(range(min = 1))]
+    pub from: i32,
+    #[serde(default = "default_to")]
+    #[validate(range(min = 1))]
+    pub to: i32,
+    // Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+    #[validate(length(max = 1000))]
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i32>,
+    // The total is always returned for offset pagination, and only on request with a cursor
+    pub include_total: Option<bool>,
+    // Sorted by creation date. Defaults to newest first, and oldest first for replies
+    pub direction: Option<SortDirection>,
+}
+
+fn default_from() -> i32 {
+    1
+}
+
+fn default_to() -> i32 {
+    20
+}
+
+#[derive(Serialize)]
+pub struct ListProjectCommentsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    pub results: Vec<ProjectCommentViewModel>,
+}
@@ -0,0 +1,5 @@
+pub mod create_project_comment_dto;
+pub mod list_project_comments_dto;
+pub mod project_comment_view_model;
+pub mod report_project_comment_dto;
+pub mod update_project_comment_dto;
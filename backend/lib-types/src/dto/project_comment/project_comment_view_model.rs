@@ -0,0 +1,57 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::project_comment_entity::{CommentBadge, ProjectCommentEntity},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectCommentViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    // None if the comment was deleted, or hidden by a moderator
+    pub body: Option<String>,
+    pub badge: Option<CommentBadge>,
+    pub reply_count: i64,
+    pub deleted: bool,
+    pub hidden: bool,
+    // Only returned to moderators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_count: Option<i32>,
+    pub edited_at: Option<JsDate>,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+// Moderators can read hidden comments
+pub fn to_api_response(entity: ProjectCommentEntity, is_moderator: bool) -> ProjectCommentViewModel {
+    let deleted = entity.deleted_at.is_some();
+    let hidden = entity.hidden_at.is_some();
+    let readable = !deleted && (!hidden || is_moderator);
+    ProjectCommentViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        user_id: entity.user_id,
+        parent_id: entity.parent_id,
+        body: if readable { Some(entity.body) } else { None },
+        badge: entity.badge,
+        reply_count: entity.reply_count,
+        deleted,
+        hidden,
+        report_count: if is_moderator {
+            Some(entity.report_count)
+        } else {
+            None
+        },
+        edited_at: entity.edited_at.map(|timestamp| JsDate { timestamp }),
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReportProjectCommentDto {
+    #[validate(length(min = 3, max = 500))]
+    pub reason: String,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateProjectCommentDto {
+    #[validate(length(min = 1, max = 2000))]
+    pub body: String,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::{entity::project_member_entity::ProjectMemberRole, type_util::REGEX_ETH_ADDRESS};
+
+// Exactly one of `email` or `eth_address` is required
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectInvitationDto {
+    #[validate(email)]
+    pub email: Option<String>,
+    #[validate(regex(path = "*REGEX_ETH_ADDRESS"))]
+    pub eth_address: Option<String>,
+    pub role: ProjectMemberRole,
+}
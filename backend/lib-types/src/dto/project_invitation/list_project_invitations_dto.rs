@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+use super::project_invitation_view_model::ProjectInvitationViewModel;
+
+#[derive(Serialize)]
+pub struct ListProjectInvitationsResponse {
+    pub results: Vec<ProjectInvitationViewModel>,
+}
@@ -0,0 +1,3 @@
+pub mod create_project_invitation_dto;
+pub mod list_project_invitations_dto;
+pub mod project_invitation_view_model;
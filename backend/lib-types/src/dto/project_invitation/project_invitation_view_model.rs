@@ -0,0 +1,40 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::project_member_entity::{
+        ProjectInvitationEntity, ProjectInvitationState, ProjectMemberRole,
+    },
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectInvitationViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub email: Option<String>,
+    pub eth_address: Option<String>,
+    pub role: ProjectMemberRole,
+    pub state: ProjectInvitationState,
+    pub invited_by: Uuid,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+pub fn to_api_response(entity: ProjectInvitationEntity) -> ProjectInvitationViewModel {
+    ProjectInvitationViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        email: entity.email,
+        eth_address: entity.eth_address,
+        role: entity.role,
+        state: entity.state,
+        invited_by: entity.invited_by,
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
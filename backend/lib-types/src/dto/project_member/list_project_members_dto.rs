@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+use super::project_member_view_model::ProjectMemberViewModel;
+
+#[derive(Serialize)]
+pub struct ListProjectMembersResponse {
+    pub results: Vec<ProjectMemberViewModel>,
+}
@@ -0,0 +1,3 @@
+pub mod list_project_members_dto;
+pub mod project_member_view_model;
+pub mod update_project_member_dto;
@@ -0,0 +1,32 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::project_member_entity::{ProjectMemberEntity, ProjectMemberRole},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectMemberViewModel {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub role: ProjectMemberRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+pub fn to_api_response(entity: ProjectMemberEntity) -> ProjectMemberViewModel {
+    ProjectMemberViewModel {
+        project_id: entity.project_id,
+        user_id: entity.user_id,
+        role: entity.role,
+        invited_by: entity.invited_by,
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::entity::project_member_entity::ProjectMemberRole;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateProjectMemberDto {
+    pub role: ProjectMemberRole,
+}
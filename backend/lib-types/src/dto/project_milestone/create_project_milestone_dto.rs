@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectMilestoneDto {
+    #[validate(length(min = 3, max = 100))]
+    pub title: String,
+    #[validate(length(max = 5000))]
+    pub description: String,
+    // Epoch seconds
+    pub target_date: i64,
+    // Percent of funds released when approved. Milestones total up to 100
+    #[validate(range(min = 1, max = 100))]
+    pub release_percent: i32,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use super::project_milestone_view_model::ProjectMilestoneViewModel;
+
+#[derive(Serialize)]
+pub struct ListProjectMilestonesResponse {
+    // Percent of funds unlocked by approved milestones
+    pub released_percent: i32,
+    // Amount of `total_pledged` unlocked by approved milestones
+    pub released_amount: String,
+    pub results: Vec<ProjectMilestoneViewModel>,
+}
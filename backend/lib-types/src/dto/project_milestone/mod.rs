@@ -0,0 +1,6 @@
+pub mod create_project_milestone_dto;
+pub mod list_project_milestones_dto;
+pub mod project_milestone_view_model;
+pub mod resolve_project_milestones_dto;
+pub mod update_project_milestone_dto;
+pub mod vote_project_milestone_dto;
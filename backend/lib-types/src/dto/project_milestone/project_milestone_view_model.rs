@@ -0,0 +1,53 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    dto::project::get_project_dto::serialize_big,
+    entity::project_milestone_entity::{MilestoneState, ProjectMilestoneEntity},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectMilestoneViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub target_date: i64,
+    pub release_percent: i32,
+    pub state: MilestoneState,
+    pub voting_starts_at: Option<JsDate>,
+    pub voting_ends_at: Option<JsDate>,
+    pub approve_weight: String,
+    pub reject_weight: String,
+    pub vote_count: i32,
+    pub resolved_at: Option<JsDate>,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+pub fn to_api_response(entity: ProjectMilestoneEntity) -> ProjectMilestoneViewModel {
+    ProjectMilestoneViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        title: entity.title,
+        description: entity.description,
+        target_date: entity.target_date,
+        release_percent: entity.release_percent,
+        state: entity.state,
+        voting_starts_at: entity
+            .voting_starts_at
+            .map(|timestamp| JsDate { timestamp }),
+        voting_ends_at: entity.voting_ends_at.map(|timestamp| JsDate { timestamp }),
+        approve_weight: serialize_big(&entity.approve_weight),
+        reject_weight: serialize_big(&entity.reject_weight),
+        vote_count: entity.vote_count,
+        resolved_at: entity.resolved_at.map(|timestamp| JsDate { timestamp }),
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
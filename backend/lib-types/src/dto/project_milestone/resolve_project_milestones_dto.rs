@@ -0,0 +1,9 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+// Milestones resolved after their voting window ended
+#[derive(Serialize)]
+pub struct ResolveProjectMilestonesResponse {
+    pub approved: Vec<Uuid>,
+    pub rejected: Vec<Uuid>,
+}
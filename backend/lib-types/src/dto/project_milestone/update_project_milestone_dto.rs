@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// Only Pending milestones can be updated
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateProjectMilestoneDto {
+    #[validate(length(min = 3, max = 100))]
+    pub title: Option<String>,
+    #[validate(length(max = 5000))]
+    pub description: Option<String>,
+    pub target_date: Option<i64>,
+    #[validate(range(min = 1, max = 100))]
+    pub release_percent: Option<i32>,
+}
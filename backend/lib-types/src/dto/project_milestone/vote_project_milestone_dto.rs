@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// The backer signs "Approve CrowdTrust milestone {id} with {eth_address}",
+// or "Reject CrowdTrust milestone {id} with {eth_address}"
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct VoteProjectMilestoneDto {
+    pub approve: bool,
+    #[validate(length(min = 50, max = 300))]
+    pub eth_address_signature: String,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::project_preview_token_view_model::ProjectPreviewTokenViewModel;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectPreviewTokenDto {
+    // TTL in minutes, up to 30 days
+    #[validate(range(min = 1, max = 43200))]
+    pub ttl: i64,
+}
+
+// The token is only returned on creation
+#[derive(Serialize)]
+pub struct CreateProjectPreviewTokenResponse {
+    pub token: String,
+    pub preview_token: ProjectPreviewTokenViewModel,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+use super::project_preview_token_view_model::ProjectPreviewTokenViewModel;
+
+#[derive(Serialize)]
+pub struct ListProjectPreviewTokensResponse {
+    pub results: Vec<ProjectPreviewTokenViewModel>,
+}
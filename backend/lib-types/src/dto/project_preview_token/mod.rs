@@ -0,0 +1,3 @@
+pub mod create_project_preview_token_dto;
+pub mod list_project_preview_tokens_dto;
+pub mod project_preview_token_view_model;
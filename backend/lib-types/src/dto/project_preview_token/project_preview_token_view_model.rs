@@ -0,0 +1,39 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::project_preview_token_entity::ProjectPreviewTokenEntity, shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectPreviewTokenViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub expires_at: JsDate,
+    pub revoked_at: Option<JsDate>,
+    pub view_count: i32,
+    pub last_viewed_at: Option<JsDate>,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+pub fn to_api_response(entity: ProjectPreviewTokenEntity) -> ProjectPreviewTokenViewModel {
+    ProjectPreviewTokenViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        user_id: entity.user_id,
+        expires_at: JsDate {
+            timestamp: entity.expires_at,
+        },
+        revoked_at: entity.revoked_at.map(|timestamp| JsDate { timestamp }),
+        view_count: entity.view_count,
+        last_viewed_at: entity.last_viewed_at.map(|timestamp| JsDate { timestamp }),
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::type_util::REGEX_UUID;
+
+use super::project_revision_view_model::ProjectRevisionViewModel;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ListProjectRevisionsQuery {
+    // Only list the revisions of a reward
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub reward_id: Option<String>,
+    #[serde(default = "default_from")]
+    #[validate(range(min = 1))]
+    pub from: i32,
+    #[serde(default = "default_to")]
+    #[validate(range(min = 1))]
+    pub to: i32,
+}
+
+fn default_from() -> i32 {
+    1
+}
+
+fn default_to() -> i32 {
+    25
+}
+
+#[derive(Serialize)]
+pub struct ListProjectRevisionsResponse {
+    pub total: i64,
+    pub results: Vec<ProjectRevisionViewModel>,
+}
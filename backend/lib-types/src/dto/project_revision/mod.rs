@@ -0,0 +1,2 @@
+pub mod list_project_revisions_dto;
+pub mod project_revision_view_model;
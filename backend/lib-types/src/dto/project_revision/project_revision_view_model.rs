@@ -0,0 +1,32 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::project_revision_entity::{ProjectRevisionEntity, RevisionAction, RevisionChange},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectRevisionViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub reward_id: Option<Uuid>,
+    pub user_id: Uuid,
+    pub action: RevisionAction,
+    pub changes: Vec<RevisionChange>,
+    pub created_at: JsDate,
+}
+
+pub fn to_api_response(entity: ProjectRevisionEntity) -> ProjectRevisionViewModel {
+    ProjectRevisionViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        reward_id: entity.reward_id,
+        user_id: entity.user_id,
+        action: entity.action,
+        changes: entity.changes,
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectSignupDto {
+    #[validate(email)]
+    pub email: String,
+}
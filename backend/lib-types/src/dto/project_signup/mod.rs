@@ -0,0 +1,3 @@
+pub mod confirm_project_signup_dto;
+pub mod create_project_signup_dto;
+pub mod project_signup_counts_view_model;
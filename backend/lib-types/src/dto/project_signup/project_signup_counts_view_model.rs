@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+use crate::entity::project_signup_entity::ProjectSignupCounts;
+
+#[derive(Serialize)]
+pub struct ProjectSignupCountsViewModel {
+    pub total: i64,
+    pub confirmed: i64,
+    pub notified: i64,
+}
+
+pub fn to_api_response(entity: ProjectSignupCounts) -> ProjectSignupCountsViewModel {
+    ProjectSignupCountsViewModel {
+        total: entity.total,
+        confirmed: entity.confirmed,
+        notified: entity.notified,
+    }
+}
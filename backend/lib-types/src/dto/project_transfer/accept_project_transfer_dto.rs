@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// The recipient signs "Accept CrowdTrust project transfer {id} with {eth_address}"
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AcceptProjectTransferDto {
+    #[validate(length(min = 50, max = 300))]
+    pub eth_address_signature: String,
+    // Sets the project payment address to the recipient's eth address
+    pub update_payment_address: Option<bool>,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::type_util::REGEX_ETH_ADDRESS;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectTransferDto {
+    // Eth address of the recipient account
+    #[validate(regex(path = "*REGEX_ETH_ADDRESS"))]
+    pub eth_address: String,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+use super::project_transfer_view_model::ProjectTransferViewModel;
+
+#[derive(Serialize)]
+pub struct ListProjectTransfersResponse {
+    pub results: Vec<ProjectTransferViewModel>,
+}
@@ -0,0 +1,4 @@
+pub mod accept_project_transfer_dto;
+pub mod create_project_transfer_dto;
+pub mod list_project_transfers_dto;
+pub mod project_transfer_view_model;
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::project_transfer_entity::{ProjectTransferEntity, ProjectTransferState},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct ProjectTransferViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub state: ProjectTransferState,
+    pub expires_at: JsDate,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+pub fn to_api_response(entity: ProjectTransferEntity) -> ProjectTransferViewModel {
+    ProjectTransferViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        from_user_id: entity.from_user_id,
+        to_user_id: entity.to_user_id,
+        state: entity.state,
+        expires_at: JsDate {
+            timestamp: entity.expires_at,
+        },
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
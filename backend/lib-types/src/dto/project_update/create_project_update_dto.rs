@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::entity::project_update_entity::ProjectUpdateState;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectUpdateDto {
+    #[validate(length(min = 3, max = 100))]
+    pub title: String,
+    // Markdown
+    #[validate(length(min = 1, max = 20000))]
+    pub body: String,
+    pub backers_only: Option<bool>,
+    // Defaults to Draft
+    pub state: Option<ProjectUpdateState>,
+}
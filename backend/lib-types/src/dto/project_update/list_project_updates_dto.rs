@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::entity::project_update_entity::ProjectUpdateState;
+
+use super::project_update_view_model::ProjectUpdateViewModel;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ListProjectUpdatesQuery {
+    // Drafts are only listed for the project owner
+    pub state: Option<ProjectUpdateState>,
+    #[serde(default = "default_from")]
+    #[validate(range(min = 1))]
+    pub from: i32,
+    #[serde(default = "default_to")]
+    #[validate(range(min = 1))]
+    pub to: i32,
+}
+
+fn default_from() -> i32 {
+    1
+}
+
+fn default_to() -> i32 {
+    25
+}
+
+#[derive(Serialize)]
+pub struct ListProjectUpdatesResponse {
+    pub total: i64,
+    pub results: Vec<ProjectUpdateViewModel>,
+}
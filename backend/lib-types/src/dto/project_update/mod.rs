@@ -0,0 +1,4 @@
+pub mod create_project_update_dto;
+pub mod list_project_updates_dto;
+pub mod project_update_view_model;
+pub mod update_project_update_dto;
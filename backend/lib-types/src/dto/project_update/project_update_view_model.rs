@@ -0,0 +1,64 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    dto::project_update_asset::project_update_asset_viewmodel::{
+        to_api_response as asset_api_response, ProjectUpdateAssetViewModel,
+    },
+    entity::project_update_entity::{ProjectUpdateEntity, ProjectUpdateState},
+    shared::{asset::AssetState, js_date::JsDate},
+};
+
+#[derive(Serialize)]
+pub struct ProjectUpdateViewModel {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    // None if the update is locked
+    pub body: Option<String>,
+    pub state: ProjectUpdateState,
+    pub backers_only: bool,
+    // Backer-only update, and the requester isn't a backer
+    pub locked: bool,
+    pub published_at: Option<JsDate>,
+    pub assets: Vec<ProjectUpdateAssetViewModel>,
+    pub created_at: JsDate,
+    pub updated_at: JsDate,
+}
+
+// Locked updates omit the body and attachments. Pending uploads are only returned to the owner
+pub fn to_api_response(
+    entity: ProjectUpdateEntity,
+    locked: bool,
+    is_owner: bool,
+) -> ProjectUpdateViewModel {
+    let assets = if locked {
+        vec![]
+    } else {
+        entity
+            .assets
+            .into_iter()
+            .filter(|asset| is_owner || asset.state == AssetState::Uploaded)
+            .map(asset_api_response)
+            .collect()
+    };
+    ProjectUpdateViewModel {
+        id: entity.id,
+        project_id: entity.project_id,
+        user_id: entity.user_id,
+        title: entity.title,
+        body: if locked { None } else { Some(entity.body) },
+        state: entity.state,
+        backers_only: entity.backers_only,
+        locked,
+        published_at: entity.published_at.map(|timestamp| JsDate { timestamp }),
+        assets,
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+        updated_at: JsDate {
+            timestamp: entity.updated_at,
+        },
+    }
+}
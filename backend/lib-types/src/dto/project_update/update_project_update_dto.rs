@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::entity::project_update_entity::ProjectUpdateState;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateProjectUpdateDto {
+    #[validate(length(min = 3, max = 100))]
+    pub title: Option<String>,
+    #[validate(length(min = 1, max = 20000))]
+    pub body: Option<String>,
+    pub backers_only: Option<bool>,
+    pub state: Option<ProjectUpdateState>,
+}
@@ -0,0 +1,3 @@
+pub mod create_project_update_asset_dto;
+pub mod project_update_asset_viewmodel;
+pub mod verify_project_update_asset_dto;
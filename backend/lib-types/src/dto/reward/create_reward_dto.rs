@@ -2,7 +2,44 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::type_util::REGEX_POSITIVE_NUMBER;
+use crate::type_util::{REGEX_POSITIVE_NUMBER, REGEX_SHIPPING_REGION, REGEX_UUID};
+
+#[derive(Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ShippingCostDto {
+    #[validate(regex(path = "*REGEX_SHIPPING_REGION"))]
+    pub region: String,
+    #[validate(length(min = 0, max = 100), regex(path = "*REGEX_POSITIVE_NUMBER"))]
+    pub cost: String,
+}
+
+#[derive(Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RewardVariantDto {
+    // ID of an existing variant, when updating
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+    #[validate(length(min = 0, max = 100), regex(path = "*REGEX_POSITIVE_NUMBER"))]
+    pub price: Option<String>,
+    #[validate(range(min = 1, max = 1000000000))]
+    pub backer_limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PriceTierDto {
+    // ID of an existing tier, when updating
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub id: Option<String>,
+    #[validate(length(min = 0, max = 100), regex(path = "*REGEX_POSITIVE_NUMBER"))]
+    pub price: String,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+    #[validate(range(min = 1, max = 1000000000))]
+    pub backer_limit: Option<i32>,
+}
 
 #[derive(Deserialize, Validate)]
 #[serde(deny_unknown_fields)]
@@ -16,6 +53,21 @@ pub struct CreateRewardDto {
     pub delivery_time: i64,
     #[validate(range(min = 1, max = 1000000000))]
     pub backer_limit: i64,
+    #[serde(default)]
+    pub shipping_required: bool,
+    #[serde(default)]
+    #[validate(length(max = 250), nested)]
+    pub shipping_costs: Vec<ShippingCostDto>,
+    #[serde(default)]
+    #[validate(length(max = 50), nested)]
+    pub variants: Vec<RewardVariantDto>,
+    // Applied in order, the first active tier overrides `price`
+    #[serde(default)]
+    #[validate(length(max = 10), nested)]
+    pub price_tiers: Vec<PriceTierDto>,
+    // Creates an add-on for the parent reward
+    #[validate(regex(path = "*REGEX_UUID"))]
+    pub parent_reward_id: Option<String>,
 }
 
 #[derive(Serialize)]
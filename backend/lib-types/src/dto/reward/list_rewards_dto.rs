@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use validator::Validate;
+
+use crate::dto::sort_direction::SortDirection;
+
+use super::reward_view_model::RewardViewModel;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum RewardSortColumn {
+    // Order set by the project owner in `rewards_order`
+    Order,
+    Price,
+    BackerCount,
+    CreatedAt,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ListRewardsQuery {
+    // Only applies to the project owner, other users only see visible rewards
+    pub visible: Option<bool>,
+    // Filter by rewards that are not sold out
+    pub available: Option<bool>,
+    // Filter by add-ons, or base rewards
+    pub addons: Option<bool>,
+    pub column: Option<RewardSortColumn>,
+    pub direction: Option<SortDirection>,
+}
+
+#[derive(Serialize)]
+pub struct ListRewardsResponse {
+    pub results: Vec<RewardViewModel>,
+}
@@ -1,3 +1,4 @@
 pub mod create_reward_dto;
+pub mod list_rewards_dto;
 pub mod reward_view_model;
 pub mod update_reward_dto;
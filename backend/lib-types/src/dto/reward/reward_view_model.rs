@@ -3,7 +3,8 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use crate::{
-    dto::project::get_project_dto::serialize_big, entity::reward_entity::RewardEntity,
+    dto::project::get_project_dto::serialize_big,
+    entity::reward_entity::{active_price_tier, PriceTier, RewardEntity},
     shared::asset::AssetContentType,
 };
 
@@ -16,11 +17,57 @@ pub struct RewardViewModel {
     pub price: String,
     pub backer_limit: i32,
     pub backer_count: i32,
+    pub visible: bool,
+    pub shipping_required: bool,
+    pub shipping_costs: Vec<ShippingCostViewModel>,
+    pub variants: Vec<RewardVariantViewModel>,
+    pub price_tiers: Vec<PriceTierViewModel>,
+    // Tier applied to a single unit pledged now, if any
+    pub active_price_tier: Option<PriceTierViewModel>,
+    pub parent_reward_id: Option<Uuid>,
     pub image: Option<RewardAssetViewModelRelation>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ShippingCostViewModel {
+    pub region: String,
+    pub cost: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RewardVariantViewModel {
+    pub id: Uuid,
+    pub name: String,
+    pub price: Option<String>,
+    pub backer_limit: i32,
+    pub backer_count: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PriceTierViewModel {
+    pub id: Uuid,
+    pub price: String,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+    pub backer_limit: Option<i32>,
+    pub backer_count: i32,
+    pub remaining: Option<i32>,
+}
+
+fn price_tier_view_model(tier: &PriceTier) -> PriceTierViewModel {
+    PriceTierViewModel {
+        id: tier.id,
+        price: serialize_big(&tier.price),
+        starts_at: tier.starts_at,
+        ends_at: tier.ends_at,
+        backer_limit: tier.backer_limit,
+        backer_count: tier.backer_count,
+        remaining: tier.remaining(),
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct RewardAssetViewModelRelation {
     pub id: Uuid,
@@ -30,6 +77,9 @@ pub struct RewardAssetViewModelRelation {
 }
 
 pub fn to_api_response(reward_entity: RewardEntity) -> RewardViewModel {
+    let active_price_tier =
+        active_price_tier(&reward_entity.price_tiers, Utc::now().timestamp(), 1)
+            .map(price_tier_view_model);
     return RewardViewModel {
         id: reward_entity.id,
         name: reward_entity.name,
@@ -38,6 +88,34 @@ pub fn to_api_response(reward_entity: RewardEntity) -> RewardViewModel {
         price: serialize_big(&reward_entity.price),
         backer_limit: reward_entity.backer_limit,
         backer_count: reward_entity.backer_count,
+        visible: reward_entity.visible,
+        shipping_required: reward_entity.shipping_required,
+        shipping_costs: reward_entity
+            .shipping_costs
+            .into_iter()
+            .map(|c| ShippingCostViewModel {
+                region: c.region,
+                cost: serialize_big(&c.cost),
+            })
+            .collect(),
+        variants: reward_entity
+            .variants
+            .into_iter()
+            .map(|v| RewardVariantViewModel {
+                id: v.id,
+                name: v.name,
+                price: v.price.map(|p| serialize_big(&p)),
+                backer_limit: v.backer_limit,
+                backer_count: v.backer_count,
+            })
+            .collect(),
+        price_tiers: reward_entity
+            .price_tiers
+            .iter()
+            .map(price_tier_view_model)
+            .collect(),
+        active_price_tier,
+        parent_reward_id: reward_entity.parent_reward_id,
         image: reward_entity.image.and_then(|image| {
             Some(RewardAssetViewModelRelation {
                 id: image.id,
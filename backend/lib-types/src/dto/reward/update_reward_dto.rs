@@ -3,6 +3,8 @@ use validator::Validate;
 
 use crate::type_util::REGEX_POSITIVE_NUMBER;
 
+use super::create_reward_dto::{PriceTierDto, RewardVariantDto, ShippingCostDto};
+
 #[derive(Deserialize, Validate)]
 #[serde(deny_unknown_fields)]
 pub struct UpdateRewardDto {
@@ -16,4 +18,11 @@ pub struct UpdateRewardDto {
     #[validate(range(min = 1, max = 1000000000))]
     pub backer_limit: Option<i64>,
     pub visible: Option<bool>,
+    pub shipping_required: Option<bool>,
+    #[validate(length(max = 250), nested)]
+    pub shipping_costs: Option<Vec<ShippingCostDto>>,
+    #[validate(length(max = 50), nested)]
+    pub variants: Option<Vec<RewardVariantDto>>,
+    #[validate(length(max = 10), nested)]
+    pub price_tiers: Option<Vec<PriceTierDto>>,
 }
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+// Number of users with a new reputation snapshot
+#[derive(Serialize)]
+pub struct ComputeUserReputationsResponse {
+    pub computed: u64,
+}
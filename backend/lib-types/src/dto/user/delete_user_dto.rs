@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DeleteUserDto {
+    // Required when users delete their own account
+    #[validate(length(min = 8, max = 50))]
+    pub password: Option<String>,
+}
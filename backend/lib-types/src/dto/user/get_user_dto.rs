@@ -3,10 +3,12 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use crate::{
-    entity::user_entity::UserEntity,
+    entity::{user_entity::UserEntity, user_reputation_entity::UserReputationEntity},
     shared::user::{UserStatus, UserType},
 };
 
+use super::user_reputation_view_model::{self, UserReputationViewModel};
+
 #[derive(Serialize)]
 pub struct GetUserResponse {
     pub id: Uuid,
@@ -14,7 +16,10 @@ pub struct GetUserResponse {
     pub description: String,
     pub link: String,
     pub location: String,
+    pub avatar_url: Option<String>,
+    pub banner_url: Option<String>,
     pub eth_address: String,
+    pub reputation: Option<UserReputationViewModel>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -25,39 +30,56 @@ pub struct GetUserPrivateResponse {
     pub description: String,
     pub link: String,
     pub location: String,
+    pub avatar_url: Option<String>,
+    pub banner_url: Option<String>,
     pub email: String,
     pub eth_address: String,
     pub user_type: UserType,
     pub user_status: UserStatus,
     pub email_confirmed: bool,
+    pub show_backed_count: bool,
+    pub reputation: Option<UserReputationViewModel>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-pub fn to_api_response(user_entity: UserEntity) -> GetUserResponse {
+pub fn to_api_response(
+    user_entity: UserEntity,
+    reputation: Option<UserReputationEntity>,
+) -> GetUserResponse {
     return GetUserResponse {
         id: user_entity.id,
         name: user_entity.name,
         description: user_entity.description,
         link: user_entity.link,
         location: user_entity.location,
+        avatar_url: user_entity.avatar_url,
+        banner_url: user_entity.banner_url,
         eth_address: user_entity.eth_address,
+        reputation: reputation.map(user_reputation_view_model::to_api_response),
         created_at: user_entity.created_at,
     };
 }
 
-pub fn to_api_response_private(user_entity: UserEntity) -> GetUserPrivateResponse {
+pub fn to_api_response_private(
+    user_entity: UserEntity,
+    reputation: Option<UserReputationEntity>,
+) -> GetUserPrivateResponse {
     return GetUserPrivateResponse {
         id: user_entity.id,
         name: user_entity.name,
         description: user_entity.description,
         link: user_entity.link,
         location: user_entity.location,
+        avatar_url: user_entity.avatar_url,
+        banner_url: user_entity.banner_url,
         email: user_entity.email,
         eth_address: user_entity.eth_address,
         user_type: user_entity.user_type,
         user_status: user_entity.user_status,
         email_confirmed: user_entity.email_confirmed,
+        show_backed_count: user_entity.show_backed_count,
+        reputation: reputation.map(user_reputation_view_model::to_api_response),
         created_at: user_entity.created_at,
         updated_at: user_entity.updated_at,
     };
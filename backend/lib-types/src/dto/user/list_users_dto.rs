@@ -14,6 +14,13 @@ pub struct ListUsersQuery {
     #[serde(default = "default_to")]
     #[validate(range(min = 1))]
     pub to: i32,
+    // Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+    #[validate(length(max = 1000))]
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i32>,
+    // The total is always returned for offset pagination, and only on request with a cursor
+    pub include_total: Option<bool>,
     #[serde(rename = "type")]
     pub user_type: Option<UserType>,
     pub status: Option<UserStatus>,
@@ -29,6 +36,9 @@ fn default_to() -> i32 {
 
 #[derive(Serialize)]
 pub struct ListUsersResponse {
-    pub total: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
     pub results: Vec<UserViewModel>,
 }
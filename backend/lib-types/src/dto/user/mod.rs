@@ -1,6 +1,10 @@
+pub mod compute_user_reputations_dto;
+pub mod delete_user_dto;
 pub mod get_user_dto;
 pub mod list_users_dto;
 pub mod register_user_dto;
 pub mod update_user_dto;
 pub mod user_exists_dto;
+pub mod user_profile_dto;
+pub mod user_reputation_view_model;
 pub mod user_view_model;
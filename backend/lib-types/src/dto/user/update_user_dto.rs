@@ -8,8 +8,6 @@ use crate::type_util::REGEX_ETH_ADDRESS;
 #[derive(Deserialize, Validate)]
 #[serde(deny_unknown_fields)]
 pub struct UpdateUserDto {
-    #[validate(email)]
-    pub email: Option<String>,
     #[validate(length(min = 2, max = 20))]
     pub name: Option<String>,
     #[validate(length(min = 0, max = 400))]
@@ -26,6 +24,10 @@ pub struct UpdateUserDto {
     pub eth_address: Option<String>,
     #[validate(length(min = 50, max = 300))]
     pub eth_address_signature: Option<String>,
+    // Unix timestamp included in the signed message, so the signature can't be replayed later
+    pub signature_expires_at: Option<i64>,
+    // Show the number of projects backed on the public profile
+    pub show_backed_count: Option<bool>,
     pub user_type: Option<UserType>,
     pub user_status: Option<UserStatus>,
 }
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    dto::project::project_view_model::{self, ProjectViewModel},
+    entity::{
+        project_entity::ProjectEntity, user_entity::UserEntity,
+        user_reputation_entity::UserReputationEntity,
+    },
+};
+
+use super::user_reputation_view_model::{self, UserReputationViewModel};
+
+// Public creator profile, excludes email and account status
+#[derive(Serialize)]
+pub struct UserProfileResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub link: String,
+    pub location: String,
+    pub avatar_url: Option<String>,
+    pub banner_url: Option<String>,
+    pub projects: Vec<ProjectViewModel>,
+    pub project_count: i64,
+    // Only set if the user opted in
+    pub backed_count: Option<i64>,
+    pub reputation: Option<UserReputationViewModel>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub fn to_api_response(
+    user_entity: UserEntity,
+    projects: Vec<ProjectEntity>,
+    project_count: i64,
+    backed_count: Option<i64>,
+    reputation: Option<UserReputationEntity>,
+) -> UserProfileResponse {
+    UserProfileResponse {
+        id: user_entity.id,
+        name: user_entity.name,
+        description: user_entity.description,
+        link: user_entity.link,
+        location: user_entity.location,
+        avatar_url: user_entity.avatar_url,
+        banner_url: user_entity.banner_url,
+        projects: projects
+            .into_iter()
+            .map(project_view_model::to_api_response)
+            .collect(),
+        project_count,
+        backed_count,
+        reputation: reputation.map(user_reputation_view_model::to_api_response),
+        created_at: user_entity.created_at,
+    }
+}
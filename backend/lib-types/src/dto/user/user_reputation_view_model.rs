@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::entity::user_reputation_entity::UserReputationEntity;
+
+#[derive(Serialize)]
+pub struct UserReputationViewModel {
+    // Null until there is enough delivery history to score
+    pub score: Option<i32>,
+    pub projects_completed: i32,
+    pub projects_funded: i32,
+    pub items_due: i32,
+    pub items_on_time: i32,
+    pub items_shipped: i32,
+    pub items_delivered: i32,
+    pub items_disputed: i32,
+    pub pledges_total: i32,
+    pub pledges_refunded: i32,
+    pub computed_at: DateTime<Utc>,
+}
+
+pub fn to_api_response(entity: UserReputationEntity) -> UserReputationViewModel {
+    UserReputationViewModel {
+        score: entity.score,
+        projects_completed: entity.stats.projects_completed,
+        projects_funded: entity.stats.projects_funded,
+        items_due: entity.stats.items_due,
+        items_on_time: entity.stats.items_on_time,
+        items_shipped: entity.stats.items_shipped,
+        items_delivered: entity.stats.items_delivered,
+        items_disputed: entity.stats.items_disputed,
+        pledges_total: entity.stats.pledges_total,
+        pledges_refunded: entity.stats.pledges_refunded,
+        computed_at: entity.computed_at,
+    }
+}
@@ -15,10 +15,13 @@ pub struct UserViewModel {
     pub link: String,
     pub email: String,
     pub location: String,
+    pub avatar_url: Option<String>,
+    pub banner_url: Option<String>,
     pub eth_address: String,
     pub user_type: UserType,
     pub user_status: UserStatus,
     pub email_confirmed: bool,
+    pub show_backed_count: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -31,10 +34,13 @@ pub fn to_api_response(user_entity: UserEntity) -> UserViewModel {
         description: user_entity.description,
         link: user_entity.link,
         location: user_entity.location,
+        avatar_url: user_entity.avatar_url,
+        banner_url: user_entity.banner_url,
         eth_address: user_entity.eth_address,
         user_type: user_entity.user_type,
         user_status: user_entity.user_status,
         email_confirmed: user_entity.email_confirmed,
+        show_backed_count: user_entity.show_backed_count,
         created_at: user_entity.created_at,
         updated_at: user_entity.updated_at,
     };
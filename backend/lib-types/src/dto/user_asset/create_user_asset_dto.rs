@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    entity::user_asset_entity::UserAssetEntity,
+    shared::asset::{AssetContentType, AssetState, UserAssetKind},
+};
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateUserAssetDto {
+    pub kind: UserAssetKind,
+    // Limited further by `UserAssetKind::max_size`
+    #[validate(range(min = 0, max = 5000000))]
+    pub content_size: i64,
+    pub content_type: AssetContentType,
+}
+
+#[derive(Serialize)]
+pub struct CreateUserAssetResponse {
+    pub id: Uuid,
+    pub signed_url: String,
+    pub kind: UserAssetKind,
+    pub size: i64,
+    pub content_type: String,
+    pub state: AssetState,
+    pub user_id: Uuid,
+    pub upload_expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub fn to_api_response(entity: UserAssetEntity, signed_url: String) -> CreateUserAssetResponse {
+    CreateUserAssetResponse {
+        id: entity.id,
+        signed_url,
+        kind: entity.kind,
+        size: entity.size,
+        content_type: entity.content_type.to_string(),
+        state: entity.state,
+        user_id: entity.user_id,
+        upload_expires_at: entity.upload_expires_at,
+        created_at: entity.created_at,
+        updated_at: entity.updated_at,
+    }
+}
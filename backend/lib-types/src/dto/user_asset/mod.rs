@@ -0,0 +1,2 @@
+pub mod create_user_asset_dto;
+pub mod verify_user_asset_dto;
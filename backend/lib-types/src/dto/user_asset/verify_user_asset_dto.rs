@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct VerifyUserAssetResponse {
+    pub verified: bool,
+    // Object key of the asset, set once it's the user's active avatar or banner
+    pub url: Option<String>,
+}
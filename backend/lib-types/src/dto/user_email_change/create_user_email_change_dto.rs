@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// Users confirm with their password, or by signing with their primary wallet
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateUserEmailChangeDto {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 8, max = 50))]
+    pub password: Option<String>,
+    #[validate(length(min = 50, max = 300))]
+    pub eth_address_signature: Option<String>,
+    // Unix timestamp included in the signed message, so the signature can't be replayed later
+    pub signature_expires_at: Option<i64>,
+}
@@ -0,0 +1,2 @@
+pub mod create_user_email_change_dto;
+pub mod user_email_change_view_model;
@@ -0,0 +1,36 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::user_email_change_entity::{EmailChangeState, UserEmailChangeEntity},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct UserEmailChangeViewModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub new_email: String,
+    pub state: EmailChangeState,
+    pub expires_at: JsDate,
+    pub confirmed_at: Option<JsDate>,
+    pub reverted_at: Option<JsDate>,
+    pub created_at: JsDate,
+}
+
+pub fn to_api_response(entity: UserEmailChangeEntity) -> UserEmailChangeViewModel {
+    UserEmailChangeViewModel {
+        id: entity.id,
+        user_id: entity.user_id,
+        new_email: entity.new_email,
+        state: entity.state,
+        expires_at: JsDate {
+            timestamp: entity.expires_at,
+        },
+        confirmed_at: entity.confirmed_at.map(|timestamp| JsDate { timestamp }),
+        reverted_at: entity.reverted_at.map(|timestamp| JsDate { timestamp }),
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+// Exports built by a job run
+#[derive(Serialize)]
+pub struct BuildUserExportsResponse {
+    pub built: Vec<Uuid>,
+}
@@ -0,0 +1,2 @@
+pub mod build_user_exports_dto;
+pub mod user_export_view_model;
@@ -0,0 +1,28 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    entity::user_export_entity::{UserExportEntity, UserExportState},
+    shared::js_date::JsDate,
+};
+
+#[derive(Serialize)]
+pub struct UserExportViewModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub state: UserExportState,
+    pub completed_at: Option<JsDate>,
+    pub created_at: JsDate,
+}
+
+pub fn to_api_response(entity: UserExportEntity) -> UserExportViewModel {
+    UserExportViewModel {
+        id: entity.id,
+        user_id: entity.user_id,
+        state: entity.state,
+        completed_at: entity.completed_at.map(|timestamp| JsDate { timestamp }),
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::type_util::REGEX_ETH_ADDRESS;
+
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct LinkUserWalletDto {
+    #[validate(regex(path = "*REGEX_ETH_ADDRESS"))]
+    pub eth_address: String,
+    #[validate(length(min = 50, max = 300))]
+    pub eth_address_signature: String,
+    // Make the new wallet the login wallet
+    pub primary: Option<bool>,
+}
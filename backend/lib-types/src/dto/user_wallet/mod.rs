@@ -0,0 +1,3 @@
+pub mod link_user_wallet_dto;
+pub mod user_wallet_signature_dto;
+pub mod user_wallet_view_model;
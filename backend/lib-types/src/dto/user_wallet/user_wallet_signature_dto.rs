@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// Signature for unlinking a wallet or making it primary
+#[derive(Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UserWalletSignatureDto {
+    #[validate(length(min = 50, max = 300))]
+    pub eth_address_signature: String,
+}
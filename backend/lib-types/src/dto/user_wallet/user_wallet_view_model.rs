@@ -0,0 +1,25 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{entity::user_wallet_entity::UserWalletEntity, shared::js_date::JsDate};
+
+#[derive(Serialize)]
+pub struct UserWalletViewModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub eth_address: String,
+    pub is_primary: bool,
+    pub created_at: JsDate,
+}
+
+pub fn to_api_response(entity: UserWalletEntity) -> UserWalletViewModel {
+    UserWalletViewModel {
+        id: entity.id,
+        user_id: entity.user_id,
+        eth_address: entity.eth_address,
+        is_primary: entity.is_primary,
+        created_at: JsDate {
+            timestamp: entity.created_at,
+        },
+    }
+}
@@ -1,6 +1,20 @@
+pub mod notification_entity;
 pub mod pledge_entity;
 pub mod project_asset_entity;
+pub mod project_comment_entity;
 pub mod project_entity;
+pub mod project_member_entity;
+pub mod project_milestone_entity;
+pub mod project_preview_token_entity;
+pub mod project_revision_entity;
+pub mod project_signup_entity;
+pub mod project_transfer_entity;
+pub mod project_update_entity;
 pub mod reward_asset_entity;
 pub mod reward_entity;
+pub mod user_asset_entity;
+pub mod user_email_change_entity;
 pub mod user_entity;
+pub mod user_export_entity;
+pub mod user_reputation_entity;
+pub mod user_wallet_entity;
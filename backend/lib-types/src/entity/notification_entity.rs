@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum NotificationKind {
+    // A followed project went live
+    ProjectLaunched,
+    // A followed or backed project posted an update
+    UpdatePosted,
+    // A followed or backed project reached its funding goal
+    GoalReached,
+    // A followed project ends in 48 hours
+    EndingSoon,
+    // A backed reward was shipped
+    RewardShipped,
+}
+
+pub const NOTIFICATION_KINDS: [NotificationKind; 5] = [
+    NotificationKind::ProjectLaunched,
+    NotificationKind::UpdatePosted,
+    NotificationKind::GoalReached,
+    NotificationKind::EndingSoon,
+    NotificationKind::RewardShipped,
+];
+
+// Event specific references
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct NotificationData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pledge_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotificationEntity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: NotificationKind,
+    pub project_id: Option<Uuid>,
+    pub data: NotificationData,
+    pub in_app: bool,
+    pub email: bool,
+    pub read_at: Option<DateTime<Utc>>,
+    pub emailed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotificationListResults {
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
+    pub unread_count: i64,
+    pub results: Vec<NotificationEntity>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationPreferenceEntity {
+    pub kind: NotificationKind,
+    pub in_app: bool,
+    pub email: bool,
+}
+
+impl NotificationPreferenceEntity {
+    // In-app notifications are on by default, emails are opt in
+    pub fn default_for(kind: NotificationKind) -> Self {
+        Self {
+            kind,
+            in_app: true,
+            email: false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FollowsEntity {
+    pub project_ids: Vec<Uuid>,
+    pub creator_ids: Vec<Uuid>,
+}
@@ -3,7 +3,11 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::shared::project::{BlockchainStatus, PaymentCurrency};
+use crate::shared::{
+    pledge::FulfillmentStatus,
+    project::{BlockchainStatus, PaymentCurrency},
+    terms::PledgeTerms,
+};
 
 #[derive(Debug, Deserialize, Serialize, sqlx::Type)]
 pub struct PledgeEntity {
@@ -11,8 +15,20 @@ pub struct PledgeEntity {
     pub project_id: Uuid,
     pub user_id: Uuid,
     pub comment: String,
+    pub shipping_address: Option<String>,
+    // Amount pledged on top of rewards, or without a reward
+    pub extra_amount: BigDecimal,
+    // Rewards, shipping and extra amount. Expected on-chain amount
+    pub total_amount: BigDecimal,
+    pub paid_currency: PaymentCurrency,
     pub blockchain_status: BlockchainStatus,
     pub transaction_hash: Option<String>,
+    // Linked wallet the pledge was paid from
+    pub eth_address: Option<String>,
+    // Project and reward terms when the pledge was made. None for older pledges
+    pub terms: Option<PledgeTerms>,
+    // Set when an all-or-nothing project ends unfunded
+    pub refundable: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -23,9 +39,16 @@ pub struct PledgeEntityRelations {
     pub project_id: Uuid,
     pub user_id: Uuid,
     pub comment: String,
+    pub shipping_address: Option<String>,
+    pub extra_amount: BigDecimal,
+    pub total_amount: BigDecimal,
+    pub paid_currency: PaymentCurrency,
     pub pledge_items: Vec<PledgeItemEntity>,
     pub blockchain_status: BlockchainStatus,
     pub transaction_hash: Option<String>,
+    pub eth_address: Option<String>,
+    pub terms: Option<PledgeTerms>,
+    pub refundable: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -35,15 +58,23 @@ pub struct PledgeItemEntity {
     pub id: Uuid,
     pub pledge_id: Uuid,
     pub reward_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub price_tier_id: Option<Uuid>,
     pub quantity: i32,
     pub paid_price: BigDecimal,
     pub paid_currency: PaymentCurrency,
+    pub paid_shipping: BigDecimal,
+    pub fulfillment_status: FulfillmentStatus,
+    pub tracking_number: Option<String>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug)]
 pub struct PledgeListResults {
-    pub total: i64,
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
     pub results: Vec<PledgeEntityRelations>,
 }
@@ -28,7 +28,8 @@ impl ProjectAssetEntity {
 
 #[derive(Debug)]
 pub struct ProjectAssetListResults {
-    pub total: i64,
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
     pub total_usage: u64,
     pub results: Vec<ProjectAssetEntity>,
 }
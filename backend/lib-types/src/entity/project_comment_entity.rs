@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+// Marks comments from the project creator, or a backer
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum CommentBadge {
+    Creator,
+    Backer,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectCommentEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    // Set for replies
+    pub parent_id: Option<Uuid>,
+    pub body: String,
+    pub badge: Option<CommentBadge>,
+    pub reply_count: i64,
+    pub report_count: i32,
+    pub edited_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    // Hidden by a moderator
+    pub hidden_at: Option<DateTime<Utc>>,
+    pub hidden_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectCommentListResults {
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
+    pub results: Vec<ProjectCommentEntity>,
+}
@@ -7,7 +7,10 @@ use crate::{
     dto::project::project_view_model::ProjectAssetViewModelRelation,
     shared::{
         asset::AssetContentType,
-        project::{BlockchainStatus, PaymentCurrency, ProjectCategory, ProjectStatus},
+        project::{
+            BlockchainStatus, FundingModel, FundingOutcome, PaymentCurrency, ProjectCategory,
+            ProjectStatus,
+        },
     },
 };
 
@@ -18,12 +21,17 @@ pub struct ProjectEntity {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub blurb: String,
     pub contract_address: String,
     pub payment_address: String,
     pub category: ProjectCategory,
     pub funding_goal: BigDecimal,
+    pub funding_model: FundingModel,
+    // Set when the campaign ends
+    pub funding_outcome: Option<FundingOutcome>,
+    pub outcome_at: Option<DateTime<Utc>>,
     pub start_time: i64,
     pub duration: i64,
     pub total_pledged: BigDecimal,
@@ -35,6 +43,13 @@ pub struct ProjectEntity {
     pub blockchain_status: BlockchainStatus,
     pub transaction_hash: Option<String>,
     pub rewards_order: Vec<String>,
+    // Highlighted description excerpt, only set for search results
+    pub search_snippet: Option<String>,
+    // Computed columns, only set for project lists
+    pub percent_funded: Option<BigDecimal>,
+    pub trending_score: Option<BigDecimal>,
+    // Score of the creator's latest reputation snapshot
+    pub creator_reputation: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -44,12 +59,17 @@ pub struct ProjectEntityRelations {
     pub id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub blurb: String,
     pub contract_address: String,
     pub payment_address: String,
     pub category: ProjectCategory,
     pub funding_goal: BigDecimal,
+    pub funding_model: FundingModel,
+    // Set when the campaign ends
+    pub funding_outcome: Option<FundingOutcome>,
+    pub outcome_at: Option<DateTime<Utc>>,
     pub start_time: i64,
     pub duration: i64,
     pub total_pledged: BigDecimal,
@@ -87,7 +107,8 @@ impl ProjectAssetEntityRelation {
 
 #[derive(Debug)]
 pub struct ProjectListResults {
-    pub total: i64,
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
     pub results: Vec<ProjectEntity>,
 }
 
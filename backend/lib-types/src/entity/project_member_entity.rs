@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+// Actions a collaborator can be allowed on a project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectPermission {
+    // Read unpublished projects and hidden rewards
+    View,
+    // Edit the project, rewards and assets
+    Edit,
+    // Read backer reports and update pledge fulfillment
+    Fulfill,
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum ProjectMemberRole {
+    Editor,
+    Fulfillment,
+    Viewer,
+}
+
+impl ProjectMemberRole {
+    pub fn allows(&self, permission: ProjectPermission) -> bool {
+        match permission {
+            ProjectPermission::View => true,
+            ProjectPermission::Edit => *self == ProjectMemberRole::Editor,
+            ProjectPermission::Fulfill => *self == ProjectMemberRole::Fulfillment,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectMemberEntity {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub role: ProjectMemberRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum ProjectInvitationState {
+    Pending,
+    Accepted,
+    Declined,
+    Revoked,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectInvitationEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    // Exactly one of `email` or `eth_address` is set
+    pub email: Option<String>,
+    pub eth_address: Option<String>,
+    pub role: ProjectMemberRole,
+    pub state: ProjectInvitationState,
+    pub invited_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectInvitationEntity {
+    // True if the invitation is addressed to the user's email or eth address
+    pub fn is_for(&self, email: &str, eth_address: &str) -> bool {
+        self.email
+            .as_ref()
+            .is_some_and(|e| e.eq_ignore_ascii_case(email))
+            || self
+                .eth_address
+                .as_ref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(eth_address))
+    }
+}
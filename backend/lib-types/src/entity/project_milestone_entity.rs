@@ -0,0 +1,63 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum MilestoneState {
+    // Editable by the creator
+    Pending,
+    // Backers can vote until `voting_ends_at`
+    Voting,
+    // Funds for the milestone can be released
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectMilestoneEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub target_date: i64,
+    pub release_percent: i32,
+    pub state: MilestoneState,
+    pub voting_starts_at: Option<DateTime<Utc>>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub approve_weight: BigDecimal,
+    pub reject_weight: BigDecimal,
+    pub vote_count: i32,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectMilestoneEntity {
+    pub fn is_voting(&self, now: DateTime<Utc>) -> bool {
+        self.state == MilestoneState::Voting
+            && self.voting_ends_at.map(|end| end > now).unwrap_or(false)
+    }
+
+    // Backers must vote to release funds, so a milestone with no votes is rejected.
+    // Ties between voters are approved
+    pub fn outcome(&self) -> MilestoneState {
+        if self.vote_count > 0 && self.approve_weight >= self.reject_weight {
+            MilestoneState::Approved
+        } else {
+            MilestoneState::Rejected
+        }
+    }
+}
+
+// Percent of funds unlocked by approved milestones
+pub fn released_percent(milestones: &[ProjectMilestoneEntity]) -> i32 {
+    milestones
+        .iter()
+        .filter(|m| m.state == MilestoneState::Approved)
+        .map(|m| m.release_percent)
+        .sum()
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum RevisionAction {
+    Create,
+    Update,
+    Delete,
+}
+
+// Field level change. `old` is null for created entities, `new` is null for deleted entities
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevisionChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+// Project or reward content change. `reward_id` is set for reward changes
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectRevisionEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub reward_id: Option<Uuid>,
+    pub user_id: Uuid,
+    pub action: RevisionAction,
+    pub changes: Vec<RevisionChange>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectRevisionListResults {
+    pub total: i64,
+    pub results: Vec<ProjectRevisionEntity>,
+}
+
+fn to_fields<T: Serialize>(value: Option<&T>) -> Map<String, Value> {
+    match value.and_then(|v| serde_json::to_value(v).ok()) {
+        Some(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    }
+}
+
+// Compares the serialized fields of two versions of an entity, and returns the fields that changed
+pub fn revision_changes<T: Serialize>(old: Option<&T>, new: Option<&T>) -> Vec<RevisionChange> {
+    let old = to_fields(old);
+    let mut new = to_fields(new);
+    let mut changes = vec![];
+    for (field, old_value) in old.into_iter() {
+        let new_value = new.remove(&field).unwrap_or(Value::Null);
+        if old_value != new_value {
+            changes.push(RevisionChange {
+                field,
+                old: old_value,
+                new: new_value,
+            });
+        }
+    }
+    for (field, new_value) in new.into_iter() {
+        changes.push(RevisionChange {
+            field,
+            old: Value::Null,
+            new: new_value,
+        });
+    }
+    changes
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectSignupEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub email: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub notified_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectSignupCounts {
+    pub total: i64,
+    pub confirmed: i64,
+    pub notified: i64,
+}
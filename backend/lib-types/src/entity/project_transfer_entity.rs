@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+use super::project_entity::ProjectEntity;
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum ProjectTransferState {
+    Pending,
+    Accepted,
+    Declined,
+    Cancelled,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectTransferEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub state: ProjectTransferState,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectTransferEntity {
+    pub fn is_pending(&self, now: DateTime<Utc>) -> bool {
+        self.state == ProjectTransferState::Pending && self.expires_at > now
+    }
+}
+
+// Ownership fields recorded in the project revision history on transfer
+#[derive(Debug, Serialize)]
+pub struct ProjectOwnership {
+    pub user_id: Uuid,
+    pub payment_address: String,
+}
+
+impl From<&ProjectEntity> for ProjectOwnership {
+    fn from(project: &ProjectEntity) -> Self {
+        Self {
+            user_id: project.user_id,
+            payment_address: project.payment_address.clone(),
+        }
+    }
+}
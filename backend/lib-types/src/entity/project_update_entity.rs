@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+use crate::shared::asset::{AssetContentType, AssetState};
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum ProjectUpdateState {
+    Draft,
+    Published,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectUpdateEntity {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    // Markdown
+    pub body: String,
+    pub state: ProjectUpdateState,
+    // Only backers of the project can read the body and attachments
+    pub backers_only: bool,
+    // Set when the update is first published
+    pub published_at: Option<DateTime<Utc>>,
+    pub assets: Vec<ProjectUpdateAssetEntity>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectUpdateListResults {
+    pub total: i64,
+    pub results: Vec<ProjectUpdateEntity>,
+}
+
+// Attachment uploaded through the asset presign/verify flow
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectUpdateAssetEntity {
+    pub id: Uuid,
+    pub update_id: Uuid,
+    pub user_id: Uuid,
+    pub size: i64,
+    pub content_type: AssetContentType,
+    pub state: AssetState,
+    pub upload_expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectUpdateAssetEntity {
+    pub fn relative_url(&self, project_id: Uuid) -> String {
+        let ext = self.content_type.get_ext();
+        let container = project_id.to_string();
+
+        format!("{}/{}.{}", container, self.id, ext)
+    }
+}
@@ -15,11 +15,73 @@ pub struct RewardEntity {
     pub price: BigDecimal,
     pub backer_limit: i32,
     pub backer_count: i32,
+    // Hidden rewards are only visible to the project owner, and can't be pledged
+    pub visible: bool,
+    pub shipping_required: bool,
+    pub shipping_costs: Vec<ShippingCost>,
+    pub variants: Vec<RewardVariant>,
+    pub price_tiers: Vec<PriceTier>,
+    // Set for add-ons, which can only be pledged alongside the parent reward
+    pub parent_reward_id: Option<Uuid>,
     pub image: Option<RewardAssetEntityRelation>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+// Per unit shipping cost for a region. Stored as JSON on the reward
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
+pub struct ShippingCost {
+    // ISO 3166 country code, or `*` for all other regions
+    pub region: String,
+    pub cost: BigDecimal,
+}
+
+// Reward option such as a size or colour, with its own stock limit. Stored as JSON on the reward
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
+pub struct RewardVariant {
+    pub id: Uuid,
+    pub name: String,
+    // Overrides the reward price when set
+    pub price: Option<BigDecimal>,
+    pub backer_limit: i32,
+    pub backer_count: i32,
+}
+
+// Early-bird price, limited by a time window and/or quantity. Stored as JSON on the reward
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
+pub struct PriceTier {
+    pub id: Uuid,
+    pub price: BigDecimal,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+    pub backer_limit: Option<i32>,
+    pub backer_count: i32,
+}
+
+impl PriceTier {
+    // Slots left before the quantity cap is reached
+    pub fn remaining(&self) -> Option<i32> {
+        self.backer_limit
+            .map(|limit| (limit - self.backer_count).max(0))
+    }
+
+    pub fn is_active(&self, now: i64, quantity: i32) -> bool {
+        self.starts_at
+            .map(|starts_at| now >= starts_at)
+            .unwrap_or(true)
+            && self.ends_at.map(|ends_at| now < ends_at).unwrap_or(true)
+            && self
+                .remaining()
+                .map(|remaining| remaining >= quantity)
+                .unwrap_or(true)
+    }
+}
+
+// First tier in order that is active for the given quantity
+pub fn active_price_tier(tiers: &[PriceTier], now: i64, quantity: i32) -> Option<&PriceTier> {
+    tiers.iter().find(|t| t.is_active(now, quantity))
+}
+
 #[derive(Debug, Deserialize, Serialize, sqlx::Type)]
 pub struct RewardAssetEntityRelation {
     pub id: Uuid,
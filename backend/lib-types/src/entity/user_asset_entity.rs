@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::asset::{AssetContentType, AssetState, UserAssetKind};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserAssetEntity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: UserAssetKind,
+    pub size: i64,
+    pub content_type: AssetContentType,
+    pub state: AssetState,
+    pub upload_expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserAssetEntity {
+    pub fn relative_url(&self) -> String {
+        let ext = self.content_type.get_ext();
+
+        format!("users/{}/{}.{}", self.user_id, self.id, ext)
+    }
+}
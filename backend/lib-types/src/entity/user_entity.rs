@@ -17,6 +17,9 @@ pub struct UserEntity {
     pub user_type: UserType,
     pub user_status: UserStatus,
     pub email_confirmed: bool,
+    pub show_backed_count: bool,
+    pub avatar_url: Option<String>,
+    pub banner_url: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -36,6 +39,7 @@ pub struct UserUpdateParams {
     pub user_type: Option<UserType>,
     pub user_status: Option<UserStatus>,
     pub email_confirmed: Option<bool>,
+    pub show_backed_count: Option<bool>,
 }
 
 impl UserUpdateParams {
@@ -51,6 +55,7 @@ impl UserUpdateParams {
             user_type: None,
             user_status: None,
             email_confirmed: None,
+            show_backed_count: None,
         }
     }
     pub fn password(password: String) -> Self {
@@ -65,6 +70,7 @@ impl UserUpdateParams {
             user_type: None,
             user_status: None,
             email_confirmed: None,
+            show_backed_count: None,
         }
     }
     pub fn email_confirmed(confirmed: bool) -> Self {
@@ -79,12 +85,14 @@ impl UserUpdateParams {
             user_type: None,
             user_status: None,
             email_confirmed: Some(confirmed),
+            show_backed_count: None,
         }
     }
 }
 
 #[derive(Debug)]
 pub struct UserListResults {
-    pub total: i64,
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
     pub results: Vec<UserEntity>,
 }
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum UserExportState {
+    Pending,
+    Ready,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserExportEntity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub state: UserExportState,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Delivery history of a creator, aggregated over their completed projects
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserReputationStats {
+    pub user_id: Uuid,
+    pub projects_completed: i32,
+    pub projects_funded: i32,
+    pub items_due: i32,
+    pub items_on_time: i32,
+    pub items_shipped: i32,
+    pub items_delivered: i32,
+    pub items_disputed: i32,
+    pub pledges_total: i32,
+    pub pledges_refunded: i32,
+}
+
+// Delivered items needed before a score is given, so a single delivery can't set it
+pub const MIN_REPUTATION_DELIVERIES: i32 = 5;
+
+fn rate(count: i32, total: i32) -> f64 {
+    if total > 0 {
+        count as f64 / total as f64
+    } else {
+        1.0
+    }
+}
+
+impl UserReputationStats {
+    // Score from 0 to 100. On-time delivery weighs the most, disputes are penalized.
+    // None until the creator has enough delivered items
+    pub fn score(&self) -> Option<i32> {
+        if self.items_delivered < MIN_REPUTATION_DELIVERIES {
+            return None;
+        }
+        let funded = rate(self.projects_funded, self.projects_completed);
+        let on_time = rate(self.items_on_time, self.items_due);
+        let received = rate(self.items_delivered, self.items_shipped);
+        let not_refunded = 1.0 - rate(self.pledges_refunded, self.pledges_total);
+        let disputed = if self.items_shipped > 0 {
+            rate(self.items_disputed, self.items_shipped)
+        } else {
+            0.0
+        };
+
+        let score = 100.0 * (0.2 * funded + 0.35 * on_time + 0.25 * received + 0.2 * not_refunded)
+            - 50.0 * disputed;
+        Some(score.round().clamp(0.0, 100.0) as i32)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserReputationEntity {
+    pub id: Uuid,
+    pub score: Option<i32>,
+    pub stats: UserReputationStats,
+    pub computed_at: DateTime<Utc>,
+}
@@ -17,6 +17,8 @@ pub enum ApiErrorCode {
     InvalidFormData,
     InvalidOldPassword,
     InvalidNumber,
+    InvalidCursor,
+    InvalidPreview,
     InvalidSignature,
     ProjectExists,
     ProjectDuration,
@@ -25,10 +27,39 @@ pub enum ApiErrorCode {
     ProjectInactive,
     UnknownReward,
     RewardDelivery,
+    RewardVariant,
+    RewardAddon,
+    RewardSoldOut,
+    RewardPriceTier,
+    PledgeEmpty,
+    PledgeMinimum,
+    ShippingRequired,
+    ShippingRegion,
+    ShippingLocked,
+    CommentDeleted,
+    CommentReported,
+    InvitationExists,
+    InvitationClosed,
+    MemberExists,
+    TransferExists,
+    TransferClosed,
+    SignupClosed,
+    MilestoneLocked,
+    MilestonePercent,
+    VotingClosed,
+    VoteExists,
+    AssetContentType,
+    AssetSize,
+    ExportPending,
+    EmailChangeClosed,
+    WalletPrimary,
+    UnknownWallet,
+    FulfillmentStatus,
     RestrictedStatus,
     SignatureRequired,
     UserExists,
     NoUpdates,
+    RateLimited,
     Unauthorized,
     None,
 }
@@ -10,6 +10,25 @@ pub enum AssetState {
     Expired,
 }
 
+// User profile images
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum UserAssetKind {
+    Avatar,
+    Banner,
+}
+
+impl UserAssetKind {
+    // Max upload size in bytes
+    pub fn max_size(&self) -> i64 {
+        match self {
+            UserAssetKind::Avatar => 2000000,
+            UserAssetKind::Banner => 5000000,
+        }
+    }
+}
+
 #[derive(
     Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
 )]
@@ -57,4 +76,15 @@ impl AssetContentType {
         }
         .into()
     }
+
+    // Raster images, SVG is excluded since it can contain scripts
+    pub fn is_image(&self) -> bool {
+        matches!(
+            self,
+            AssetContentType::Jpeg
+                | AssetContentType::Png
+                | AssetContentType::Webp
+                | AssetContentType::Gif
+        )
+    }
 }
@@ -2,5 +2,7 @@ pub mod api_error;
 pub mod asset;
 pub mod core;
 pub mod js_date;
+pub mod pledge;
 pub mod project;
+pub mod terms;
 pub mod user;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum FulfillmentStatus {
+    Pending,
+    Shipped,
+    Delivered,
+    Disputed,
+}
+
+impl FulfillmentStatus {
+    // Fulfillment changes a creator can make. Delivered and Disputed items are the backer's to change
+    pub fn creator_can_change_to(&self, to: FulfillmentStatus) -> bool {
+        use FulfillmentStatus::*;
+        matches!(
+            (self, to),
+            (Pending, Pending | Shipped | Delivered) | (Shipped, Pending | Shipped | Delivered)
+        )
+    }
+}
@@ -1,3 +1,4 @@
+use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
@@ -39,6 +40,36 @@ pub enum ProjectStatus {
     Complete,
 }
 
+// All-or-nothing projects refund backers if the funding goal isn't met,
+// flexible projects keep what they raise
+#[derive(
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumString,
+    Display,
+    sqlx::Type,
+)]
+pub enum FundingModel {
+    #[default]
+    AllOrNothing,
+    Flexible,
+}
+
+// Recorded when a campaign ends
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
+)]
+pub enum FundingOutcome {
+    Funded,
+    Unfunded,
+}
+
 #[derive(
     Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, sqlx::Type,
 )]
@@ -46,3 +77,15 @@ pub enum PaymentCurrency {
     Ethereum,
     Tsc,
 }
+
+impl PaymentCurrency {
+    // Smallest pledge accepted, in the currency's base unit
+    pub fn min_pledge_amount(&self) -> BigDecimal {
+        match self {
+            // 0.001 ETH
+            PaymentCurrency::Ethereum => BigDecimal::from(1_000_000_000_000_000i64),
+            // 1 TSC
+            PaymentCurrency::Tsc => BigDecimal::from(1_000_000_000_000_000_000i128),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::entity::{
+    project_entity::{ProjectEntity, ProjectEntityRelations},
+    reward_entity::{PriceTier, RewardEntity, RewardVariant, ShippingCost},
+};
+
+use super::project::{FundingModel, PaymentCurrency, ProjectCategory};
+
+// Project content backers pledge against. Used for revision diffs and pledge snapshots
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
+pub struct ProjectTerms {
+    pub name: String,
+    pub blurb: String,
+    pub description: String,
+    pub category: ProjectCategory,
+    pub funding_goal: BigDecimal,
+    // Older snapshots predate funding models, and were all-or-nothing
+    #[serde(default)]
+    pub funding_model: FundingModel,
+    pub start_time: i64,
+    pub duration: i64,
+    pub base_currency: PaymentCurrency,
+    pub payment_address: String,
+}
+
+impl From<&ProjectEntity> for ProjectTerms {
+    fn from(project: &ProjectEntity) -> Self {
+        Self {
+            name: project.name.clone(),
+            blurb: project.blurb.clone(),
+            description: project.description.clone(),
+            category: project.category,
+            funding_goal: project.funding_goal.clone(),
+            funding_model: project.funding_model,
+            start_time: project.start_time,
+            duration: project.duration,
+            base_currency: project.base_currency,
+            payment_address: project.payment_address.clone(),
+        }
+    }
+}
+
+impl From<&ProjectEntityRelations> for ProjectTerms {
+    fn from(project: &ProjectEntityRelations) -> Self {
+        Self {
+            name: project.name.clone(),
+            blurb: project.blurb.clone(),
+            description: project.description.clone(),
+            category: project.category,
+            funding_goal: project.funding_goal.clone(),
+            funding_model: project.funding_model,
+            start_time: project.start_time,
+            duration: project.duration,
+            base_currency: project.base_currency,
+            payment_address: project.payment_address.clone(),
+        }
+    }
+}
+
+// Reward content backers pledge against
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
+pub struct RewardTerms {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub delivery_time: i64,
+    pub price: BigDecimal,
+    pub backer_limit: i32,
+    pub visible: bool,
+    pub shipping_required: bool,
+    pub shipping_costs: Vec<ShippingCost>,
+    pub variants: Vec<RewardVariant>,
+    pub price_tiers: Vec<PriceTier>,
+    pub parent_reward_id: Option<Uuid>,
+}
+
+impl From<&RewardEntity> for RewardTerms {
+    fn from(reward: &RewardEntity) -> Self {
+        Self {
+            id: reward.id,
+            name: reward.name.clone(),
+            description: reward.description.clone(),
+            delivery_time: reward.delivery_time,
+            price: reward.price.clone(),
+            backer_limit: reward.backer_limit,
+            visible: reward.visible,
+            shipping_required: reward.shipping_required,
+            shipping_costs: reward.shipping_costs.clone(),
+            variants: reward.variants.clone(),
+            price_tiers: reward.price_tiers.clone(),
+            parent_reward_id: reward.parent_reward_id,
+        }
+    }
+}
+
+// Snapshot of the project and pledged rewards when a pledge was made
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type)]
+pub struct PledgeTerms {
+    pub project: ProjectTerms,
+    pub rewards: Vec<RewardTerms>,
+}
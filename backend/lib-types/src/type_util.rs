@@ -19,6 +19,9 @@ lazy_static! {
     pub static ref REGEX_TABLE_NAME: Regex = Regex::new(r"^[a-zA-Z][a-zA-Z0-9_-]{1,99}$").unwrap();
     pub static ref REGEX_ETH_ADDRESS: Regex = Regex::new(r"^(0x)?[0-9a-fA-F]{40}$").unwrap();
     pub static ref REGEX_ETH_TX: Regex = Regex::new(r"^(0x)?[0-9a-fA-F]{64}$").unwrap();
+    pub static ref REGEX_COUNTRY_CODE: Regex = Regex::new(r"^[A-Z]{2}$").unwrap();
+    // ISO 3166 country code, or `*` for all other regions
+    pub static ref REGEX_SHIPPING_REGION: Regex = Regex::new(r"^([A-Z]{2}|\*)$").unwrap();
 }
 
 pub fn is_uuid(str: &str) -> bool {
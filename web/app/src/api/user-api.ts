@@ -1,4 +1,5 @@
 import {
+  ICreateUserEmailChangeApiRequest,
   IGetUserApiResponse,
   ILoginUserApiRequest,
   ILoginUserApiResponse,
@@ -7,6 +8,7 @@ import {
   IUpdateUserApiRequest,
   IUpdateUserApiResponse,
   IUserExistsApiRequest,
+  IUserEmailChangeViewModel,
   IUserExistsApiResponse,
 } from '@app/types'
 import { rootApi } from './root-api'
@@ -61,3 +63,15 @@ export const apiUpdateUser = async (id: string, payload: IUpdateUserApiRequest)
   })
   return data as IUpdateUserApiResponse
 }
+
+export const apiCreateUserEmailChange = async (
+  id: string,
+  payload: ICreateUserEmailChangeApiRequest,
+): Promise<IUserEmailChangeViewModel> => {
+  const { data } = await rootApi.authRequest({
+    url: `users/${id}/email-changes`,
+    method: 'POST',
+    data: payload,
+  })
+  return data as IUserEmailChangeViewModel
+}
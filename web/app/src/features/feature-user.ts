@@ -1,6 +1,6 @@
-import { apiGetUser, apiUpdateUser } from '@app/api'
+import { apiCreateUserEmailChange, apiGetUser, apiUpdateUser } from '@app/api'
 import { store } from '@app/store'
-import { IUpdateUserApiRequest } from '@app/types'
+import { ICreateUserEmailChangeApiRequest, IUpdateUserApiRequest } from '@app/types'
 import { errorToKey, minidenticon } from '@app/util'
 import { Ref } from 'vue'
 
@@ -52,7 +52,6 @@ export const updateUser = async (
     try {
       await apiUpdateUser(id, payload)
       store.user.updateUser({
-        email: payload.email ?? store.user.email.value,
         ethAddress: payload.eth_address ?? store.user.ethAddress.value,
         name: payload.name ?? store.user.name.value,
         description: payload.description ?? store.user.description.value,
@@ -66,3 +65,21 @@ export const updateUser = async (
     console.error('User ID not found')
   }
 }
+
+// The new email is applied after it's confirmed from the link sent to the new address
+export const createEmailChange = async (
+  payload: ICreateUserEmailChangeApiRequest,
+  error: Ref<string | undefined>,
+) => {
+  error.value = undefined
+  const id = store.auth.userId?.value
+  if (id) {
+    try {
+      await apiCreateUserEmailChange(id, payload)
+    } catch (e) {
+      error.value = errorToKey(e)
+    }
+  } else {
+    console.error('User ID not found')
+  }
+}
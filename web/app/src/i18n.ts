@@ -219,6 +219,8 @@ const fallback: I18nObject = {
       'The Ethereum address used to back projects and receive funds. Changing your wallet will not affect previously backed or created projects.',
     update_email: 'Update Email',
     new_email: 'New email',
+    current_password: 'Current password',
+    email_change_sent: 'Check your new email for a confirmation link.',
     update_password: 'Update Password',
     update_wallet: 'Update Wallet',
     old_password: 'Old password',
@@ -311,6 +313,7 @@ const fallback: I18nObject = {
     signer: 'Error connecting to MetaMask',
     signature_failed: 'Wallet signature failed',
     InvalidSignature: 'Unable to verify signature, please try again.',
+    InvalidOldPassword: 'Incorrect password.',
     EthAddressUnique: 'Address is already in use.',
     UserExists: 'Email or wallet is already in use.',
     FILE_SIZE_BIG: 'Maximum image size is 20 MB',
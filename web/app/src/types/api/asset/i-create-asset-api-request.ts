@@ -1,4 +1,5 @@
 import { AssetContentType } from './asset-content-type'
+import { UserAssetKind } from './user-asset-kind'
 
 export interface ICreateAssetRequest {
   content_size: number
@@ -11,3 +12,17 @@ export interface ICreateRewardAssetRequest {
   content_type: AssetContentType
   reward_id: string
 }
+
+export interface ICreateProjectUpdateAssetRequest {
+  content_size: number
+  content_type: AssetContentType
+  update_id: string
+}
+
+export interface ICreateUserAssetRequest {
+  kind: UserAssetKind
+  // At most 2MB for avatars and 5MB for banners
+  content_size: number
+  // Jpeg, Png, Webp or Gif
+  content_type: AssetContentType
+}
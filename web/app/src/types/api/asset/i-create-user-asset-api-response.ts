@@ -0,0 +1,15 @@
+import { AssetState } from './asset-state'
+import { UserAssetKind } from './user-asset-kind'
+
+export interface ICreateUserAssetResponse {
+  id: string
+  signed_url: string
+  kind: UserAssetKind
+  size: number
+  content_type: string
+  state: AssetState
+  user_id: string
+  upload_expires_at: Date
+  created_at: Date
+  updated_at: Date
+}
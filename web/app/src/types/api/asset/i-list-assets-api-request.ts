@@ -1,8 +1,8 @@
-import { ISortOption } from '../shared'
+import { ICursorApiRequest, ISortOption } from '../shared'
 import { AssetContentType } from './asset-content-type'
 import { AssetState } from './asset-state'
 
-export interface IListAssetsRequest extends ISortOption {
+export interface IListAssetsRequest extends ISortOption, ICursorApiRequest {
   user_id?: string
   project_id?: string
   content_type?: AssetContentType
@@ -1,7 +1,10 @@
 import { IAssetViewModel } from './i-asset.view-model'
 
 export interface IListAssetsResponse {
-  total: number
+  // Omitted for cursor pages unless `include_total` is set
+  total?: number
+  // Omitted on the last cursor page
+  next_cursor?: string
   total_usage: number
   results: IAssetViewModel[]
 }
@@ -0,0 +1,5 @@
+export interface IVerifyUserAssetResponse {
+  verified: boolean
+  // Object key of the asset, set once it's the user's active avatar or banner
+  url?: string
+}
@@ -1,11 +1,14 @@
 export * from './i-create-asset-api-request'
 export * from './i-create-asset-api-response'
 export * from './i-verify-asset-api-response'
+export * from './i-create-user-asset-api-response'
+export * from './i-verify-user-asset-api-response'
 export * from './i-list-assets-api-request'
 export * from './i-list-assets-api-response'
 export * from './i-update-asset-api-request'
 export * from './i-update-asset-api-response'
 export * from './asset-content-type'
 export * from './asset-state'
+export * from './user-asset-kind'
 export * from './i-asset.view-model'
 export * from './size-constants'
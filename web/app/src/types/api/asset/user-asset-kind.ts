@@ -0,0 +1,4 @@
+export enum UserAssetKind {
+  Avatar = 'Avatar',
+  Banner = 'Banner',
+}
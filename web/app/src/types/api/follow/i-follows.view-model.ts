@@ -0,0 +1,5 @@
+// Projects and creators followed by the current user
+export interface IFollowsViewModel {
+  project_ids: string[]
+  creator_ids: string[]
+}
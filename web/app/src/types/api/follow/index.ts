@@ -0,0 +1 @@
+export * from './i-follows.view-model'
@@ -8,3 +8,16 @@ export * from './shared'
 export * from './i-api-error'
 export * from './asset'
 export * from './admin'
+export * from './project-transfer'
+export * from './project-milestone'
+export * from './user-wallet'
+export * from './user-email-change'
+export * from './project-preview-token'
+export * from './project-revision'
+export * from './project-update'
+export * from './project-comment'
+export * from './project-member'
+export * from './follow'
+export * from './notification'
+export * from './project-signup'
+export * from './user-export'
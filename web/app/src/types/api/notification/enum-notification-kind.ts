@@ -0,0 +1,12 @@
+export enum NotificationKind {
+  // A followed project went live
+  ProjectLaunched = 'ProjectLaunched',
+  // A followed or backed project posted an update
+  UpdatePosted = 'UpdatePosted',
+  // A followed or backed project reached its funding goal
+  GoalReached = 'GoalReached',
+  // A followed project ends in 48 hours
+  EndingSoon = 'EndingSoon',
+  // A backed reward was shipped
+  RewardShipped = 'RewardShipped',
+}
@@ -0,0 +1,8 @@
+import { ICursorApiRequest } from '../shared'
+
+export interface IListNotificationsApiRequest extends ICursorApiRequest {
+  // Only lists unread notifications
+  unread?: boolean
+  readonly from?: number
+  readonly to?: number
+}
@@ -0,0 +1,10 @@
+import { INotificationViewModel } from './i-notification.view-model'
+
+export interface IListNotificationsApiResponse {
+  // Omitted for cursor pages unless `include_total` is set
+  total?: number
+  // Omitted on the last cursor page
+  next_cursor?: string
+  unread_count: number
+  results: INotificationViewModel[]
+}
@@ -0,0 +1,8 @@
+import { NotificationKind } from './enum-notification-kind'
+
+export interface INotificationPreference {
+  kind: NotificationKind
+  // Listed in `GET /notifications`
+  in_app: boolean
+  email: boolean
+}
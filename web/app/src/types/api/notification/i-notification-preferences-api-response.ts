@@ -0,0 +1,6 @@
+import { INotificationPreference } from './i-notification-preference'
+
+// A preference for every kind, with defaults for kinds the user hasn't set
+export interface INotificationPreferencesApiResponse {
+  preferences: INotificationPreference[]
+}
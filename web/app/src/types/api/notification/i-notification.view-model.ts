@@ -0,0 +1,17 @@
+import { NotificationKind } from './enum-notification-kind'
+
+// Event specific references
+export interface INotificationData {
+  update_id?: string
+  pledge_id?: string
+}
+
+export interface INotificationViewModel {
+  id: string
+  kind: NotificationKind
+  project_id?: string
+  data: INotificationData
+  read: boolean
+  read_at?: string
+  created_at: string
+}
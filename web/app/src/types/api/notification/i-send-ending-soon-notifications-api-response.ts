@@ -0,0 +1,4 @@
+export interface ISendEndingSoonNotificationsApiResponse {
+  // Number of projects notified
+  projects: number
+}
@@ -0,0 +1,6 @@
+import { INotificationPreference } from './i-notification-preference'
+
+export interface IUpdateNotificationPreferencesApiRequest {
+  // Kinds that aren't listed are unchanged
+  preferences: INotificationPreference[]
+}
@@ -0,0 +1,8 @@
+export * from './enum-notification-kind'
+export * from './i-notification.view-model'
+export * from './i-list-notifications-api-request'
+export * from './i-list-notifications-api-response'
+export * from './i-notification-preference'
+export * from './i-update-notification-preferences-api-request'
+export * from './i-notification-preferences-api-response'
+export * from './i-send-ending-soon-notifications-api-response'
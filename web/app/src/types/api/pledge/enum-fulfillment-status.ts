@@ -0,0 +1,6 @@
+export enum FulfillmentStatus {
+  Pending = 'Pending',
+  Shipped = 'Shipped',
+  Delivered = 'Delivered',
+  Disputed = 'Disputed',
+}
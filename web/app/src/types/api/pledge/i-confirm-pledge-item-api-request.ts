@@ -0,0 +1,4 @@
+export interface IConfirmPledgeItemApiRequest {
+  // False opens a dispute
+  received: boolean
+}
@@ -1,6 +1,6 @@
-import { SortDirection } from '../shared'
+import { ICursorApiRequest, SortDirection } from '../shared'
 
-export interface IListPledgesApiRequest {
+export interface IListPledgesApiRequest extends ICursorApiRequest {
   readonly from?: number
   readonly to?: number
   project_id?: string
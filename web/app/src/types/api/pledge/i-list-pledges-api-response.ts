@@ -1,6 +1,9 @@
 import { IGetPledgeViewModel } from './i-pledge.view-model'
 
 export interface IListPledgesApiResponse {
-  total: number
+  // Omitted for cursor pages unless `include_total` is set
+  total?: number
+  // Omitted on the last cursor page
+  next_cursor?: string
   results: IGetPledgeViewModel[]
 }
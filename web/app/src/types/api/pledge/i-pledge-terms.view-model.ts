@@ -0,0 +1,40 @@
+import { FundingModel, PaymentCurrency, ProjectCategory } from '../project'
+import {
+  IPriceTierViewModel,
+  IRewardVariantViewModel,
+  IShippingCostViewModel,
+} from '../reward'
+
+export interface IProjectTermsViewModel {
+  name: string
+  blurb: string
+  description: string
+  category: ProjectCategory
+  funding_goal: string
+  funding_model: FundingModel
+  start_time: number
+  duration: number
+  base_currency: PaymentCurrency
+  payment_address: string
+}
+
+export interface IRewardTermsViewModel {
+  id: string
+  name: string
+  description: string
+  delivery_time: number
+  price: string
+  backer_limit: number
+  visible: boolean
+  shipping_required: boolean
+  shipping_costs: IShippingCostViewModel[]
+  variants: IRewardVariantViewModel[]
+  price_tiers: IPriceTierViewModel[]
+  parent_reward_id?: string
+}
+
+// Snapshot of the project and pledged rewards when a pledge was made
+export interface IPledgeTermsViewModel {
+  project: IProjectTermsViewModel
+  rewards: IRewardTermsViewModel[]
+}
@@ -1,10 +1,15 @@
 import { BlockchainStatus, PaymentCurrency } from '../project'
+import { FulfillmentStatus } from './enum-fulfillment-status'
+import { IPledgeTermsViewModel } from './i-pledge-terms.view-model'
 
 export interface IPledgeViewModel {
   id: string
   project_id: string
   user_id: string
   comment: string
+  extra_amount: string
+  total_amount: string
+  paid_currency: PaymentCurrency
   created_at: Date
   updated_at: Date
 }
@@ -14,9 +19,17 @@ export interface IGetPledgeViewModel {
   project_id: string
   user_id: string
   comment: string
+  extra_amount: string
+  total_amount: string
+  paid_currency: PaymentCurrency
   pledge_items: IPledgeItemViewModel[]
   blockchain_status: BlockchainStatus
   transaction_hash?: string
+  eth_address?: string
+  // Project and reward terms when the pledge was made
+  terms?: IPledgeTermsViewModel
+  // The project ended unfunded, and the pledge can be refunded
+  refundable: boolean
   created_at: Date
   updated_at: Date
 }
@@ -28,6 +41,10 @@ export interface IPledgeItemViewModel {
   quantity: number
   paid_price: string
   paid_currency: PaymentCurrency
+  fulfillment_status: FulfillmentStatus
+  tracking_number?: string
+  shipped_at?: Date
+  delivered_at?: Date
   created_at: Date
   updated_at: Date
 }
@@ -4,4 +4,6 @@ export interface IUpdatePledgeApiRequest {
   comment?: string
   transaction_hash?: string
   blockchain_status?: BlockchainStatus
+  // Wallet the transaction was sent from. Any of the user's linked wallets
+  eth_address?: string
 }
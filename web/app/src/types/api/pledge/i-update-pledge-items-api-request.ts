@@ -0,0 +1,11 @@
+import { FulfillmentStatus } from './enum-fulfillment-status'
+
+export interface IUpdatePledgeItemApiRequest {
+  id: string
+  fulfillment_status: FulfillmentStatus
+  tracking_number?: string
+}
+
+export interface IUpdatePledgeItemsApiRequest {
+  items: IUpdatePledgeItemApiRequest[]
+}
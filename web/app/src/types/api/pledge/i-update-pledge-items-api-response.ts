@@ -0,0 +1,5 @@
+import { IPledgeItemViewModel } from './i-pledge.view-model'
+
+export interface IUpdatePledgeItemsApiResponse {
+  results: IPledgeItemViewModel[]
+}
@@ -4,3 +4,8 @@ export * from './i-update-pledge-api-response'
 export * from './i-list-pledges-api-request'
 export * from './i-list-pledges-api-response'
 export * from './i-get-pledge-api-response'
+export * from './enum-fulfillment-status'
+export * from './i-update-pledge-items-api-request'
+export * from './i-update-pledge-items-api-response'
+export * from './i-confirm-pledge-item-api-request'
+export * from './i-pledge-terms.view-model'
@@ -0,0 +1,4 @@
+export enum CommentBadge {
+  Creator = 'Creator',
+  Backer = 'Backer',
+}
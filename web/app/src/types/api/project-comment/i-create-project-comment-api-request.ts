@@ -0,0 +1,5 @@
+export interface ICreateProjectCommentApiRequest {
+  body: string
+  // Replies to another comment on the project
+  parent_id?: string
+}
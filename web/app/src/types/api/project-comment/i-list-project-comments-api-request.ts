@@ -0,0 +1,12 @@
+import { ICursorApiRequest, SortDirection } from '../shared'
+
+export interface IListProjectCommentsApiRequest extends ICursorApiRequest {
+  // Lists replies to a comment. Top level comments are listed if not set
+  parent_id?: string
+  // Only lists reported comments, at any level. Moderators only
+  reported?: boolean
+  readonly from?: number
+  readonly to?: number
+  // Defaults to newest first, and oldest first for replies
+  direction?: SortDirection
+}
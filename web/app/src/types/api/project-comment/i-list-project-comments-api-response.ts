@@ -0,0 +1,9 @@
+import { IProjectCommentViewModel } from './i-project-comment.view-model'
+
+export interface IListProjectCommentsApiResponse {
+  // Omitted for cursor pages unless `include_total` is set
+  total?: number
+  // Omitted on the last cursor page
+  next_cursor?: string
+  results: IProjectCommentViewModel[]
+}
@@ -0,0 +1,19 @@
+import { CommentBadge } from './enum-comment-badge'
+
+export interface IProjectCommentViewModel {
+  id: string
+  project_id: string
+  user_id: string
+  parent_id?: string
+  // Omitted if the comment was deleted, or hidden by a moderator
+  body?: string
+  badge?: CommentBadge
+  reply_count: number
+  deleted: boolean
+  hidden: boolean
+  // Only returned to moderators
+  report_count?: number
+  edited_at?: string
+  created_at: string
+  updated_at: string
+}
@@ -0,0 +1,3 @@
+export interface IReportProjectCommentApiRequest {
+  reason: string
+}
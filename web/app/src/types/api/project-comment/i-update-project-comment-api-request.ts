@@ -0,0 +1,3 @@
+export interface IUpdateProjectCommentApiRequest {
+  body: string
+}
@@ -0,0 +1,7 @@
+export * from './enum-comment-badge'
+export * from './i-project-comment.view-model'
+export * from './i-create-project-comment-api-request'
+export * from './i-update-project-comment-api-request'
+export * from './i-report-project-comment-api-request'
+export * from './i-list-project-comments-api-request'
+export * from './i-list-project-comments-api-response'
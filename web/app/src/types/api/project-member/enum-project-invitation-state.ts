@@ -0,0 +1,6 @@
+export enum ProjectInvitationState {
+  Pending = 'Pending',
+  Accepted = 'Accepted',
+  Declined = 'Declined',
+  Revoked = 'Revoked',
+}
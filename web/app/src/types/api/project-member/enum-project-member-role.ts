@@ -0,0 +1,8 @@
+export enum ProjectMemberRole {
+  // Edits the project, rewards and assets
+  Editor = 'Editor',
+  // Reads backer reports and updates pledge fulfillment
+  Fulfillment = 'Fulfillment',
+  // Reads the unpublished project and hidden rewards
+  Viewer = 'Viewer',
+}
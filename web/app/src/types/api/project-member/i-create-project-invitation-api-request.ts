@@ -0,0 +1,8 @@
+import { ProjectMemberRole } from './enum-project-member-role'
+
+// Exactly one of `email` or `eth_address` is required
+export interface ICreateProjectInvitationApiRequest {
+  email?: string
+  eth_address?: string
+  role: ProjectMemberRole
+}
@@ -0,0 +1,5 @@
+import { IProjectInvitationViewModel } from './i-project-invitation.view-model'
+
+export interface IListProjectInvitationsApiResponse {
+  results: IProjectInvitationViewModel[]
+}
@@ -0,0 +1,5 @@
+import { IProjectMemberViewModel } from './i-project-member.view-model'
+
+export interface IListProjectMembersApiResponse {
+  results: IProjectMemberViewModel[]
+}
@@ -0,0 +1,14 @@
+import { ProjectInvitationState } from './enum-project-invitation-state'
+import { ProjectMemberRole } from './enum-project-member-role'
+
+export interface IProjectInvitationViewModel {
+  id: string
+  project_id: string
+  email?: string
+  eth_address?: string
+  role: ProjectMemberRole
+  state: ProjectInvitationState
+  invited_by: string
+  created_at: string
+  updated_at: string
+}
@@ -0,0 +1,10 @@
+import { ProjectMemberRole } from './enum-project-member-role'
+
+export interface IProjectMemberViewModel {
+  project_id: string
+  user_id: string
+  role: ProjectMemberRole
+  invited_by?: string
+  created_at: string
+  updated_at: string
+}
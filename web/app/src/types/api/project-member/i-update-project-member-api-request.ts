@@ -0,0 +1,5 @@
+import { ProjectMemberRole } from './enum-project-member-role'
+
+export interface IUpdateProjectMemberApiRequest {
+  role: ProjectMemberRole
+}
@@ -0,0 +1,8 @@
+export * from './enum-project-member-role'
+export * from './enum-project-invitation-state'
+export * from './i-project-member.view-model'
+export * from './i-project-invitation.view-model'
+export * from './i-create-project-invitation-api-request'
+export * from './i-list-project-invitations-api-response'
+export * from './i-update-project-member-api-request'
+export * from './i-list-project-members-api-response'
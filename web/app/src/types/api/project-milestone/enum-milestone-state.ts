@@ -0,0 +1,6 @@
+export enum MilestoneState {
+  Pending = 'Pending',
+  Voting = 'Voting',
+  Approved = 'Approved',
+  Rejected = 'Rejected',
+}
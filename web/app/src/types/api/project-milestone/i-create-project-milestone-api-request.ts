@@ -0,0 +1,6 @@
+export interface ICreateProjectMilestoneApiRequest {
+  title: string
+  description: string
+  target_date: number
+  release_percent: number
+}
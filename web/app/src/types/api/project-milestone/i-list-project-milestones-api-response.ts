@@ -0,0 +1,8 @@
+import { IProjectMilestoneViewModel } from './i-project-milestone.view-model'
+
+export interface IListProjectMilestonesApiResponse {
+  // Percent of funds unlocked by approved milestones
+  released_percent: number
+  released_amount: string
+  results: IProjectMilestoneViewModel[]
+}
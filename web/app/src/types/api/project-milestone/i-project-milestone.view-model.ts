@@ -0,0 +1,19 @@
+import { MilestoneState } from './enum-milestone-state'
+
+export interface IProjectMilestoneViewModel {
+  id: string
+  project_id: string
+  title: string
+  description: string
+  target_date: number
+  release_percent: number
+  state: MilestoneState
+  voting_starts_at?: string
+  voting_ends_at?: string
+  approve_weight: string
+  reject_weight: string
+  vote_count: number
+  resolved_at?: string
+  created_at: string
+  updated_at: string
+}
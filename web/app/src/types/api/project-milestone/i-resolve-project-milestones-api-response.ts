@@ -0,0 +1,4 @@
+export interface IResolveProjectMilestonesApiResponse {
+  approved: string[]
+  rejected: string[]
+}
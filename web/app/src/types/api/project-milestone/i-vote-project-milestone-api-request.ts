@@ -0,0 +1,4 @@
+export interface IVoteProjectMilestoneApiRequest {
+  approve: boolean
+  eth_address_signature: string
+}
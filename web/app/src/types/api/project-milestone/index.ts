@@ -0,0 +1,6 @@
+export * from './enum-milestone-state'
+export * from './i-project-milestone.view-model'
+export * from './i-create-project-milestone-api-request'
+export * from './i-vote-project-milestone-api-request'
+export * from './i-list-project-milestones-api-response'
+export * from './i-resolve-project-milestones-api-response'
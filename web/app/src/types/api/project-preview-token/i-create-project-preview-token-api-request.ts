@@ -0,0 +1,4 @@
+export interface ICreateProjectPreviewTokenApiRequest {
+  // Minutes until the token expires, up to 30 days
+  ttl: number
+}
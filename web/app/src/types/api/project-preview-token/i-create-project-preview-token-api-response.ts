@@ -0,0 +1,7 @@
+import { IProjectPreviewTokenViewModel } from './i-project-preview-token.view-model'
+
+// The token is only returned on creation
+export interface ICreateProjectPreviewTokenApiResponse {
+  token: string
+  preview_token: IProjectPreviewTokenViewModel
+}
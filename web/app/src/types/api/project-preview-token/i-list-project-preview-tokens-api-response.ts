@@ -0,0 +1,5 @@
+import { IProjectPreviewTokenViewModel } from './i-project-preview-token.view-model'
+
+export interface IListProjectPreviewTokensApiResponse {
+  results: IProjectPreviewTokenViewModel[]
+}
@@ -0,0 +1,11 @@
+export interface IProjectPreviewTokenViewModel {
+  id: string
+  project_id: string
+  user_id: string
+  expires_at: string
+  revoked_at?: string
+  view_count: number
+  last_viewed_at?: string
+  created_at: string
+  updated_at: string
+}
@@ -0,0 +1,4 @@
+export * from './i-project-preview-token.view-model'
+export * from './i-create-project-preview-token-api-request'
+export * from './i-create-project-preview-token-api-response'
+export * from './i-list-project-preview-tokens-api-response'
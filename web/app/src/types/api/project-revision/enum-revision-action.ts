@@ -0,0 +1,5 @@
+export enum RevisionAction {
+  Create = 'Create',
+  Update = 'Update',
+  Delete = 'Delete',
+}
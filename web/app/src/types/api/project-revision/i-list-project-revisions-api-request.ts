@@ -0,0 +1,6 @@
+export interface IListProjectRevisionsApiRequest {
+  // Only list the revisions of a reward
+  reward_id?: string
+  from?: number
+  to?: number
+}
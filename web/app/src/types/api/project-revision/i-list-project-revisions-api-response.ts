@@ -0,0 +1,6 @@
+import { IProjectRevisionViewModel } from './i-project-revision.view-model'
+
+export interface IListProjectRevisionsApiResponse {
+  total: number
+  results: IProjectRevisionViewModel[]
+}
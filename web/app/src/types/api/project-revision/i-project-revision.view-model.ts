@@ -0,0 +1,19 @@
+import { RevisionAction } from './enum-revision-action'
+
+// Field level change. `old` is null for created entities, `new` is null for deleted entities
+export interface IRevisionChangeViewModel {
+  field: string
+  old: unknown
+  new: unknown
+}
+
+// Project or reward content change. `reward_id` is set for reward changes
+export interface IProjectRevisionViewModel {
+  id: string
+  project_id: string
+  reward_id?: string
+  user_id: string
+  action: RevisionAction
+  changes: IRevisionChangeViewModel[]
+  created_at: string
+}
@@ -0,0 +1,4 @@
+export * from './enum-revision-action'
+export * from './i-project-revision.view-model'
+export * from './i-list-project-revisions-api-request'
+export * from './i-list-project-revisions-api-response'
@@ -0,0 +1,4 @@
+export interface IConfirmProjectSignupApiRequest {
+  // Code from the confirm email
+  code: string
+}
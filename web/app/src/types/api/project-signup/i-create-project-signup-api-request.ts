@@ -0,0 +1,3 @@
+export interface ICreateProjectSignupApiRequest {
+  email: string
+}
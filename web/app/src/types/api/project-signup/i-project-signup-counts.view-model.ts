@@ -0,0 +1,6 @@
+export interface IProjectSignupCountsViewModel {
+  total: number
+  confirmed: number
+  // Confirmed signups sent the launch email
+  notified: number
+}
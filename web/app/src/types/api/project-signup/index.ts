@@ -0,0 +1,3 @@
+export * from './i-create-project-signup-api-request'
+export * from './i-confirm-project-signup-api-request'
+export * from './i-project-signup-counts.view-model'
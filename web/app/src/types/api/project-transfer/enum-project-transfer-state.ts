@@ -0,0 +1,6 @@
+export enum ProjectTransferState {
+  Pending = 'Pending',
+  Accepted = 'Accepted',
+  Declined = 'Declined',
+  Cancelled = 'Cancelled',
+}
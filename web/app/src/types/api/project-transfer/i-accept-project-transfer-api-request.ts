@@ -0,0 +1,4 @@
+export interface IAcceptProjectTransferApiRequest {
+  eth_address_signature: string
+  update_payment_address?: boolean
+}
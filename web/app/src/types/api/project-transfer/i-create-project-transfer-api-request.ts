@@ -0,0 +1,3 @@
+export interface ICreateProjectTransferApiRequest {
+  eth_address: string
+}
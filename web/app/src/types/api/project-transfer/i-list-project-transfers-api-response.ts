@@ -0,0 +1,5 @@
+import { IProjectTransferViewModel } from './i-project-transfer.view-model'
+
+export interface IListProjectTransfersApiResponse {
+  results: IProjectTransferViewModel[]
+}
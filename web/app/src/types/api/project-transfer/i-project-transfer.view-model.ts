@@ -0,0 +1,12 @@
+import { ProjectTransferState } from './enum-project-transfer-state'
+
+export interface IProjectTransferViewModel {
+  id: string
+  project_id: string
+  from_user_id: string
+  to_user_id: string
+  state: ProjectTransferState
+  expires_at: string
+  created_at: string
+  updated_at: string
+}
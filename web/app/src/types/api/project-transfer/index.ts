@@ -0,0 +1,5 @@
+export * from './enum-project-transfer-state'
+export * from './i-project-transfer.view-model'
+export * from './i-create-project-transfer-api-request'
+export * from './i-accept-project-transfer-api-request'
+export * from './i-list-project-transfers-api-response'
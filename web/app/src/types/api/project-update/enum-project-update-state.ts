@@ -0,0 +1,4 @@
+export enum ProjectUpdateState {
+  Draft = 'Draft',
+  Published = 'Published',
+}
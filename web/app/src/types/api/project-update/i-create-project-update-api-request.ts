@@ -0,0 +1,10 @@
+import { ProjectUpdateState } from './enum-project-update-state'
+
+export interface ICreateProjectUpdateApiRequest {
+  title: string
+  // Markdown
+  body: string
+  backers_only?: boolean
+  // Defaults to Draft
+  state?: ProjectUpdateState
+}
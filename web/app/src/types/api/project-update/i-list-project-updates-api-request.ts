@@ -0,0 +1,8 @@
+import { ProjectUpdateState } from './enum-project-update-state'
+
+export interface IListProjectUpdatesApiRequest {
+  // Drafts are only listed for the project owner
+  state?: ProjectUpdateState
+  from?: number
+  to?: number
+}
@@ -0,0 +1,6 @@
+import { IProjectUpdateViewModel } from './i-project-update.view-model'
+
+export interface IListProjectUpdatesApiResponse {
+  total: number
+  results: IProjectUpdateViewModel[]
+}
@@ -0,0 +1,31 @@
+import { AssetState } from '../asset'
+import { ProjectUpdateState } from './enum-project-update-state'
+
+export interface IProjectUpdateAssetViewModel {
+  id: string
+  size: number
+  content_type: string
+  state: AssetState
+  user_id: string
+  update_id: string
+  upload_expires_at: string
+  created_at: string
+  updated_at: string
+}
+
+export interface IProjectUpdateViewModel {
+  id: string
+  project_id: string
+  user_id: string
+  title: string
+  // Markdown, omitted if the update is locked
+  body?: string
+  state: ProjectUpdateState
+  backers_only: boolean
+  // Backer-only update, and the requester isn't a backer
+  locked: boolean
+  published_at?: string
+  assets: IProjectUpdateAssetViewModel[]
+  created_at: string
+  updated_at: string
+}
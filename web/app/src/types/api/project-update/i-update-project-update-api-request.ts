@@ -0,0 +1,8 @@
+import { ProjectUpdateState } from './enum-project-update-state'
+
+export interface IUpdateProjectUpdateApiRequest {
+  title?: string
+  body?: string
+  backers_only?: boolean
+  state?: ProjectUpdateState
+}
@@ -0,0 +1,6 @@
+export * from './enum-project-update-state'
+export * from './i-project-update.view-model'
+export * from './i-create-project-update-api-request'
+export * from './i-update-project-update-api-request'
+export * from './i-list-project-updates-api-request'
+export * from './i-list-project-updates-api-response'
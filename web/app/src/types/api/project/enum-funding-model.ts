@@ -0,0 +1,4 @@
+export enum FundingModel {
+  AllOrNothing = 'AllOrNothing',
+  Flexible = 'Flexible',
+}
@@ -0,0 +1,4 @@
+export enum FundingOutcome {
+  Funded = 'Funded',
+  Unfunded = 'Unfunded',
+}
@@ -1,8 +1,24 @@
 export interface IPledgeItemDto {
   reward_id: string
+  variant_id?: string
   quantity: number
 }
 
+export interface IShippingAddressDto {
+  name: string
+  line1: string
+  line2?: string
+  city: string
+  state?: string
+  postal_code: string
+  country: string
+  phone?: string
+}
+
 export interface IBackProjectApiRequest {
+  // May be empty if `extra_amount` is set
   rewards: IPledgeItemDto[]
+  // Pledged on top of rewards, or alone for a reward-less pledge
+  extra_amount?: string
+  shipping_address?: IShippingAddressDto
 }
@@ -1,3 +1,4 @@
 import { IPledgeViewModel } from '../pledge'
 
+// `total_amount` is the amount to send on-chain
 export interface IBackProjectApiResponse extends IPledgeViewModel {}
@@ -1,3 +1,4 @@
+import { FundingModel } from './enum-funding-model'
 import { ProjectCategory } from './enum-project-category'
 
 export interface ICreateProjectApiRequest {
@@ -6,6 +7,7 @@ export interface ICreateProjectApiRequest {
   blurb: string
   category: ProjectCategory
   funding_goal: string
+  funding_model?: FundingModel
   start_time: number
   duration: number
 }
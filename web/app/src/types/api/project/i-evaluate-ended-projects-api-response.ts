@@ -0,0 +1,4 @@
+export interface IEvaluateEndedProjectsApiResponse {
+  funded: string[]
+  unfunded: string[]
+}
@@ -0,0 +1,4 @@
+export interface IGetProjectApiRequest {
+  // Grants access to an unpublished project
+  preview_token?: string
+}
@@ -1,13 +1,32 @@
-import { SortDirection } from '../shared'
+import { ICursorApiRequest, SortDirection } from '../shared'
 import { ProjectCategory } from './enum-project-category'
 import { ProjectStatus } from './enum-project-status'
 
-export interface IListProjectsApiRequest {
+export interface IListProjectsApiRequest extends ICursorApiRequest {
   readonly from?: number
   readonly to?: number
   categories?: ProjectCategory[]
   statuses?: ProjectStatus[]
   user_id?: string
-  column?: 'total_pledged' | 'funding_goal' | 'created_at' | 'updated_at'
+  // Keyword search over name, blurb and description. Every word is prefix matched
+  q?: string
+  // True for projects that reached their funding goal, false for projects that haven't
+  funded?: boolean
+  // True for active projects between their start and end time
+  live?: boolean
+  min_percent_funded?: number
+  max_percent_funded?: number
+  // Window for the trending sort, defaults to 7 days
+  trending_days?: number
+  column?:
+    | 'total_pledged'
+    | 'funding_goal'
+    | 'created_at'
+    | 'updated_at'
+    | 'relevance'
+    | 'end_time'
+    | 'percent_funded'
+    | 'backer_count'
+    | 'trending'
   direction?: SortDirection
 }
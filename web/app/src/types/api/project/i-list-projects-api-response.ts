@@ -1,6 +1,9 @@
 import { IProjectViewModel } from './i-project.view-model'
 
 export interface IListProjectsApiResponse {
-  total: number
+  // Omitted for cursor pages unless `include_total` is set
+  total?: number
+  // Omitted on the last cursor page
+  next_cursor?: string
   results: IProjectViewModel[]
 }
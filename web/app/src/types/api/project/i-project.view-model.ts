@@ -1,6 +1,8 @@
 import { AssetContentType } from '../asset'
 import { IRewardViewModel } from '../reward'
 import { BlockchainStatus } from './enum-blockchain-status'
+import { FundingModel } from './enum-funding-model'
+import { FundingOutcome } from './enum-funding-outcome'
 import { PaymentCurrency } from './enum-payment-currency'
 import { ProjectCategory } from './enum-project-category'
 import { ProjectStatus } from './enum-project-status'
@@ -16,12 +18,18 @@ export interface IProjectViewModel {
   id: string
   user_id: string
   name: string
+  // Unique URL name, changes when the project is renamed
+  slug: string
   description: string
   blurb: string
   contract_address: string
   payment_address: string
   category: ProjectCategory
   funding_goal: string
+  funding_model: FundingModel
+  funding_outcome?: FundingOutcome
+  // When the funding outcome was settled
+  outcome_at?: Date
   start_time: number
   duration: number
   total_pledged: string
@@ -34,6 +42,13 @@ export interface IProjectViewModel {
   rewards_order: string[]
   assets: IProjectAssetViewModelRelation[]
   assets_order: string[]
+  // Highlighted match, only set when searching
+  search_snippet?: string
+  percent_funded?: string
+  // Pledges per day, only set when sorting by trending
+  trending_score?: string
+  // Creator reputation score from 0 to 100, unset until enough items are delivered
+  creator_reputation?: number
   created_at: Date
   updated_at: Date
 }
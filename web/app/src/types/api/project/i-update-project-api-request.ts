@@ -1,4 +1,5 @@
 import { BlockchainStatus } from './enum-blockchain-status'
+import { FundingModel } from './enum-funding-model'
 import { ProjectCategory } from './enum-project-category'
 import { ProjectStatus } from './enum-project-status'
 
@@ -10,6 +11,7 @@ export interface IUpdateProjectApiRequest {
   status?: ProjectStatus
   category?: ProjectCategory
   funding_goal?: string
+  funding_model?: FundingModel
   start_time?: number
   duration?: number
   assets_order?: string[]
@@ -1,7 +1,10 @@
 export * from './enum-blockchain-status'
+export * from './enum-funding-model'
+export * from './enum-funding-outcome'
 export * from './enum-payment-currency'
 export * from './enum-project-category'
 export * from './enum-project-status'
+export * from './i-get-project-api-request'
 export * from './i-get-project-api-response'
 export * from './i-project.view-model'
 export * from './i-list-projects-api-request'
@@ -12,3 +15,4 @@ export * from './i-update-project-api-request'
 export * from './i-update-project-api-response'
 export * from './i-back-project-api-request'
 export * from './i-back-project-api-response'
+export * from './i-evaluate-ended-projects-api-response'
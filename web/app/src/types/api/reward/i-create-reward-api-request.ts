@@ -1,7 +1,32 @@
+export interface IShippingCostDto {
+  region: string
+  cost: string
+}
+
+export interface IRewardVariantDto {
+  id?: string
+  name: string
+  price?: string
+  backer_limit: number
+}
+
+export interface IPriceTierDto {
+  id?: string
+  price: string
+  starts_at?: number
+  ends_at?: number
+  backer_limit?: number
+}
+
 export interface ICreateRewardApiRequest {
   name: string
   description: string
   price: string
   delivery_time: number
   backer_limit: number
+  shipping_required?: boolean
+  shipping_costs?: IShippingCostDto[]
+  variants?: IRewardVariantDto[]
+  price_tiers?: IPriceTierDto[]
+  parent_reward_id?: string
 }
@@ -0,0 +1,12 @@
+import { SortDirection } from '../shared'
+
+export interface IListRewardsApiRequest {
+  // Only applies to the project owner, other users only see visible rewards
+  visible?: boolean
+  // Filter by rewards that are not sold out
+  available?: boolean
+  // Filter by add-ons, or base rewards
+  addons?: boolean
+  column?: 'order' | 'price' | 'backer_count' | 'created_at'
+  direction?: SortDirection
+}
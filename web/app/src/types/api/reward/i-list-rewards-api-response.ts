@@ -0,0 +1,5 @@
+import { IRewardViewModel } from './i-reward.view-model'
+
+export interface IListRewardsApiResponse {
+  results: IRewardViewModel[]
+}
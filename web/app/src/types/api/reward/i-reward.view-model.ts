@@ -7,6 +7,29 @@ export interface IRewardAssetViewModel {
   content_type: AssetContentType
 }
 
+export interface IShippingCostViewModel {
+  region: string
+  cost: string
+}
+
+export interface IRewardVariantViewModel {
+  id: string
+  name: string
+  price?: string
+  backer_limit: number
+  backer_count: number
+}
+
+export interface IPriceTierViewModel {
+  id: string
+  price: string
+  starts_at?: number
+  ends_at?: number
+  backer_limit?: number
+  backer_count: number
+  remaining?: number
+}
+
 export interface IRewardViewModel {
   id: string
   name: string
@@ -15,6 +38,13 @@ export interface IRewardViewModel {
   price: string
   backer_limit: number
   backer_count: number
+  visible: boolean
+  shipping_required: boolean
+  shipping_costs: IShippingCostViewModel[]
+  variants: IRewardVariantViewModel[]
+  price_tiers: IPriceTierViewModel[]
+  active_price_tier?: IPriceTierViewModel
+  parent_reward_id?: string
   image?: IRewardAssetViewModel
   created_at: Date
   updated_at: Date
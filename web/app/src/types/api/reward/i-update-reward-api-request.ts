@@ -1,3 +1,9 @@
+import {
+  IPriceTierDto,
+  IRewardVariantDto,
+  IShippingCostDto,
+} from './i-create-reward-api-request'
+
 export interface IUpdateRewardApiRequest {
   name?: string
   description?: string
@@ -5,4 +11,8 @@ export interface IUpdateRewardApiRequest {
   delivery_time?: number
   backer_limit?: number
   visible?: boolean
+  shipping_required?: boolean
+  shipping_costs?: IShippingCostDto[]
+  variants?: IRewardVariantDto[]
+  price_tiers?: IPriceTierDto[]
 }
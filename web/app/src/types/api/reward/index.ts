@@ -2,3 +2,5 @@ export * from './i-reward.view-model'
 export * from './i-create-reward-api-request'
 export * from './i-create-reward-api-response'
 export * from './i-update-reward-api-request'
+export * from './i-list-rewards-api-request'
+export * from './i-list-rewards-api-response'
@@ -0,0 +1,7 @@
+// Cursor pagination, `from` and `to` are ignored if `cursor` or `limit` are set
+export interface ICursorApiRequest {
+  cursor?: string
+  limit?: number
+  // The total is always returned for offset pagination, and only on request with a cursor
+  include_total?: boolean
+}
@@ -1,2 +1,3 @@
 export * from './enum-sort-direction'
 export * from './i-sort-option'
+export * from './i-cursor-api-request'
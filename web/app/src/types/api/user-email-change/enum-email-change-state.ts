@@ -0,0 +1,6 @@
+export enum EmailChangeState {
+  Pending = 'Pending',
+  Confirmed = 'Confirmed',
+  Reverted = 'Reverted',
+  Cancelled = 'Cancelled',
+}
@@ -0,0 +1,4 @@
+// Used to confirm or revert an email change
+export interface IConfirmUserEmailChangeApiRequest {
+  code: string
+}
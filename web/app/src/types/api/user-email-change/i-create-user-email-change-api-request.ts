@@ -0,0 +1,8 @@
+// Users confirm with their password, or by signing with their primary wallet
+export interface ICreateUserEmailChangeApiRequest {
+  email: string
+  password?: string
+  eth_address_signature?: string
+  // Unix timestamp included in the signed message
+  signature_expires_at?: number
+}
@@ -0,0 +1,12 @@
+import { EmailChangeState } from './enum-email-change-state'
+
+export interface IUserEmailChangeViewModel {
+  id: string
+  user_id: string
+  new_email: string
+  state: EmailChangeState
+  expires_at: string
+  confirmed_at?: string
+  reverted_at?: string
+  created_at: string
+}
@@ -0,0 +1,4 @@
+export * from './enum-email-change-state'
+export * from './i-user-email-change.view-model'
+export * from './i-create-user-email-change-api-request'
+export * from './i-confirm-user-email-change-api-request'
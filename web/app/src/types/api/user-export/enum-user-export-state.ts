@@ -0,0 +1,5 @@
+export enum UserExportState {
+  // Waiting for the scheduled build
+  Pending = 'Pending',
+  Ready = 'Ready',
+}
@@ -0,0 +1,4 @@
+export interface IBuildUserExportsApiResponse {
+  // IDs of the exports built by this run
+  built: string[]
+}
@@ -0,0 +1,9 @@
+import { UserExportState } from './enum-user-export-state'
+
+export interface IUserExportViewModel {
+  id: string
+  user_id: string
+  state: UserExportState
+  completed_at?: Date
+  created_at: Date
+}
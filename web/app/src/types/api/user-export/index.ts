@@ -0,0 +1,3 @@
+export * from './enum-user-export-state'
+export * from './i-user-export.view-model'
+export * from './i-build-user-exports-api-response'
@@ -0,0 +1,5 @@
+export interface ILinkUserWalletApiRequest {
+  eth_address: string
+  eth_address_signature: string
+  primary?: boolean
+}
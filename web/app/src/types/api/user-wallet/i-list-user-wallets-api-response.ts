@@ -0,0 +1,3 @@
+import { IUserWalletViewModel } from './i-user-wallet.view-model'
+
+export type IListUserWalletsApiResponse = IUserWalletViewModel[]
@@ -0,0 +1,3 @@
+export interface IUserWalletSignatureApiRequest {
+  eth_address_signature: string
+}
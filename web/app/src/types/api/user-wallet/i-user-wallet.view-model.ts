@@ -0,0 +1,7 @@
+export interface IUserWalletViewModel {
+  id: string
+  user_id: string
+  eth_address: string
+  is_primary: boolean
+  created_at: string
+}
@@ -0,0 +1,4 @@
+export * from './i-user-wallet.view-model'
+export * from './i-link-user-wallet-api-request'
+export * from './i-user-wallet-signature-api-request'
+export * from './i-list-user-wallets-api-response'
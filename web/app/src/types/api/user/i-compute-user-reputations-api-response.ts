@@ -0,0 +1,3 @@
+export interface IComputeUserReputationsApiResponse {
+  computed: number
+}
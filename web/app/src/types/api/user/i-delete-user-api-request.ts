@@ -0,0 +1,3 @@
+export interface IDeleteUserApiRequest {
+  password?: string
+}
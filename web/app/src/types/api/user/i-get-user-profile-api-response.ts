@@ -0,0 +1,19 @@
+import { IProjectViewModel } from '../project'
+import { IUserReputationViewModel } from './i-user-reputation.view-model'
+
+// Public creator profile, excludes email and account status
+export interface IGetUserProfileApiResponse {
+  id: string
+  name: string
+  description: string
+  link: string
+  location: string
+  avatar_url?: string
+  banner_url?: string
+  projects: IProjectViewModel[]
+  project_count: number
+  // Only set if the user opted in
+  backed_count?: number
+  reputation?: IUserReputationViewModel
+  created_at: Date
+}
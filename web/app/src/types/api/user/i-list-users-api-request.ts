@@ -1,7 +1,8 @@
+import { ICursorApiRequest } from '../shared'
 import { UserStatus } from './enum-user-status'
 import { UserType } from './enum-user-type'
 
-export interface IListUsersApiRequest {
+export interface IListUsersApiRequest extends ICursorApiRequest {
   readonly from?: number
   readonly to?: number
   type?: UserType
@@ -1,6 +1,9 @@
 import { IUserViewModel } from './i-user.view-model'
 
 export interface IListUsersApiResponse {
-  total: number
+  // Omitted for cursor pages unless `include_total` is set
+  total?: number
+  // Omitted on the last cursor page
+  next_cursor?: string
   results: IUserViewModel[]
 }
@@ -1,5 +1,4 @@
 export interface IUpdateUserApiRequest {
-  email?: string
   name?: string
   description?: string
   link?: string
@@ -8,6 +7,10 @@ export interface IUpdateUserApiRequest {
   new_password?: string
   eth_address?: string
   eth_address_signature?: string
+  // Unix timestamp included in the signed message
+  signature_expires_at?: number
+  // Show the number of projects backed on the public profile
+  show_backed_count?: boolean
   user_type?: string
   user_status?: string
 }
@@ -0,0 +1,14 @@
+export interface IUserReputationViewModel {
+  // Null until there is enough delivery history to score
+  score: number | null
+  projects_completed: number
+  projects_funded: number
+  items_due: number
+  items_on_time: number
+  items_shipped: number
+  items_delivered: number
+  items_disputed: number
+  pledges_total: number
+  pledges_refunded: number
+  computed_at: Date
+}
@@ -1,5 +1,6 @@
 import { UserStatus } from './enum-user-status'
 import { UserType } from './enum-user-type'
+import { IUserReputationViewModel } from './i-user-reputation.view-model'
 
 export interface IUserViewModel {
   id: string
@@ -7,10 +8,14 @@ export interface IUserViewModel {
   description: string
   link: string
   location: string
+  avatar_url?: string
+  banner_url?: string
   email: string
   eth_address: string
   user_type: UserType
   user_status: UserStatus
   email_confirmed: boolean
+  show_backed_count: boolean
+  reputation?: IUserReputationViewModel
   created_at: Date
 }
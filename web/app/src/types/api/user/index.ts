@@ -3,6 +3,7 @@ export * from './i-register-user-api-response'
 export * from './i-update-user-api-request'
 export * from './i-get-user-api-response'
 export * from './i-update-user-api-response'
+export * from './i-delete-user-api-request'
 export * from './i-list-users-api-request'
 export * from './i-list-users-api-response'
 export * from './i-user-exists-api-request'
@@ -10,3 +11,6 @@ export * from './i-user-exists-api-response'
 export * from './enum-user-status'
 export * from './enum-user-type'
 export * from './i-user.view-model'
+export * from './i-user-reputation.view-model'
+export * from './i-compute-user-reputations-api-response'
+export * from './i-get-user-profile-api-response'
@@ -1,3 +1,7 @@
-export const ethSwitchMessage = (address: string): string => {
-  return `Switch CrowdTrust address to ${address}`
+export const ethSwitchMessage = (
+  userId: string,
+  address: string,
+  expiresAt: number,
+): string => {
+  return `Switch CrowdTrust account ${userId} address to ${address}, valid until ${expiresAt}`
 }
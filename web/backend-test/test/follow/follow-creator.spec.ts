@@ -0,0 +1,63 @@
+import { IFollowsViewModel } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Follow Creator', () => {
+  const followEndpoint = (id: string) => `/api/users/${id}/actions/follow`
+  const unfollowEndpoint = (id: string) => `/api/users/${id}/actions/unfollow`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const creatorId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+  })
+
+  const listFollows = async (): Promise<IFollowsViewModel> => {
+    const response = await api
+      .get('/api/follows')
+      .set('Authorization', userAuth)
+      .expect(200)
+    return response.body
+  }
+
+  test('follows and unfollows a creator', async () => {
+    await api.post(followEndpoint(creatorId)).set('Authorization', userAuth).expect(204)
+    expect(await listFollows()).toEqual({ project_ids: [], creator_ids: [creatorId] })
+
+    await api.post(unfollowEndpoint(creatorId)).set('Authorization', userAuth).expect(204)
+    expect((await listFollows()).creator_ids).toEqual([])
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when following yourself', async () => {
+      await api.post(followEndpoint(userId)).set('Authorization', userAuth).expect(400, {
+        status: 400,
+        message: 'Cannot follow yourself',
+        code: 'None',
+      })
+    })
+
+    test('returns 404 when user does not exist', async () => {
+      await api
+        .post(followEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', userAuth)
+        .expect(404)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(followEndpoint(creatorId)).expect(401)
+    })
+  })
+})
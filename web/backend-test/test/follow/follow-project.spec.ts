@@ -0,0 +1,65 @@
+import { IFollowsViewModel } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Follow Project', () => {
+  const followEndpoint = (id: string) => `/api/projects/${id}/actions/follow`
+  const unfollowEndpoint = (id: string) => `/api/projects/${id}/actions/unfollow`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  const projectId = '0c9d3f3e-8027-4582-b573-99b2d6f87ebc'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+  })
+
+  const listFollows = async (): Promise<IFollowsViewModel> => {
+    const response = await api
+      .get('/api/follows')
+      .set('Authorization', userAuth)
+      .expect(200)
+    return response.body
+  }
+
+  test('follows and unfollows a project', async () => {
+    expect((await listFollows()).project_ids).toEqual([])
+
+    await api.post(followEndpoint(projectId)).set('Authorization', userAuth).expect(204)
+    // Following again has no effect
+    await api.post(followEndpoint(projectId)).set('Authorization', userAuth).expect(204)
+    expect(await listFollows()).toEqual({ project_ids: [projectId], creator_ids: [] })
+
+    await api.post(unfollowEndpoint(projectId)).set('Authorization', userAuth).expect(204)
+    expect((await listFollows()).project_ids).toEqual([])
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 403 when project is not published', async () => {
+      await api
+        .post(followEndpoint('bbe3791a-96af-4de6-8796-5d2f5c8ca144'))
+        .set('Authorization', userAuth)
+        .expect(403)
+    })
+
+    test('returns 404 when project does not exist', async () => {
+      await api
+        .post(followEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', userAuth)
+        .expect(404)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(followEndpoint(projectId)).expect(401)
+    })
+  })
+})
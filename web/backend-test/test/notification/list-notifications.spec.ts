@@ -0,0 +1,157 @@
+import {
+  IListNotificationsApiRequest,
+  IListNotificationsApiResponse,
+  NotificationKind,
+  ProjectUpdateState,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Notifications', () => {
+  const testEndpoint = '/api/notifications'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let creatorAuth: string
+  const creatorId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    creatorAuth = userAuthHeader(creatorId)
+  })
+
+  const listNotifications = async (
+    query?: IListNotificationsApiRequest,
+  ): Promise<IListNotificationsApiResponse> => {
+    const response = await api
+      .get(testEndpoint)
+      .set('Authorization', userAuth)
+      .query(query ?? {})
+      .expect(200)
+    return response.body
+  }
+
+  const postUpdate = async (backers_only = false): Promise<string> => {
+    const response = await api
+      .post(`/api/projects/${projectId}/updates`)
+      .set('Authorization', creatorAuth)
+      .send({
+        title: 'Shipping soon',
+        body: 'Boxes are packed',
+        state: ProjectUpdateState.Published,
+        backers_only,
+      })
+      .expect(201)
+    return response.body.id
+  }
+
+  test('followers are notified of posted updates', async () => {
+    await api
+      .post(`/api/projects/${projectId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    const updateId = await postUpdate()
+
+    const body = await listNotifications()
+    expect(body.total).toEqual(1)
+    expect(body.unread_count).toEqual(1)
+    const notification = body.results[0]
+    expect(notification.kind).toEqual(NotificationKind.UpdatePosted)
+    expect(notification.project_id).toEqual(projectId)
+    expect(notification.data).toEqual({ update_id: updateId })
+    expect(notification.read).toEqual(false)
+    expect(notification.read_at).toBeNull()
+  })
+
+  test('creator followers are notified of project updates', async () => {
+    await api
+      .post(`/api/users/${creatorId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    await postUpdate()
+
+    expect((await listNotifications()).total).toEqual(1)
+  })
+
+  test('followers are not notified of backer-only updates', async () => {
+    await api
+      .post(`/api/projects/${projectId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    await postUpdate(true)
+
+    expect((await listNotifications()).total).toEqual(0)
+  })
+
+  test('followers are notified when a project launches', async () => {
+    const prelaunchId = '0c9d3f3e-8027-4582-b573-99b2d6f87ebc'
+    await api
+      .post(`/api/projects/${prelaunchId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    await api
+      .patch(`/api/projects/${prelaunchId}`)
+      .set('Authorization', adminAuthHeader())
+      .send({ status: 'Active' })
+      .expect(200)
+
+    const body = await listNotifications()
+    expect(body.results.map((n) => n.kind)).toEqual([NotificationKind.ProjectLaunched])
+  })
+
+  test('disabled in-app notifications are not listed', async () => {
+    await api
+      .patch('/api/notification-preferences')
+      .set('Authorization', userAuth)
+      .send({
+        preferences: [
+          { kind: NotificationKind.UpdatePosted, in_app: false, email: false },
+        ],
+      })
+      .expect(200)
+    await api
+      .post(`/api/projects/${projectId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    await postUpdate()
+
+    expect((await listNotifications()).total).toEqual(0)
+  })
+
+  test('pages notifications with a cursor', async () => {
+    await api
+      .post(`/api/projects/${projectId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    await postUpdate()
+    await postUpdate()
+
+    const page1 = await listNotifications({ limit: 1 })
+    expect(page1.results).toHaveLength(1)
+    expect(page1.unread_count).toEqual(2)
+    const page2 = await listNotifications({ limit: 1, cursor: page1.next_cursor })
+    expect(page2.results).toHaveLength(1)
+    expect(page2.results[0].id).not.toEqual(page1.results[0].id)
+    expect(page2.next_cursor).toBeUndefined()
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.get(testEndpoint).expect(401)
+  })
+})
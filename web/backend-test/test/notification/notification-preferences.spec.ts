@@ -0,0 +1,76 @@
+import {
+  INotificationPreferencesApiResponse,
+  IUpdateNotificationPreferencesApiRequest,
+  NotificationKind,
+} from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Notification Preferences', () => {
+  const testEndpoint = '/api/notification-preferences'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+  })
+
+  test('returns defaults for every kind', async () => {
+    const response = await api
+      .get(testEndpoint)
+      .set('Authorization', userAuth)
+      .expect(200)
+    const body: INotificationPreferencesApiResponse = response.body
+
+    expect(body.preferences.map((p) => p.kind)).toEqual(Object.values(NotificationKind))
+    expect(body.preferences.every((p) => p.in_app && !p.email)).toBe(true)
+  })
+
+  test('updates listed kinds', async () => {
+    const payload: IUpdateNotificationPreferencesApiRequest = {
+      preferences: [{ kind: NotificationKind.EndingSoon, in_app: false, email: true }],
+    }
+    const response = await api
+      .patch(testEndpoint)
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(200)
+    const body: INotificationPreferencesApiResponse = response.body
+
+    expect(body.preferences).toHaveLength(5)
+    expect(body.preferences.find((p) => p.kind === NotificationKind.EndingSoon)).toEqual(
+      payload.preferences[0],
+    )
+    const kind = NotificationKind.GoalReached
+    const unchanged = body.preferences.find((p) => p.kind === kind)
+    expect(unchanged).toEqual({ kind, in_app: true, email: false })
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when preferences are empty', async () => {
+      await api
+        .patch(testEndpoint)
+        .set('Authorization', userAuth)
+        .send({ preferences: [] })
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.get(testEndpoint).expect(401)
+    })
+  })
+})
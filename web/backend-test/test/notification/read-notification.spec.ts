@@ -0,0 +1,86 @@
+import { IListNotificationsApiResponse } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Read Notification', () => {
+  const readEndpoint = (id: string) => `/api/notifications/${id}/actions/read`
+  const readAllEndpoint = '/api/notifications/actions/read-all'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+
+    await api
+      .post(`/api/projects/${projectId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+    for (const title of ['First update', 'Second update']) {
+      await api
+        .post(`/api/projects/${projectId}/updates`)
+        .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+        .send({ title, body: 'Boxes are packed', state: 'Published' })
+        .expect(201)
+    }
+  })
+
+  const listNotifications = async (unread?: boolean) => {
+    const response = await api
+      .get('/api/notifications')
+      .set('Authorization', userAuth)
+      .query(unread ? { unread } : {})
+      .expect(200)
+    const body: IListNotificationsApiResponse = response.body
+    return body
+  }
+
+  test('reads a notification', async () => {
+    const notification = (await listNotifications()).results[0]
+
+    await api
+      .post(readEndpoint(notification.id))
+      .set('Authorization', userAuth)
+      .expect(204)
+
+    const body = await listNotifications()
+    expect(body.unread_count).toEqual(1)
+    const read = body.results.find((n) => n.id === notification.id)
+    expect(read?.read).toEqual(true)
+    expect(read?.read_at).not.toBeNull()
+    expect((await listNotifications(true)).results.map((n) => n.id)).not.toContain(
+      notification.id,
+    )
+  })
+
+  test('reads all notifications', async () => {
+    await api.post(readAllEndpoint).set('Authorization', userAuth).expect(204)
+
+    const body = await listNotifications()
+    expect(body.unread_count).toEqual(0)
+    expect(body.results.every((n) => n.read)).toBe(true)
+  })
+
+  test('returns 404 when notification belongs to another user', async () => {
+    const notification = (await listNotifications()).results[0]
+
+    await api
+      .post(readEndpoint(notification.id))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(404)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.post(readAllEndpoint).expect(401)
+  })
+})
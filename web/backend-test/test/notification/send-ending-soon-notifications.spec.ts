@@ -0,0 +1,94 @@
+import {
+  IListNotificationsApiResponse,
+  ISendEndingSoonNotificationsApiResponse,
+  NotificationKind,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  dayToSec,
+  now,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Send Ending Soon Notifications', () => {
+  const testEndpoint = '/api/notifications/actions/send-ending-soon'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let userAuth: string
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+
+    await api
+      .post(`/api/projects/${projectId}/actions/follow`)
+      .set('Authorization', userAuth)
+      .expect(204)
+  })
+
+  const listEndingSoon = async () => {
+    const response = await api
+      .get('/api/notifications')
+      .set('Authorization', userAuth)
+      .expect(200)
+    const body: IListNotificationsApiResponse = response.body
+    return body.results.filter((n) => n.kind === NotificationKind.EndingSoon)
+  }
+
+  describe('when requester is Admin', () => {
+    test('notifies followers of projects ending within 48 hours once', async () => {
+      await api
+        .patch(`/api/projects/${projectId}`)
+        .set('Authorization', adminAuth)
+        .send({ start_time: now() - dayToSec(5), duration: dayToSec(6) })
+        .expect(200)
+
+      const response = await api
+        .post(testEndpoint)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const body: ISendEndingSoonNotificationsApiResponse = response.body
+      expect(body.projects).toBeGreaterThan(0)
+      expect((await listEndingSoon()).map((n) => n.project_id)).toEqual([projectId])
+
+      await api.post(testEndpoint).set('Authorization', adminAuth).expect(200)
+      expect(await listEndingSoon()).toHaveLength(1)
+    })
+
+    test('does not notify followers of projects ending later', async () => {
+      await api
+        .patch(`/api/projects/${projectId}`)
+        .set('Authorization', adminAuth)
+        .send({ start_time: now() - dayToSec(1), duration: dayToSec(30) })
+        .expect(200)
+
+      await api.post(testEndpoint).set('Authorization', adminAuth).expect(200)
+      expect(await listEndingSoon()).toEqual([])
+    })
+  })
+
+  describe('when requester is not Admin', () => {
+    test('returns 403 when requester is User', async () => {
+      await api.post(testEndpoint).set('Authorization', userAuth).expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint).expect(401)
+    })
+  })
+})
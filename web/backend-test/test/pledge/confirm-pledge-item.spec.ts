@@ -0,0 +1,141 @@
+import {
+  FulfillmentStatus,
+  IConfirmPledgeItemApiRequest,
+  IPledgeItemViewModel,
+  IUpdatePledgeItemsApiRequest,
+  IUpdatePledgeItemsApiResponse,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Confirm Pledge Item', () => {
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let creatorAuth: string
+  let backerAuth: string
+  let projectId: string
+  let pledgeItemId: string
+  let payload: IConfirmPledgeItemApiRequest
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    adminAuth = adminAuthHeader()
+    creatorAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    backerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    pledgeItemId = 'd0742e9a-86c3-4a0a-9731-1395c7f7fc62'
+  })
+
+  const shipItem = async () => {
+    const shipPayload: IUpdatePledgeItemsApiRequest = {
+      items: [
+        {
+          id: pledgeItemId,
+          fulfillment_status: FulfillmentStatus.Shipped,
+          tracking_number: 'TRACK1',
+        },
+      ],
+    }
+    const response = await api
+      .patch(`/api/projects/${projectId}/pledge-items`)
+      .set('Authorization', creatorAuth)
+      .send(shipPayload)
+      .expect(200)
+    const body: IUpdatePledgeItemsApiResponse = response.body
+    return body.results[0]
+  }
+
+  describe('when requestor is the backer', () => {
+    test('return 200 when confirming receipt', async () => {
+      const shipped = await shipItem()
+      payload = { received: true }
+
+      const response = await api
+        .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+        .set('Authorization', backerAuth)
+        .send(payload)
+        .expect(200)
+      const body: IPledgeItemViewModel = response.body
+
+      expect(body.fulfillment_status).toEqual(FulfillmentStatus.Delivered)
+      expect(body.delivered_at).toBeDefined()
+      expect(body.shipped_at).toEqual(shipped.shipped_at)
+      expect(body.tracking_number).toEqual('TRACK1')
+    })
+
+    test('return 200 when opening a dispute', async () => {
+      await shipItem()
+      payload = { received: false }
+
+      const response = await api
+        .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+        .set('Authorization', backerAuth)
+        .send(payload)
+        .expect(200)
+      const body: IPledgeItemViewModel = response.body
+
+      expect(body.fulfillment_status).toEqual(FulfillmentStatus.Disputed)
+    })
+
+    test('return 400 when item has not been shipped', async () => {
+      payload = { received: true }
+
+      await api
+        .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+        .set('Authorization', backerAuth)
+        .send(payload)
+        .expect(400, {
+          code: 'FulfillmentStatus',
+          message: 'Pledge item has not been shipped',
+          status: 400,
+        })
+    })
+  })
+
+  test('return 200 when requestor is Admin', async () => {
+    await shipItem()
+    payload = { received: true }
+
+    await api
+      .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+      .set('Authorization', adminAuth)
+      .send(payload)
+      .expect(200)
+  })
+
+  test('return 403 when requestor is not the backer', async () => {
+    await shipItem()
+    payload = { received: true }
+
+    await api
+      .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+      .set('Authorization', creatorAuth)
+      .send(payload)
+      .expect(403)
+  })
+
+  test('returns 404 when pledge item does not exist', async () => {
+    payload = { received: true }
+
+    await api
+      .post('/api/pledge-items/cbd7a9ff-18f5-489e-b61e-cdd4a1394968/actions/confirm')
+      .set('Authorization', backerAuth)
+      .send(payload)
+      .expect(404)
+  })
+})
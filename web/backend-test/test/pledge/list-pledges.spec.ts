@@ -1,5 +1,11 @@
 import { IListPledgesApiRequest, IListPledgesApiResponse } from '@app/types'
-import { testagent, TestAgent, adminAuthHeader, userAuthHeader } from '../helpers'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  USER3_ADDRESS,
+} from '../helpers'
 import { testConfig } from '../test.config'
 import { AppDbResetService } from '../helpers'
 import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
@@ -56,6 +62,31 @@ describe('List Pledges', () => {
       expect(pledges[1].id).toEqual('ac69089a-fbe6-4879-bbb2-ced6446092c0')
     })
 
+    test('pages through pledges with a cursor', async () => {
+      query = { limit: 2 }
+      const first = await api
+        .get(testEndpoint)
+        .query(query)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const firstBody: IListPledgesApiResponse = first.body
+      expect(firstBody.results).toHaveLength(2)
+      expect(firstBody.next_cursor).toBeDefined()
+
+      query = { limit: 2, cursor: firstBody.next_cursor }
+      const second = await api
+        .get(testEndpoint)
+        .query(query)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const secondBody: IListPledgesApiResponse = second.body
+      expect(secondBody.results).toHaveLength(TotalPledges - 2)
+      expect(secondBody.next_cursor).toBeUndefined()
+
+      const ids = [...firstBody.results, ...secondBody.results].map((p) => p.id)
+      expect(new Set(ids).size).toEqual(TotalPledges)
+    })
+
     test('filters by user_id', async () => {
       const response = await api
         .get(testEndpoint)
@@ -92,6 +123,24 @@ describe('List Pledges', () => {
       expect(pledges[1].id).toEqual('8e766cf6-c74a-4263-9974-4a0c201b728c')
     })
 
+    test('returns the wallet a pledge was paid from', async () => {
+      const pledgeId = 'ac69089a-fbe6-4879-bbb2-ced6446092c0'
+      await api
+        .patch(`/api/pledges/${pledgeId}`)
+        .set('Authorization', adminAuth)
+        .send({ eth_address: USER3_ADDRESS })
+        .expect(200)
+
+      const response = await api
+        .get(testEndpoint)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const body: IListPledgesApiResponse = response.body
+      const pledge = body.results.find((p) => p.id === pledgeId)
+
+      expect(pledge?.eth_address).toEqual(USER3_ADDRESS)
+    })
+
     test('filters by project_id and user_id', async () => {
       const response = await api
         .get(testEndpoint)
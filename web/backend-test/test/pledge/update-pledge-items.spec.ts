@@ -0,0 +1,150 @@
+import {
+  FulfillmentStatus,
+  IUpdatePledgeItemsApiRequest,
+  IUpdatePledgeItemsApiResponse,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Update Pledge Items', () => {
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let creatorAuth: string
+  let backerAuth: string
+  let projectId: string
+  let pledgeItemId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    adminAuth = adminAuthHeader()
+    creatorAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    backerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    pledgeItemId = 'd0742e9a-86c3-4a0a-9731-1395c7f7fc62'
+  })
+
+  const updateItem = (
+    auth: string,
+    fulfillment_status: FulfillmentStatus,
+    tracking_number?: string,
+  ) => {
+    const payload: IUpdatePledgeItemsApiRequest = {
+      items: [{ id: pledgeItemId, fulfillment_status, tracking_number }],
+    }
+    return api
+      .patch(`/api/projects/${projectId}/pledge-items`)
+      .set('Authorization', auth)
+      .send(payload)
+  }
+
+  describe('when requestor is the project creator', () => {
+    test('return 200 when shipping an item', async () => {
+      const response = await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK1')
+      expect(response.status).toEqual(200)
+      const body: IUpdatePledgeItemsApiResponse = response.body
+
+      expect(body.results[0].fulfillment_status).toEqual(FulfillmentStatus.Shipped)
+      expect(body.results[0].tracking_number).toEqual('TRACK1')
+      expect(body.results[0].shipped_at).toBeDefined()
+    })
+
+    test('keeps shipped_at when Shipped is resubmitted', async () => {
+      const shipped = await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK1')
+      const first: IUpdatePledgeItemsApiResponse = shipped.body
+
+      const response = await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK2')
+      expect(response.status).toEqual(200)
+      const body: IUpdatePledgeItemsApiResponse = response.body
+
+      expect(body.results[0].tracking_number).toEqual('TRACK2')
+      expect(body.results[0].shipped_at).toEqual(first.results[0].shipped_at)
+    })
+
+    test('clears shipping when rolled back to Pending', async () => {
+      await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK1').expect(200)
+
+      const response = await updateItem(creatorAuth, FulfillmentStatus.Pending)
+      expect(response.status).toEqual(200)
+      const body: IUpdatePledgeItemsApiResponse = response.body
+
+      expect(body.results[0].fulfillment_status).toEqual(FulfillmentStatus.Pending)
+      expect(body.results[0].tracking_number).toBeFalsy()
+      expect(body.results[0].shipped_at).toBeFalsy()
+    })
+
+    test('return 400 when disputing an item', async () => {
+      await updateItem(creatorAuth, FulfillmentStatus.Disputed).expect(400, {
+        code: 'FulfillmentStatus',
+        message: 'Cannot change pledge item from Pending to Disputed',
+        status: 400,
+      })
+    })
+
+    test('return 400 when changing a disputed item', async () => {
+      await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK1').expect(200)
+      await api
+        .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+        .set('Authorization', backerAuth)
+        .send({ received: false })
+        .expect(200)
+
+      await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK2').expect(400, {
+        code: 'FulfillmentStatus',
+        message: 'Cannot change pledge item from Disputed to Shipped',
+        status: 400,
+      })
+    })
+
+    test('return 400 when changing a delivered item', async () => {
+      await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK1').expect(200)
+      await api
+        .post(`/api/pledge-items/${pledgeItemId}/actions/confirm`)
+        .set('Authorization', backerAuth)
+        .send({ received: true })
+        .expect(200)
+
+      await updateItem(creatorAuth, FulfillmentStatus.Pending).expect(400, {
+        code: 'FulfillmentStatus',
+        message: 'Cannot change pledge item from Delivered to Pending',
+        status: 400,
+      })
+    })
+  })
+
+  test('return 200 when requestor is Admin', async () => {
+    await updateItem(adminAuth, FulfillmentStatus.Shipped, 'TRACK1').expect(200)
+  })
+
+  test('return 403 when requestor is the backer', async () => {
+    await updateItem(backerAuth, FulfillmentStatus.Shipped, 'TRACK1').expect(403)
+  })
+
+  test('return 404 when pledge item is not in the project', async () => {
+    pledgeItemId = 'd80c42bf-2cd0-422f-96b9-b78d98f72589'
+
+    await updateItem(creatorAuth, FulfillmentStatus.Shipped, 'TRACK1').expect(404)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.patch(`/api/projects/${projectId}/pledge-items`).expect(401, {
+      code: 'Unauthorized',
+      message: 'Unauthorized',
+      status: 401,
+    })
+  })
+})
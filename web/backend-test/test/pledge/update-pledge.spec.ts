@@ -9,6 +9,8 @@ import {
   adminAuthHeader,
   userAuthHeader,
   AppDbResetService,
+  TEST_ADDRESS1,
+  USER3_ADDRESS,
 } from '../helpers'
 import { testConfig } from '../test.config'
 import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
@@ -56,6 +58,9 @@ describe('Update Pledge', () => {
     if (payload.transaction_hash) {
       expect(pledge?.transaction_hash).toEqual(payload.transaction_hash)
     }
+    if (payload.eth_address) {
+      expect(pledge?.eth_address).toEqual(payload.eth_address)
+    }
   }
 
   describe('when requestor is Admin', () => {
@@ -106,6 +111,32 @@ describe('Update Pledge', () => {
       await verifyPledge(projectId, pledgeId, adminAuth)
     })
 
+    test('return 200 when setting the wallet the pledge was paid from', async () => {
+      payload = { eth_address: USER3_ADDRESS }
+
+      await api
+        .patch(`/api/pledges/${pledgeId}`)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(200)
+
+      await verifyPledge(projectId, pledgeId, adminAuth)
+    })
+
+    test('return 400 when wallet is not linked to the backer', async () => {
+      payload = { eth_address: TEST_ADDRESS1 }
+
+      await api
+        .patch(`/api/pledges/${pledgeId}`)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          code: 'UnknownWallet',
+          message: `Eth address ${TEST_ADDRESS1} is not linked to the backer`,
+          status: 400,
+        })
+    })
+
     test('return 400 when blockchain_status is invalid', async () => {
       payload = { blockchain_status: 'ok' as BlockchainStatus }
 
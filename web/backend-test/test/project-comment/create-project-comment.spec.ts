@@ -0,0 +1,161 @@
+import {
+  CommentBadge,
+  ICreateProjectCommentApiRequest,
+  IProjectCommentViewModel,
+} from '@app/types'
+import { commonRegex } from '@app/util'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create Project Comment', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/comments`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let payload: ICreateProjectCommentApiRequest
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const creatorId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+    payload = { body: 'When will this ship?' }
+  })
+
+  const createComment = async (
+    auth: string,
+    id = projectId,
+  ): Promise<IProjectCommentViewModel> => {
+    const response = await api
+      .post(testEndpoint(id))
+      .set('Authorization', auth)
+      .send(payload)
+      .expect(201)
+    return response.body
+  }
+
+  test('creates a top level comment', async () => {
+    const body = await createComment(userAuth)
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.project_id).toEqual(projectId)
+    expect(body.user_id).toEqual(userId)
+    expect(body.parent_id).toBeNull()
+    expect(body.body).toEqual(payload.body)
+    expect(body.badge).toBeNull()
+    expect(body.reply_count).toEqual(0)
+    expect(body.deleted).toEqual(false)
+    expect(body.hidden).toEqual(false)
+    expect(body.edited_at).toBeNull()
+    expect(body).not.toHaveProperty('report_count')
+  })
+
+  test('creator replies with a badge', async () => {
+    const parent = await createComment(userAuth)
+
+    payload = { body: 'Next month', parent_id: parent.id }
+    const body = await createComment(userAuthHeader(creatorId))
+
+    expect(body.parent_id).toEqual(parent.id)
+    expect(body.badge).toEqual(CommentBadge.Creator)
+    expect(body.report_count).toEqual(0)
+  })
+
+  test('rate limits frequent comments', async () => {
+    for (let i = 0; i < 5; i += 1) {
+      await createComment(userAuth)
+    }
+
+    await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(429, {
+        status: 429,
+        message: 'Commenting too often, try again later',
+        code: 'RateLimited',
+      })
+  })
+
+  test('admins are not rate limited', async () => {
+    for (let i = 0; i < 6; i += 1) {
+      await createComment(adminAuthHeader())
+    }
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when body is empty', async () => {
+      payload.body = ''
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 400 when parent is on another project', async () => {
+      const parent = await createComment(userAuth, '9e8f0c6f-1edf-4d68-a096-7a2bb4625c98')
+
+      payload = { body: 'Reply', parent_id: parent.id }
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Parent comment is not on this project',
+          code: 'None',
+        })
+    })
+
+    test('returns 400 when parent was deleted', async () => {
+      const parent = await createComment(userAuth)
+      await api
+        .delete(`/api/project-comments/${parent.id}`)
+        .set('Authorization', userAuth)
+        .expect(200)
+
+      payload = { body: 'Reply', parent_id: parent.id }
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Comment was deleted',
+          code: 'CommentDeleted',
+        })
+    })
+
+    test('returns 403 when project is not published', async () => {
+      await api
+        .post(testEndpoint('14bfe82a-1003-446b-b6bb-20a176e848e0'))
+        .set('Authorization', userAuthHeader(creatorId))
+        .send(payload)
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint(projectId)).send(payload).expect(401)
+    })
+  })
+})
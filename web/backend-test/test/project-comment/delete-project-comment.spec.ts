@@ -0,0 +1,75 @@
+import { IListProjectCommentsApiResponse } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Delete Project Comment', () => {
+  const testEndpoint = (id: string) => `/api/project-comments/${id}`
+  const commentsEndpoint = '/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/comments'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let commentId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+
+    const response = await api
+      .post(commentsEndpoint)
+      .set('Authorization', userAuth)
+      .send({ body: 'When will this ship?' })
+      .expect(201)
+    commentId = response.body.id
+  })
+
+  const getComment = async () => {
+    const response = await api.get(commentsEndpoint).expect(200)
+    const body: IListProjectCommentsApiResponse = response.body
+    return body.results.find((c) => c.id === commentId)
+  }
+
+  test('author soft deletes a comment', async () => {
+    await api.delete(testEndpoint(commentId)).set('Authorization', userAuth).expect(200)
+
+    const comment = await getComment()
+    expect(comment?.deleted).toEqual(true)
+    expect(comment?.body).toBeNull()
+
+    // Deleting again has no effect
+    await api.delete(testEndpoint(commentId)).set('Authorization', userAuth).expect(200)
+  })
+
+  test('project owner deletes a comment', async () => {
+    await api
+      .delete(testEndpoint(commentId))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(200)
+
+    expect((await getComment())?.deleted).toEqual(true)
+  })
+
+  test('returns 403 when user is not the author or a moderator', async () => {
+    const response = await api
+      .post(commentsEndpoint)
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .send({ body: 'Next month' })
+      .expect(201)
+
+    await api
+      .delete(testEndpoint(response.body.id))
+      .set('Authorization', userAuth)
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.delete(testEndpoint(commentId)).expect(401)
+  })
+})
@@ -0,0 +1,105 @@
+import { IListProjectCommentsApiResponse, IProjectCommentViewModel } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Hide Project Comment', () => {
+  const hideEndpoint = (id: string) => `/api/project-comments/${id}/actions/hide`
+  const restoreEndpoint = (id: string) => `/api/project-comments/${id}/actions/restore`
+  const commentsEndpoint = '/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/comments'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let moderatorAuth: string
+  let commentId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    moderatorAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post(commentsEndpoint)
+      .set('Authorization', userAuth)
+      .send({ body: 'Rude comment' })
+      .expect(201)
+    commentId = response.body.id
+  })
+
+  const getComment = async (auth?: string) => {
+    const request = api.get(commentsEndpoint)
+    if (auth) {
+      request.set('Authorization', auth)
+    }
+    const response = await request.expect(200)
+    const body: IListProjectCommentsApiResponse = response.body
+    return body.results.find((c) => c.id === commentId)
+  }
+
+  test('hidden comments are only readable by moderators', async () => {
+    const response = await api
+      .post(hideEndpoint(commentId))
+      .set('Authorization', moderatorAuth)
+      .expect(200)
+    const body: IProjectCommentViewModel = response.body
+    expect(body.hidden).toEqual(true)
+    expect(body.body).toEqual('Rude comment')
+
+    const comment = await getComment(userAuth)
+    expect(comment?.hidden).toEqual(true)
+    expect(comment?.body).toBeNull()
+    expect((await getComment(moderatorAuth))?.body).toEqual('Rude comment')
+  })
+
+  test('restores a hidden comment', async () => {
+    await api
+      .post(hideEndpoint(commentId))
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+
+    const response = await api
+      .post(restoreEndpoint(commentId))
+      .set('Authorization', moderatorAuth)
+      .expect(200)
+    const body: IProjectCommentViewModel = response.body
+    expect(body.hidden).toEqual(false)
+    expect((await getComment())?.body).toEqual('Rude comment')
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 403 when hiding as a user', async () => {
+      await api.post(hideEndpoint(commentId)).set('Authorization', userAuth).expect(403)
+    })
+
+    test('returns 403 when restoring as a user', async () => {
+      await api
+        .post(restoreEndpoint(commentId))
+        .set('Authorization', userAuth)
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(hideEndpoint(commentId)).expect(401)
+    })
+
+    test('returns 404 when comment does not exist', async () => {
+      await api
+        .post(hideEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', moderatorAuth)
+        .expect(404)
+    })
+  })
+})
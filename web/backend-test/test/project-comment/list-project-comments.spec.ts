@@ -0,0 +1,179 @@
+import {
+  CommentBadge,
+  IListProjectCommentsApiRequest,
+  IListProjectCommentsApiResponse,
+  IProjectCommentViewModel,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Project Comments', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/comments`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let creatorAuth: string
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    creatorAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+  })
+
+  const createComment = async (
+    auth: string,
+    body: string,
+    parent_id?: string,
+  ): Promise<IProjectCommentViewModel> => {
+    const response = await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', auth)
+      .send({ body, parent_id })
+      .expect(201)
+    return response.body
+  }
+
+  const listComments = async (
+    query?: IListProjectCommentsApiRequest,
+    auth?: string,
+  ): Promise<IListProjectCommentsApiResponse> => {
+    const request = api.get(testEndpoint(projectId)).query(query ?? {})
+    if (auth) {
+      request.set('Authorization', auth)
+    }
+    const response = await request.expect(200)
+    return response.body
+  }
+
+  const backProject = async (confirm: boolean) => {
+    const response = await api
+      .post(`/api/projects/${projectId}/actions/back`)
+      .set('Authorization', userAuth)
+      .send({
+        rewards: [{ reward_id: '8fe4b678-e9ac-4e1d-b37a-1254ec33656f', quantity: 1 }],
+      })
+      .expect(201)
+    if (confirm) {
+      await api
+        .patch(`/api/pledges/${response.body.id}`)
+        .set('Authorization', adminAuthHeader())
+        .send({ blockchain_status: 'Success' })
+        .expect(200)
+    }
+  }
+
+  test('lists top level comments newest first', async () => {
+    const first = await createComment(userAuth, 'First')
+    const second = await createComment(userAuth, 'Second')
+    await createComment(creatorAuth, 'Reply', first.id)
+
+    const body = await listComments()
+    expect(body.total).toEqual(2)
+    expect(body.results.map((c) => c.id)).toEqual([second.id, first.id])
+    expect(body.results[1].reply_count).toEqual(1)
+    expect(body.results[0]).not.toHaveProperty('report_count')
+  })
+
+  test('lists replies oldest first', async () => {
+    const parent = await createComment(userAuth, 'Question')
+    const first = await createComment(creatorAuth, 'First reply', parent.id)
+    const second = await createComment(userAuth, 'Second reply', parent.id)
+
+    const body = await listComments({ parent_id: parent.id })
+    expect(body.results.map((c) => c.id)).toEqual([first.id, second.id])
+  })
+
+  test('marks confirmed backers with a badge', async () => {
+    await createComment(userAuth, 'Before backing')
+    expect((await listComments()).results[0].badge).toBeNull()
+
+    await backProject(false)
+    expect((await listComments()).results[0].badge).toBeNull()
+
+    await backProject(true)
+    expect((await listComments()).results[0].badge).toEqual(CommentBadge.Backer)
+  })
+
+  test('marks the creator with a badge', async () => {
+    await createComment(creatorAuth, 'Thanks for backing')
+
+    expect((await listComments()).results[0].badge).toEqual(CommentBadge.Creator)
+  })
+
+  test('keeps deleted comments with replies without the body', async () => {
+    const parent = await createComment(userAuth, 'Question')
+    await createComment(creatorAuth, 'Answer', parent.id)
+    await api
+      .delete(`/api/project-comments/${parent.id}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+
+    const comment = (await listComments()).results[0]
+    expect(comment.id).toEqual(parent.id)
+    expect(comment.deleted).toEqual(true)
+    expect(comment.body).toBeNull()
+    expect(comment.reply_count).toEqual(1)
+  })
+
+  test('pages comments with a cursor', async () => {
+    for (const body of ['First', 'Second', 'Third']) {
+      await createComment(userAuth, body)
+    }
+
+    const page1 = await listComments({ limit: 2 })
+    expect(page1.results.map((c) => c.body)).toEqual(['Third', 'Second'])
+    expect(page1.total).toBeUndefined()
+    expect(page1.next_cursor).toBeDefined()
+
+    const page2 = await listComments({ limit: 2, cursor: page1.next_cursor })
+    expect(page2.results.map((c) => c.body)).toEqual(['First'])
+    expect(page2.next_cursor).toBeUndefined()
+  })
+
+  test('moderators list reported comments', async () => {
+    const comment = await createComment(userAuth, 'Spam')
+    await createComment(userAuth, 'Fine')
+    await api
+      .post(`/api/project-comments/${comment.id}/reports`)
+      .set('Authorization', creatorAuth)
+      .send({ reason: 'Spam link' })
+      .expect(201)
+
+    const body = await listComments({ reported: true }, creatorAuth)
+    expect(body.results.map((c) => c.id)).toEqual([comment.id])
+    expect(body.results[0].report_count).toEqual(1)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 403 when listing reported comments as a user', async () => {
+      await api
+        .get(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .query({ reported: true })
+        .expect(403)
+    })
+
+    test('returns 400 when parent ID is invalid', async () => {
+      await api.get(testEndpoint(projectId)).query({ parent_id: 'invalid' }).expect(400)
+    })
+
+    test('returns 403 when project is not published', async () => {
+      await api.get(testEndpoint('14bfe82a-1003-446b-b6bb-20a176e848e0')).expect(403)
+    })
+  })
+})
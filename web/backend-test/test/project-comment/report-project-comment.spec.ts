@@ -0,0 +1,78 @@
+import { IReportProjectCommentApiRequest } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Report Project Comment', () => {
+  const testEndpoint = (id: string) => `/api/project-comments/${id}/reports`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let reporterAuth: string
+  let commentId: string
+  let payload: IReportProjectCommentApiRequest
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    reporterAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    payload = { reason: 'Spam link' }
+
+    const response = await api
+      .post('/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/comments')
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .send({ body: 'Buy cheap players here' })
+      .expect(201)
+    commentId = response.body.id
+  })
+
+  test('reports a comment once', async () => {
+    await api
+      .post(testEndpoint(commentId))
+      .set('Authorization', reporterAuth)
+      .send(payload)
+      .expect(201)
+
+    await api
+      .post(testEndpoint(commentId))
+      .set('Authorization', reporterAuth)
+      .send(payload)
+      .expect(400, {
+        status: 400,
+        message: 'Comment already reported',
+        code: 'CommentReported',
+      })
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when reason is too short', async () => {
+      payload.reason = 'No'
+      await api
+        .post(testEndpoint(commentId))
+        .set('Authorization', reporterAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint(commentId)).send(payload).expect(401)
+    })
+
+    test('returns 404 when comment does not exist', async () => {
+      await api
+        .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', reporterAuth)
+        .send(payload)
+        .expect(404)
+    })
+  })
+})
@@ -0,0 +1,100 @@
+import { IProjectCommentViewModel, IUpdateProjectCommentApiRequest } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Update Project Comment', () => {
+  const testEndpoint = (id: string) => `/api/project-comments/${id}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let commentId: string
+  let payload: IUpdateProjectCommentApiRequest
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    payload = { body: 'When will this ship to Europe?' }
+
+    const response = await api
+      .post('/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/comments')
+      .set('Authorization', userAuth)
+      .send({ body: 'When will this ship?' })
+      .expect(201)
+    commentId = response.body.id
+  })
+
+  test('author edits a comment', async () => {
+    const response = await api
+      .patch(testEndpoint(commentId))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(200)
+    const body: IProjectCommentViewModel = response.body
+
+    expect(body.body).toEqual(payload.body)
+    expect(body.edited_at).not.toBeNull()
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when body is too long', async () => {
+      payload.body = 'a'.repeat(2001)
+      await api
+        .patch(testEndpoint(commentId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 400 when comment was deleted', async () => {
+      await api.delete(testEndpoint(commentId)).set('Authorization', userAuth).expect(200)
+
+      await api
+        .patch(testEndpoint(commentId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Comment was deleted',
+          code: 'CommentDeleted',
+        })
+    })
+
+    test('returns 403 when user is not the author', async () => {
+      await api
+        .patch(testEndpoint(commentId))
+        .set('Authorization', adminAuthHeader())
+        .send(payload)
+        .expect(403)
+    })
+
+    test('returns 404 when comment does not exist', async () => {
+      await api
+        .patch(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(404, {
+          status: 404,
+          message: 'Comment not found',
+          code: 'None',
+        })
+    })
+  })
+})
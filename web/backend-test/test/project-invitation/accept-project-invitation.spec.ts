@@ -0,0 +1,140 @@
+import {
+  IListProjectInvitationsApiResponse,
+  IProjectMemberViewModel,
+  ProjectMemberRole,
+} from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Accept Project Invitation', () => {
+  const testEndpoint = (id: string) => `/api/project-invitations/${id}/actions/accept`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let inviteeAuth: string
+  const inviteeId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    inviteeAuth = userAuthHeader(inviteeId)
+  })
+
+  const invite = async (role: ProjectMemberRole): Promise<string> => {
+    const response = await api
+      .post(`/api/projects/${projectId}/invitations`)
+      .set('Authorization', ownerAuth)
+      .send({ email: 'user3@crowdtrust.app', role })
+      .expect(201)
+    return response.body.id
+  }
+
+  const accept = async (role: ProjectMemberRole): Promise<IProjectMemberViewModel> => {
+    const invitationId = await invite(role)
+    const response = await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', inviteeAuth)
+      .expect(200)
+    return response.body
+  }
+
+  const renameProject = (auth: string) =>
+    api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', auth)
+      .send({ name: 'Game Box 2' })
+
+  test('invitee lists pending invitations', async () => {
+    const invitationId = await invite(ProjectMemberRole.Editor)
+
+    const response = await api
+      .get('/api/project-invitations')
+      .set('Authorization', inviteeAuth)
+      .expect(200)
+    const body: IListProjectInvitationsApiResponse = response.body
+    expect(body.results.map((i) => i.id)).toEqual([invitationId])
+  })
+
+  test('editor edits the project', async () => {
+    await renameProject(inviteeAuth).expect(403)
+
+    const member = await accept(ProjectMemberRole.Editor)
+    expect(member.project_id).toEqual(projectId)
+    expect(member.user_id).toEqual(inviteeId)
+    expect(member.role).toEqual(ProjectMemberRole.Editor)
+
+    await renameProject(inviteeAuth).expect(200)
+  })
+
+  test('viewer reads but does not edit the unpublished project', async () => {
+    await accept(ProjectMemberRole.Viewer)
+
+    await api
+      .get(`/api/projects/${projectId}`)
+      .set('Authorization', inviteeAuth)
+      .expect(200)
+    await renameProject(inviteeAuth).expect(403)
+  })
+
+  test('fulfillment member lists project pledges', async () => {
+    const listPledges = () =>
+      api
+        .get('/api/pledges')
+        .set('Authorization', inviteeAuth)
+        .query({ project_id: projectId })
+    await listPledges().expect(403)
+
+    await accept(ProjectMemberRole.Fulfillment)
+
+    await listPledges().expect(200)
+    await renameProject(inviteeAuth).expect(403)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when invitation was already accepted', async () => {
+      const invitationId = await invite(ProjectMemberRole.Viewer)
+      await api
+        .post(testEndpoint(invitationId))
+        .set('Authorization', inviteeAuth)
+        .expect(200)
+
+      await api
+        .post(testEndpoint(invitationId))
+        .set('Authorization', inviteeAuth)
+        .expect(400, {
+          status: 400,
+          message: 'Invitation is no longer pending',
+          code: 'InvitationClosed',
+        })
+    })
+
+    test('returns 403 when user is not the invitee', async () => {
+      const invitationId = await invite(ProjectMemberRole.Viewer)
+
+      await api
+        .post(testEndpoint(invitationId))
+        .set('Authorization', ownerAuth)
+        .expect(403)
+    })
+
+    test('returns 404 when invitation does not exist', async () => {
+      await api
+        .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', inviteeAuth)
+        .expect(404, {
+          status: 404,
+          message: 'Invitation not found',
+          code: 'None',
+        })
+    })
+  })
+})
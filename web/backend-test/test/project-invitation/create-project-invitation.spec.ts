@@ -0,0 +1,119 @@
+import {
+  ICreateProjectInvitationApiRequest,
+  IProjectInvitationViewModel,
+  ProjectInvitationState,
+  ProjectMemberRole,
+} from '@app/types'
+import {
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  USER3_ADDRESS,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create Project Invitation', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/invitations`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let payload: ICreateProjectInvitationApiRequest
+  const ownerId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader(ownerId)
+    payload = { email: 'user3@crowdtrust.app', role: ProjectMemberRole.Editor }
+  })
+
+  const postInvitation = () =>
+    api.post(testEndpoint(projectId)).set('Authorization', ownerAuth).send(payload)
+
+  test('invites by email', async () => {
+    const response = await postInvitation().expect(201)
+    const body: IProjectInvitationViewModel = response.body
+
+    expect(body.project_id).toEqual(projectId)
+    expect(body.email).toEqual(payload.email)
+    expect(body.eth_address).toBeNull()
+    expect(body.role).toEqual(ProjectMemberRole.Editor)
+    expect(body.state).toEqual(ProjectInvitationState.Pending)
+    expect(body.invited_by).toEqual(ownerId)
+  })
+
+  test('invites by eth address', async () => {
+    payload = { eth_address: USER3_ADDRESS, role: ProjectMemberRole.Viewer }
+    const response = await postInvitation().expect(201)
+    const body: IProjectInvitationViewModel = response.body
+
+    expect(body.email).toBeNull()
+    expect(body.eth_address).toEqual(USER3_ADDRESS)
+  })
+
+  test('owner lists project invitations', async () => {
+    await postInvitation().expect(201)
+
+    const response = await api
+      .get(testEndpoint(projectId))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    expect(response.body.results).toHaveLength(1)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when email and eth address are both set', async () => {
+      payload.eth_address = USER3_ADDRESS
+      await postInvitation().expect(400, {
+        status: 400,
+        message: 'Either email or eth_address is required',
+        code: 'InvalidFormData',
+      })
+    })
+
+    test('returns 400 when email and eth address are missing', async () => {
+      payload = { role: ProjectMemberRole.Editor }
+      await postInvitation().expect(400)
+    })
+
+    test('returns 400 when invitation is already pending', async () => {
+      await postInvitation().expect(201)
+
+      await postInvitation().expect(400, {
+        status: 400,
+        message: 'Invitation already pending',
+        code: 'InvitationExists',
+      })
+    })
+
+    test('returns 400 when user is already on the team', async () => {
+      payload.email = 'user1@crowdtrust.app'
+      await postInvitation().expect(400, {
+        status: 400,
+        message: 'User is already on the project team',
+        code: 'MemberExists',
+      })
+    })
+
+    test('returns 403 when user is not the owner', async () => {
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+        .send(payload)
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint(projectId)).send(payload).expect(401)
+    })
+  })
+})
@@ -0,0 +1,64 @@
+import { IProjectInvitationViewModel, ProjectInvitationState } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Decline Project Invitation', () => {
+  const testEndpoint = (id: string) => `/api/project-invitations/${id}/actions/decline`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let inviteeAuth: string
+  let invitationId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    inviteeAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post('/api/projects/14bfe82a-1003-446b-b6bb-20a176e848e0/invitations')
+      .set('Authorization', ownerAuth)
+      .send({ email: 'user3@crowdtrust.app', role: 'Viewer' })
+      .expect(201)
+    invitationId = response.body.id
+  })
+
+  test('invitee declines an invitation', async () => {
+    const response = await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', inviteeAuth)
+      .expect(200)
+    const body: IProjectInvitationViewModel = response.body
+    expect(body.state).toEqual(ProjectInvitationState.Declined)
+
+    const listResponse = await api
+      .get('/api/project-invitations')
+      .set('Authorization', inviteeAuth)
+      .expect(200)
+    expect(listResponse.body.results).toEqual([])
+  })
+
+  test('returns 400 when invitation was already declined', async () => {
+    await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', inviteeAuth)
+      .expect(200)
+
+    await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', inviteeAuth)
+      .expect(400)
+  })
+
+  test('returns 403 when user is not the invitee', async () => {
+    await api.post(testEndpoint(invitationId)).set('Authorization', ownerAuth).expect(403)
+  })
+})
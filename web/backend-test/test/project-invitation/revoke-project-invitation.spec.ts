@@ -0,0 +1,72 @@
+import { IProjectInvitationViewModel, ProjectInvitationState } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Revoke Project Invitation', () => {
+  const testEndpoint = (id: string) => `/api/project-invitations/${id}/actions/revoke`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let inviteeAuth: string
+  let invitationId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    inviteeAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post('/api/projects/14bfe82a-1003-446b-b6bb-20a176e848e0/invitations')
+      .set('Authorization', ownerAuth)
+      .send({ email: 'user3@crowdtrust.app', role: 'Editor' })
+      .expect(201)
+    invitationId = response.body.id
+  })
+
+  test('owner revokes an invitation', async () => {
+    const response = await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IProjectInvitationViewModel = response.body
+    expect(body.state).toEqual(ProjectInvitationState.Revoked)
+
+    // Revoked invitations can't be accepted
+    await api
+      .post(`/api/project-invitations/${invitationId}/actions/accept`)
+      .set('Authorization', inviteeAuth)
+      .expect(400, {
+        status: 400,
+        message: 'Invitation is no longer pending',
+        code: 'InvitationClosed',
+      })
+  })
+
+  test('admin revokes an invitation', async () => {
+    await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+  })
+
+  test('returns 403 when user is not the owner', async () => {
+    await api
+      .post(testEndpoint(invitationId))
+      .set('Authorization', inviteeAuth)
+      .expect(403)
+  })
+})
@@ -0,0 +1,73 @@
+import { IListProjectMembersApiResponse, ProjectMemberRole } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Delete Project Member', () => {
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+  const memberId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const testEndpoint = (userId: string) => `/api/projects/${projectId}/members/${userId}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let memberAuth: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    memberAuth = userAuthHeader(memberId)
+
+    const response = await api
+      .post(`/api/projects/${projectId}/invitations`)
+      .set('Authorization', ownerAuth)
+      .send({ email: 'user3@crowdtrust.app', role: ProjectMemberRole.Editor })
+      .expect(201)
+    await api
+      .post(`/api/project-invitations/${response.body.id}/actions/accept`)
+      .set('Authorization', memberAuth)
+      .expect(200)
+  })
+
+  const listMembers = async (): Promise<IListProjectMembersApiResponse> => {
+    const response = await api
+      .get(`/api/projects/${projectId}/members`)
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    return response.body
+  }
+
+  test('owner removes a member', async () => {
+    await api.delete(testEndpoint(memberId)).set('Authorization', ownerAuth).expect(200)
+
+    expect((await listMembers()).results).toEqual([])
+    // Removed members lose access to the unpublished project
+    await api
+      .get(`/api/projects/${projectId}`)
+      .set('Authorization', memberAuth)
+      .expect(403)
+  })
+
+  test('member leaves the team', async () => {
+    await api.delete(testEndpoint(memberId)).set('Authorization', memberAuth).expect(200)
+
+    expect((await listMembers()).results).toEqual([])
+  })
+
+  test('returns 403 when member removes another user', async () => {
+    await api
+      .delete(testEndpoint('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .set('Authorization', memberAuth)
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.delete(testEndpoint(memberId)).expect(401)
+  })
+})
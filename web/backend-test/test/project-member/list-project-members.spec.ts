@@ -0,0 +1,84 @@
+import { IListProjectMembersApiResponse, ProjectMemberRole } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Project Members', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/members`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let memberAuth: string
+  const ownerId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const memberId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader(ownerId)
+    memberAuth = userAuthHeader(memberId)
+  })
+
+  const addMember = async () => {
+    const response = await api
+      .post(`/api/projects/${projectId}/invitations`)
+      .set('Authorization', ownerAuth)
+      .send({ email: 'user3@crowdtrust.app', role: ProjectMemberRole.Viewer })
+      .expect(201)
+    await api
+      .post(`/api/project-invitations/${response.body.id}/actions/accept`)
+      .set('Authorization', memberAuth)
+      .expect(200)
+  }
+
+  const listMembers = async (auth: string): Promise<IListProjectMembersApiResponse> => {
+    const response = await api
+      .get(testEndpoint(projectId))
+      .set('Authorization', auth)
+      .expect(200)
+    return response.body
+  }
+
+  test('owner lists members', async () => {
+    expect((await listMembers(ownerAuth)).results).toEqual([])
+
+    await addMember()
+
+    const body = await listMembers(ownerAuth)
+    expect(body.results).toHaveLength(1)
+    expect(body.results[0].user_id).toEqual(memberId)
+    expect(body.results[0].role).toEqual(ProjectMemberRole.Viewer)
+    expect(body.results[0].invited_by).toEqual(ownerId)
+  })
+
+  test('members and admins list members', async () => {
+    await addMember()
+
+    expect((await listMembers(memberAuth)).results).toHaveLength(1)
+    expect((await listMembers(adminAuthHeader())).results).toHaveLength(1)
+  })
+
+  test('returns 403 when user is not on the team', async () => {
+    await api.get(testEndpoint(projectId)).set('Authorization', memberAuth).expect(403)
+  })
+
+  test('returns 404 when project does not exist', async () => {
+    await api
+      .get(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+      .set('Authorization', ownerAuth)
+      .expect(404)
+  })
+})
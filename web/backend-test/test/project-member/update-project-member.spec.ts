@@ -0,0 +1,88 @@
+import {
+  IProjectMemberViewModel,
+  IUpdateProjectMemberApiRequest,
+  ProjectMemberRole,
+} from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Update Project Member', () => {
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+  const memberId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const testEndpoint = (userId: string) => `/api/projects/${projectId}/members/${userId}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let memberAuth: string
+  let payload: IUpdateProjectMemberApiRequest
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    memberAuth = userAuthHeader(memberId)
+    payload = { role: ProjectMemberRole.Editor }
+
+    const response = await api
+      .post(`/api/projects/${projectId}/invitations`)
+      .set('Authorization', ownerAuth)
+      .send({ email: 'user3@crowdtrust.app', role: ProjectMemberRole.Viewer })
+      .expect(201)
+    await api
+      .post(`/api/project-invitations/${response.body.id}/actions/accept`)
+      .set('Authorization', memberAuth)
+      .expect(200)
+  })
+
+  test('owner changes a member role', async () => {
+    const renameProject = () =>
+      api
+        .patch(`/api/projects/${projectId}`)
+        .set('Authorization', memberAuth)
+        .send({ name: 'Game Box 2' })
+    await renameProject().expect(403)
+
+    const response = await api
+      .patch(testEndpoint(memberId))
+      .set('Authorization', ownerAuth)
+      .send(payload)
+      .expect(200)
+    const body: IProjectMemberViewModel = response.body
+    expect(body.role).toEqual(ProjectMemberRole.Editor)
+
+    await renameProject().expect(200)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when role is invalid', async () => {
+      await api
+        .patch(testEndpoint(memberId))
+        .set('Authorization', ownerAuth)
+        .send({ role: 'Owner' })
+        .expect(400)
+    })
+
+    test('returns 403 when member changes their own role', async () => {
+      await api
+        .patch(testEndpoint(memberId))
+        .set('Authorization', memberAuth)
+        .send(payload)
+        .expect(403)
+    })
+
+    test('returns 404 when user is not a member', async () => {
+      await api
+        .patch(testEndpoint('f481a6d5-ad06-4c3e-b3a5-4af0be50bb29'))
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(404)
+    })
+  })
+})
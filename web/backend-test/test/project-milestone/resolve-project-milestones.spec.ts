@@ -0,0 +1,81 @@
+import {
+  IListProjectMilestonesApiResponse,
+  IResolveProjectMilestonesApiResponse,
+  MilestoneState,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Resolve Project Milestones', () => {
+  const testEndpoint = '/api/project-milestones/actions/resolve-ended'
+  // Seeded milestone with a voting window that ended without votes
+  const milestoneId = '6f4c1d2e-8b3a-4e5f-9c7d-2a1b0e9f8d76'
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+  })
+
+  test('rejects a milestone with no votes', async () => {
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const body: IResolveProjectMilestonesApiResponse = response.body
+
+    expect(body.approved).toEqual([])
+    expect(body.rejected).toEqual([milestoneId])
+
+    const listResponse = await api
+      .get(`/api/projects/${projectId}/milestones`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const list: IListProjectMilestonesApiResponse = listResponse.body
+    const milestone = list.results.find((m) => m.id === milestoneId)
+
+    expect(milestone?.state).toEqual(MilestoneState.Rejected)
+    expect(list.released_percent).toEqual(0)
+  })
+
+  test('resolves each milestone once', async () => {
+    await api.post(testEndpoint).set('Authorization', adminAuth).expect(200)
+
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const body: IResolveProjectMilestonesApiResponse = response.body
+
+    expect(body.approved).toEqual([])
+    expect(body.rejected).toEqual([])
+  })
+
+  test('returns 403 when requestor is User', () => {
+    return api
+      .post(testEndpoint)
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 401 when unauthorized', () => {
+    return api.post(testEndpoint).expect(401)
+  })
+})
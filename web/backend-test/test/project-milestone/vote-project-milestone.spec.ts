@@ -0,0 +1,147 @@
+import {
+  ICreateProjectMilestoneApiRequest,
+  IProjectMilestoneViewModel,
+  IVoteProjectMilestoneApiRequest,
+  MilestoneState,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  dayToSec,
+  now,
+  signMessage,
+  testagent,
+  TestAgent,
+  USER3_ADDRESS,
+  USER3_PRIVATE_KEY,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Vote Project Milestone', () => {
+  const testEndpoint = (id: string) => `/api/project-milestones/${id}/votes`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let ownerAuth: string
+  let user1Auth: string
+  let projectId: string
+  let milestoneId: string
+
+  const sign = (approve: boolean, id: string) => {
+    const action = approve ? 'Approve' : 'Reject'
+    const message = `${action} CrowdTrust milestone ${id} with ${USER3_ADDRESS}`
+    return signMessage(USER3_PRIVATE_KEY, message)
+  }
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    // User3 owns the project, and has a confirmed pledge to it
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    user1Auth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+    // User1 backs the project, but the pledge isn't confirmed on-chain
+    await api
+      .post(`/api/projects/${projectId}/actions/back`)
+      .set('Authorization', user1Auth)
+      .send({
+        rewards: [{ reward_id: '8fe4b678-e9ac-4e1d-b37a-1254ec33656f', quantity: 1 }],
+      })
+      .expect(201)
+
+    // End the project, and fund it
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', adminAuth)
+      .send({
+        start_time: now() - dayToSec(2),
+        duration: dayToSec(1),
+        funding_goal: '100000000000000000',
+      })
+      .expect(200)
+    await api
+      .post('/api/projects/actions/evaluate-ended')
+      .set('Authorization', adminAuth)
+      .expect(200)
+
+    const payload: ICreateProjectMilestoneApiRequest = {
+      title: 'First prototype',
+      description: 'A working prototype of the product',
+      target_date: now() + dayToSec(30),
+      release_percent: 50,
+    }
+    const response = await api
+      .post(`/api/projects/${projectId}/milestones`)
+      .set('Authorization', ownerAuth)
+      .send(payload)
+      .expect(201)
+    const body: IProjectMilestoneViewModel = response.body
+    milestoneId = body.id
+
+    await api
+      .post(`/api/project-milestones/${milestoneId}/actions/open-voting`)
+      .set('Authorization', ownerAuth)
+      .expect(200)
+  })
+
+  test('backer with confirmed pledge votes', async () => {
+    const payload: IVoteProjectMilestoneApiRequest = {
+      approve: true,
+      eth_address_signature: await sign(true, milestoneId),
+    }
+    const response = await api
+      .post(testEndpoint(milestoneId))
+      .set('Authorization', ownerAuth)
+      .send(payload)
+      .expect(200)
+    const body: IProjectMilestoneViewModel = response.body
+
+    expect(body.state).toEqual(MilestoneState.Voting)
+    expect(body.vote_count).toEqual(1)
+    expect(body.approve_weight).toEqual('100000000000000000')
+    expect(body.reject_weight).toEqual('0')
+
+    // Votes can't be repeated
+    await api
+      .post(testEndpoint(milestoneId))
+      .set('Authorization', ownerAuth)
+      .send(payload)
+      .expect(400, {
+        status: 400,
+        message: 'Already voted on this milestone',
+        code: 'VoteExists',
+      })
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 403 when pledge is not confirmed', async () => {
+      await api
+        .post(testEndpoint(milestoneId))
+        .set('Authorization', user1Auth)
+        .send({ approve: true, eth_address_signature: await sign(true, milestoneId) })
+        .expect(403)
+    })
+
+    test('when signature does not match the vote', async () => {
+      await api
+        .post(testEndpoint(milestoneId))
+        .set('Authorization', ownerAuth)
+        .send({ approve: false, eth_address_signature: await sign(true, milestoneId) })
+        .expect(400, {
+          status: 400,
+          message: 'Failed to verify signature',
+          code: 'InvalidSignature',
+        })
+    })
+  })
+})
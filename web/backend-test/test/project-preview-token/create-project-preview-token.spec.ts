@@ -0,0 +1,136 @@
+import {
+  ICreateProjectPreviewTokenApiRequest,
+  ICreateProjectPreviewTokenApiResponse,
+  IGetProjectApiRequest,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create Project Preview Token', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/preview-tokens`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let projectId: string
+  let payload: ICreateProjectPreviewTokenApiRequest
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    payload = { ttl: 60 }
+  })
+
+  const createToken = async (
+    auth: string,
+  ): Promise<ICreateProjectPreviewTokenApiResponse> => {
+    const response = await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', auth)
+      .send(payload)
+      .expect(201)
+    return response.body
+  }
+
+  describe('when requester is the owner', () => {
+    test('creates a preview token', async () => {
+      const body = await createToken(ownerAuth)
+
+      expect(body.token).toBeDefined()
+      expect(body.preview_token.project_id).toEqual(projectId)
+      expect(body.preview_token.user_id).toEqual('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+      expect(body.preview_token.view_count).toEqual(0)
+      expect(body.preview_token.revoked_at).toBeNull()
+      expect(body.preview_token.last_viewed_at).toBeNull()
+    })
+
+    test('preview token grants access to unpublished project', async () => {
+      const { token } = await createToken(ownerAuth)
+      await api.get(`/api/projects/${projectId}`).expect(403)
+
+      const query: IGetProjectApiRequest = { preview_token: token }
+      const response = await api
+        .get(`/api/projects/${projectId}`)
+        .query(query)
+        .expect(200)
+      expect(response.body.id).toEqual(projectId)
+    })
+
+    test('preview token does not grant access to another project', async () => {
+      const { token } = await createToken(ownerAuth)
+
+      await api
+        .get('/api/projects/a3a2b1c4-a1ee-42d5-a729-bb6ff6fdfdfe')
+        .query({ preview_token: token })
+        .expect(403)
+    })
+
+    test('returns 403 when preview token is malformed', async () => {
+      await api
+        .get(`/api/projects/${projectId}`)
+        .query({ preview_token: 'not-a-token' })
+        .expect(403, {
+          status: 403,
+          message: 'Invalid or expired preview token',
+          code: 'InvalidPreview',
+        })
+    })
+  })
+
+  describe('when requester is Admin', () => {
+    test('creates a preview token', async () => {
+      const body = await createToken(adminAuthHeader())
+
+      expect(body.preview_token.project_id).toEqual(projectId)
+    })
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when ttl is out of range', async () => {
+      payload.ttl = 0
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 403 when user is not the owner', async () => {
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+        .send(payload)
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint(projectId)).send(payload).expect(401)
+    })
+
+    test('returns 404 when project does not exist', async () => {
+      await api
+        .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(404)
+    })
+  })
+})
@@ -0,0 +1,90 @@
+import {
+  ICreateProjectPreviewTokenApiResponse,
+  IListProjectPreviewTokensApiResponse,
+} from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Project Preview Tokens', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/preview-tokens`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+  })
+
+  const createToken = async (): Promise<ICreateProjectPreviewTokenApiResponse> => {
+    const response = await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', ownerAuth)
+      .send({ ttl: 60 })
+      .expect(201)
+    return response.body
+  }
+
+  const listTokens = async (): Promise<IListProjectPreviewTokensApiResponse> => {
+    const response = await api
+      .get(testEndpoint(projectId))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    return response.body
+  }
+
+  test('lists preview tokens without the token value', async () => {
+    const created = await createToken()
+
+    const body = await listTokens()
+    expect(body.results).toHaveLength(1)
+    expect(body.results[0].id).toEqual(created.preview_token.id)
+    expect(body.results[0]).not.toHaveProperty('token')
+  })
+
+  test('counts views of the project', async () => {
+    const { token } = await createToken()
+
+    const viewProject = () =>
+      api.get(`/api/projects/${projectId}`).query({ preview_token: token }).expect(200)
+    await viewProject()
+    await viewProject()
+
+    const body = await listTokens()
+    expect(body.results[0].view_count).toEqual(2)
+    expect(body.results[0].last_viewed_at).toBeDefined()
+  })
+
+  test('owner views do not count', async () => {
+    const { token } = await createToken()
+
+    await api
+      .get(`/api/projects/${projectId}`)
+      .set('Authorization', ownerAuth)
+      .query({ preview_token: token })
+      .expect(200)
+
+    const body = await listTokens()
+    expect(body.results[0].view_count).toEqual(0)
+  })
+
+  test('returns 403 when user is not the owner', async () => {
+    await api
+      .get(testEndpoint(projectId))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.get(testEndpoint(projectId)).expect(401)
+  })
+})
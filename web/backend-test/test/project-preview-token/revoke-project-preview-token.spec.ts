@@ -0,0 +1,81 @@
+import {
+  ICreateProjectPreviewTokenApiResponse,
+  IProjectPreviewTokenViewModel,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Revoke Project Preview Token', () => {
+  const testEndpoint = (id: string) => `/api/project-preview-tokens/${id}/actions/revoke`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let created: ICreateProjectPreviewTokenApiResponse
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+
+    const response = await api
+      .post(`/api/projects/${projectId}/preview-tokens`)
+      .set('Authorization', ownerAuth)
+      .send({ ttl: 60 })
+      .expect(201)
+    created = response.body
+  })
+
+  test('revoked token no longer grants access', async () => {
+    const response = await api
+      .post(testEndpoint(created.preview_token.id))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IProjectPreviewTokenViewModel = response.body
+
+    expect(body.id).toEqual(created.preview_token.id)
+    expect(body.revoked_at).toBeDefined()
+    await api
+      .get(`/api/projects/${projectId}`)
+      .query({ preview_token: created.token })
+      .expect(403, {
+        status: 403,
+        message: 'Invalid or expired preview token',
+        code: 'InvalidPreview',
+      })
+  })
+
+  test('admin revokes token', async () => {
+    await api
+      .post(testEndpoint(created.preview_token.id))
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+  })
+
+  test('returns 403 when user is not the owner', async () => {
+    await api
+      .post(testEndpoint(created.preview_token.id))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 404 when token does not exist', async () => {
+    await api
+      .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+      .set('Authorization', ownerAuth)
+      .expect(404)
+  })
+})
@@ -0,0 +1,164 @@
+import {
+  IBackProjectApiResponse,
+  IGetPledgeViewModel,
+  IListProjectRevisionsApiRequest,
+  IListProjectRevisionsApiResponse,
+  RevisionAction,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Project Revisions', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/revisions`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let ownerAuth: string
+  const ownerId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+  const rewardId = '1ab089a5-89eb-458f-bf04-15518e9e866f'
+  const activeProjectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader(ownerId)
+  })
+
+  const listRevisions = async (
+    id: string,
+    auth?: string,
+    query?: IListProjectRevisionsApiRequest,
+  ): Promise<IListProjectRevisionsApiResponse> => {
+    const request = api.get(testEndpoint(id)).query(query ?? {})
+    if (auth) {
+      request.set('Authorization', auth)
+    }
+    const response = await request.expect(200)
+    return response.body
+  }
+
+  test('records changed project fields', async () => {
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', ownerAuth)
+      .send({ name: 'Game Box Deluxe' })
+      .expect(200)
+
+    const body = await listRevisions(projectId, ownerAuth)
+    expect(body.total).toEqual(1)
+    const revision = body.results[0]
+    expect(revision.action).toEqual(RevisionAction.Update)
+    expect(revision.user_id).toEqual(ownerId)
+    expect(revision.reward_id).toBeNull()
+    expect(revision.changes).toEqual([
+      { field: 'name', old: 'Game Box', new: 'Game Box Deluxe' },
+    ])
+  })
+
+  test('does not record updates without changes', async () => {
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', ownerAuth)
+      .send({ name: 'Game Box' })
+      .expect(200)
+
+    const body = await listRevisions(projectId, ownerAuth)
+    expect(body.total).toEqual(0)
+  })
+
+  test('records reward changes and filters by reward', async () => {
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', ownerAuth)
+      .send({ name: 'Game Box Deluxe' })
+      .expect(200)
+    await api
+      .patch(`/api/rewards/${rewardId}`)
+      .set('Authorization', ownerAuth)
+      .send({ name: 'Basic Handheld Deluxe' })
+      .expect(200)
+
+    expect((await listRevisions(projectId, ownerAuth)).total).toEqual(2)
+    const body = await listRevisions(projectId, ownerAuth, { reward_id: rewardId })
+    expect(body.total).toEqual(1)
+    expect(body.results[0].reward_id).toEqual(rewardId)
+    expect(body.results[0].changes).toEqual([
+      { field: 'name', old: 'Basic Handheld', new: 'Basic Handheld Deluxe' },
+    ])
+  })
+
+  test('lists admin changes to a published project without authorization', async () => {
+    await api
+      .patch(`/api/projects/${activeProjectId}`)
+      .set('Authorization', adminAuth)
+      .send({ name: 'Vintage Player Pro' })
+      .expect(200)
+
+    const body = await listRevisions(activeProjectId)
+    expect(body.total).toEqual(1)
+    expect(body.results[0].user_id).toEqual('f481a6d5-ad06-4c3e-b3a5-4af0be50bb29')
+  })
+
+  test('pledge keeps the terms it was made against', async () => {
+    const backResponse = await api
+      .post(`/api/projects/${activeProjectId}/actions/back`)
+      .set('Authorization', ownerAuth)
+      .send({
+        rewards: [{ reward_id: '8fe4b678-e9ac-4e1d-b37a-1254ec33656f', quantity: 1 }],
+      })
+      .expect(201)
+    const pledge: IBackProjectApiResponse = backResponse.body
+    await api
+      .patch(`/api/projects/${activeProjectId}`)
+      .set('Authorization', adminAuth)
+      .send({ name: 'Vintage Player Pro' })
+      .expect(200)
+
+    const response = await api
+      .get(`/api/pledges/${pledge.id}`)
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IGetPledgeViewModel = response.body
+    expect(body.terms?.project.name).toEqual('Vintage Player')
+    expect(body.terms?.rewards.map((r) => r.id)).toEqual([
+      '8fe4b678-e9ac-4e1d-b37a-1254ec33656f',
+    ])
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when reward ID is invalid', async () => {
+      await api
+        .get(testEndpoint(projectId))
+        .set('Authorization', ownerAuth)
+        .query({ reward_id: 'invalid' })
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 403 when project is not published', async () => {
+      await api.get(testEndpoint(projectId)).expect(403)
+    })
+
+    test('returns 404 when project does not exist', async () => {
+      await api.get(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968')).expect(404)
+    })
+  })
+})
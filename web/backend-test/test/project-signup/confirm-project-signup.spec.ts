@@ -0,0 +1,65 @@
+import { IConfirmProjectSignupApiRequest } from '@app/types'
+import {
+  AppDbResetService,
+  generateConfirmToken,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Confirm Project Signup', () => {
+  const testEndpoint = '/api/project-signups/confirm'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  // Unconfirmed signup to project d13b990d
+  const signupId = 'a47d0f92-3c61-4e8b-9d25-6f1b8e0c4a73'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+  })
+
+  test('confirms a signup', async () => {
+    const payload: IConfirmProjectSignupApiRequest = {
+      code: generateConfirmToken(signupId),
+    }
+
+    await api.post(testEndpoint).send(payload).expect(200)
+
+    const response = await api
+      .get('/api/projects/d13b990d-172e-4a01-aeea-43f6ef505a7c/signups/counts')
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .expect(200)
+    expect(response.body.confirmed).toEqual(2)
+  })
+
+  test('returns 400 when code is expired', async () => {
+    await api
+      .post(testEndpoint)
+      .send({ code: generateConfirmToken(signupId, '-90s') })
+      .expect(400, {
+        status: 400,
+        message: 'Confirm token decode error: ExpiredSignature',
+        code: 'ConfirmExpired',
+      })
+  })
+
+  test('returns 400 when signup does not exist', async () => {
+    await api
+      .post(testEndpoint)
+      .send({ code: generateConfirmToken('cbd7a9ff-18f5-489e-b61e-cdd4a1394968') })
+      .expect(400, {
+        status: 400,
+        message: 'Signup not found',
+        code: 'None',
+      })
+  })
+})
@@ -0,0 +1,92 @@
+import { ICreateProjectSignupApiRequest, IProjectSignupCountsViewModel } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create Project Signup', () => {
+  const testEndpoint = (projectId: string) => `/api/projects/${projectId}/signups`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  const projectId = 'd13b990d-172e-4a01-aeea-43f6ef505a7c'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+  })
+
+  const getCounts = async (): Promise<IProjectSignupCountsViewModel> => {
+    const response = await api
+      .get(`${testEndpoint(projectId)}/counts`)
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .expect(200)
+    return response.body
+  }
+
+  test('anonymous visitors sign up to a Prelaunch project', async () => {
+    const payload: ICreateProjectSignupApiRequest = { email: 'New.Fan@Example.com' }
+
+    await api.post(testEndpoint(projectId)).send(payload).expect(202)
+
+    expect(await getCounts()).toEqual({ total: 3, confirmed: 1, notified: 0 })
+  })
+
+  test('signing up again does not add a signup', async () => {
+    await api
+      .post(testEndpoint(projectId))
+      .send({ email: 'FAN1@example.com' })
+      .expect(202)
+
+    expect(await getCounts()).toEqual({ total: 2, confirmed: 1, notified: 0 })
+  })
+
+  test('returns 429 when an email signs up too often', async () => {
+    const payload = { email: 'new.fan@example.com' }
+    await api.post(testEndpoint(projectId)).send(payload).expect(202)
+
+    await api
+      .post(testEndpoint('0c9d3f3e-8027-4582-b573-99b2d6f87ebc'))
+      .send(payload)
+      .expect(429, {
+        status: 429,
+        message: 'Signing up too often, try again later',
+        code: 'RateLimited',
+      })
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when project is not Prelaunch', async () => {
+      await api
+        .post(testEndpoint('3e42e273-546d-4989-a97c-f6eb173e8450'))
+        .send({ email: 'new.fan@example.com' })
+        .expect(400, {
+          status: 400,
+          message: 'Project is not accepting signups',
+          code: 'SignupClosed',
+        })
+    })
+
+    test('returns 400 when email is not valid', async () => {
+      await api
+        .post(testEndpoint(projectId))
+        .send({ email: 'not-an-email' })
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 404 when project does not exist', async () => {
+      await api
+        .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .send({ email: 'new.fan@example.com' })
+        .expect(404)
+    })
+  })
+})
@@ -0,0 +1,57 @@
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Export Project Signups', () => {
+  const testEndpoint = '/api/projects/d13b990d-172e-4a01-aeea-43f6ef505a7c/signups/export'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+  })
+
+  test('exports confirmed signups as CSV', async () => {
+    const response = await api
+      .get(testEndpoint)
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .expect(200)
+
+    expect(response.headers['content-type']).toEqual('text/csv; charset=utf-8')
+    expect(response.headers['content-disposition']).toEqual(
+      'attachment; filename="signups-donate-to-the-needy.csv"',
+    )
+    const lines = response.text.trim().split('\n')
+    expect(lines[0]).toEqual('email,confirmed_at,notified_at')
+    expect(lines).toHaveLength(2)
+    expect(lines[1]).toMatch(/^"fan1@example.com",\S+,$/)
+  })
+
+  test('exports signups for Admin', async () => {
+    await api.get(testEndpoint).set('Authorization', adminAuthHeader()).expect(200)
+  })
+
+  test('returns 403 when user is not a project member', async () => {
+    await api
+      .get(testEndpoint)
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.get(testEndpoint).expect(401)
+  })
+})
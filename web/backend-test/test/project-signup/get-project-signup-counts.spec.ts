@@ -0,0 +1,74 @@
+import { IProjectSignupCountsViewModel } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  generateConfirmToken,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Get Project Signup Counts', () => {
+  const projectId = 'd13b990d-172e-4a01-aeea-43f6ef505a7c'
+  const testEndpoint = `/api/projects/${projectId}/signups/counts`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+  })
+
+  test('returns signup counts to the creator', async () => {
+    const response = await api
+      .get(testEndpoint)
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IProjectSignupCountsViewModel = response.body
+
+    expect(body).toEqual({ total: 2, confirmed: 1, notified: 0 })
+  })
+
+  test('confirmed signups are notified once when the project launches', async () => {
+    await api
+      .post('/api/project-signups/confirm')
+      .send({ code: generateConfirmToken('a47d0f92-3c61-4e8b-9d25-6f1b8e0c4a73') })
+      .expect(200)
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', adminAuthHeader())
+      .send({ status: 'Active' })
+      .expect(200)
+
+    const response = await api
+      .get(testEndpoint)
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    expect(response.body).toEqual({ total: 2, confirmed: 2, notified: 2 })
+  })
+
+  test('returns signup counts to Admin', async () => {
+    await api.get(testEndpoint).set('Authorization', adminAuthHeader()).expect(200)
+  })
+
+  test('returns 403 when user is not a project member', async () => {
+    await api
+      .get(testEndpoint)
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.get(testEndpoint).expect(401)
+  })
+})
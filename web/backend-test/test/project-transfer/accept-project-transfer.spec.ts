@@ -0,0 +1,113 @@
+import {
+  IAcceptProjectTransferApiRequest,
+  IListProjectTransfersApiResponse,
+  IProjectTransferViewModel,
+  IProjectViewModel,
+} from '@app/types'
+import {
+  AppDbResetService,
+  signMessage,
+  testagent,
+  TestAgent,
+  TEST_PRIVATE_KEY1,
+  USER3_ADDRESS,
+  USER3_PRIVATE_KEY,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Accept Project Transfer', () => {
+  const testEndpoint = (id: string) => `/api/project-transfers/${id}/actions/accept`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let recipientAuth: string
+  let projectId: string
+  let transferId: string
+
+  const sign = (key: string, id: string) =>
+    signMessage(key, `Accept CrowdTrust project transfer ${id} with ${USER3_ADDRESS}`)
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    recipientAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+    const response = await api
+      .post(`/api/projects/${projectId}/transfers`)
+      .set('Authorization', ownerAuth)
+      .send({ eth_address: USER3_ADDRESS })
+      .expect(201)
+    const body: IProjectTransferViewModel = response.body
+    transferId = body.id
+  })
+
+  test('recipient sees pending transfer', async () => {
+    const response = await api
+      .get('/api/project-transfers')
+      .set('Authorization', recipientAuth)
+      .expect(200)
+    const body: IListProjectTransfersApiResponse = response.body
+
+    expect(body.results.map((t) => t.id)).toEqual([transferId])
+  })
+
+  test('recipient accepts transfer', async () => {
+    const payload: IAcceptProjectTransferApiRequest = {
+      eth_address_signature: await sign(USER3_PRIVATE_KEY, transferId),
+      update_payment_address: true,
+    }
+    const response = await api
+      .post(testEndpoint(transferId))
+      .set('Authorization', recipientAuth)
+      .send(payload)
+      .expect(200)
+    const body: IProjectViewModel = response.body
+
+    expect(body.id).toEqual(projectId)
+    expect(body.user_id).toEqual('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    expect(body.payment_address).toEqual(USER3_ADDRESS)
+
+    // Transfer can't be accepted twice
+    await api
+      .post(testEndpoint(transferId))
+      .set('Authorization', recipientAuth)
+      .send(payload)
+      .expect(400, {
+        status: 400,
+        message: 'Transfer is no longer pending',
+        code: 'TransferClosed',
+      })
+  })
+
+  describe('when request is not valid', () => {
+    test('when signature is from another wallet', async () => {
+      await api
+        .post(testEndpoint(transferId))
+        .set('Authorization', recipientAuth)
+        .send({ eth_address_signature: await sign(TEST_PRIVATE_KEY1, transferId) })
+        .expect(400, {
+          status: 400,
+          message: 'Failed to verify signature',
+          code: 'InvalidSignature',
+        })
+    })
+
+    test('returns 403 when user is not the recipient', async () => {
+      await api
+        .post(testEndpoint(transferId))
+        .set('Authorization', ownerAuth)
+        .send({ eth_address_signature: await sign(USER3_PRIVATE_KEY, transferId) })
+        .expect(403)
+    })
+  })
+})
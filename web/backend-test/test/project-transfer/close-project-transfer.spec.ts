@@ -0,0 +1,85 @@
+import { IProjectTransferViewModel, ProjectTransferState } from '@app/types'
+import {
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  USER3_ADDRESS,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Decline and Cancel Project Transfer', () => {
+  const actionEndpoint = (id: string, action: string) =>
+    `/api/project-transfers/${id}/actions/${action}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let recipientAuth: string
+  let transferId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    recipientAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post('/api/projects/14bfe82a-1003-446b-b6bb-20a176e848e0/transfers')
+      .set('Authorization', ownerAuth)
+      .send({ eth_address: USER3_ADDRESS })
+      .expect(201)
+    const body: IProjectTransferViewModel = response.body
+    transferId = body.id
+  })
+
+  test('recipient declines transfer', async () => {
+    const response = await api
+      .post(actionEndpoint(transferId, 'decline'))
+      .set('Authorization', recipientAuth)
+      .expect(200)
+    const body: IProjectTransferViewModel = response.body
+
+    expect(body.state).toEqual(ProjectTransferState.Declined)
+  })
+
+  test('owner cancels transfer', async () => {
+    const response = await api
+      .post(actionEndpoint(transferId, 'cancel'))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IProjectTransferViewModel = response.body
+
+    expect(body.state).toEqual(ProjectTransferState.Cancelled)
+
+    // Closed transfers can't be declined
+    await api
+      .post(actionEndpoint(transferId, 'decline'))
+      .set('Authorization', recipientAuth)
+      .expect(400, {
+        status: 400,
+        message: 'Transfer is no longer pending',
+        code: 'TransferClosed',
+      })
+  })
+
+  test('returns 403 when owner declines transfer', async () => {
+    await api
+      .post(actionEndpoint(transferId, 'decline'))
+      .set('Authorization', ownerAuth)
+      .expect(403)
+  })
+
+  test('returns 403 when recipient cancels transfer', async () => {
+    await api
+      .post(actionEndpoint(transferId, 'cancel'))
+      .set('Authorization', recipientAuth)
+      .expect(403)
+  })
+})
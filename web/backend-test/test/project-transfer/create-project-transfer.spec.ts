@@ -0,0 +1,110 @@
+import {
+  ICreateProjectTransferApiRequest,
+  IProjectTransferViewModel,
+  ProjectTransferState,
+} from '@app/types'
+import { commonRegex } from '@app/util'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  USER3_ADDRESS,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create Project Transfer', () => {
+  const testEndpoint = (projectId: string) => `/api/projects/${projectId}/transfers`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: ICreateProjectTransferApiRequest
+  let userAuth: string
+  let projectId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    payload = { eth_address: USER3_ADDRESS }
+  })
+
+  test('owner starts a transfer', async () => {
+    const response = await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+    const body: IProjectTransferViewModel = response.body
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.project_id).toEqual(projectId)
+    expect(body.from_user_id).toEqual('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    expect(body.to_user_id).toEqual('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    expect(body.state).toEqual(ProjectTransferState.Pending)
+  })
+
+  test('admin starts a transfer', async () => {
+    await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', adminAuthHeader())
+      .send(payload)
+      .expect(201)
+  })
+
+  describe('when request is not valid', () => {
+    test('when project already has a pending transfer', async () => {
+      await api.post(testEndpoint(projectId)).set('Authorization', userAuth).send(payload)
+
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Project already has a pending transfer',
+          code: 'TransferExists',
+        })
+    })
+
+    test('when recipient owns the project', async () => {
+      payload.eth_address = '0x886ffe3d8b8851ecdf48888d9c630afd95c85fd1'
+
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Recipient already owns the project',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('when recipient does not exist', async () => {
+      payload.eth_address = '0x0000000000000000000000000000000000000009'
+
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(404)
+    })
+
+    test('returns 403 when user does not own the project', async () => {
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+        .send(payload)
+        .expect(403)
+    })
+  })
+})
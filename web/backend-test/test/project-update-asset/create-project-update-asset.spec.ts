@@ -0,0 +1,119 @@
+import {
+  AssetContentType,
+  ICreateAssetResponse,
+  ICreateProjectUpdateAssetRequest,
+} from '@app/types'
+import { commonRegex } from '@app/util'
+import { AppDbResetService, TestAgent, testagent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { beforeAll, beforeEach, describe, expect, test } from 'vitest'
+
+describe('Create Project Update Asset', () => {
+  const testEndpoint = '/api/project-update-assets'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: ICreateProjectUpdateAssetRequest
+  let ownerAuth: string
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post(`/api/projects/${projectId}/updates`)
+      .set('Authorization', ownerAuth)
+      .send({ title: 'Shipping soon', body: 'Boxes are packed' })
+      .expect(201)
+    payload = {
+      content_size: 100000,
+      content_type: AssetContentType.Jpeg,
+      update_id: response.body.id,
+    }
+  })
+
+  test('returns 201 status code and signed url', async () => {
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', ownerAuth)
+      .send(payload)
+      .expect(201)
+    const body: ICreateAssetResponse = response.body
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.project_id).toEqual(projectId)
+    const uploadUrl = new URL(body.signed_url)
+    expect(uploadUrl.pathname).toContain(`/${projectId}/${body.id}.jpg`)
+    expect(uploadUrl.searchParams.get('X-Amz-Expires')).toEqual('600')
+  })
+
+  test('pending uploads are only returned to the owner', async () => {
+    await api.post(testEndpoint).set('Authorization', ownerAuth).send(payload).expect(201)
+    await api
+      .patch(`/api/project-updates/${payload.update_id}`)
+      .set('Authorization', ownerAuth)
+      .send({ state: 'Published' })
+      .expect(200)
+
+    const ownerResponse = await api
+      .get(`/api/project-updates/${payload.update_id}`)
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    expect(ownerResponse.body.assets).toHaveLength(1)
+    const response = await api
+      .get(`/api/project-updates/${payload.update_id}`)
+      .expect(200)
+    expect(response.body.assets).toEqual([])
+  })
+
+  describe('when request is invalid', () => {
+    test('when requester is not project owner', async () => {
+      await api
+        .post(testEndpoint)
+        .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+        .send(payload)
+        .expect(403, {
+          code: 'None',
+          message: 'Forbidden',
+          status: 403,
+        })
+    })
+
+    test('when user is not authorized', async () => {
+      await api.post(testEndpoint).send(payload).expect(401, {
+        code: 'Unauthorized',
+        message: 'Unauthorized',
+        status: 401,
+      })
+    })
+
+    test('when content_size is not valid', async () => {
+      payload.content_size = 1000000001
+      await api
+        .post(testEndpoint)
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(400, {
+          code: 'InvalidFormData',
+          message: 'Failed to validate request',
+          status: 400,
+        })
+    })
+
+    test('when update does not exist', async () => {
+      payload.update_id = 'cbd7a9ff-18f5-489e-b61e-cdd4a1394968'
+      await api
+        .post(testEndpoint)
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(404)
+    })
+  })
+})
@@ -0,0 +1,97 @@
+import {
+  AssetContentType,
+  AssetState,
+  ICreateAssetResponse,
+  ICreateProjectUpdateAssetRequest,
+  IProjectUpdateViewModel,
+  IVerifyAssetResponse,
+} from '@app/types'
+import { beforeAll, beforeEach, describe, it, expect } from 'vitest'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import fs from 'fs'
+import { testConfig } from '../test.config'
+
+describe('Verify Project Update Asset', () => {
+  const testEndpoint = '/api/project-update-assets'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let fileBuffer: Buffer
+  let updateId: string
+  let updateAsset: ICreateAssetResponse
+
+  const verifyEndpoint = (assetId: string) => `${testEndpoint}/${assetId}/actions/verify`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    fileBuffer = fs.readFileSync('./web/backend-test/test/assets/test-asset.jpg')
+
+    const updateResponse = await api
+      .post('/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/updates')
+      .set('Authorization', ownerAuth)
+      .send({ title: 'Shipping soon', body: 'Boxes are packed', state: 'Published' })
+      .expect(201)
+    updateId = updateResponse.body.id
+
+    const payload: ICreateProjectUpdateAssetRequest = {
+      content_size: fileBuffer.length,
+      content_type: AssetContentType.Jpeg,
+      update_id: updateId,
+    }
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', ownerAuth)
+      .send(payload)
+      .expect(201)
+    updateAsset = response.body
+  })
+
+  it('returns verified and lists the attachment', async () => {
+    await testagent(updateAsset.signed_url).put('').send(fileBuffer).expect(200)
+
+    const response = await api
+      .post(verifyEndpoint(updateAsset.id))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IVerifyAssetResponse = response.body
+    expect(body.verified).toEqual(true)
+
+    const updateResponse = await api.get(`/api/project-updates/${updateId}`).expect(200)
+    const update: IProjectUpdateViewModel = updateResponse.body
+    expect(update.assets.map((a) => a.id)).toEqual([updateAsset.id])
+    expect(update.assets[0].state).toEqual(AssetState.Uploaded)
+  }, 10000)
+
+  it('returns not verified before upload', async () => {
+    const response = await api
+      .post(verifyEndpoint(updateAsset.id))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IVerifyAssetResponse = response.body
+    expect(body.verified).toEqual(false)
+  })
+
+  describe('when request is invalid', () => {
+    it('returns 403 when user did not create the asset', async () => {
+      await api
+        .post(verifyEndpoint(updateAsset.id))
+        .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+        .expect(403)
+    })
+
+    it('returns 404 when asset does not exist', async () => {
+      await api
+        .post(verifyEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', ownerAuth)
+        .expect(404)
+    })
+  })
+})
@@ -0,0 +1,126 @@
+import {
+  ICreateProjectUpdateApiRequest,
+  IProjectUpdateViewModel,
+  ProjectUpdateState,
+} from '@app/types'
+import { commonRegex } from '@app/util'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create Project Update', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/updates`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let payload: ICreateProjectUpdateApiRequest
+  const ownerId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader(ownerId)
+    payload = {
+      title: 'Production started',
+      body: '# Update\n\nThe first batch is **on its way**.',
+    }
+  })
+
+  const createUpdate = async (auth: string): Promise<IProjectUpdateViewModel> => {
+    const response = await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', auth)
+      .send(payload)
+      .expect(201)
+    return response.body
+  }
+
+  test('creates a draft by default', async () => {
+    const body = await createUpdate(ownerAuth)
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.project_id).toEqual(projectId)
+    expect(body.user_id).toEqual(ownerId)
+    expect(body.title).toEqual(payload.title)
+    expect(body.body).toEqual(payload.body)
+    expect(body.state).toEqual(ProjectUpdateState.Draft)
+    expect(body.backers_only).toEqual(false)
+    expect(body.locked).toEqual(false)
+    expect(body.published_at).toBeNull()
+    expect(body.assets).toEqual([])
+  })
+
+  test('creates a published backer-only update', async () => {
+    payload.state = ProjectUpdateState.Published
+    payload.backers_only = true
+    const body = await createUpdate(ownerAuth)
+
+    expect(body.state).toEqual(ProjectUpdateState.Published)
+    expect(body.backers_only).toEqual(true)
+    expect(body.published_at).toBeDefined()
+    expect(body.published_at).not.toBeNull()
+  })
+
+  test('admin creates an update', async () => {
+    const body = await createUpdate(adminAuthHeader())
+
+    expect(body.project_id).toEqual(projectId)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when title is too short', async () => {
+      payload.title = 'Hi'
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 400 when body is empty', async () => {
+      payload.body = ''
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(400)
+    })
+
+    test('returns 403 when user is not the owner', async () => {
+      await api
+        .post(testEndpoint(projectId))
+        .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+        .send(payload)
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint(projectId)).send(payload).expect(401)
+    })
+
+    test('returns 404 when project does not exist', async () => {
+      await api
+        .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', ownerAuth)
+        .send(payload)
+        .expect(404)
+    })
+  })
+})
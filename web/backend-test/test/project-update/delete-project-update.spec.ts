@@ -0,0 +1,54 @@
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Delete Project Update', () => {
+  const testEndpoint = (id: string) => `/api/project-updates/${id}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let updateId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post('/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/updates')
+      .set('Authorization', ownerAuth)
+      .send({ title: 'Shipping soon', body: 'Boxes are packed', state: 'Published' })
+      .expect(201)
+    updateId = response.body.id
+  })
+
+  test('deletes an update', async () => {
+    await api.delete(testEndpoint(updateId)).set('Authorization', ownerAuth).expect(200)
+
+    await api.get(testEndpoint(updateId)).expect(404)
+  })
+
+  test('returns 403 when user is not the owner', async () => {
+    await api
+      .delete(testEndpoint(updateId))
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.delete(testEndpoint(updateId)).expect(401)
+  })
+
+  test('returns 404 when update does not exist', async () => {
+    await api
+      .delete(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+      .set('Authorization', ownerAuth)
+      .expect(404)
+  })
+})
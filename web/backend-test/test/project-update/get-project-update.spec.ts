@@ -0,0 +1,85 @@
+import { IProjectUpdateViewModel, ProjectUpdateState } from '@app/types'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Get Project Update', () => {
+  const testEndpoint = (id: string) => `/api/project-updates/${id}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+  })
+
+  const createUpdate = async (
+    state: ProjectUpdateState,
+    backers_only: boolean,
+  ): Promise<IProjectUpdateViewModel> => {
+    const response = await api
+      .post(`/api/projects/${projectId}/updates`)
+      .set('Authorization', ownerAuth)
+      .send({ title: 'Shipping soon', body: 'Boxes are packed', state, backers_only })
+      .expect(201)
+    return response.body
+  }
+
+  test('returns published update without authorization', async () => {
+    const update = await createUpdate(ProjectUpdateState.Published, false)
+
+    const response = await api.get(testEndpoint(update.id)).expect(200)
+    const body: IProjectUpdateViewModel = response.body
+    expect(body.id).toEqual(update.id)
+    expect(body.body).toEqual('Boxes are packed')
+    expect(body.locked).toEqual(false)
+  })
+
+  test('returns locked backer-only update', async () => {
+    const update = await createUpdate(ProjectUpdateState.Published, true)
+
+    const response = await api.get(testEndpoint(update.id)).expect(200)
+    const body: IProjectUpdateViewModel = response.body
+    expect(body.locked).toEqual(true)
+    expect(body.body).toBeNull()
+    expect(body.assets).toEqual([])
+
+    const ownerResponse = await api
+      .get(testEndpoint(update.id))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    expect(ownerResponse.body.locked).toEqual(false)
+  })
+
+  test('returns draft to the owner', async () => {
+    const update = await createUpdate(ProjectUpdateState.Draft, false)
+
+    await api.get(testEndpoint(update.id)).set('Authorization', ownerAuth).expect(200)
+  })
+
+  test('returns 404 when update is a draft', async () => {
+    const update = await createUpdate(ProjectUpdateState.Draft, false)
+
+    await api
+      .get(testEndpoint(update.id))
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .expect(404, {
+        status: 404,
+        message: 'Project update not found',
+        code: 'None',
+      })
+  })
+
+  test('returns 404 when update does not exist', async () => {
+    await api.get(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968')).expect(404)
+  })
+})
@@ -0,0 +1,146 @@
+import {
+  IBackProjectApiResponse,
+  IListProjectUpdatesApiRequest,
+  IListProjectUpdatesApiResponse,
+  IProjectUpdateViewModel,
+  ProjectUpdateState,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Project Updates', () => {
+  const testEndpoint = (id: string) => `/api/projects/${id}/updates`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let backerAuth: string
+  let backersOnlyUpdate: IProjectUpdateViewModel
+  const projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  const createUpdate = async (
+    title: string,
+    state: ProjectUpdateState,
+    backers_only: boolean,
+  ): Promise<IProjectUpdateViewModel> => {
+    const response = await api
+      .post(testEndpoint(projectId))
+      .set('Authorization', ownerAuth)
+      .send({ title, body: `${title} body`, state, backers_only })
+      .expect(201)
+    return response.body
+  }
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    backerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+
+    await createUpdate('Draft update', ProjectUpdateState.Draft, false)
+    await createUpdate('Public update', ProjectUpdateState.Published, false)
+    backersOnlyUpdate = await createUpdate(
+      'Backer update',
+      ProjectUpdateState.Published,
+      true,
+    )
+  })
+
+  const listUpdates = async (
+    auth?: string,
+    query?: IListProjectUpdatesApiRequest,
+  ): Promise<IListProjectUpdatesApiResponse> => {
+    const request = api.get(testEndpoint(projectId)).query(query ?? {})
+    if (auth) {
+      request.set('Authorization', auth)
+    }
+    const response = await request.expect(200)
+    return response.body
+  }
+
+  const backProject = async (confirm: boolean) => {
+    const response = await api
+      .post(`/api/projects/${projectId}/actions/back`)
+      .set('Authorization', backerAuth)
+      .send({
+        rewards: [{ reward_id: '8fe4b678-e9ac-4e1d-b37a-1254ec33656f', quantity: 1 }],
+      })
+      .expect(201)
+    const pledge: IBackProjectApiResponse = response.body
+    if (confirm) {
+      await api
+        .patch(`/api/pledges/${pledge.id}`)
+        .set('Authorization', adminAuthHeader())
+        .send({ blockchain_status: 'Success' })
+        .expect(200)
+    }
+  }
+
+  const findBackersOnly = (body: IListProjectUpdatesApiResponse) =>
+    body.results.find((u) => u.id === backersOnlyUpdate.id)
+
+  test('lists published updates without authorization', async () => {
+    const body = await listUpdates()
+
+    expect(body.total).toEqual(2)
+    expect(body.results.every((u) => u.state === ProjectUpdateState.Published)).toBe(true)
+  })
+
+  test('locks backer-only updates for other users', async () => {
+    const update = findBackersOnly(await listUpdates(backerAuth))
+
+    expect(update?.locked).toEqual(true)
+    expect(update?.title).toEqual('Backer update')
+    expect(update?.body).toBeNull()
+  })
+
+  test('unconfirmed pledges do not unlock backer-only updates', async () => {
+    await backProject(false)
+
+    const update = findBackersOnly(await listUpdates(backerAuth))
+    expect(update?.locked).toEqual(true)
+  })
+
+  test('confirmed backers read backer-only updates', async () => {
+    await backProject(true)
+
+    const update = findBackersOnly(await listUpdates(backerAuth))
+    expect(update?.locked).toEqual(false)
+    expect(update?.body).toEqual('Backer update body')
+  })
+
+  test('owner lists and filters drafts', async () => {
+    expect((await listUpdates(ownerAuth)).total).toEqual(3)
+
+    const body = await listUpdates(ownerAuth, { state: ProjectUpdateState.Draft })
+    expect(body.total).toEqual(1)
+    expect(body.results[0].title).toEqual('Draft update')
+  })
+
+  test('other users cannot list drafts', async () => {
+    const body = await listUpdates(backerAuth, { state: ProjectUpdateState.Draft })
+
+    expect(body.total).toEqual(2)
+    expect(body.results.every((u) => u.state === ProjectUpdateState.Published)).toBe(true)
+  })
+
+  test('returns 403 when project is not published', async () => {
+    await api.get(testEndpoint('14bfe82a-1003-446b-b6bb-20a176e848e0')).expect(403)
+  })
+
+  test('returns 404 when project does not exist', async () => {
+    await api.get(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968')).expect(404)
+  })
+})
@@ -0,0 +1,114 @@
+import {
+  IProjectUpdateViewModel,
+  IUpdateProjectUpdateApiRequest,
+  ProjectUpdateState,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Update Project Update', () => {
+  const testEndpoint = (id: string) => `/api/project-updates/${id}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  let updateId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+
+    const response = await api
+      .post('/api/projects/3e42e273-546d-4989-a97c-f6eb173e8450/updates')
+      .set('Authorization', ownerAuth)
+      .send({ title: 'Shipping soon', body: 'Boxes are packed' })
+      .expect(201)
+    updateId = response.body.id
+  })
+
+  const updateUpdate = async (
+    payload: IUpdateProjectUpdateApiRequest,
+    auth?: string,
+  ): Promise<IProjectUpdateViewModel> => {
+    const response = await api
+      .patch(testEndpoint(updateId))
+      .set('Authorization', auth ?? ownerAuth)
+      .send(payload)
+      .expect(200)
+    return response.body
+  }
+
+  test('updates title and body', async () => {
+    const body = await updateUpdate({ title: 'Shipping now', body: 'Boxes are sent' })
+
+    expect(body.title).toEqual('Shipping now')
+    expect(body.body).toEqual('Boxes are sent')
+    expect(body.state).toEqual(ProjectUpdateState.Draft)
+  })
+
+  test('publishing keeps the first publish date', async () => {
+    const published = await updateUpdate({ state: ProjectUpdateState.Published })
+    expect(published.published_at).not.toBeNull()
+
+    await updateUpdate({ state: ProjectUpdateState.Draft })
+    const republished = await updateUpdate({ state: ProjectUpdateState.Published })
+    expect(republished.published_at).toEqual(published.published_at)
+  })
+
+  test('admin updates an update', async () => {
+    const body = await updateUpdate({ backers_only: true }, adminAuthHeader())
+
+    expect(body.backers_only).toEqual(true)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when there are no fields to update', async () => {
+      await api
+        .patch(testEndpoint(updateId))
+        .set('Authorization', ownerAuth)
+        .send({})
+        .expect(400, {
+          status: 400,
+          message: 'No fields to update',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 400 when title is too short', async () => {
+      await api
+        .patch(testEndpoint(updateId))
+        .set('Authorization', ownerAuth)
+        .send({ title: 'Hi' })
+        .expect(400)
+    })
+
+    test('returns 403 when user is not the owner', async () => {
+      await api
+        .patch(testEndpoint(updateId))
+        .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+        .send({ title: 'Shipping now' })
+        .expect(403)
+    })
+
+    test('returns 404 when update does not exist', async () => {
+      await api
+        .patch(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+        .set('Authorization', ownerAuth)
+        .send({ title: 'Shipping now' })
+        .expect(404)
+    })
+  })
+})
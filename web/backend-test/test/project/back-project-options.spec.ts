@@ -0,0 +1,276 @@
+import {
+  IBackProjectApiRequest,
+  ICreateRewardApiRequest,
+  ICreateRewardApiResponse,
+  IGetProjectApiResponse,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  dayToSec,
+  now,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Back Project with reward options', () => {
+  const testEndpoint = '/api/projects'
+  const backEndpoint = (id: string) => `${testEndpoint}/${id}/actions/back`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let userAuth: string
+  let projectId: string
+  let rewardPayload: ICreateRewardApiRequest
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+    rewardPayload = {
+      name: 'Reward with options',
+      description: 'A reward with options for the backer',
+      price: '100000000000000000',
+      delivery_time: now() + dayToSec(90),
+      backer_limit: 10,
+    }
+  })
+
+  const createReward = async (payload: ICreateRewardApiRequest): Promise<string> => {
+    const response = await api
+      .post(`${testEndpoint}/${projectId}/rewards`)
+      .set('Authorization', adminAuth)
+      .send(payload)
+      .expect(201)
+    const body: ICreateRewardApiResponse = response.body
+    return body.id
+  }
+
+  const getProject = async (): Promise<IGetProjectApiResponse> => {
+    const response = await api
+      .get(`${testEndpoint}/${projectId}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const body: IGetProjectApiResponse = response.body
+    return body
+  }
+
+  const getReward = async (rewardId: string) =>
+    (await getProject()).rewards.find((r) => r.id === rewardId)
+
+  describe('variants', () => {
+    beforeEach(() => {
+      rewardPayload.variants = [
+        { name: 'Small', backer_limit: 2 },
+        { name: 'Large', price: '200000000000000000', backer_limit: 5 },
+      ]
+    })
+
+    test('user backs reward variant', async () => {
+      const rewardId = await createReward(rewardPayload)
+      const variants = (await getReward(rewardId))?.variants
+      const large = variants?.find((v) => v.name === 'Large')
+
+      const payload: IBackProjectApiRequest = {
+        rewards: [{ reward_id: rewardId, variant_id: large?.id, quantity: 2 }],
+      }
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(201)
+
+      const project = await getProject()
+      expect(project.total_pledged).toEqual('500000000000000000')
+      const reward = project.rewards.find((r) => r.id === rewardId)
+      expect(reward?.backer_count).toEqual(2)
+      expect(reward?.variants.find((v) => v.id === large?.id)?.backer_count).toEqual(2)
+      expect(reward?.variants.find((v) => v.name === 'Small')?.backer_count).toEqual(0)
+    })
+
+    test('returns 400 when variant is missing', async () => {
+      const rewardId = await createReward(rewardPayload)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({ rewards: [{ reward_id: rewardId, quantity: 1 }] })
+        .expect(400, {
+          status: 400,
+          message: 'Invalid reward variant',
+          code: 'RewardVariant',
+        })
+    })
+
+    test('returns 400 when variant is sold out', async () => {
+      const rewardId = await createReward(rewardPayload)
+      const variants = (await getReward(rewardId))?.variants
+      const small = variants?.find((v) => v.name === 'Small')
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({ rewards: [{ reward_id: rewardId, variant_id: small?.id, quantity: 3 }] })
+        .expect(400, {
+          status: 400,
+          message: 'Reward variant Small is sold out',
+          code: 'RewardSoldOut',
+        })
+
+      // Nothing is counted for the failed pledge
+      const reward = await getReward(rewardId)
+      expect(reward?.backer_count).toEqual(0)
+    })
+
+    test('concurrent pledges do not oversell a variant', async () => {
+      const rewardId = await createReward(rewardPayload)
+      const variants = (await getReward(rewardId))?.variants
+      const small = variants?.find((v) => v.name === 'Small')
+      const payload: IBackProjectApiRequest = {
+        rewards: [{ reward_id: rewardId, variant_id: small?.id, quantity: 1 }],
+      }
+
+      const responses = await Promise.all(
+        [0, 1, 2].map(() =>
+          api.post(backEndpoint(projectId)).set('Authorization', userAuth).send(payload),
+        ),
+      )
+      expect(responses.filter((r) => r.status === 201).length).toEqual(2)
+      expect(responses.filter((r) => r.status === 400).length).toEqual(1)
+
+      const reward = await getReward(rewardId)
+      expect(reward?.backer_count).toEqual(2)
+      expect(reward?.variants.find((v) => v.id === small?.id)?.backer_count).toEqual(2)
+    })
+  })
+
+  describe('price tiers', () => {
+    beforeEach(() => {
+      rewardPayload.price_tiers = [{ price: '50000000000000000', backer_limit: 2 }]
+    })
+
+    test('user backs reward at early-bird price', async () => {
+      const rewardId = await createReward(rewardPayload)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({ rewards: [{ reward_id: rewardId, quantity: 2 }] })
+        .expect(201)
+
+      const project = await getProject()
+      expect(project.total_pledged).toEqual('200000000000000000')
+      const reward = project.rewards.find((r) => r.id === rewardId)
+      expect(reward?.price_tiers[0]?.backer_count).toEqual(2)
+      expect(reward?.price_tiers[0]?.remaining).toEqual(0)
+      expect(reward?.active_price_tier).toBeUndefined()
+    })
+
+    test('falls back to reward price when quantity exceeds tier cap', async () => {
+      const rewardId = await createReward(rewardPayload)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({ rewards: [{ reward_id: rewardId, quantity: 3 }] })
+        .expect(201)
+
+      const project = await getProject()
+      expect(project.total_pledged).toEqual('400000000000000000')
+      const reward = project.rewards.find((r) => r.id === rewardId)
+      expect(reward?.backer_count).toEqual(3)
+      expect(reward?.price_tiers[0]?.backer_count).toEqual(0)
+    })
+
+    test('counts lines for the same reward together against tier cap', async () => {
+      const rewardId = await createReward(rewardPayload)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({
+          rewards: [
+            { reward_id: rewardId, quantity: 2 },
+            { reward_id: rewardId, quantity: 1 },
+          ],
+        })
+        .expect(201)
+
+      // First line at tier price, second at reward price
+      const project = await getProject()
+      expect(project.total_pledged).toEqual('300000000000000000')
+      const reward = project.rewards.find((r) => r.id === rewardId)
+      expect(reward?.backer_count).toEqual(3)
+      expect(reward?.price_tiers[0]?.backer_count).toEqual(2)
+    })
+
+    test('concurrent pledges do not exceed tier cap', async () => {
+      const rewardId = await createReward(rewardPayload)
+
+      await Promise.all(
+        [0, 1, 2].map(() =>
+          api
+            .post(backEndpoint(projectId))
+            .set('Authorization', userAuth)
+            .send({ rewards: [{ reward_id: rewardId, quantity: 1 }] })
+            .expect(201),
+        ),
+      )
+
+      const reward = await getReward(rewardId)
+      expect(reward?.backer_count).toEqual(3)
+      expect(reward?.price_tiers[0]?.backer_count).toEqual(2)
+    })
+  })
+
+  describe('add-ons', () => {
+    const parentRewardId = '8fe4b678-e9ac-4e1d-b37a-1254ec33656f'
+
+    beforeEach(() => {
+      rewardPayload.parent_reward_id = parentRewardId
+    })
+
+    test('user backs add-on with its parent reward', async () => {
+      const addonId = await createReward(rewardPayload)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({
+          rewards: [
+            { reward_id: parentRewardId, quantity: 1 },
+            { reward_id: addonId, quantity: 1 },
+          ],
+        })
+        .expect(201)
+
+      expect((await getReward(addonId))?.backer_count).toEqual(1)
+      expect((await getReward(parentRewardId))?.backer_count).toEqual(2)
+    })
+
+    test('returns 400 when add-on is backed without its parent', async () => {
+      const addonId = await createReward(rewardPayload)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send({ rewards: [{ reward_id: addonId, quantity: 1 }] })
+        .expect(400, {
+          status: 400,
+          message: 'Add-on requires its parent reward',
+          code: 'RewardAddon',
+        })
+    })
+  })
+})
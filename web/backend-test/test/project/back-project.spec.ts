@@ -7,6 +7,8 @@ import { commonRegex } from '@app/util'
 import {
   adminAuthHeader,
   AppDbResetService,
+  dayToSec,
+  now,
   testagent,
   TestAgent,
   userAuthHeader,
@@ -104,6 +106,33 @@ describe('Back Project', () => {
     expect(reward2?.backer_count).toEqual(2)
   })
 
+  test('user backs project without rewards', async () => {
+    payload = { rewards: [], extra_amount: '2000000000000000' }
+    const response = await api
+      .post(backEndpoint(projectId))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+    const body: IBackProjectApiResponse = response.body
+
+    expect(body.project_id).toEqual(projectId)
+    expect(body.total_amount).toEqual('2000000000000000')
+  })
+
+  test('user backs reward with extra amount', async () => {
+    const project = await getProject()
+    const reward = project.rewards.find((r) => r.id === payload.rewards[0].reward_id)
+    payload.extra_amount = '1000000000000000'
+    const response = await api
+      .post(backEndpoint(projectId))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+    const body: IBackProjectApiResponse = response.body
+
+    expect(BigInt(body.total_amount)).toBeGreaterThan(BigInt(reward?.price ?? '0'))
+  })
+
   describe('when request is not valid', () => {
     test('when quantity is invalid', async () => {
       payload.rewards[0].quantity = 0
@@ -129,6 +158,45 @@ describe('Back Project', () => {
         })
     })
 
+    test('when pledge has no reward or amount', async () => {
+      payload = { rewards: [] }
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Pledge requires a reward or an amount',
+          code: 'PledgeEmpty',
+        })
+    })
+
+    test('when pledge is below the minimum', async () => {
+      payload = { rewards: [], extra_amount: '1000' }
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Pledge must be at least 1000000000000000 Ethereum',
+          code: 'PledgeMinimum',
+        })
+    })
+
+    test('when extra amount is not a number', async () => {
+      payload.extra_amount = '-5'
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
     test('when reward ID is invalid', () => {
       payload.rewards[0].reward_id = '1234'
 
@@ -157,6 +225,24 @@ describe('Back Project', () => {
         })
     })
 
+    test('when project has ended', async () => {
+      await api
+        .patch(`${testEndpoint}/${projectId}`)
+        .set('Authorization', adminAuth)
+        .send({ start_time: now() - dayToSec(10), duration: dayToSec(5) })
+        .expect(200)
+
+      await api
+        .post(backEndpoint(projectId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Cannot back ended project',
+          code: 'ProjectInactive',
+        })
+    })
+
     test('when project does not exist', async () => {
       projectId = 'cbd7a9ff-18f5-489e-b61e-cdd4a1394968'
       await api
@@ -0,0 +1,150 @@
+import {
+  FundingModel,
+  FundingOutcome,
+  IEvaluateEndedProjectsApiResponse,
+  IGetPledgeApiResponse,
+  IGetProjectApiResponse,
+  IUpdateProjectApiRequest,
+  ProjectStatus,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  dayToSec,
+  now,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Evaluate Ended Projects', () => {
+  const testEndpoint = '/api/projects/actions/evaluate-ended'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let projectId: string
+  let pledgeId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+    pledgeId = '23c0599a-7990-4949-820c-3254079955f2'
+  })
+
+  const endProject = async (payload?: IUpdateProjectApiRequest) => {
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', adminAuth)
+      .send({ start_time: now() - dayToSec(10), duration: dayToSec(5), ...payload })
+      .expect(200)
+  }
+
+  const evaluate = async (): Promise<IEvaluateEndedProjectsApiResponse> => {
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    return response.body
+  }
+
+  const getProject = async (): Promise<IGetProjectApiResponse> => {
+    const response = await api
+      .get(`/api/projects/${projectId}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    return response.body
+  }
+
+  const getPledge = async (): Promise<IGetPledgeApiResponse> => {
+    const response = await api
+      .get(`/api/pledges/${pledgeId}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    return response.body
+  }
+
+  describe('when requester is Admin', () => {
+    test('skips projects before the end time', async () => {
+      const body = await evaluate()
+
+      expect(body.funded).not.toContain(projectId)
+      expect(body.unfunded).not.toContain(projectId)
+      const project = await getProject()
+      expect(project.status).toEqual(ProjectStatus.Active)
+      expect(project.funding_outcome).toBeNull()
+    })
+
+    test('completes funded project', async () => {
+      await endProject({ funding_goal: '100000000000000000' })
+
+      const body = await evaluate()
+
+      expect(body.funded).toContain(projectId)
+      const project = await getProject()
+      expect(project.status).toEqual(ProjectStatus.Complete)
+      expect(project.funding_outcome).toEqual(FundingOutcome.Funded)
+      const pledge = await getPledge()
+      expect(pledge.refundable).toEqual(false)
+    })
+
+    test('makes pledges refundable when all-or-nothing project is unfunded', async () => {
+      await endProject()
+
+      const body = await evaluate()
+
+      expect(body.unfunded).toContain(projectId)
+      const project = await getProject()
+      expect(project.status).toEqual(ProjectStatus.Complete)
+      expect(project.funding_outcome).toEqual(FundingOutcome.Unfunded)
+      const pledge = await getPledge()
+      expect(pledge.refundable).toEqual(true)
+    })
+
+    test('keeps pledges when flexible project is unfunded', async () => {
+      await endProject({ funding_model: FundingModel.Flexible })
+
+      const body = await evaluate()
+
+      expect(body.unfunded).toContain(projectId)
+      const pledge = await getPledge()
+      expect(pledge.refundable).toEqual(false)
+    })
+
+    test('evaluates each project once', async () => {
+      await endProject()
+      await evaluate()
+
+      const body = await evaluate()
+
+      expect(body.funded).not.toContain(projectId)
+      expect(body.unfunded).not.toContain(projectId)
+    })
+  })
+
+  describe('when requester is not Admin', () => {
+    test('returns 403 when requester is User', async () => {
+      await api
+        .post(testEndpoint)
+        .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint).expect(401, {
+        code: 'Unauthorized',
+        message: 'Unauthorized',
+        status: 401,
+      })
+    })
+  })
+})
@@ -0,0 +1,115 @@
+import { IGetProjectApiResponse } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Get Project By Slug', () => {
+  const slugEndpoint = (slug: string) => `/api/projects/by-slug/${slug}`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let ownerAuth: string
+  const projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+  })
+
+  const renameProject = async (name: string) => {
+    await api
+      .patch(`/api/projects/${projectId}`)
+      .set('Authorization', ownerAuth)
+      .send({ name })
+      .expect(200)
+  }
+
+  test('returns published project without authorization', async () => {
+    const response = await api.get(slugEndpoint('vintage-player')).expect(200)
+    const body: IGetProjectApiResponse = response.body
+
+    expect(body.id).toEqual('3e42e273-546d-4989-a97c-f6eb173e8450')
+    expect(body.slug).toEqual('vintage-player')
+    expect(body.rewards.length).toBeGreaterThan(0)
+  })
+
+  test('renaming a project changes its slug', async () => {
+    await renameProject('Game Box: Deluxe!')
+
+    const response = await api
+      .get(slugEndpoint('game-box-deluxe'))
+      .set('Authorization', ownerAuth)
+      .expect(200)
+    const body: IGetProjectApiResponse = response.body
+
+    expect(body.id).toEqual(projectId)
+    expect(body.slug).toEqual('game-box-deluxe')
+  })
+
+  test('redirects previous slug to the current slug', async () => {
+    await renameProject('Game Box Deluxe')
+
+    const response = await api
+      .get(slugEndpoint('game-box'))
+      .set('Authorization', ownerAuth)
+      .expect(307)
+
+    expect(response.headers.location).toEqual('/api/projects/by-slug/game-box-deluxe')
+  })
+
+  test('keeps preview token when redirecting', async () => {
+    await renameProject('Game Box Deluxe')
+    const tokenResponse = await api
+      .post(`/api/projects/${projectId}/preview-tokens`)
+      .set('Authorization', ownerAuth)
+      .send({ ttl: 60 })
+      .expect(201)
+    const token: string = tokenResponse.body.token
+
+    const response = await api
+      .get(slugEndpoint('game-box'))
+      .query({ preview_token: token })
+      .expect(307)
+
+    expect(response.headers.location).toEqual(
+      `/api/projects/by-slug/game-box-deluxe?preview_token=${token}`,
+    )
+  })
+
+  test('reuses a slug only for the same project', async () => {
+    await renameProject('Game Box Deluxe')
+    await renameProject('Game Box')
+
+    const response = await api
+      .get(slugEndpoint('game-box'))
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+    expect(response.body.id).toEqual(projectId)
+  })
+
+  test('returns 403 when project is not published', async () => {
+    await api.get(slugEndpoint('game-box')).expect(403)
+  })
+
+  test('returns 403 before redirecting when project is not published', async () => {
+    await renameProject('Game Box Deluxe')
+
+    await api.get(slugEndpoint('game-box')).expect(403)
+  })
+
+  test('returns 404 when slug does not exist', async () => {
+    await api.get(slugEndpoint('no-such-project')).expect(404)
+  })
+})
@@ -1,4 +1,9 @@
-import { IListProjectsApiRequest, IListProjectsApiResponse } from '@app/types'
+import {
+  IListProjectsApiRequest,
+  IListProjectsApiResponse,
+  ProjectStatus,
+  SortDirection,
+} from '@app/types'
 import { testagent, TestAgent, adminAuthHeader, userAuthHeader } from '../helpers'
 import { testConfig } from '../test.config'
 import { AppDbResetService } from '../helpers'
@@ -57,6 +62,55 @@ describe('List Projects', () => {
       expect(projects[1].id).toEqual('a3a2b1c4-a1ee-42d5-a729-bb6ff6fdfdfe')
     })
 
+    test('pages through projects with a cursor', async () => {
+      const ids: string[] = []
+      query = { limit: 4 }
+      let body: IListProjectsApiResponse
+      do {
+        const response = await api
+          .get(testEndpoint)
+          .query(query)
+          .set('Authorization', adminAuth)
+          .expect(200)
+        body = response.body
+
+        expect(body.total).toBeUndefined()
+        expect(body.results.length).toBeLessThanOrEqual(4)
+        ids.push(...body.results.map((p) => p.id))
+        query = { limit: 4, cursor: body.next_cursor }
+      } while (body.next_cursor)
+
+      expect(ids).toHaveLength(TotalProjects)
+      expect(new Set(ids).size).toEqual(TotalProjects)
+    })
+
+    test('returns total with a cursor when requested', async () => {
+      query = { limit: 2, include_total: true }
+      const response = await api
+        .get(testEndpoint)
+        .query(query)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const body: IListProjectsApiResponse = response.body
+
+      expect(body.total).toEqual(TotalProjects)
+      expect(body.results).toHaveLength(2)
+      expect(body.next_cursor).toBeDefined()
+    })
+
+    test('returns 400 when cursor is invalid', async () => {
+      query = { cursor: 'not-a-cursor' }
+      await api
+        .get(testEndpoint)
+        .query(query)
+        .set('Authorization', adminAuth)
+        .expect(400, {
+          code: 'InvalidCursor',
+          message: 'Invalid cursor',
+          status: 400,
+        })
+    })
+
     test('filters by one category', async () => {
       const response = await api
         .get(testEndpoint)
@@ -131,6 +185,76 @@ describe('List Projects', () => {
     })
   })
 
+  describe('when discovering projects', () => {
+    const listProjects = async (): Promise<IListProjectsApiResponse> => {
+      const response = await api.get(testEndpoint).query(query).expect(200)
+      return response.body
+    }
+
+    test('filters live projects', async () => {
+      query = { live: true }
+      const body = await listProjects()
+      const nowSec = Math.floor(Date.now() / 1000)
+
+      expect(body.results.length).toBeGreaterThan(0)
+      body.results.forEach((p) => {
+        expect(p.status).toEqual(ProjectStatus.Active)
+        expect(p.start_time).toBeLessThanOrEqual(nowSec)
+        expect(p.start_time + p.duration).toBeGreaterThan(nowSec)
+      })
+    })
+
+    test('filters funded and unfunded projects', async () => {
+      query = { funded: true }
+      const funded = await listProjects()
+      funded.results.forEach((p) => {
+        expect(BigInt(p.total_pledged) >= BigInt(p.funding_goal)).toEqual(true)
+      })
+
+      query = { funded: false }
+      const unfunded = await listProjects()
+      unfunded.results.forEach((p) => {
+        expect(BigInt(p.total_pledged) < BigInt(p.funding_goal)).toEqual(true)
+      })
+      expect((funded.total ?? 0) + (unfunded.total ?? 0)).toEqual(7)
+    })
+
+    test('filters by funding percentage', async () => {
+      query = { min_percent_funded: 1, max_percent_funded: 100 }
+      const body = await listProjects()
+
+      body.results.forEach((p) => {
+        expect(Number(p.percent_funded)).toBeGreaterThanOrEqual(1)
+        expect(Number(p.percent_funded)).toBeLessThanOrEqual(100)
+      })
+    })
+
+    test('sorts by end time', async () => {
+      query = { column: 'end_time', direction: SortDirection.Asc }
+      const body = await listProjects()
+
+      const endTimes = body.results.map((p) => p.start_time + p.duration)
+      expect(endTimes).toEqual([...endTimes].sort((a, b) => a - b))
+    })
+
+    test('sorts by trending', async () => {
+      query = { column: 'trending', trending_days: 30 }
+      const body = await listProjects()
+
+      const scores = body.results.map((p) => Number(p.trending_score))
+      expect(scores).toEqual([...scores].sort((a, b) => b - a))
+    })
+
+    test('returns 400 when trending window is too long', async () => {
+      query = { column: 'trending', trending_days: 91 }
+      await api.get(testEndpoint).query(query).expect(400, {
+        code: 'InvalidFormData',
+        message: 'Failed to validate request',
+        status: 400,
+      })
+    })
+  })
+
   describe('when requester is User', () => {
     let userAuth: string
 
@@ -208,6 +332,25 @@ describe('List Projects', () => {
       expect(body.results[6].id).toEqual('00df0e23-22af-4959-874c-aca385b54eed')
     })
 
+    test('searches by word prefix', async () => {
+      query = { q: 'vint play' }
+      const response = await api.get(testEndpoint).query(query).expect(200)
+      const body: IListProjectsApiResponse = response.body
+
+      expect(body.total).toEqual(1)
+      expect(body.results[0].id).toEqual('3e42e273-546d-4989-a97c-f6eb173e8450')
+      expect(body.results[0].search_snippet).toBeDefined()
+    })
+
+    test('returns 400 when search has no words', async () => {
+      query = { q: '!? -- &' }
+      await api.get(testEndpoint).query(query).expect(400, {
+        code: 'InvalidFormData',
+        message: 'Search must contain a word',
+        status: 400,
+      })
+    })
+
     test('filters by user_id without active or completed products', async () => {
       query = { user_id: '45013993-2a1a-4ee5-8dbd-b4b63d9af34f' }
       const response = await api
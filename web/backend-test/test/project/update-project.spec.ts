@@ -244,6 +244,17 @@ describe('Update Project', () => {
           message: 'Failed to validate request',
           status: 400,
         })
+
+      payload = { duration: dayToSec(30) }
+      await api
+        .patch(`/api/projects/${projectId}`)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          code: 'ProjectActive',
+          message: 'Failed to validate request',
+          status: 400,
+        })
     })
 
     test('return 400 when transaction_hash is invalid', async () => {
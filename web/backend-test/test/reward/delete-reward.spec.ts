@@ -0,0 +1,99 @@
+import {
+  ICreateRewardApiRequest,
+  ICreateRewardApiResponse,
+  IGetProjectApiResponse,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  dayToSec,
+  now,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Delete Reward', () => {
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: ICreateRewardApiRequest
+  let projectId: string
+  let adminAuth: string
+  let userAuth: string
+
+  const testEndpoint = (id: string) => `/api/rewards/${id}`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f')
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    payload = {
+      name: 'My New Reward',
+      description: 'A reward that will be deleted',
+      price: '1000000000000000000',
+      delivery_time: now() + dayToSec(90),
+      backer_limit: 100,
+    }
+  })
+
+  const createReward = async (payload: ICreateRewardApiRequest): Promise<string> => {
+    const response = await api
+      .post(`/api/projects/${projectId}/rewards`)
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+    const body: ICreateRewardApiResponse = response.body
+    return body.id
+  }
+
+  const getProject = async (): Promise<IGetProjectApiResponse> => {
+    const response = await api
+      .get(`/api/projects/${projectId}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const body: IGetProjectApiResponse = response.body
+    return body
+  }
+
+  test('user deletes reward', async () => {
+    const rewardId = await createReward(payload)
+
+    await api.delete(testEndpoint(rewardId)).set('Authorization', userAuth).expect(200)
+
+    const project = await getProject()
+    expect(project.rewards.find((r) => r.id === rewardId)).toBeUndefined()
+  })
+
+  test('user deletes add-on, then its parent reward', async () => {
+    const parentId = await createReward(payload)
+    const addonId = await createReward({ ...payload, parent_reward_id: parentId })
+
+    await api.delete(testEndpoint(parentId)).set('Authorization', userAuth).expect(400, {
+      status: 400,
+      message: 'Cannot delete a reward with add-ons',
+      code: 'RewardAddon',
+    })
+
+    await api.delete(testEndpoint(addonId)).set('Authorization', userAuth).expect(200)
+    await api.delete(testEndpoint(parentId)).set('Authorization', userAuth).expect(200)
+  })
+
+  test('returns 403 when user does not own the project', async () => {
+    const rewardId = await createReward(payload)
+
+    await api
+      .delete(testEndpoint(rewardId))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+})
@@ -0,0 +1,111 @@
+import { IListRewardsApiRequest, IListRewardsApiResponse } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('List Rewards', () => {
+  const rewardsEndpoint = (id: string) => `/api/projects/${id}/rewards`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let ownerAuth: string
+  let projectId: string
+  const hiddenRewardId = 'b63ae027-4c66-496d-87ff-cf610a161309'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    ownerAuth = userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd')
+    projectId = '3e42e273-546d-4989-a97c-f6eb173e8450'
+  })
+
+  const hideReward = async () => {
+    await api
+      .patch(`/api/rewards/${hiddenRewardId}`)
+      .set('Authorization', adminAuth)
+      .send({ visible: false })
+      .expect(200)
+  }
+
+  const listRewards = async (
+    auth?: string,
+    query?: IListRewardsApiRequest,
+  ): Promise<IListRewardsApiResponse> => {
+    const request = api.get(rewardsEndpoint(projectId)).query(query ?? {})
+    if (auth) {
+      request.set('Authorization', auth)
+    }
+    const response = await request.expect(200)
+    return response.body
+  }
+
+  test('returns rewards of published project without authorization', async () => {
+    const body = await listRewards()
+
+    expect(body.results).toHaveLength(2)
+    expect(body.results.map((r) => r.id)).toContain(hiddenRewardId)
+  })
+
+  test('hides hidden rewards from other users', async () => {
+    await hideReward()
+
+    const body = await listRewards(userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+
+    expect(body.results).toHaveLength(1)
+    expect(body.results.map((r) => r.id)).not.toContain(hiddenRewardId)
+  })
+
+  test('owner sees and filters hidden rewards', async () => {
+    await hideReward()
+
+    expect((await listRewards(ownerAuth)).results).toHaveLength(2)
+    const body = await listRewards(ownerAuth, { visible: false })
+    expect(body.results).toHaveLength(1)
+    expect(body.results[0].id).toEqual(hiddenRewardId)
+    expect(body.results[0].visible).toEqual(false)
+  })
+
+  test('returns 400 when backing hidden reward', async () => {
+    await hideReward()
+
+    await api
+      .post(`/api/projects/${projectId}/actions/back`)
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .send({ rewards: [{ reward_id: hiddenRewardId, quantity: 1 }] })
+      .expect(400, {
+        code: 'UnknownReward',
+        message: 'Reward is not available',
+        status: 400,
+      })
+  })
+
+  test('owner lists rewards of unpublished project', async () => {
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    const body = await listRewards(userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+
+    expect(body.results).toHaveLength(2)
+  })
+
+  test('returns 403 when listing rewards of unpublished project', async () => {
+    projectId = '14bfe82a-1003-446b-b6bb-20a176e848e0'
+    await api.get(rewardsEndpoint(projectId)).set('Authorization', ownerAuth).expect(403)
+  })
+
+  test('returns 404 when project does not exist', async () => {
+    projectId = 'cbd7a9ff-18f5-489e-b61e-cdd4a1394968'
+    await api.get(rewardsEndpoint(projectId)).expect(404)
+  })
+})
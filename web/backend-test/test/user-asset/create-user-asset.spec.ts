@@ -0,0 +1,115 @@
+import {
+  AssetContentType,
+  AssetState,
+  ICreateUserAssetRequest,
+  ICreateUserAssetResponse,
+  UserAssetKind,
+} from '@app/types'
+import { AppDbResetService, TestAgent, testagent, userAuthHeader } from '../helpers'
+import { testConfig } from '../test.config'
+import { commonRegex } from '@app/util'
+import { beforeAll, beforeEach, describe, expect, test } from 'vitest'
+
+describe('Create User Asset', () => {
+  const testEndpoint = '/api/user-assets'
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: ICreateUserAssetRequest
+  let userAuth: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+    payload = {
+      kind: UserAssetKind.Avatar,
+      content_size: 100000,
+      content_type: AssetContentType.Png,
+    }
+  })
+
+  test('returns 201 status code and signed url', async () => {
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+
+    const body: ICreateUserAssetResponse = response.body
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.user_id).toEqual(userId)
+    expect(body.kind).toEqual(UserAssetKind.Avatar)
+    expect(body.state).toEqual(AssetState.Created)
+    const uploadUrl = new URL(body.signed_url)
+    expect(`${uploadUrl.protocol}//${uploadUrl.host}`).toMatch(commonRegex.assetUrl)
+    expect(uploadUrl.pathname).toContain(`/users/${userId}/${body.id}.png`)
+    expect(uploadUrl.searchParams.get('X-Amz-Expires')).toEqual('600')
+  })
+
+  test('allows larger banners', async () => {
+    payload.kind = UserAssetKind.Banner
+    payload.content_size = 4000000
+
+    await api.post(testEndpoint).set('Authorization', userAuth).send(payload).expect(201)
+  })
+
+  describe('when request is invalid', () => {
+    test('when content_type is not a raster image', () => {
+      payload.content_type = AssetContentType.Svg
+
+      return api
+        .post(testEndpoint)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Unsupported content type: image/svg+xml',
+          code: 'AssetContentType',
+        })
+    })
+
+    test('when avatar is larger than 2MB', () => {
+      payload.content_size = 3000000
+
+      return api
+        .post(testEndpoint)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Avatar must be at most 2000000 bytes',
+          code: 'AssetSize',
+        })
+    })
+
+    test('when content_size is not valid', () => {
+      payload.content_size = -1
+
+      return api
+        .post(testEndpoint)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('when user is not authorized', () => {
+      return api.post(testEndpoint).send(payload).expect(401, {
+        code: 'Unauthorized',
+        message: 'Unauthorized',
+        status: 401,
+      })
+    })
+  })
+})
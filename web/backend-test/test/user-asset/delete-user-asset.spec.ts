@@ -0,0 +1,88 @@
+import { AssetContentType, ICreateUserAssetResponse, UserAssetKind } from '@app/types'
+import { beforeAll, beforeEach, describe, expect, test } from 'vitest'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import fs from 'fs'
+import { testConfig } from '../test.config'
+
+describe('Delete User Asset', () => {
+  const testEndpoint = '/api/user-assets'
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let asset: ICreateUserAssetResponse
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+
+    const fileBuffer = fs.readFileSync('./web/backend-test/test/assets/test-asset.jpg')
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', userAuth)
+      .send({
+        kind: UserAssetKind.Banner,
+        content_size: fileBuffer.length,
+        content_type: AssetContentType.Jpeg,
+      })
+      .expect(201)
+    asset = response.body
+
+    await testagent(asset.signed_url).put('').send(fileBuffer).expect(200)
+    await api
+      .post(`${testEndpoint}/${asset.id}/actions/verify`)
+      .set('Authorization', userAuth)
+      .expect(200)
+  })
+
+  test('deletes the banner and removes it from the user', async () => {
+    await api
+      .delete(`${testEndpoint}/${asset.id}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+
+    const response = await api
+      .get(`/api/users/${userId}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    expect(response.body.banner_url).toBeNull()
+  })
+
+  test('Admin deletes a user asset', async () => {
+    await api
+      .delete(`${testEndpoint}/${asset.id}`)
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+  })
+
+  test('returns 403 when asset belongs to another user', async () => {
+    await api
+      .delete(`${testEndpoint}/${asset.id}`)
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 404 when asset does not exist', async () => {
+    await api
+      .delete(`${testEndpoint}/cbd7a9ff-18f5-489e-b61e-cdd4a1394968`)
+      .set('Authorization', userAuth)
+      .expect(404)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.delete(`${testEndpoint}/${asset.id}`).expect(401)
+  })
+})
@@ -0,0 +1,123 @@
+import {
+  AssetContentType,
+  ICreateUserAssetResponse,
+  IGetUserApiResponse,
+  IGetUserProfileApiResponse,
+  IVerifyUserAssetResponse,
+  UserAssetKind,
+} from '@app/types'
+import { beforeAll, beforeEach, describe, expect, test } from 'vitest'
+import { AppDbResetService, testagent, TestAgent, userAuthHeader } from '../helpers'
+import fs from 'fs'
+import { testConfig } from '../test.config'
+
+describe('Verify User Asset', () => {
+  const testEndpoint = '/api/user-assets'
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let fileBuffer: Buffer
+
+  const verifyEndpoint = (assetId: string) => `${testEndpoint}/${assetId}/actions/verify`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+    fileBuffer = fs.readFileSync('./web/backend-test/test/assets/test-asset.jpg')
+  })
+
+  const createAvatar = async (): Promise<ICreateUserAssetResponse> => {
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', userAuth)
+      .send({
+        kind: UserAssetKind.Avatar,
+        content_size: fileBuffer.length,
+        content_type: AssetContentType.Jpeg,
+      })
+      .expect(201)
+    return response.body
+  }
+
+  const uploadAndVerify = async (asset: ICreateUserAssetResponse) => {
+    await testagent(asset.signed_url).put('').send(fileBuffer).expect(200)
+    const response = await api
+      .post(verifyEndpoint(asset.id))
+      .set('Authorization', userAuth)
+      .expect(200)
+    const body: IVerifyUserAssetResponse = response.body
+    return body
+  }
+
+  test('sets the avatar of the user', async () => {
+    const asset = await createAvatar()
+
+    const body = await uploadAndVerify(asset)
+    expect(body).toEqual({ verified: true, url: `users/${userId}/${asset.id}.jpg` })
+
+    const userResponse = await api
+      .get(`/api/users/${userId}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    const user: IGetUserApiResponse = userResponse.body
+    expect(user.avatar_url).toEqual(body.url)
+    expect(user.banner_url).toBeNull()
+
+    const profileResponse = await api.get(`/api/users/${userId}/profile`).expect(200)
+    const profile: IGetUserProfileApiResponse = profileResponse.body
+    expect(profile.avatar_url).toEqual(body.url)
+  })
+
+  test('a new avatar replaces the previous avatar', async () => {
+    const previous = await createAvatar()
+    await uploadAndVerify(previous)
+
+    const asset = await createAvatar()
+    const body = await uploadAndVerify(asset)
+
+    const userResponse = await api
+      .get(`/api/users/${userId}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    expect(userResponse.body.avatar_url).toEqual(body.url)
+    await api.post(verifyEndpoint(previous.id)).set('Authorization', userAuth).expect(404)
+  })
+
+  test('returns verified false when asset was not uploaded', async () => {
+    const asset = await createAvatar()
+
+    const response = await api
+      .post(verifyEndpoint(asset.id))
+      .set('Authorization', userAuth)
+      .expect(200)
+    expect(response.body).toEqual({ verified: false, url: null })
+  })
+
+  test('returns 403 when asset belongs to another user', async () => {
+    const asset = await createAvatar()
+
+    await api
+      .post(verifyEndpoint(asset.id))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 404 when asset does not exist', async () => {
+    await api
+      .post(verifyEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+      .set('Authorization', userAuth)
+      .expect(404, {
+        status: 404,
+        message: 'User asset not found',
+        code: 'None',
+      })
+  })
+})
@@ -0,0 +1,123 @@
+import {
+  EmailChangeState,
+  IConfirmUserEmailChangeApiRequest,
+  IGetUserApiResponse,
+  IUserEmailChangeViewModel,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+  generateEmailChangeToken,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Confirm User Email Change', () => {
+  const testEndpoint = '/api/auth/email-changes/confirm'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: IConfirmUserEmailChangeApiRequest
+
+  let adminAuth: string
+  let change: IUserEmailChangeViewModel
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    adminAuth = adminAuthHeader()
+    await dbResetService.resetDb()
+
+    const response = await api
+      .post(`/api/users/${user3Id}/email-changes`)
+      .set('Authorization', userAuthHeader(user3Id))
+      .send({ email: 'new@crowdtrust.app', password: 'password3' })
+      .expect(202)
+    change = response.body
+    payload = { code: generateEmailChangeToken(change.id, 'confirm') }
+  })
+
+  test('confirms the email change', async () => {
+    const response = await api.post(testEndpoint).send(payload).expect(200)
+    const body: IUserEmailChangeViewModel = response.body
+
+    expect(body.id).toEqual(change.id)
+    expect(body.state).toEqual(EmailChangeState.Confirmed)
+    expect(body.confirmed_at).toBeDefined()
+
+    const userResponse = await api
+      .get(`/api/users/${user3Id}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const user: IGetUserApiResponse = userResponse.body
+
+    expect(user.email).toEqual('new@crowdtrust.app')
+    expect(user.email_confirmed).toBe(true)
+  })
+
+  test('returns 400 when email change is already confirmed', async () => {
+    await api.post(testEndpoint).send(payload).expect(200)
+
+    return api.post(testEndpoint).send(payload).expect(400, {
+      status: 400,
+      message: 'Email change is no longer pending',
+      code: 'EmailChangeClosed',
+    })
+  })
+
+  test('returns 400 when email is taken before confirming', async () => {
+    const otherResponse = await api
+      .post('/api/users/45013993-2a1a-4ee5-8dbd-b4b63d9af34f/email-changes')
+      .set('Authorization', adminAuth)
+      .send({ email: 'new@crowdtrust.app' })
+      .expect(202)
+    const other: IUserEmailChangeViewModel = otherResponse.body
+    await api
+      .post(testEndpoint)
+      .send({ code: generateEmailChangeToken(other.id, 'confirm') })
+      .expect(200)
+
+    return api.post(testEndpoint).send(payload).expect(400, {
+      status: 400,
+      message: 'Email new@crowdtrust.app already exists',
+      code: 'UserExists',
+    })
+  })
+
+  test('returns 400 when code is expired', async () => {
+    payload.code = generateEmailChangeToken(change.id, 'confirm', '-90s')
+
+    const response = await api.post(testEndpoint).send(payload).expect(400)
+
+    expect(response.body.code).toEqual('ConfirmExpired')
+  })
+
+  test('returns 400 when code is for reverting', () => {
+    payload.code = generateEmailChangeToken(change.id, 'revert')
+
+    return api.post(testEndpoint).send(payload).expect(400, {
+      status: 400,
+      message: 'Invalid email change token',
+      code: 'None',
+    })
+  })
+
+  test('returns 404 when email change does not exist', () => {
+    const id = '3f1e7a0c-5d2b-4c8e-9a61-0b7d2e4f8c13'
+    payload.code = generateEmailChangeToken(id, 'confirm')
+
+    return api.post(testEndpoint).send(payload).expect(404, {
+      status: 404,
+      message: 'Email change not found',
+      code: 'None',
+    })
+  })
+})
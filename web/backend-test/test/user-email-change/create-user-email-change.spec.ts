@@ -0,0 +1,254 @@
+import {
+  EmailChangeState,
+  ICreateUserEmailChangeApiRequest,
+  IGetUserApiResponse,
+  IUserEmailChangeViewModel,
+} from '@app/types'
+import { commonRegex } from '@app/util'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+  signMessage,
+  now,
+  USER3_PRIVATE_KEY,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create User Email Change', () => {
+  const user1Id = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: ICreateUserEmailChangeApiRequest
+
+  let adminAuth: string
+  let userAuth: string
+
+  const testEndpoint = (userId: string) => `/api/users/${userId}/email-changes`
+  const changeMessage = (userId: string, email: string, expiresAt: number) =>
+    `Change CrowdTrust account ${userId} email to ${email}, valid until ${expiresAt}`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    adminAuth = adminAuthHeader()
+    userAuth = userAuthHeader(user3Id)
+    await dbResetService.resetDb()
+    payload = { email: 'new@crowdtrust.app', password: 'password3' }
+  })
+
+  test('creates a pending email change with password', async () => {
+    const response = await api
+      .post(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(202)
+    const body: IUserEmailChangeViewModel = response.body
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.user_id).toEqual(user3Id)
+    expect(body.new_email).toEqual(payload.email)
+    expect(body.state).toEqual(EmailChangeState.Pending)
+
+    // The email is applied once confirmed
+    const userResponse = await api
+      .get(`/api/users/${user3Id}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const user: IGetUserApiResponse = userResponse.body
+
+    expect(user.email).toEqual('user3@crowdtrust.app')
+  })
+
+  test('creates a pending email change with signature', async () => {
+    const expiresAt = now() + 300
+    payload = {
+      email: 'new@crowdtrust.app',
+      eth_address_signature: await signMessage(
+        USER3_PRIVATE_KEY,
+        changeMessage(user3Id, 'new@crowdtrust.app', expiresAt),
+      ),
+      signature_expires_at: expiresAt,
+    }
+
+    const response = await api
+      .post(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(202)
+    const body: IUserEmailChangeViewModel = response.body
+
+    expect(body.state).toEqual(EmailChangeState.Pending)
+  })
+
+  test('creates an email change without password when requestor is Admin', () => {
+    return api
+      .post(testEndpoint(user3Id))
+      .set('Authorization', adminAuth)
+      .send({ email: 'new@crowdtrust.app' })
+      .expect(202)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when password and signature are missing', () => {
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send({ email: 'new@crowdtrust.app' })
+        .expect(400, {
+          status: 400,
+          message: 'Password or signature required when changing email',
+          code: 'SignatureRequired',
+        })
+    })
+
+    test('returns 400 when password is wrong', () => {
+      payload.password = 'wrong.password'
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Invalid password',
+          code: 'InvalidOldPassword',
+        })
+    })
+
+    test('returns 400 when signature is for another account', async () => {
+      const expiresAt = now() + 300
+      payload = {
+        email: 'new@crowdtrust.app',
+        eth_address_signature: await signMessage(
+          USER3_PRIVATE_KEY,
+          changeMessage(user1Id, 'new@crowdtrust.app', expiresAt),
+        ),
+        signature_expires_at: expiresAt,
+      }
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to verify signature',
+          code: 'InvalidSignature',
+        })
+    })
+
+    test('returns 400 when signature is expired', async () => {
+      const expiresAt = now() - 60
+      payload = {
+        email: 'new@crowdtrust.app',
+        eth_address_signature: await signMessage(
+          USER3_PRIVATE_KEY,
+          changeMessage(user3Id, 'new@crowdtrust.app', expiresAt),
+        ),
+        signature_expires_at: expiresAt,
+      }
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Signature expired',
+          code: 'ConfirmExpired',
+        })
+    })
+
+    test('returns 400 when signature expiry is too far in the future', async () => {
+      const expiresAt = now() + 3600
+      payload = {
+        email: 'new@crowdtrust.app',
+        eth_address_signature: await signMessage(
+          USER3_PRIVATE_KEY,
+          changeMessage(user3Id, 'new@crowdtrust.app', expiresAt),
+        ),
+        signature_expires_at: expiresAt,
+      }
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Signature expired',
+          code: 'ConfirmExpired',
+        })
+    })
+
+    test('returns 400 when signature expiry is missing', async () => {
+      payload = {
+        email: 'new@crowdtrust.app',
+        eth_address_signature: await signMessage(
+          USER3_PRIVATE_KEY,
+          changeMessage(user3Id, 'new@crowdtrust.app', now() + 300),
+        ),
+      }
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Signature expiry required',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 400 when email already exists', () => {
+      payload.email = 'admin1@crowdtrust.app'
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: `Email ${payload.email} already exists`,
+          code: 'UserExists',
+        })
+    })
+
+    test('returns 400 when email is not an email', () => {
+      payload.email = 'not-an-email'
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+  })
+
+  test('returns 403 when changing email of another user', () => {
+    return api
+      .post(testEndpoint(user1Id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(403)
+  })
+
+  test('returns 401 when unauthorized', () => {
+    return api.post(testEndpoint(user3Id)).send(payload).expect(401)
+  })
+})
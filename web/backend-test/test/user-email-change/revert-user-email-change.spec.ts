@@ -0,0 +1,111 @@
+import {
+  EmailChangeState,
+  IConfirmUserEmailChangeApiRequest,
+  IGetUserApiResponse,
+  IUserEmailChangeViewModel,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+  generateEmailChangeToken,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Revert User Email Change', () => {
+  const testEndpoint = '/api/auth/email-changes/revert'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: IConfirmUserEmailChangeApiRequest
+
+  let adminAuth: string
+  let change: IUserEmailChangeViewModel
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    adminAuth = adminAuthHeader()
+    await dbResetService.resetDb()
+
+    const response = await api
+      .post(`/api/users/${user3Id}/email-changes`)
+      .set('Authorization', userAuthHeader(user3Id))
+      .send({ email: 'new@crowdtrust.app', password: 'password3' })
+      .expect(202)
+    change = response.body
+    payload = { code: generateEmailChangeToken(change.id, 'revert') }
+  })
+
+  const confirmChange = () =>
+    api
+      .post('/api/auth/email-changes/confirm')
+      .send({ code: generateEmailChangeToken(change.id, 'confirm') })
+      .expect(200)
+
+  test('restores the old email', async () => {
+    await confirmChange()
+
+    const response = await api.post(testEndpoint).send(payload).expect(200)
+    const body: IUserEmailChangeViewModel = response.body
+
+    expect(body.id).toEqual(change.id)
+    expect(body.state).toEqual(EmailChangeState.Reverted)
+    expect(body.reverted_at).toBeDefined()
+
+    const userResponse = await api
+      .get(`/api/users/${user3Id}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const user: IGetUserApiResponse = userResponse.body
+
+    expect(user.email).toEqual('user3@crowdtrust.app')
+  })
+
+  test('returns 400 when email change is not confirmed', () => {
+    return api.post(testEndpoint).send(payload).expect(400, {
+      status: 400,
+      message: 'Email change can no longer be reverted',
+      code: 'EmailChangeClosed',
+    })
+  })
+
+  test('returns 400 when email change is already reverted', async () => {
+    await confirmChange()
+    await api.post(testEndpoint).send(payload).expect(200)
+
+    return api.post(testEndpoint).send(payload).expect(400, {
+      status: 400,
+      message: 'Email change can no longer be reverted',
+      code: 'EmailChangeClosed',
+    })
+  })
+
+  test('returns 400 when code is for confirming', async () => {
+    await confirmChange()
+    payload.code = generateEmailChangeToken(change.id, 'confirm')
+
+    return api.post(testEndpoint).send(payload).expect(400, {
+      status: 400,
+      message: 'Invalid email change token',
+      code: 'None',
+    })
+  })
+
+  test('returns 400 when code is expired', async () => {
+    await confirmChange()
+    payload.code = generateEmailChangeToken(change.id, 'revert', '-90s')
+
+    const response = await api.post(testEndpoint).send(payload).expect(400)
+
+    expect(response.body.code).toEqual('ConfirmExpired')
+  })
+})
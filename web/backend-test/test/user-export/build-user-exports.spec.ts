@@ -0,0 +1,74 @@
+import {
+  IBuildUserExportsApiResponse,
+  IUserExportViewModel,
+  UserExportState,
+} from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Build User Exports', () => {
+  const testEndpoint = '/api/user-exports/actions/build'
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  let userAuth: string
+  let exportId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+
+    const response = await api
+      .post(`/api/users/${userId}/exports`)
+      .set('Authorization', userAuth)
+      .expect(202)
+    exportId = response.body.id
+  })
+
+  test('builds pending exports once', async () => {
+    const response = await api
+      .post(testEndpoint)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    const body: IBuildUserExportsApiResponse = response.body
+    expect(body.built).toEqual([exportId])
+
+    const exportResponse = await api
+      .get(`/api/user-exports/${exportId}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    const userExport: IUserExportViewModel = exportResponse.body
+    expect(userExport.state).toEqual(UserExportState.Ready)
+    expect(userExport.completed_at).not.toBeNull()
+
+    const rebuild = await api
+      .post(testEndpoint)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    expect(rebuild.body.built).toEqual([])
+  })
+
+  test('returns 403 when requester is User', async () => {
+    await api.post(testEndpoint).set('Authorization', userAuth).expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.post(testEndpoint).expect(401)
+  })
+})
@@ -0,0 +1,67 @@
+import { IUserExportViewModel, UserExportState } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Create User Export', () => {
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const testEndpoint = (id: string) => `/api/users/${id}/exports`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+  })
+
+  test('requests a pending export', async () => {
+    const response = await api
+      .post(testEndpoint(userId))
+      .set('Authorization', userAuth)
+      .expect(202)
+    const body: IUserExportViewModel = response.body
+
+    expect(body.user_id).toEqual(userId)
+    expect(body.state).toEqual(UserExportState.Pending)
+    expect(body.completed_at).toBeNull()
+  })
+
+  test('Admin requests an export for a user', async () => {
+    await api
+      .post(testEndpoint(userId))
+      .set('Authorization', adminAuthHeader())
+      .expect(202)
+  })
+
+  test('returns 403 when requesting an export for another user', async () => {
+    await api
+      .post(testEndpoint('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .set('Authorization', userAuth)
+      .expect(403)
+  })
+
+  test('returns 404 when user does not exist', async () => {
+    await api
+      .post(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+      .set('Authorization', adminAuthHeader())
+      .expect(404)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.post(testEndpoint(userId)).expect(401)
+  })
+})
@@ -0,0 +1,94 @@
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Download User Export', () => {
+  const testEndpoint = (id: string) => `/api/user-exports/${id}/download`
+  const userId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let exportId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+
+    const response = await api
+      .post(`/api/users/${userId}/exports`)
+      .set('Authorization', userAuth)
+      .expect(202)
+    exportId = response.body.id
+  })
+
+  const buildExports = async () => {
+    await api
+      .post('/api/user-exports/actions/build')
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+  }
+
+  test('downloads the archive of a Ready export', async () => {
+    await buildExports()
+
+    const response = await api
+      .get(testEndpoint(exportId))
+      .set('Authorization', userAuth)
+      .expect(200)
+
+    expect(response.headers['content-type']).toEqual('application/json; charset=utf-8')
+    expect(response.headers['content-disposition']).toEqual(
+      `attachment; filename="crowdtrust-export-${exportId}.json"`,
+    )
+    const archive = JSON.parse(response.text)
+    expect(archive.profile.id).toEqual(userId)
+    expect(archive.profile).not.toHaveProperty('password_hash')
+    expect(archive.projects.map((p: { user_id: string }) => p.user_id)).toContain(userId)
+    expect(archive.pledges.map((p: { id: string }) => p.id)).toContain(
+      '23c0599a-7990-4949-820c-3254079955f2',
+    )
+    expect(Object.keys(archive.assets).sort()).toEqual([
+      'project',
+      'project_update',
+      'reward',
+      'user',
+    ])
+  })
+
+  test('returns 400 when export is not Ready', async () => {
+    await api
+      .get(testEndpoint(exportId))
+      .set('Authorization', userAuth)
+      .expect(400, {
+        status: 400,
+        message: 'Export is not ready',
+        code: 'ExportPending',
+      })
+  })
+
+  test('returns 403 when export belongs to another user', async () => {
+    await buildExports()
+
+    await api
+      .get(testEndpoint(exportId))
+      .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+      .expect(403)
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.get(testEndpoint(exportId)).expect(401)
+  })
+})
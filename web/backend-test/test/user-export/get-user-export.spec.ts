@@ -0,0 +1,77 @@
+import { IUserExportViewModel, UserExportState } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Get User Export', () => {
+  const testEndpoint = (id: string) => `/api/user-exports/${id}`
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let userAuth: string
+  let exportId: string
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+    userAuth = userAuthHeader(userId)
+
+    const response = await api
+      .post(`/api/users/${userId}/exports`)
+      .set('Authorization', userAuth)
+      .expect(202)
+    exportId = response.body.id
+  })
+
+  test('returns the export to its user', async () => {
+    const response = await api
+      .get(testEndpoint(exportId))
+      .set('Authorization', userAuth)
+      .expect(200)
+    const body: IUserExportViewModel = response.body
+
+    expect(body.id).toEqual(exportId)
+    expect(body.state).toEqual(UserExportState.Pending)
+  })
+
+  test('returns the export to Admin', async () => {
+    await api
+      .get(testEndpoint(exportId))
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+  })
+
+  test('returns 403 when export belongs to another user', async () => {
+    await api
+      .get(testEndpoint(exportId))
+      .set('Authorization', userAuthHeader('00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'))
+      .expect(403)
+  })
+
+  test('returns 404 when export does not exist', async () => {
+    await api
+      .get(testEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968'))
+      .set('Authorization', userAuth)
+      .expect(404, {
+        status: 404,
+        message: 'Export not found',
+        code: 'None',
+      })
+  })
+
+  test('returns 401 when user is not authorized', async () => {
+    await api.get(testEndpoint(exportId)).expect(401)
+  })
+})
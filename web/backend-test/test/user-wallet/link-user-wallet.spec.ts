@@ -0,0 +1,219 @@
+import {
+  IGetUserApiResponse,
+  ILinkUserWalletApiRequest,
+  IListUserWalletsApiResponse,
+  IUserWalletViewModel,
+} from '@app/types'
+import { commonRegex } from '@app/util'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+  signMessage,
+  TEST_ADDRESS1,
+  TEST_PRIVATE_KEY1,
+  TEST_PRIVATE_KEY2,
+  USER3_ADDRESS,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Link User Wallet', () => {
+  const user1Id = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: ILinkUserWalletApiRequest
+
+  let adminAuth: string
+  let userAuth: string
+
+  const testEndpoint = (userId: string) => `/api/users/${userId}/wallets`
+  const linkMessage = (address: string, userId: string) =>
+    `Link ${address} to CrowdTrust account ${userId}`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    adminAuth = adminAuthHeader()
+    userAuth = userAuthHeader(user3Id)
+    await dbResetService.resetDb()
+    payload = {
+      eth_address: TEST_ADDRESS1,
+      eth_address_signature: await signMessage(
+        TEST_PRIVATE_KEY1,
+        linkMessage(TEST_ADDRESS1, user3Id),
+      ),
+    }
+  })
+
+  test('lists the primary wallet of a user', async () => {
+    const response = await api
+      .get(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .expect(200)
+    const body: IListUserWalletsApiResponse = response.body
+
+    expect(body.length).toEqual(1)
+    expect(body[0].eth_address.toLowerCase()).toEqual(USER3_ADDRESS)
+    expect(body[0].is_primary).toBe(true)
+  })
+
+  test('links a wallet', async () => {
+    const response = await api
+      .post(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+    const body: IUserWalletViewModel = response.body
+
+    expect(body.id).toMatch(new RegExp(commonRegex.uuid))
+    expect(body.user_id).toEqual(user3Id)
+    expect(body.eth_address).toEqual(TEST_ADDRESS1)
+    expect(body.is_primary).toBe(false)
+
+    const listResponse = await api
+      .get(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .expect(200)
+    const wallets: IListUserWalletsApiResponse = listResponse.body
+
+    expect(wallets.map((wallet) => wallet.id)).toContain(body.id)
+  })
+
+  test('links a wallet as primary wallet', async () => {
+    payload.primary = true
+
+    const response = await api
+      .post(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(201)
+    const body: IUserWalletViewModel = response.body
+
+    expect(body.is_primary).toBe(true)
+
+    const listResponse = await api
+      .get(testEndpoint(user3Id))
+      .set('Authorization', userAuth)
+      .expect(200)
+    const wallets: IListUserWalletsApiResponse = listResponse.body
+    const primary = wallets.filter((wallet) => wallet.is_primary)
+
+    expect(primary.length).toEqual(1)
+    expect(primary[0].id).toEqual(body.id)
+
+    const userResponse = await api
+      .get(`/api/users/${user3Id}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    const user: IGetUserApiResponse = userResponse.body
+
+    expect(user.eth_address).toEqual(TEST_ADDRESS1)
+  })
+
+  test('links a wallet when requestor is Admin', async () => {
+    await api
+      .post(testEndpoint(user3Id))
+      .set('Authorization', adminAuth)
+      .send(payload)
+      .expect(201)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when signature is from another wallet', async () => {
+      payload.eth_address_signature = await signMessage(
+        TEST_PRIVATE_KEY2,
+        linkMessage(TEST_ADDRESS1, user3Id),
+      )
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to verify signature',
+          code: 'InvalidSignature',
+        })
+    })
+
+    test('returns 400 when signature is for another account', async () => {
+      payload.eth_address_signature = await signMessage(
+        TEST_PRIVATE_KEY1,
+        linkMessage(TEST_ADDRESS1, user1Id),
+      )
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to verify signature',
+          code: 'InvalidSignature',
+        })
+    })
+
+    test('returns 400 when wallet is linked to another user', async () => {
+      await api
+        .post(testEndpoint(user1Id))
+        .set('Authorization', userAuthHeader(user1Id))
+        .send({
+          eth_address: TEST_ADDRESS1,
+          eth_address_signature: await signMessage(
+            TEST_PRIVATE_KEY1,
+            linkMessage(TEST_ADDRESS1, user1Id),
+          ),
+        })
+        .expect(201)
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: `Eth address ${TEST_ADDRESS1} already exists`,
+          code: 'UserExists',
+        })
+    })
+
+    test('returns 400 when eth address is invalid', () => {
+      payload.eth_address = '0x1234'
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to validate request',
+          code: 'InvalidFormData',
+        })
+    })
+  })
+
+  test('returns 403 when linking a wallet to another user', () => {
+    return api
+      .post(testEndpoint(user1Id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(403)
+  })
+
+  test('returns 403 when listing wallets of another user', () => {
+    return api.get(testEndpoint(user1Id)).set('Authorization', userAuth).expect(403)
+  })
+
+  test('returns 401 when unauthorized', () => {
+    return api.post(testEndpoint(user3Id)).send(payload).expect(401)
+  })
+})
@@ -0,0 +1,152 @@
+import {
+  IGetUserApiResponse,
+  ILinkUserWalletApiRequest,
+  IListUserWalletsApiResponse,
+  IUserWalletSignatureApiRequest,
+  IUserWalletViewModel,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  userAuthHeader,
+  AppDbResetService,
+  signMessage,
+  TEST_ADDRESS1,
+  TEST_PRIVATE_KEY1,
+  TEST_PRIVATE_KEY2,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Make Primary User Wallet', () => {
+  const user1Id = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: IUserWalletSignatureApiRequest
+
+  let userAuth: string
+  let wallet: IUserWalletViewModel
+
+  const testEndpoint = (walletId: string) =>
+    `/api/user-wallets/${walletId}/actions/make-primary`
+  const primaryMessage = (address: string) =>
+    `Use ${address} as primary CrowdTrust wallet`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    userAuth = userAuthHeader(user3Id)
+    await dbResetService.resetDb()
+
+    const linkPayload: ILinkUserWalletApiRequest = {
+      eth_address: TEST_ADDRESS1,
+      eth_address_signature: await signMessage(
+        TEST_PRIVATE_KEY1,
+        `Link ${TEST_ADDRESS1} to CrowdTrust account ${user3Id}`,
+      ),
+    }
+    const response = await api
+      .post(`/api/users/${user3Id}/wallets`)
+      .set('Authorization', userAuth)
+      .send(linkPayload)
+      .expect(201)
+    wallet = response.body
+
+    payload = {
+      eth_address_signature: await signMessage(
+        TEST_PRIVATE_KEY1,
+        primaryMessage(TEST_ADDRESS1),
+      ),
+    }
+  })
+
+  test('makes a wallet primary', async () => {
+    const response = await api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(200)
+    const body: IUserWalletViewModel = response.body
+
+    expect(body.id).toEqual(wallet.id)
+    expect(body.is_primary).toBe(true)
+
+    const listResponse = await api
+      .get(`/api/users/${user3Id}/wallets`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    const wallets: IListUserWalletsApiResponse = listResponse.body
+    const primary = wallets.filter((w) => w.is_primary)
+
+    expect(primary.length).toEqual(1)
+    expect(primary[0].id).toEqual(wallet.id)
+
+    const userResponse = await api
+      .get(`/api/users/${user3Id}`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    const user: IGetUserApiResponse = userResponse.body
+
+    expect(user.eth_address).toEqual(TEST_ADDRESS1)
+  })
+
+  test('returns the wallet when it is already primary', async () => {
+    await api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(200)
+
+    const response = await api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(200)
+    const body: IUserWalletViewModel = response.body
+
+    expect(body.is_primary).toBe(true)
+  })
+
+  test('returns 400 when signature is from another wallet', async () => {
+    payload.eth_address_signature = await signMessage(
+      TEST_PRIVATE_KEY2,
+      primaryMessage(TEST_ADDRESS1),
+    )
+
+    return api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(400, {
+        status: 400,
+        message: 'Failed to verify signature',
+        code: 'InvalidSignature',
+      })
+  })
+
+  test('returns 403 when wallet belongs to another user', () => {
+    return api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuthHeader(user1Id))
+      .send(payload)
+      .expect(403)
+  })
+
+  test('returns 404 when wallet does not exist', () => {
+    return api
+      .post(testEndpoint('3f1e7a0c-5d2b-4c8e-9a61-0b7d2e4f8c13'))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(404)
+  })
+
+  test('returns 401 when unauthorized', () => {
+    return api.post(testEndpoint(wallet.id)).send(payload).expect(401)
+  })
+})
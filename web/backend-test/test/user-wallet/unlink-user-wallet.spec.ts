@@ -0,0 +1,168 @@
+import {
+  ILinkUserWalletApiRequest,
+  IListUserWalletsApiResponse,
+  IUserWalletSignatureApiRequest,
+  IUserWalletViewModel,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+  signMessage,
+  TEST_ADDRESS1,
+  TEST_PRIVATE_KEY1,
+  TEST_PRIVATE_KEY2,
+  USER3_PRIVATE_KEY,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Unlink User Wallet', () => {
+  const user1Id = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: IUserWalletSignatureApiRequest
+
+  let adminAuth: string
+  let userAuth: string
+  let wallet: IUserWalletViewModel
+
+  const testEndpoint = (walletId: string) =>
+    `/api/user-wallets/${walletId}/actions/unlink`
+  const unlinkMessage = (address: string, userId: string) =>
+    `Unlink ${address} from CrowdTrust account ${userId}`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    adminAuth = adminAuthHeader()
+    userAuth = userAuthHeader(user3Id)
+    await dbResetService.resetDb()
+
+    const linkPayload: ILinkUserWalletApiRequest = {
+      eth_address: TEST_ADDRESS1,
+      eth_address_signature: await signMessage(
+        TEST_PRIVATE_KEY1,
+        `Link ${TEST_ADDRESS1} to CrowdTrust account ${user3Id}`,
+      ),
+    }
+    const response = await api
+      .post(`/api/users/${user3Id}/wallets`)
+      .set('Authorization', userAuth)
+      .send(linkPayload)
+      .expect(201)
+    wallet = response.body
+
+    payload = {
+      eth_address_signature: await signMessage(
+        TEST_PRIVATE_KEY1,
+        unlinkMessage(TEST_ADDRESS1, user3Id),
+      ),
+    }
+  })
+
+  const listWallets = async (): Promise<IListUserWalletsApiResponse> => {
+    const response = await api
+      .get(`/api/users/${user3Id}/wallets`)
+      .set('Authorization', userAuth)
+      .expect(200)
+    return response.body
+  }
+
+  test('unlinks a wallet signed by the wallet', async () => {
+    await api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(204)
+
+    const wallets = await listWallets()
+    expect(wallets.map((w) => w.id)).not.toContain(wallet.id)
+  })
+
+  test('unlinks a wallet signed by the primary wallet', async () => {
+    payload.eth_address_signature = await signMessage(
+      USER3_PRIVATE_KEY,
+      unlinkMessage(TEST_ADDRESS1, user3Id),
+    )
+
+    await api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(204)
+
+    const wallets = await listWallets()
+    expect(wallets.map((w) => w.id)).not.toContain(wallet.id)
+  })
+
+  test('unlinks a wallet when requestor is Admin', () => {
+    return api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', adminAuth)
+      .send(payload)
+      .expect(204)
+  })
+
+  describe('when request is not valid', () => {
+    test('returns 400 when unlinking the primary wallet', async () => {
+      const wallets = await listWallets()
+      const primary = wallets.find((w) => w.is_primary)
+
+      return api
+        .post(testEndpoint(primary?.id ?? ''))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: "Primary wallet can't be unlinked",
+          code: 'WalletPrimary',
+        })
+    })
+
+    test('returns 400 when signature is from another wallet', async () => {
+      payload.eth_address_signature = await signMessage(
+        TEST_PRIVATE_KEY2,
+        unlinkMessage(TEST_ADDRESS1, user3Id),
+      )
+
+      return api
+        .post(testEndpoint(wallet.id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Failed to verify signature',
+          code: 'InvalidSignature',
+        })
+    })
+  })
+
+  test('returns 403 when wallet belongs to another user', () => {
+    return api
+      .post(testEndpoint(wallet.id))
+      .set('Authorization', userAuthHeader(user1Id))
+      .send(payload)
+      .expect(403)
+  })
+
+  test('returns 404 when wallet does not exist', () => {
+    return api
+      .post(testEndpoint('3f1e7a0c-5d2b-4c8e-9a61-0b7d2e4f8c13'))
+      .set('Authorization', userAuth)
+      .send(payload)
+      .expect(404)
+  })
+
+  test('returns 401 when unauthorized', () => {
+    return api.post(testEndpoint(wallet.id)).send(payload).expect(401)
+  })
+})
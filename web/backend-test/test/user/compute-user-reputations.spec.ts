@@ -0,0 +1,80 @@
+import { IComputeUserReputationsApiResponse, IGetUserApiResponse } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Compute User Reputations', () => {
+  const testEndpoint = '/api/users/actions/compute-reputation'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let adminAuth: string
+  const creatorId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+    adminAuth = adminAuthHeader()
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+  })
+
+  const getCreator = async (): Promise<IGetUserApiResponse> => {
+    const response = await api
+      .get(`/api/users/${creatorId}`)
+      .set('Authorization', adminAuth)
+      .expect(200)
+    return response.body
+  }
+
+  describe('when requester is Admin', () => {
+    test('stores a snapshot for creators with completed projects', async () => {
+      expect((await getCreator()).reputation).toBeNull()
+
+      const response = await api
+        .post(testEndpoint)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const body: IComputeUserReputationsApiResponse = response.body
+
+      expect(body.computed).toBeGreaterThan(0)
+      const creator = await getCreator()
+      expect(creator.reputation?.projects_completed).toBeGreaterThan(0)
+      expect(creator.reputation?.computed_at).toBeDefined()
+    })
+
+    test('returns null score without enough delivered items', async () => {
+      await api.post(testEndpoint).set('Authorization', adminAuth).expect(200)
+
+      const creator = await getCreator()
+      expect(creator.reputation?.items_delivered).toBeLessThan(5)
+      expect(creator.reputation?.score).toBeNull()
+    })
+  })
+
+  describe('when requester is not Admin', () => {
+    test('returns 403 when requester is User', async () => {
+      await api
+        .post(testEndpoint)
+        .set('Authorization', userAuthHeader('45013993-2a1a-4ee5-8dbd-b4b63d9af34f'))
+        .expect(403)
+    })
+
+    test('returns 401 when user is not authorized', async () => {
+      await api.post(testEndpoint).expect(401, {
+        code: 'Unauthorized',
+        message: 'Unauthorized',
+        status: 401,
+      })
+    })
+  })
+})
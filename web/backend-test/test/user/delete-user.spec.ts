@@ -0,0 +1,191 @@
+import {
+  IDeleteUserApiRequest,
+  IGetUserApiResponse,
+  IRegisterUserApiRequest,
+  IRegisterUserApiResponse,
+  UserStatus,
+} from '@app/types'
+import {
+  testagent,
+  TestAgent,
+  adminAuthHeader,
+  userAuthHeader,
+  AppDbResetService,
+  registerSignature,
+  TEST_ADDRESS1,
+  TEST_PRIVATE_KEY1,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Delete User', () => {
+  const user1Id = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const user3Id = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  let payload: IDeleteUserApiRequest
+
+  let adminAuth: string
+  let userId: string
+  let userAuth: string
+
+  const testEndpoint = (id: string) => `/api/users/${id}/actions/delete`
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    adminAuth = adminAuthHeader()
+    await dbResetService.resetDb()
+
+    const registration: IRegisterUserApiRequest = {
+      email: 'delete@crowdtrust.app',
+      password: '12345678',
+      eth_address: TEST_ADDRESS1,
+      eth_address_signature: await registerSignature(TEST_PRIVATE_KEY1, TEST_ADDRESS1),
+    }
+    const response = await api
+      .post('/api/users/registrations')
+      .send(registration)
+      .expect(201)
+    const body: IRegisterUserApiResponse = response.body
+    userId = body.id
+    userAuth = userAuthHeader(userId)
+    payload = { password: '12345678' }
+  })
+
+  describe('when requestor is User', () => {
+    test('anonymizes the account', async () => {
+      await api
+        .post(testEndpoint(userId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(204)
+
+      const response = await api
+        .get(`/api/users/${userId}`)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const body: IGetUserApiResponse = response.body
+
+      expect(body.user_status).toEqual(UserStatus.Removed)
+      expect(body.name).toEqual('Deleted user')
+      expect(body.email).toEqual(`${userId}@deleted.invalid`)
+      expect(body.eth_address).toEqual(`deleted:${userId}`)
+    })
+
+    test('frees the email and eth address for a new account', async () => {
+      await api
+        .post(testEndpoint(userId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(204)
+
+      const registration: IRegisterUserApiRequest = {
+        email: 'delete@crowdtrust.app',
+        password: '12345678',
+        eth_address: TEST_ADDRESS1,
+        eth_address_signature: await registerSignature(TEST_PRIVATE_KEY1, TEST_ADDRESS1),
+      }
+      await api.post('/api/users/registrations').send(registration).expect(201)
+    })
+
+    test('returns 401 when using a token issued before deletion', async () => {
+      await api
+        .post(testEndpoint(userId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(204)
+
+      return api.get(`/api/users/${userId}`).set('Authorization', userAuth).expect(401)
+    })
+
+    test('returns 400 when password is missing', () => {
+      return api
+        .post(testEndpoint(userId))
+        .set('Authorization', userAuth)
+        .send({})
+        .expect(400, {
+          status: 400,
+          message: 'Password required',
+          code: 'InvalidFormData',
+        })
+    })
+
+    test('returns 400 when password is wrong', () => {
+      payload.password = 'wrong.password'
+
+      return api
+        .post(testEndpoint(userId))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Invalid password',
+          code: 'InvalidOldPassword',
+        })
+    })
+
+    test('returns 400 when user has an open project', () => {
+      payload.password = 'password3'
+
+      return api
+        .post(testEndpoint(user3Id))
+        .set('Authorization', userAuthHeader(user3Id))
+        .send(payload)
+        .expect(400, {
+          status: 400,
+          message: 'Complete or transfer open projects before deleting the account',
+          code: 'ProjectActive',
+        })
+    })
+
+    test('returns 403 when deleting another user', () => {
+      return api
+        .post(testEndpoint(user1Id))
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect(403)
+    })
+  })
+
+  describe('when requestor is Admin', () => {
+    test('deletes the account without a password', async () => {
+      await api
+        .post(testEndpoint(user1Id))
+        .set('Authorization', adminAuth)
+        .send({})
+        .expect(204)
+
+      const response = await api
+        .get(`/api/users/${user1Id}`)
+        .set('Authorization', adminAuth)
+        .expect(200)
+      const body: IGetUserApiResponse = response.body
+
+      expect(body.user_status).toEqual(UserStatus.Removed)
+    })
+
+    test('returns 404 when user is already deleted', async () => {
+      await api
+        .post(testEndpoint(user1Id))
+        .set('Authorization', adminAuth)
+        .send({})
+        .expect(204)
+
+      return api
+        .post(testEndpoint(user1Id))
+        .set('Authorization', adminAuth)
+        .send({})
+        .expect(404)
+    })
+  })
+
+  test('returns 401 when unauthorized', () => {
+    return api.post(testEndpoint(userId)).send(payload).expect(401)
+  })
+})
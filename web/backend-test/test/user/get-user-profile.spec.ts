@@ -0,0 +1,87 @@
+import { IGetUserProfileApiResponse, ProjectStatus } from '@app/types'
+import {
+  adminAuthHeader,
+  AppDbResetService,
+  testagent,
+  TestAgent,
+  userAuthHeader,
+} from '../helpers'
+import { testConfig } from '../test.config'
+import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
+
+describe('Get User Profile', () => {
+  const profileEndpoint = (id: string) => `/api/users/${id}/profile`
+  let api: TestAgent
+  let testHelperApiUrl: string
+  let dbResetService: AppDbResetService
+  const userId = '45013993-2a1a-4ee5-8dbd-b4b63d9af34f'
+  const creatorId = '00e8ee0b-843b-43e7-84c1-6d7a64cd5cfd'
+
+  beforeAll(() => {
+    api = testagent(testConfig.get('apiUrl'))
+    testHelperApiUrl = testConfig.get('apiTestHelperUrl')
+    dbResetService = new AppDbResetService(testHelperApiUrl)
+  })
+
+  beforeEach(async () => {
+    await dbResetService.resetDb()
+  })
+
+  const getProfile = async (id: string): Promise<IGetUserProfileApiResponse> => {
+    const response = await api.get(profileEndpoint(id)).expect(200)
+    return response.body
+  }
+
+  test('returns public profile without authorization', async () => {
+    const body = await getProfile(creatorId)
+
+    expect(body.id).toEqual(creatorId)
+    expect(body.name).toBeDefined()
+    expect(body).not.toHaveProperty('email')
+    expect(body).not.toHaveProperty('user_status')
+    expect(body.project_count).toEqual(6)
+    expect(body.projects).toHaveLength(6)
+  })
+
+  test('lists only visible projects', async () => {
+    const body = await getProfile(userId)
+
+    expect(body.project_count).toEqual(1)
+    expect(body.projects[0].id).toEqual('d13b990d-172e-4a01-aeea-43f6ef505a7c')
+    expect(body.projects[0].status).toEqual(ProjectStatus.Prelaunch)
+  })
+
+  test('shows backed count when the user opts in', async () => {
+    expect((await getProfile(creatorId)).backed_count).toBeNull()
+
+    await api
+      .patch(`/api/users/${creatorId}`)
+      .set('Authorization', userAuthHeader(creatorId))
+      .send({ show_backed_count: true })
+      .expect(200)
+
+    const body = await getProfile(creatorId)
+    expect(body.backed_count).toEqual(expect.any(Number))
+  })
+
+  test('shows reputation without a score until enough items are delivered', async () => {
+    expect((await getProfile(creatorId)).reputation).toBeNull()
+
+    await api
+      .post('/api/users/actions/compute-reputation')
+      .set('Authorization', adminAuthHeader())
+      .expect(200)
+
+    const body = await getProfile(creatorId)
+    expect(body.reputation?.projects_completed).toBeGreaterThan(0)
+    expect(body.reputation?.score).toBeNull()
+  })
+
+  test('returns 404 when user is blocked', async () => {
+    await api.get(profileEndpoint('276168ed-9228-4d6b-aec2-ed53bb7c1901')).expect(404)
+  })
+
+  test('returns 404 when user does not exist', async () => {
+    await api.get(profileEndpoint('cbd7a9ff-18f5-489e-b61e-cdd4a1394968')).expect(404)
+  })
+})
@@ -16,6 +16,7 @@ import {
   TEST_ADDRESS1,
   TEST_PRIVATE_KEY1,
   registerSignature,
+  now,
 } from '../helpers'
 import { testConfig } from '../test.config'
 import { describe, expect, test, beforeAll, beforeEach } from 'vitest'
@@ -54,7 +55,7 @@ describe('Update User', () => {
 
   describe('when requestor is Admin', () => {
     test('return 200 when updating user with type Admin', async () => {
-      payload = { email: 'new@crowdtrust.app' }
+      payload = { name: 'NewAdmin' }
 
       const response = await api
         .patch(`/api/users/${adminId}`)
@@ -64,8 +65,7 @@ describe('Update User', () => {
 
       const body: IGetUserApiResponse = response.body
 
-      expect(body.email).toEqual(payload.email)
-      expect(body.email_confirmed).toBe(false)
+      expect(body.name).toEqual(payload.name)
     })
 
     test('return 200 when updating user with type User', async () => {
@@ -83,27 +83,28 @@ describe('Update User', () => {
   })
 
   describe('when requestor is User', () => {
-    test('return 200 when updating user with type User', async () => {
-      payload = { email: 'new@crowdtrust.app' }
-
+    test('return 400 when updating email', async () => {
+      // Email is changed with POST /users/:user_id/email-changes
       const response = await api
         .patch(`/api/users/${userId}`)
         .set('Authorization', userAuth)
-        .send(payload)
-        .expect(200)
-      const body: IGetUserApiResponse = response.body
+        .send({ email: 'new@crowdtrust.app' })
+        .expect(400)
 
-      expect(body.email).toEqual(payload.email)
-      expect(body.email_confirmed).toBe(false)
+      expect(response.body.code).toEqual('InvalidFormData')
     })
 
-    test('return 200 when updating eth_address', async () => {
-      const message = ethSwitchMessage(TEST_ADDRESS1)
-      const signature = await signMessage(TEST_PRIVATE_KEY1, message)
-      payload = {
+    const switchPayload = async (expiresAt: number): Promise<IUpdateUserApiRequest> => {
+      const message = ethSwitchMessage(userId, TEST_ADDRESS1, expiresAt)
+      return {
         eth_address: TEST_ADDRESS1,
-        eth_address_signature: signature,
+        eth_address_signature: await signMessage(TEST_PRIVATE_KEY1, message),
+        signature_expires_at: expiresAt,
       }
+    }
+
+    test('return 200 when updating eth_address', async () => {
+      payload = await switchPayload(now() + 300)
 
       const response = await api
         .patch(`/api/users/${userId}`)
@@ -199,11 +200,61 @@ describe('Update User', () => {
         })
     })
 
+    test('return 400 when signature expiry is missing', async () => {
+      payload = await switchPayload(now() + 300)
+      delete payload.signature_expires_at
+
+      await api
+        .patch(`/api/users/${userId}`)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect({
+          code: 'InvalidFormData',
+          message: 'Signature expiry required',
+          status: 400,
+        })
+    })
+
+    test('return 400 when signature has expired', async () => {
+      payload = await switchPayload(now() - 10)
+
+      await api
+        .patch(`/api/users/${userId}`)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect({
+          code: 'ConfirmExpired',
+          message: 'Signature expired',
+          status: 400,
+        })
+    })
+
+    test('return 400 when signature is for another user', async () => {
+      const expiresAt = now() + 300
+      const message = ethSwitchMessage(adminId, TEST_ADDRESS1, expiresAt)
+      payload = {
+        eth_address: TEST_ADDRESS1,
+        eth_address_signature: await signMessage(TEST_PRIVATE_KEY1, message),
+        signature_expires_at: expiresAt,
+      }
+
+      await api
+        .patch(`/api/users/${userId}`)
+        .set('Authorization', userAuth)
+        .send(payload)
+        .expect({
+          code: 'InvalidSignature',
+          message: 'Failed to verify signature',
+          status: 400,
+        })
+    })
+
     test('return 400 when eth_address_signature is invalid', async () => {
       payload = {
         eth_address: '0x8628be3e373c3597C9643Ab587c964A061e70123',
         eth_address_signature:
           '0x8628be3e373c3597C9643Ab587c964A061e701230x8628be3e373c3597C9643Ab587c964A061e701230x8628be3e373c3597C9643Ab587c964A061e701230x8628be3e373c3597C9643Ab587c964A061e70123',
+        signature_expires_at: now() + 300,
       }
 
       await api
@@ -231,7 +282,7 @@ describe('Update User', () => {
         .expect(201)
       const body: IRegisterUserApiResponse = response.body
 
-      payload = { email: 'test@crowdtrust.app' }
+      payload = { name: 'NewName' }
 
       return api
         .patch(`/api/users/${body.id}`)
@@ -246,7 +297,7 @@ describe('Update User', () => {
   })
 
   test('returns 404 code when user id does not exist', () => {
-    payload = { email: 'test@crowdtrust.app' }
+    payload = { name: 'NewName' }
     const id = 'cbd7a9ff-18f5-489e-b61e-cdd4a1394968'
 
     return api
@@ -260,20 +311,6 @@ describe('Update User', () => {
       })
   })
 
-  test('returns 400 code when email already exists', () => {
-    // Try to create a user with the same email as a user from fixtures
-    payload.email = 'admin1@crowdtrust.app'
-    return api
-      .patch(`/api/users/${adminId}`)
-      .set('Authorization', adminAuth)
-      .send(payload)
-      .expect({
-        code: 'UserExists',
-        message: `Email ${payload.email} already exists`,
-        status: 400,
-      })
-  })
-
   test('returns 400 code when old password does not matches current password', () => {
     payload = {
       old_password: '76543210',